@@ -0,0 +1,264 @@
+//! Parse buffers of delimited numbers, such as a row of CSV data.
+
+#![cfg(feature = "parse")]
+
+use crate::FromLexicalWithOptions;
+use core::fmt;
+use lexical_util::error::Error;
+use lexical_util::format::{NumberFormat, STANDARD};
+#[cfg(feature = "std")]
+use std::error;
+
+/// Error while parsing a field from a delimited buffer.
+///
+/// Contains the index of the field that failed to parse, the offset of
+/// the start of that field in the buffer, and the parse error. The index
+/// in the parse error is relative to the start of the field, so the index
+/// of the invalid byte in the buffer is `offset + error.index()`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldError {
+    /// The index of the field that failed to parse.
+    pub field: usize,
+    /// The offset of the start of the field in the buffer.
+    pub offset: usize,
+    /// The error that occurred while parsing the field.
+    pub error: Error,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} in field {} at offset {}", self.error, self.field, self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for FieldError {
+}
+
+/// Parse at most `limit` fields, passing each value to the callback.
+///
+/// Returns the number of parsed fields and the number of processed bytes.
+/// If the limit is reached first, the processed bytes include the delimiter
+/// after the last parsed field, so parsing can resume from that point.
+#[inline]
+fn parse_fields<N, Cb, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiter: u8,
+    options: &N::Options,
+    limit: usize,
+    mut cb: Cb,
+) -> Result<(usize, usize), FieldError>
+where
+    N: FromLexicalWithOptions,
+    Cb: FnMut(usize, N),
+{
+    // Validate the format once, rather than for every field.
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(FieldError {
+            field: 0,
+            offset: 0,
+            error: format.error(),
+        });
+    }
+
+    let mut field = 0;
+    let mut offset = 0;
+    if bytes.is_empty() {
+        return Ok((field, offset));
+    }
+    while field < limit {
+        let slc = &bytes[offset..];
+        let result = N::from_lexical_partial_with_valid_format::<FORMAT>(slc, options);
+        let count = match result {
+            Ok((value, count)) if count != 0 && (count == slc.len() || slc[count] == delimiter) => {
+                cb(field, value);
+                count
+            },
+            // The integer parsers consume no digits for an empty field.
+            Ok((_, 0)) if slc.is_empty() || slc[0] == delimiter => {
+                return Err(field_error(field, offset, Error::Empty(0)));
+            },
+            Ok((_, count)) => return Err(field_error(field, offset, Error::InvalidDigit(count))),
+            Err(error) => return Err(field_error(field, offset, error)),
+        };
+        field += 1;
+        offset += count;
+        if offset == bytes.len() {
+            return Ok((field, offset));
+        }
+        // Skip the delimiter.
+        offset += 1;
+    }
+    Ok((field, offset))
+}
+
+/// Create an error for a field.
+#[inline(always)]
+const fn field_error(field: usize, offset: usize, error: Error) -> FieldError {
+    FieldError {
+        field,
+        offset,
+        error,
+    }
+}
+
+/// Parse a buffer of delimited numbers in the standard format into a slice.
+///
+/// Parses fields until the buffer is consumed or the slice is full,
+/// returning the number of parsed fields and the number of processed
+/// bytes. If the slice is full before the buffer is consumed, the
+/// processed bytes include the trailing delimiter, so parsing can be
+/// resumed from that point. An empty buffer contains no fields.
+///
+/// * `bytes`       - Buffer containing the delimited numbers.
+/// * `delimiter`   - Byte separating each field.
+/// * `values`      - Slice to store the parsed values.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] containing the index and offset of the
+/// first field that could not be parsed, including empty fields.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// let mut values = [0.0f64; 4];
+/// let result = lexical_core::parse_delimited(b"1.5,-2,3e2", b',', &mut values);
+/// assert_eq!(result, Ok((3, 10)));
+/// assert_eq!(&values[..3], &[1.5, -2.0, 300.0]);
+///
+/// let error = lexical_core::parse_delimited(b"1.5,x,3", b',', &mut values).unwrap_err();
+/// assert_eq!((error.field, error.offset), (1, 4));
+/// # }
+/// ```
+#[inline]
+pub fn parse_delimited<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut [N],
+) -> Result<(usize, usize), FieldError> {
+    let options = N::Options::default();
+    parse_delimited_with_options::<N, STANDARD>(bytes, delimiter, values, &options)
+}
+
+/// Parse a buffer of delimited numbers with custom options into a slice.
+///
+/// Parses fields until the buffer is consumed or the slice is full,
+/// returning the number of parsed fields and the number of processed
+/// bytes. If the slice is full before the buffer is consumed, the
+/// processed bytes include the trailing delimiter, so parsing can be
+/// resumed from that point. An empty buffer contains no fields. The
+/// format is validated once for the entire buffer.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Buffer containing the delimited numbers.
+/// * `delimiter`   - Byte separating each field.
+/// * `values`      - Slice to store the parsed values.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] containing the index and offset of the
+/// first field that could not be parsed, including empty fields. If the
+/// format is invalid, the error is for the first field.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut values = [0.0f64; 2];
+/// let bytes = b"1,5;2,25;3";
+/// let result =
+///     lexical_core::parse_delimited_with_options::<_, FORMAT>(bytes, b';', &mut values, &options);
+/// assert_eq!(result, Ok((2, 9)));
+/// assert_eq!(values, [1.5, 2.25]);
+/// # }
+/// ```
+#[inline]
+pub fn parse_delimited_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut [N],
+    options: &N::Options,
+) -> Result<(usize, usize), FieldError> {
+    let limit = values.len();
+    parse_fields::<N, _, FORMAT>(bytes, delimiter, options, limit, |index, value| {
+        values[index] = value;
+    })
+}
+
+/// Parse a buffer of delimited numbers in the standard format into a vector.
+///
+/// All fields in the buffer are appended to the vector, and the number
+/// of parsed fields is returned. If any field fails to parse, the values
+/// from the previous fields are still appended. An empty buffer contains
+/// no fields.
+///
+/// * `bytes`       - Buffer containing the delimited numbers.
+/// * `delimiter`   - Byte separating each field.
+/// * `values`      - Vector to append the parsed values to.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] containing the index and offset of the
+/// first field that could not be parsed, including empty fields.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse-integers")] {
+/// let mut values: Vec<u32> = Vec::new();
+/// assert_eq!(lexical_core::parse_delimited_to_vec(b"1\t2\t3", b'\t', &mut values), Ok(3));
+/// assert_eq!(values, vec![1, 2, 3]);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_delimited_to_vec<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut Vec<N>,
+) -> Result<usize, FieldError> {
+    let options = N::Options::default();
+    parse_delimited_to_vec_with_options::<N, STANDARD>(bytes, delimiter, values, &options)
+}
+
+/// Parse a buffer of delimited numbers with custom options into a vector.
+///
+/// All fields in the buffer are appended to the vector, and the number
+/// of parsed fields is returned. If any field fails to parse, the values
+/// from the previous fields are still appended. An empty buffer contains
+/// no fields. The format is validated once for the entire buffer.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `bytes`       - Buffer containing the delimited numbers.
+/// * `delimiter`   - Byte separating each field.
+/// * `values`      - Vector to append the parsed values to.
+/// * `options`     - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns a [`FieldError`] containing the index and offset of the
+/// first field that could not be parsed, including empty fields. If the
+/// format is invalid, the error is for the first field.
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_delimited_to_vec_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    delimiter: u8,
+    values: &mut Vec<N>,
+    options: &N::Options,
+) -> Result<usize, FieldError> {
+    let (count, _) =
+        parse_fields::<N, _, FORMAT>(bytes, delimiter, options, usize::MAX, |_, value| {
+            values.push(value);
+        })?;
+    Ok(count)
+}
@@ -84,6 +84,12 @@
 #![cfg_attr(feature = "write", doc = " - [`write_unchecked`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options_unchecked`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_uninit`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_uninit`]")]
+#![cfg_attr(feature = "write", doc = " - [`try_write`]")]
+#![cfg_attr(feature = "write", doc = " - [`try_write_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_many`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_many_with_options`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -91,6 +97,10 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_delimited`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_delimited_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`scan`]")]
+#![cfg_attr(feature = "parse", doc = " - [`scan_with_options`]")]
 //!
 //! # Features
 //!
@@ -109,24 +119,33 @@
 //! ### std
 //!
 //! Enable use of the standard library. Currently, the standard library
-//! is not used for any functionality, and may be disabled without any
-//! change in functionality on stable.
+//! is only used for the arbitrary-precision `Bigint` and `Rational`,
+//! which require heap allocation, and may otherwise be disabled without
+//! any change in functionality on stable.
 //!
 //! ### write-integers
 //!
-//! Enable support for writing integers to string.
+//! Enable support for writing integers to string, including the wide,
+//! fixed-width integers such as `U256` and `I512`, and the arbitrary-precision
+//! `Bigint` with the `std` feature.
 //!
 //! ### write-floats
 //!
-//! Enable support for writing floating-point numbers to string.
+//! Enable support for writing floating-point numbers, and the exact
+//! `Decimal`, to string.
 //!
 //! ### parse-integers
 //!
-//! Enable support for parsing integers from string.
+//! Enable support for parsing integers from string, including the wide,
+//! fixed-width integers such as `U256` and `I512`, and the arbitrary-precision
+//! `Bigint` with the `std` feature.
 //!
 //! ### parsing-floats
 //!
-//! Enable support for parsing floating-point numbers from string.
+//! Enable support for parsing floating-point numbers, and the exact
+//! `Decimal`, from string. With the `std` feature, this also supports
+//! parsing the exact `Rational`, with a big-integer numerator and a
+//! power-of-radix denominator, for floats of any radix.
 //!
 //! ### format
 //!
@@ -185,6 +204,29 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! ### saturating
+//!
+//! Implement the conversion traits for `core::num::Saturating` integers,
+//! which clamp on overflow when parsed. This requires Rust 1.74 or later.
+//! The other integer wrappers, `core::num::Wrapping` and the `NonZero*`
+//! types, are always supported.
+//!
+//! ### ieee-decimal
+//!
+//! Enable support for the IEEE 754-2008 decimal floats, `Decimal64` and
+//! `Decimal128`, in the binary integer decimal (BID) encoding. These are
+//! parsed with ties-to-even rounding, preserving the quantum, and written
+//! in their canonical, shortest form. This requires the `parse-floats` or
+//! `write-floats` features.
+//!
+//! ### half
+//!
+//! Enable support for the 16-bit floats from the `half` crate, `f16` and
+//! `bf16`. These are parsed with correct rounding and written in their
+//! shortest, round-trip form. This requires Rust 1.70 or later, and the
+//! `parse-floats` or `write-floats` features.
+//! The previous `f16` feature is kept as an alias for `half`.
+//!
 //! ### safe
 //!
 //! All numeric parsers are memory-safe by default, since parsing complex
@@ -315,10 +357,20 @@
 //! [`write_unchecked`]: crate::write_unchecked
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
+//! [`write_uninit`]: crate::write_uninit
+//! [`write_with_options_uninit`]: crate::write_with_options_uninit
+//! [`try_write`]: crate::try_write
+//! [`try_write_with_options`]: crate::try_write_with_options
+//! [`write_many`]: crate::write_many
+//! [`write_many_with_options`]: crate::write_many_with_options
 //! [`parse`]: crate::parse
 //! [`parse_partial`]: crate::parse_partial
 //! [`parse_with_options`]: crate::parse_with_options
 //! [`parse_partial_with_options`]: crate::parse_partial_with_options
+//! [`parse_delimited`]: crate::parse_delimited
+//! [`parse_delimited_with_options`]: crate::parse_delimited_with_options
+//! [`scan`]: crate::scan
+//! [`scan_with_options`]: crate::scan_with_options
 //!
 //! [`NumberFormatBuilder`]: crate::NumberFormatBuilder
 //! [`ParseFloatOptions`]: crate::ParseFloatOptions
@@ -332,6 +384,17 @@
 #![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod delimited;
+mod scan;
+mod wrapper;
+mod write_many;
+
+#[cfg(feature = "write")]
+use core::fmt;
+#[cfg(feature = "write")]
+use core::mem::MaybeUninit;
+#[cfg(feature = "write")]
+use core::result;
 #[cfg(feature = "parse-floats")]
 use lexical_parse_float::{
     FromLexical as FromFloat,
@@ -352,35 +415,85 @@ use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatW
 use lexical_write_integer::{ToLexical as ToInteger, ToLexicalWithOptions as ToIntegerWithOptions};
 
 // Re-exports
+#[cfg(feature = "parse")]
+pub use self::delimited::{parse_delimited, parse_delimited_with_options, FieldError};
+#[cfg(all(feature = "parse", feature = "std"))]
+pub use self::delimited::{parse_delimited_to_vec, parse_delimited_to_vec_with_options};
+#[cfg(feature = "parse")]
+pub use self::scan::{scan, scan_with_options, Scanner};
+#[cfg(feature = "write")]
+pub use self::write_many::{
+    write_many,
+    write_many_size,
+    write_many_size_with_options,
+    write_many_with_options,
+};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::write_many::{write_many_to_vec, write_many_to_vec_with_options};
 #[cfg(feature = "parse-floats")]
 pub use lexical_parse_float::{
     options as parse_float_options,
     Options as ParseFloatOptions,
     OptionsBuilder as ParseFloatOptionsBuilder,
 };
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::token::{
+    tokenize,
+    tokenize_partial,
+    tokenize_partial_with_options,
+    tokenize_with_options,
+    Token,
+};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::validate::{
+    validate,
+    validate_partial,
+    validate_partial_with_options,
+    validate_with_options,
+    NumberKind,
+};
 #[cfg(feature = "parse-integers")]
 pub use lexical_parse_integer::{
     options as parse_integer_options,
     Options as ParseIntegerOptions,
     OptionsBuilder as ParseIntegerOptionsBuilder,
 };
+#[cfg(all(feature = "ieee-decimal", any(feature = "parse-floats", feature = "write-floats")))]
+pub use lexical_util::bid::{Decimal128, Decimal64};
+#[cfg(all(feature = "std", any(feature = "parse-integers", feature = "write-integers")))]
+pub use lexical_util::bigint::Bigint;
 #[cfg(feature = "write")]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-#[cfg(feature = "parse")]
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+pub use lexical_util::decimal::Decimal;
+#[cfg(any(feature = "parse", feature = "write"))]
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
+#[cfg(all(feature = "half", any(feature = "parse-floats", feature = "write-floats")))]
+pub use lexical_util::num::{bf16, f16};
 #[cfg(feature = "parse")]
 pub use lexical_util::options::ParseOptions;
 #[cfg(feature = "write")]
 pub use lexical_util::options::WriteOptions;
-#[cfg(feature = "parse")]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub use lexical_util::rational::Rational;
+#[cfg(any(feature = "parse", feature = "write"))]
 pub use lexical_util::result::Result;
+#[cfg(feature = "parse")]
+pub use lexical_util::suffix::{Suffix, SuffixMode};
+#[cfg(feature = "parse")]
+pub use lexical_util::whitespace::WhitespaceMode;
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_util::wide::{Int, Uint, I256, I512, U256, U512};
 #[cfg(feature = "write-floats")]
 pub use lexical_write_float::{
     options as write_float_options,
     Options as WriteFloatOptions,
     OptionsBuilder as WriteFloatOptionsBuilder,
 };
+#[cfg(feature = "write-floats")]
+#[doc(hidden)]
+pub use lexical_write_float::shared as write_float_shared;
 #[cfg(feature = "write-integers")]
 pub use lexical_write_integer::{
     options as write_integer_options,
@@ -404,7 +517,11 @@ to_lexical_with_options!();
 #[cfg(feature = "parse")]
 macro_rules! from_lexical_impl {
     ($t:ident, $from:ident, $from_options:ident, $options:ident) => {
-        impl FromLexical for $t {
+        from_lexical_impl!([] $t, $from, $from_options, $options);
+    };
+
+    ([$($gen:tt)*] $t:ty, $from:ident, $from_options:ident, $options:ident) => {
+        impl<$($gen)*> FromLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical(bytes: &[u8]) -> Result<Self> {
                 <Self as $from>::from_lexical(bytes)
@@ -416,7 +533,7 @@ macro_rules! from_lexical_impl {
             }
         }
 
-        impl FromLexicalWithOptions for $t {
+        impl<$($gen)*> FromLexicalWithOptions for $t {
             type Options = $options;
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -434,6 +551,16 @@ macro_rules! from_lexical_impl {
             ) -> Result<(Self, usize)> {
                 <Self as $from_options>::from_lexical_partial_with_options::<FORMAT>(bytes, options)
             }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                <Self as $from_options>::from_lexical_partial_with_valid_format::<FORMAT>(
+                    bytes, options,
+                )
+            }
         }
     };
 }
@@ -449,6 +576,13 @@ macro_rules! integer_from_lexical {
 #[cfg(feature = "parse-integers")]
 integer_from_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
 
+#[cfg(feature = "parse-integers")]
+from_lexical_impl!([const N: usize] Uint<N>, FromInteger, FromIntegerWithOptions, ParseIntegerOptions);
+#[cfg(feature = "parse-integers")]
+from_lexical_impl!([const N: usize] Int<N>, FromInteger, FromIntegerWithOptions, ParseIntegerOptions);
+#[cfg(all(feature = "parse-integers", feature = "std"))]
+from_lexical_impl!([] Bigint, FromInteger, FromIntegerWithOptions, ParseIntegerOptions);
+
 /// Implement `FromLexical` and `FromLexicalWithOptions` for floats.
 #[cfg(feature = "parse-floats")]
 macro_rules! float_from_lexical {
@@ -459,12 +593,24 @@ macro_rules! float_from_lexical {
 
 #[cfg(feature = "parse-floats")]
 float_from_lexical! { f32 f64 }
+#[cfg(feature = "parse-floats")]
+from_lexical_impl!(Decimal, FromFloat, FromFloatWithOptions, ParseFloatOptions);
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+from_lexical_impl!(Rational, FromFloat, FromFloatWithOptions, ParseFloatOptions);
+#[cfg(all(feature = "parse-floats", feature = "ieee-decimal"))]
+float_from_lexical! { Decimal64 Decimal128 }
+#[cfg(all(feature = "parse-floats", feature = "half"))]
+float_from_lexical! { f16 bf16 }
 
 // Implement ToLexical for numeric type.
 #[cfg(feature = "write")]
 macro_rules! to_lexical_impl {
     ($t:ident, $to:ident, $to_options:ident, $options:ident) => {
-        impl ToLexical for $t {
+        to_lexical_impl!([] $t, $to, $to_options, $options);
+    };
+
+    ([$($gen:tt)*] $t:ty, $to:ident, $to_options:ident, $options:ident) => {
+        impl<$($gen)*> ToLexical for $t {
             #[cfg_attr(not(feature = "compact"), inline)]
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
                 // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
@@ -475,9 +621,19 @@ macro_rules! to_lexical_impl {
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8] {
                 <Self as $to>::to_lexical(self, bytes)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn formatted_len(&self) -> usize {
+                <Self as $to>::formatted_len(self)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn written_len(&self) -> usize {
+                <Self as $to>::written_len(self)
+            }
         }
 
-        impl ToLexicalWithOptions for $t {
+        impl<$($gen)*> ToLexicalWithOptions for $t {
             type Options = $options;
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -502,6 +658,22 @@ macro_rules! to_lexical_impl {
             ) -> &'a mut [u8] {
                 <Self as $to_options>::to_lexical_with_options::<FORMAT>(self, bytes, options)
             }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn formatted_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> usize {
+                <Self as $to_options>::formatted_len_with_options::<FORMAT>(self, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> Result<usize> {
+                <Self as $to_options>::written_len_with_options::<FORMAT>(self, options)
+            }
         }
     };
 }
@@ -517,6 +689,15 @@ macro_rules! integer_to_lexical {
 #[cfg(feature = "write-integers")]
 integer_to_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
 
+#[cfg(feature = "write-integers")]
+to_lexical_impl!([const N: usize] Uint<N>, ToInteger, ToIntegerWithOptions, WriteIntegerOptions);
+#[cfg(feature = "write-integers")]
+to_lexical_impl!([const N: usize] Int<N>, ToInteger, ToIntegerWithOptions, WriteIntegerOptions);
+#[cfg(all(feature = "write-integers", feature = "std"))]
+to_lexical_impl!(Bigint, ToInteger, ToIntegerWithOptions, WriteIntegerOptions);
+#[cfg(all(feature = "write-integers", feature = "std"))]
+to_lexical_impl!(['v] &'v Bigint, ToInteger, ToIntegerWithOptions, WriteIntegerOptions);
+
 /// Implement `ToLexical` and `ToLexicalWithOptions` for floats.
 #[cfg(feature = "write-floats")]
 macro_rules! float_to_lexical {
@@ -527,6 +708,12 @@ macro_rules! float_to_lexical {
 
 #[cfg(feature = "write-floats")]
 float_to_lexical! { f32 f64 }
+#[cfg(feature = "write-floats")]
+to_lexical_impl!(Decimal, ToFloat, ToFloatWithOptions, WriteFloatOptions);
+#[cfg(all(feature = "write-floats", feature = "ieee-decimal"))]
+float_to_lexical! { Decimal64 Decimal128 }
+#[cfg(all(feature = "write-floats", feature = "half"))]
+float_to_lexical! { f16 bf16 }
 
 /// Write number to string.
 ///
@@ -752,6 +939,210 @@ pub unsafe fn write_with_options_unchecked<'a, N: ToLexicalWithOptions, const FO
     unsafe { n.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
 }
 
+/// Write number to an uninitialized buffer.
+///
+/// Returns a subslice of the input buffer containing the written,
+/// initialized bytes, starting from the same address in memory as
+/// the input slice. Since the buffer does not need to be initialized,
+/// it may be the spare capacity of a vector.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least `{integer}::FORMATTED_SIZE_DECIMAL` elements.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use core::mem::MaybeUninit;
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+/// let float = 3.14159265359_f32;
+///
+/// assert_eq!(lexical_core::write_uninit(float, &mut buffer), b"3.1415927");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_uninit<N: ToLexical>(n: N, bytes: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    n.to_lexical_uninit(bytes)
+}
+
+/// Write number to an uninitialized buffer with custom options.
+///
+/// Returns a subslice of the input buffer containing the written,
+/// initialized bytes, starting from the same address in memory as
+/// the input slice. Since the buffer does not need to be initialized,
+/// it may be the spare capacity of a vector.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number. In order to ensure the function will not panic, provide a
+/// buffer with at least [`WriteOptions::buffer_size`] elements.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use core::mem::MaybeUninit;
+/// use lexical_core::BUFFER_SIZE;
+///
+/// let mut buffer = [MaybeUninit::<u8>::uninit(); BUFFER_SIZE];
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+/// let digits = lexical_core::write_with_options_uninit::<_, FORMAT>(-12i64, &mut buffer, &options);
+/// assert_eq!(digits, b"-12");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_with_options_uninit<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [MaybeUninit<u8>],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    n.to_lexical_with_options_uninit::<FORMAT>(bytes, options)
+}
+
+/// Write number to string, returning an error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Unlike
+/// [`write`], the buffer may be smaller than `{integer}::FORMATTED_SIZE_DECIMAL`:
+/// the number is written as long as the serialized digits fit. Otherwise,
+/// the buffer is left unchanged, and the number of bytes required to
+/// serialize the number is returned as the error.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(lexical_core::try_write(15.1f64, &mut buffer).unwrap(), b"15.1");
+/// assert_eq!(lexical_core::try_write(15.25f64, &mut buffer), Err(5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn try_write<N: ToLexical>(n: N, bytes: &mut [u8]) -> result::Result<&mut [u8], usize> {
+    // Only calculate the exact length if the upper bound does not fit.
+    if bytes.len() >= n.formatted_len() {
+        return Ok(n.to_lexical(bytes));
+    }
+    let size = n.written_len();
+    if bytes.len() >= size {
+        // SAFETY: safe since the buffer can hold the written bytes.
+        Ok(unsafe { n.to_lexical_unchecked(bytes) })
+    } else {
+        Err(size)
+    }
+}
+
+/// Write number to string with custom options, returning an error if the buffer is too small.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. Unlike
+/// [`write_with_options`], the buffer may be smaller than the size required
+/// by the options: the number is written as long as the serialized digits
+/// fit. Otherwise, the buffer is left unchanged, and the number of bytes
+/// required to serialize the number is returned as the error.
+///
+/// If the provided `FORMAT` is not valid, or the options conflict with
+/// it, such as a decimal point that is the same as the exponent
+/// character, the buffer is left unchanged, and the error is returned
+/// as [`TryWriteError::Format`]. This includes writing a NaN or Inf
+/// when the NaN or Inf string is disabled.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::TryWriteError;
+///
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteIntegerOptions::new();
+/// let mut buffer = [0u8; 3];
+/// let result = lexical_core::try_write_with_options::<_, FORMAT>(-12i64, &mut buffer, &options);
+/// assert_eq!(result.unwrap(), b"-12");
+/// let result = lexical_core::try_write_with_options::<_, FORMAT>(-123i64, &mut buffer, &options);
+/// assert_eq!(result, Err(TryWriteError::BufferTooSmall(4)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn try_write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> result::Result<&'a mut [u8], TryWriteError> {
+    // This also validates the format and options, so the writer cannot panic.
+    let size = n.written_len_with_options::<FORMAT>(options).map_err(TryWriteError::Format)?;
+    if bytes.len() >= size {
+        // SAFETY: safe since the buffer can hold the written bytes.
+        Ok(unsafe { n.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) })
+    } else {
+        Err(TryWriteError::BufferTooSmall(size))
+    }
+}
+
+/// Error while writing a number with custom options to a buffer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg(feature = "write")]
+pub enum TryWriteError {
+    /// The buffer is too small, with the number of bytes required.
+    BufferTooSmall(usize),
+    /// The number format or options are not valid.
+    Format(Error),
+}
+
+#[cfg(feature = "write")]
+impl fmt::Display for TryWriteError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall(size) => {
+                write!(formatter, "buffer too small, {} bytes are required", size)
+            },
+            Self::Format(error) => write!(formatter, "{}", error),
+        }
+    }
+}
+
+#[cfg(all(feature = "write", feature = "std"))]
+impl std::error::Error for TryWriteError {
+}
+
 /// Parse complete number from string.
 ///
 /// This method parses the entire string, returning an error if
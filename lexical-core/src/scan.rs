@@ -0,0 +1,188 @@
+//! Scan free text for all numbers it contains.
+
+#![cfg(feature = "parse")]
+
+use crate::FromLexicalWithOptions;
+use core::marker::PhantomData;
+use core::ops::Range;
+use lexical_util::digit::char_is_digit_const;
+use lexical_util::format::{NumberFormat, STANDARD};
+
+/// Iterator over all the numbers found in a byte buffer.
+///
+/// Yields the range of each number in the buffer, and its parsed value.
+/// Created by [`scan`] or [`scan_with_options`].
+///
+/// Numbers are found by their first digit, sign, or the punctuation
+/// directly preceding a digit (such as a decimal point), and then parsed
+/// using the partial parser, so they follow the exact same grammar as
+/// [`parse_partial_with_options`]. A sign is only part of a number if it
+/// does not directly follow an alphanumeric character, so `"3-4"` contains
+/// the numbers `3` and `4`, while `"(-4)"` contains `-4`. Any text that is
+/// not a valid number, including numbers that overflow or values that are
+/// invalid for the type, is skipped. Special values, such as `NaN` or
+/// `inf`, are never found, since they cannot be distinguished from words.
+///
+/// [`parse_partial_with_options`]: crate::parse_partial_with_options
+pub struct Scanner<'a, N: FromLexicalWithOptions, const FORMAT: u128> {
+    /// The buffer to scan.
+    bytes: &'a [u8],
+    /// The index of the next byte to scan.
+    index: usize,
+    /// The options used to parse each number.
+    options: N::Options,
+    _marker: PhantomData<N>,
+}
+
+impl<'a, N: FromLexicalWithOptions, const FORMAT: u128> Scanner<'a, N, FORMAT> {
+    /// Determine if the byte at the index may be the start of a number.
+    #[inline]
+    fn is_start(&self, index: usize) -> bool {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let radix = format.mantissa_radix();
+        let c = self.bytes[index];
+        let next_is_digit = match self.bytes.get(index + 1) {
+            Some(&next) => char_is_digit_const(next, radix),
+            None => false,
+        };
+        if char_is_digit_const(c, radix) {
+            true
+        } else if c == b'+' || c == b'-' {
+            // Signs directly following a word or number are not part of this number.
+            index == 0 || !self.bytes[index - 1].is_ascii_alphanumeric()
+        } else {
+            // Punctuation may be a decimal point, and so part of the number.
+            next_is_digit && c.is_ascii_punctuation()
+        }
+    }
+
+    /// Determine if the range contains any digits.
+    ///
+    /// The partial parsers accept a lone sign as a number, which cannot be
+    /// distinguished from a dash or plus symbol in free text.
+    #[inline]
+    fn has_digits(&self, range: Range<usize>) -> bool {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let radix = format.mantissa_radix();
+        self.bytes[range].iter().any(|&c| char_is_digit_const(c, radix))
+    }
+
+    /// Get the index after the contiguous digits starting at the index.
+    #[inline]
+    fn skip_digits(&self, mut index: usize) -> usize {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let radix = format.mantissa_radix();
+        let separator = format.digit_separator();
+        while let Some(&c) = self.bytes.get(index) {
+            if char_is_digit_const(c, radix) || (separator != 0 && c == separator) {
+                index += 1;
+            } else {
+                break;
+            }
+        }
+        index
+    }
+}
+
+impl<'a, N: FromLexicalWithOptions, const FORMAT: u128> Iterator for Scanner<'a, N, FORMAT> {
+    type Item = (Range<usize>, N);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return None;
+        }
+
+        while self.index < self.bytes.len() {
+            let start = self.index;
+            if !self.is_start(start) {
+                self.index += 1;
+                continue;
+            }
+            let bytes = &self.bytes[start..];
+            match N::from_lexical_partial_with_options::<FORMAT>(bytes, &self.options) {
+                Ok((value, count)) if self.has_digits(start..start + count) => {
+                    self.index = start + count;
+                    return Some((start..start + count, value));
+                },
+                _ => {
+                    // Skip the invalid token: a sign or digit and any digits
+                    // after it, so we don't find a subset of an invalid number.
+                    let c = self.bytes[start];
+                    self.index = match c {
+                        b'+' | b'-' => self.skip_digits(start + 1),
+                        _ if c.is_ascii_punctuation() => start + 1,
+                        _ => self.skip_digits(start),
+                    };
+                },
+            }
+        }
+        None
+    }
+}
+
+/// Scan a buffer for all numbers in the standard format.
+///
+/// Returns an iterator yielding the range of each number in the buffer,
+/// and its parsed value. See [`Scanner`] for how numbers are found.
+///
+/// * `bytes`   - Byte slice containing free text.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse-integers")] {
+/// let text = b"took 15ms (-3 retries), pages 3-4";
+/// let numbers: Vec<_> = lexical_core::scan::<i32>(text).collect();
+/// assert_eq!(numbers, vec![(5..7, 15), (11..13, -3), (30..31, 3), (32..33, 4)]);
+/// # }
+/// ```
+#[inline]
+pub fn scan<N: FromLexicalWithOptions>(bytes: &[u8]) -> Scanner<'_, N, STANDARD> {
+    Scanner {
+        bytes,
+        index: 0,
+        options: N::Options::default(),
+        _marker: PhantomData,
+    }
+}
+
+/// Scan a buffer for all numbers with custom parsing options.
+///
+/// Returns an iterator yielding the range of each number in the buffer,
+/// and its parsed value. See [`Scanner`] for how numbers are found. If
+/// the provided `FORMAT` is not valid, no numbers are found.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing free text.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::ParseFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let text = b"temp: -1,5 to 2,25";
+/// let numbers: Vec<_> = lexical_core::scan_with_options::<f64, FORMAT>(text, &options).collect();
+/// assert_eq!(numbers, vec![(6..10, -1.5), (14..18, 2.25)]);
+/// # }
+/// ```
+#[inline]
+pub fn scan_with_options<'a, N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &N::Options,
+) -> Scanner<'a, N, FORMAT>
+where
+    N::Options: Clone,
+{
+    Scanner {
+        bytes,
+        index: 0,
+        options: options.clone(),
+        _marker: PhantomData,
+    }
+}
@@ -0,0 +1,471 @@
+//! Conversion routines for the integer wrapper types in `core::num`.
+//!
+//! The wrappers are parsed and written exactly as their underlying
+//! integer, other than:
+//! - Parsing `0` into a `NonZero*` type returns [`Error::InvalidZero`],
+//!   at the index of the start of the number.
+//! - Parsing a `Saturating` type clamps the value on overflow or underflow.
+//!
+//! [`Error::InvalidZero`]: crate::Error::InvalidZero
+
+#![cfg(feature = "integers")]
+
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+use crate::Result;
+#[cfg(feature = "parse-integers")]
+use crate::{Error, FromLexical, FromLexicalWithOptions, ParseIntegerOptions};
+#[cfg(feature = "write-integers")]
+use crate::{ToLexical, ToLexicalWithOptions, WriteIntegerOptions};
+#[cfg(feature = "saturating")]
+use core::num::Saturating;
+use core::num::{
+    NonZeroI128,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI8,
+    NonZeroIsize,
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU8,
+    NonZeroUsize,
+    Wrapping,
+};
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+use lexical_util::digit::char_is_digit_const;
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+use lexical_util::format::NumberFormat;
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+use lexical_util::iterator::{AsBytes, BytesIter};
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+use lexical_util::suffix::{parse_suffix, Suffix, SuffixMode};
+#[cfg(feature = "parse-integers")]
+use lexical_util::whitespace;
+
+// PARSE
+// -----
+
+/// Implement `FromLexical` and `FromLexicalWithOptions` for non-zero integers.
+#[cfg(feature = "parse-integers")]
+macro_rules! nonzero_from_lexical {
+    ($($t:ident $int:ident ; )*) => ($(
+        impl FromLexical for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                $t::new($int::from_lexical(bytes)?).ok_or(Error::InvalidZero(0))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                let (value, count) = $int::from_lexical_partial(bytes)?;
+                match $t::new(value) {
+                    Some(value) => Ok((value, count)),
+                    None => Err(Error::InvalidZero(0)),
+                }
+            }
+        }
+
+        impl FromLexicalWithOptions for $t {
+            type Options = ParseIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                let value = $int::from_lexical_with_options::<FORMAT>(bytes, options)?;
+                $t::new(value).ok_or_else(|| zero_error(bytes, options))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let (value, count) =
+                    $int::from_lexical_partial_with_options::<FORMAT>(bytes, options)?;
+                match $t::new(value) {
+                    Some(value) => Ok((value, count)),
+                    None => Err(zero_error(bytes, options)),
+                }
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                let (value, count) =
+                    $int::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)?;
+                match $t::new(value) {
+                    Some(value) => Ok((value, count)),
+                    None => Err(zero_error(bytes, options)),
+                }
+            }
+        }
+    )*);
+}
+
+/// Get the error for a zero value, at the start of the number.
+#[cfg(feature = "parse-integers")]
+#[inline]
+fn zero_error(bytes: &[u8], options: &ParseIntegerOptions) -> Error {
+    Error::InvalidZero(whitespace::leading_whitespace(bytes, options.leading_whitespace()))
+}
+
+#[cfg(feature = "parse-integers")]
+nonzero_from_lexical! {
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+}
+
+/// Implement `FromLexical` and `FromLexicalWithOptions` for `Wrapping` integers.
+#[cfg(feature = "parse-integers")]
+macro_rules! wrapping_from_lexical {
+    ($($t:ident)*) => ($(
+        impl FromLexical for Wrapping<$t> {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                $t::from_lexical(bytes).map(Wrapping)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                $t::from_lexical_partial(bytes).map(|(value, count)| (Wrapping(value), count))
+            }
+        }
+
+        impl FromLexicalWithOptions for Wrapping<$t> {
+            type Options = ParseIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                $t::from_lexical_with_options::<FORMAT>(bytes, options).map(Wrapping)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                $t::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+                    .map(|(value, count)| (Wrapping(value), count))
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                $t::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+                    .map(|(value, count)| (Wrapping(value), count))
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-integers")]
+wrapping_from_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+/// Get the number of bytes processed by a partial parser that saturated
+/// after overflowing at `index`.
+///
+/// The remaining digits, including any digit separators, and any
+/// magnitude suffix, are still part of the number.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+#[inline]
+fn saturated_count<const FORMAT: u128>(bytes: &[u8], index: usize, suffix: SuffixMode) -> usize {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    // SAFETY: safe since the overflow happened at a valid index, and
+    // the preceding bytes are kept so separators are classified correctly.
+    unsafe { iter.set_cursor(index) };
+    while let Some(&c) = iter.peek() {
+        if !char_is_digit_const(c, radix) {
+            break;
+        }
+        iter.next();
+    }
+    let mut count = iter.cursor();
+    if radix == 10 {
+        match parse_suffix(&bytes[count..], suffix) {
+            Some((Suffix::Decimal(exp), length)) if exp > 0 => count += length,
+            Some((Suffix::Binary(_), length)) => count += length,
+            _ => (),
+        }
+    }
+    count
+}
+
+/// Get the number of bytes processed by a parser with options that
+/// saturated after overflowing at `index`, including trailing whitespace.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+#[inline]
+fn saturated_options_count<const FORMAT: u128>(
+    bytes: &[u8],
+    index: usize,
+    options: &ParseIntegerOptions,
+) -> usize {
+    let count = saturated_count::<FORMAT>(bytes, index, options.suffix());
+    count + whitespace::leading_whitespace(&bytes[count..], options.trailing_whitespace())
+}
+
+/// Clamp the result of a complete parser on overflow or underflow.
+///
+/// The number must still be valid after the overflow, so `count` gets
+/// the number of bytes processed from the index of the overflow.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+#[inline]
+fn saturate_complete<T, Cb>(result: Result<T>, bytes: &[u8], min: T, max: T, count: Cb) -> Result<T>
+where
+    Cb: FnOnce(usize) -> usize,
+{
+    let (value, index) = match result {
+        Err(Error::Overflow(index)) => (max, index),
+        Err(Error::Underflow(index)) => (min, index),
+        result => return result,
+    };
+    match count(index) {
+        count if count == bytes.len() => Ok(value),
+        count => Err(Error::InvalidDigit(count)),
+    }
+}
+
+/// Clamp the result of a partial parser on overflow or underflow.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+#[inline]
+fn saturate_partial<T, Cb>(
+    result: Result<(T, usize)>,
+    min: T,
+    max: T,
+    count: Cb,
+) -> Result<(T, usize)>
+where
+    Cb: FnOnce(usize) -> usize,
+{
+    match result {
+        Err(Error::Overflow(index)) => Ok((max, count(index))),
+        Err(Error::Underflow(index)) => Ok((min, count(index))),
+        result => result,
+    }
+}
+
+/// Implement `FromLexical` and `FromLexicalWithOptions` for `Saturating` integers.
+///
+/// Unsigned integers take the signed integer of the same width, which
+/// parses negative numbers so they can be clamped to zero.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+macro_rules! saturating_from_lexical {
+    ($($t:ident $(=> $signed:ident)? ; )*) => ($(
+        impl FromLexical for Saturating<$t> {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> Result<Self> {
+                const FORMAT: u128 = lexical_util::format::STANDARD;
+                $(if bytes.first() == Some(&b'-') {
+                    return Saturating::<$signed>::from_lexical(bytes).map(|_| Saturating(0));
+                })?
+                let count = |index| saturated_count::<FORMAT>(bytes, index, SuffixMode::None);
+                let result = $t::from_lexical(bytes);
+                saturate_complete(result, bytes, $t::MIN, $t::MAX, count).map(Saturating)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+                const FORMAT: u128 = lexical_util::format::STANDARD;
+                $(if bytes.first() == Some(&b'-') {
+                    return Saturating::<$signed>::from_lexical_partial(bytes)
+                        .map(|(_, count)| (Saturating(0), count));
+                })?
+                let count = |index| saturated_count::<FORMAT>(bytes, index, SuffixMode::None);
+                let result = $t::from_lexical_partial(bytes);
+                saturate_partial(result, $t::MIN, $t::MAX, count)
+                    .map(|(value, count)| (Saturating(value), count))
+            }
+        }
+
+        impl FromLexicalWithOptions for Saturating<$t> {
+            type Options = ParseIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<Self> {
+                $(if is_negative(bytes, options) {
+                    return Saturating::<$signed>::from_lexical_with_options::<FORMAT>(bytes, options)
+                        .map(|_| Saturating(0));
+                })?
+                let count = |index| saturated_options_count::<FORMAT>(bytes, index, options);
+                let result = $t::from_lexical_with_options::<FORMAT>(bytes, options);
+                saturate_complete(result, bytes, $t::MIN, $t::MAX, count).map(Saturating)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                $(if is_negative(bytes, options) {
+                    return Saturating::<$signed>::from_lexical_partial_with_options::<FORMAT>(
+                        bytes, options,
+                    )
+                    .map(|(_, count)| (Saturating(0), count));
+                })?
+                let count = |index| saturated_options_count::<FORMAT>(bytes, index, options);
+                let result = $t::from_lexical_partial_with_options::<FORMAT>(bytes, options);
+                saturate_partial(result, $t::MIN, $t::MAX, count)
+                    .map(|(value, count)| (Saturating(value), count))
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> Result<(Self, usize)> {
+                $(if is_negative(bytes, options) {
+                    return Saturating::<$signed>::from_lexical_partial_with_valid_format::<FORMAT>(
+                        bytes, options,
+                    )
+                    .map(|(_, count)| (Saturating(0), count));
+                })?
+                let count = |index| saturated_options_count::<FORMAT>(bytes, index, options);
+                let result = $t::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options);
+                saturate_partial(result, $t::MIN, $t::MAX, count)
+                    .map(|(value, count)| (Saturating(value), count))
+            }
+        }
+    )*);
+}
+
+/// Determine if a number starts with a negative sign, after any leading whitespace.
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+#[inline]
+fn is_negative(bytes: &[u8], options: &ParseIntegerOptions) -> bool {
+    let start = whitespace::leading_whitespace(bytes, options.leading_whitespace());
+    bytes.get(start) == Some(&b'-')
+}
+
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+saturating_from_lexical! {
+    u8 => i8 ;
+    u16 => i16 ;
+    u32 => i32 ;
+    u64 => i64 ;
+    u128 => i128 ;
+    usize => isize ;
+    i8 ;
+    i16 ;
+    i32 ;
+    i64 ;
+    i128 ;
+    isize ;
+}
+
+// WRITE
+// -----
+
+/// Implement `ToLexical` and `ToLexicalWithOptions` for wrapped integers.
+#[cfg(feature = "write-integers")]
+macro_rules! wrapper_to_lexical {
+    ($($t:ty => $int:ident, $get:expr ; )*) => ($(
+        impl ToLexical for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                let value: $int = $get(self);
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe { value.to_lexical_unchecked(bytes) }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                let value: $int = $get(self);
+                value.to_lexical(bytes)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn written_len(&self) -> usize {
+                let value: $int = $get(*self);
+                value.written_len()
+            }
+        }
+
+        impl ToLexicalWithOptions for $t {
+            type Options = WriteIntegerOptions;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                let value: $int = $get(self);
+                // SAFETY: safe as long as `bytes` is large enough to hold the significant digits.
+                unsafe { value.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                let value: $int = $get(self);
+                value.to_lexical_with_options::<FORMAT>(bytes, options)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> Result<usize> {
+                let value: $int = $get(*self);
+                value.written_len_with_options::<FORMAT>(options)
+            }
+        }
+    )*);
+}
+
+/// Implement `ToLexical` and `ToLexicalWithOptions` for `Wrapping` and `Saturating` integers.
+#[cfg(feature = "write-integers")]
+macro_rules! wrapping_to_lexical {
+    ($($t:ident)*) => ($(
+        wrapper_to_lexical! { Wrapping<$t> => $t, |value: Wrapping<$t>| value.0 ; }
+        #[cfg(feature = "saturating")]
+        wrapper_to_lexical! { Saturating<$t> => $t, |value: Saturating<$t>| value.0 ; }
+    )*);
+}
+
+#[cfg(feature = "write-integers")]
+wrapper_to_lexical! {
+    NonZeroU8 => u8, NonZeroU8::get ;
+    NonZeroU16 => u16, NonZeroU16::get ;
+    NonZeroU32 => u32, NonZeroU32::get ;
+    NonZeroU64 => u64, NonZeroU64::get ;
+    NonZeroU128 => u128, NonZeroU128::get ;
+    NonZeroUsize => usize, NonZeroUsize::get ;
+    NonZeroI8 => i8, NonZeroI8::get ;
+    NonZeroI16 => i16, NonZeroI16::get ;
+    NonZeroI32 => i32, NonZeroI32::get ;
+    NonZeroI64 => i64, NonZeroI64::get ;
+    NonZeroI128 => i128, NonZeroI128::get ;
+    NonZeroIsize => isize, NonZeroIsize::get ;
+}
+
+#[cfg(feature = "write-integers")]
+wrapping_to_lexical! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
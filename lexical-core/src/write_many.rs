@@ -0,0 +1,325 @@
+//! Write slices of numbers as delimited lists, such as a row of CSV data.
+
+#![cfg(feature = "write")]
+
+use crate::{ToLexical, ToLexicalWithOptions};
+use core::ops::Range;
+
+/// Calculate the maximum number of bytes for a delimited list.
+///
+/// The elements take at most `size` bytes in total, and every element
+/// is followed by a delimiter, except for the last one.
+#[inline]
+const fn max_size(count: usize, size: usize, has_brackets: bool) -> usize {
+    let brackets = if has_brackets {
+        2
+    } else {
+        0
+    };
+    let delimiters = if count == 0 {
+        0
+    } else {
+        count - 1
+    };
+    size + delimiters + brackets
+}
+
+/// Write each value to the buffer, using the callback to serialize a value.
+///
+/// The buffer must already have been validated to be large enough.
+#[inline]
+fn write_values<'a, N, Cb>(
+    values: &[N],
+    delimiter: u8,
+    brackets: Option<(u8, u8)>,
+    bytes: &'a mut [u8],
+    offsets: &mut [Range<usize>],
+    mut cb: Cb,
+) -> &'a mut [u8]
+where
+    N: Copy,
+    Cb: FnMut(N, &mut [u8]) -> usize,
+{
+    let mut index = 0;
+    if let Some((open, _)) = brackets {
+        bytes[index] = open;
+        index += 1;
+    }
+    for (i, &value) in values.iter().enumerate() {
+        if i != 0 {
+            bytes[index] = delimiter;
+            index += 1;
+        }
+        let count = cb(value, &mut bytes[index..]);
+        if let Some(offset) = offsets.get_mut(i) {
+            *offset = index..index + count;
+        }
+        index += count;
+    }
+    if let Some((_, close)) = brackets {
+        bytes[index] = close;
+        index += 1;
+    }
+    &mut bytes[..index]
+}
+
+/// Get the maximum number of bytes required to write a delimited list.
+///
+/// The size is calculated from each value using
+/// [`formatted_len`](ToLexical::formatted_len), since the size of values
+/// such as arbitrary-precision integers depends on the value.
+///
+/// * `values`          - Numbers to serialize.
+/// * `has_brackets`    - If the list is surrounded by brackets.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// use lexical_core::FormattedSize;
+///
+/// // 3 values, 2 delimiters and 2 brackets.
+/// let size = lexical_core::write_many_size(&[1u8, 2, 3], true);
+/// assert_eq!(size, 3 * u8::FORMATTED_SIZE_DECIMAL + 4);
+/// # }
+/// ```
+#[inline]
+pub fn write_many_size<N: ToLexical>(values: &[N], has_brackets: bool) -> usize {
+    let size = values.iter().map(ToLexical::formatted_len).sum();
+    max_size(values.len(), size, has_brackets)
+}
+
+/// Get the maximum number of bytes required to write a delimited list with custom options.
+///
+/// The size is calculated from each value using
+/// [`formatted_len_with_options`](ToLexicalWithOptions::formatted_len_with_options).
+///
+/// * `FORMAT`          - Packed struct containing the number format.
+/// * `values`          - Numbers to serialize.
+/// * `has_brackets`    - If the list is surrounded by brackets.
+/// * `options`         - Options to customize number writing.
+#[inline]
+pub fn write_many_size_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    values: &[N],
+    has_brackets: bool,
+    options: &N::Options,
+) -> usize {
+    let size = values
+        .iter()
+        .map(|value| value.formatted_len_with_options::<FORMAT>(options))
+        .sum();
+    max_size(values.len(), size, has_brackets)
+}
+
+/// Write a slice of numbers as a delimited list.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// range of each written value in the buffer is stored in `offsets`,
+/// for as many values as `offsets` can hold, so an empty slice may be
+/// provided if the offsets are not required.
+///
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Byte separating each value.
+/// * `brackets`    - Optional opening and closing bytes surrounding the list.
+/// * `bytes`       - Buffer to write the list to.
+/// * `offsets`     - Slice to store the range of each written value.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// list. In order to ensure the function will not panic, provide a buffer
+/// with at least [`write_many_size`] elements.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// let values = [1.5f64, -2.0, 3e2];
+/// let mut buffer = [0u8; 256];
+/// let mut offsets: [_; 3] = Default::default();
+/// let list = lexical_core::write_many(&values, b',', Some((b'[', b']')), &mut buffer, &mut offsets);
+/// assert_eq!(list, b"[1.5,-2.0,300.0]");
+/// assert_eq!(offsets, [1..4, 5..9, 10..15]);
+/// # }
+/// ```
+#[inline]
+pub fn write_many<'a, N: ToLexical + Copy>(
+    values: &[N],
+    delimiter: u8,
+    brackets: Option<(u8, u8)>,
+    bytes: &'a mut [u8],
+    offsets: &mut [Range<usize>],
+) -> &'a mut [u8] {
+    assert!(bytes.len() >= write_many_size(values, brackets.is_some()));
+    write_values(values, delimiter, brackets, bytes, offsets, |value, bytes| {
+        value.to_lexical(bytes).len()
+    })
+}
+
+/// Write a slice of numbers as a delimited list with custom options.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// range of each written value in the buffer is stored in `offsets`,
+/// for as many values as `offsets` can hold, so an empty slice may be
+/// provided if the offsets are not required.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Byte separating each value.
+/// * `brackets`    - Optional opening and closing bytes surrounding the list.
+/// * `bytes`       - Buffer to write the list to.
+/// * `offsets`     - Slice to store the range of each written value.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// list. In order to ensure the function will not panic, provide a buffer
+/// with at least [`write_many_size_with_options`] elements.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "write-floats")] {
+/// const FORMAT: u128 = lexical_core::format::STANDARD;
+/// let options = lexical_core::WriteFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let values = [1.5f64, 2.25];
+/// let mut buffer = [0u8; 256];
+/// let list = lexical_core::write_many_with_options::<_, FORMAT>(
+///     &values,
+///     b';',
+///     None,
+///     &mut buffer,
+///     &mut [],
+///     &options,
+/// );
+/// assert_eq!(list, b"1,5;2,25");
+/// # }
+/// ```
+#[inline]
+pub fn write_many_with_options<'a, N: ToLexicalWithOptions + Copy, const FORMAT: u128>(
+    values: &[N],
+    delimiter: u8,
+    brackets: Option<(u8, u8)>,
+    bytes: &'a mut [u8],
+    offsets: &mut [Range<usize>],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    let size = write_many_size_with_options::<N, FORMAT>(values, brackets.is_some(), options);
+    assert!(bytes.len() >= size);
+    write_values(values, delimiter, brackets, bytes, offsets, |value, bytes| {
+        value.to_lexical_with_options::<FORMAT>(bytes, options).len()
+    })
+}
+
+/// Write a slice of numbers as a delimited list to the end of a vector.
+///
+/// Returns the number of written bytes. The range of each written value
+/// in the vector is stored in `offsets`, for as many values as `offsets`
+/// can hold, so an empty slice may be provided if the offsets are not
+/// required. The ranges are relative to the start of the vector, not
+/// the start of the list.
+///
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Byte separating each value.
+/// * `brackets`    - Optional opening and closing bytes surrounding the list.
+/// * `bytes`       - Vector to append the list to.
+/// * `offsets`     - Slice to store the range of each written value.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "write-integers")] {
+/// let mut buffer = b"values=".to_vec();
+/// let count = lexical_core::write_many_to_vec(&[1u32, 20, 300], b'\t', None, &mut buffer, &mut []);
+/// assert_eq!(count, 8);
+/// assert_eq!(buffer, b"values=1\t20\t300");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_many_to_vec<N: ToLexical + Copy>(
+    values: &[N],
+    delimiter: u8,
+    brackets: Option<(u8, u8)>,
+    bytes: &mut Vec<u8>,
+    offsets: &mut [Range<usize>],
+) -> usize {
+    let size = write_many_size(values, brackets.is_some());
+    append_values(bytes, size, values.len(), offsets, |bytes, offsets| {
+        write_many(values, delimiter, brackets, bytes, offsets).len()
+    })
+}
+
+/// Write a slice of numbers as a delimited list to the end of a vector with custom options.
+///
+/// Returns the number of written bytes. The range of each written value
+/// in the vector is stored in `offsets`, for as many values as `offsets`
+/// can hold, so an empty slice may be provided if the offsets are not
+/// required. The ranges are relative to the start of the vector, not
+/// the start of the list.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `values`      - Numbers to serialize.
+/// * `delimiter`   - Byte separating each value.
+/// * `brackets`    - Optional opening and closing bytes surrounding the list.
+/// * `bytes`       - Vector to append the list to.
+/// * `offsets`     - Slice to store the range of each written value.
+/// * `options`     - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_many_to_vec_with_options<N: ToLexicalWithOptions + Copy, const FORMAT: u128>(
+    values: &[N],
+    delimiter: u8,
+    brackets: Option<(u8, u8)>,
+    bytes: &mut Vec<u8>,
+    offsets: &mut [Range<usize>],
+    options: &N::Options,
+) -> usize {
+    let size = write_many_size_with_options::<N, FORMAT>(values, brackets.is_some(), options);
+    append_values(bytes, size, values.len(), offsets, |bytes, offsets| {
+        write_many_with_options::<N, FORMAT>(values, delimiter, brackets, bytes, offsets, options)
+            .len()
+    })
+}
+
+/// Append at most `size` bytes to the vector using the callback.
+///
+/// Shifts the offsets of the `count` written values so they are relative
+/// to the start of the vector.
+#[inline]
+#[cfg(feature = "std")]
+fn append_values<Cb>(
+    bytes: &mut Vec<u8>,
+    size: usize,
+    count: usize,
+    offsets: &mut [Range<usize>],
+    cb: Cb,
+) -> usize
+where
+    Cb: FnOnce(&mut [u8], &mut [Range<usize>]) -> usize,
+{
+    let start = bytes.len();
+    bytes.resize(start + size, 0);
+    let written = cb(&mut bytes[start..], offsets);
+    bytes.truncate(start + written);
+    for offset in offsets.iter_mut().take(count) {
+        *offset = offset.start + start..offset.end + start;
+    }
+    written
+}
@@ -24,6 +24,168 @@ fn float_to_string_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_try_write_test() {
+    let mut buffer = [b'x'; 5];
+    assert_eq!(lexical_core::try_write(12345u32, &mut buffer).unwrap(), b"12345");
+    assert_eq!(lexical_core::try_write(-1234i64, &mut buffer).unwrap(), b"-1234");
+    assert_eq!(lexical_core::try_write(0u128, &mut buffer[..1]).unwrap(), b"0");
+    assert_eq!(lexical_core::try_write(u64::MAX, &mut buffer), Err(20));
+    assert_eq!(lexical_core::try_write(-12345i32, &mut buffer), Err(6));
+    assert_eq!(lexical_core::try_write(1u8, &mut []), Err(1));
+    // The buffer is unchanged on error.
+    assert_eq!(&buffer, b"01234");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(123u64, &mut buffer[..3], &options)
+            .unwrap(),
+        b"123"
+    );
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1234u64, &mut buffer[..3], &options),
+        Err(lexical_core::TryWriteError::BufferTooSmall(4))
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_try_write_test() {
+    let mut buffer = [b'x'; 8];
+    assert_eq!(lexical_core::try_write(12345.0f32, &mut buffer).unwrap(), b"12345.0");
+    assert_eq!(lexical_core::try_write(1.5e300f64, &mut buffer[..7]).unwrap(), b"1.5e300");
+    assert_eq!(lexical_core::try_write(1.5e300f64, &mut buffer[..6]), Err(7));
+    assert_eq!(lexical_core::try_write(-1.5e-300f64, &mut buffer), Err(9));
+    assert_eq!(lexical_core::try_write(0.1f64 + 0.2, &mut buffer), Err(19));
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder().trim_floats(true).build().unwrap();
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(3.0f64, &mut buffer[..1], &options)
+            .unwrap(),
+        b"3"
+    );
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(3.5f64, &mut buffer[..1], &options),
+        Err(lexical_core::TryWriteError::BufferTooSmall(3))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "power-of-two"))]
+fn try_write_invalid_format_test() {
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new().mantissa_radix(1).build();
+    let options = lexical_core::WriteIntegerOptions::new();
+    let mut buffer = [b'x'; 8];
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(12u64, &mut buffer, &options),
+        Err(lexical_core::TryWriteError::Format(lexical_core::Error::InvalidMantissaRadix))
+    );
+    assert_eq!(&buffer, b"xxxxxxxx");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_try_write_large_test() {
+    // The written float is larger than `BUFFER_SIZE`, but fits in the buffer.
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(2000))
+        .build()
+        .unwrap();
+    let mut buffer = vec![b'x'; 2001];
+    let result = lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer, &options);
+    assert_eq!(result.unwrap().len(), 2001);
+    assert_eq!(&buffer[..4], b"1.50");
+    assert!(buffer[4..].iter().all(|&x| x == b'0'));
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer[..2000], &options),
+        Err(lexical_core::TryWriteError::BufferTooSmall(2001))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn bigint_try_write_test() {
+    let value = lexical_core::Bigint::from(7u32).pow(1000);
+    let mut buffer = vec![b'x'; 846];
+    // The exact length fits, but the upper bound does not.
+    assert!(
+        buffer.len() < <lexical_core::Bigint as lexical_core::ToLexical>::formatted_len(&value)
+    );
+    let digits = lexical_core::try_write(&value, &mut buffer).unwrap();
+    assert_eq!(digits.len(), 846);
+    assert_eq!(lexical_core::try_write(&value, &mut buffer[..845]), Err(846));
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn try_write_invalid_options_test() {
+    use lexical_core::{Error, TryWriteError};
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut buffer = [b'x'; 8];
+    let options = lexical_core::WriteFloatOptions::builder().decimal_point(b'e').build().unwrap();
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer, &options),
+        Err(TryWriteError::Format(Error::InvalidPunctuation))
+    );
+    let options = lexical_core::WriteFloatOptions::builder().nan_string(None).build().unwrap();
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(f64::NAN, &mut buffer, &options),
+        Err(TryWriteError::Format(Error::InvalidNanString))
+    );
+    assert_eq!(&buffer, b"xxxxxxxx");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_uninit_test() {
+    use core::mem::MaybeUninit;
+    use lexical_core::FormattedSize;
+
+    let mut buffer = [MaybeUninit::<u8>::uninit(); i64::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write_uninit(i64::MIN, &mut buffer), b"-9223372036854775808");
+    assert_eq!(lexical_core::write_uninit(7u8, &mut buffer[..u8::FORMATTED_SIZE_DECIMAL]), b"7");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    assert_eq!(
+        lexical_core::write_with_options_uninit::<_, FORMAT>(12345u32, &mut buffer, &options),
+        b"12345"
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_uninit_test() {
+    use core::mem::MaybeUninit;
+    use core::num;
+
+    let mut buffer = [MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_uninit(12345.0f32, &mut buffer), b"12345.0");
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::new();
+    assert_eq!(
+        lexical_core::write_with_options_uninit::<_, FORMAT>(-1.5f64, &mut buffer, &options),
+        b"-1.5"
+    );
+
+    // Options requiring a larger buffer than the stack buffer.
+    let options = lexical_core::WriteFloatOptions::builder()
+        .min_significant_digits(num::NonZeroUsize::new(300))
+        .build()
+        .unwrap();
+    let mut buffer = vec![MaybeUninit::<u8>::uninit(); 1024];
+    let digits =
+        lexical_core::write_with_options_uninit::<_, FORMAT>(1.5f64, &mut buffer, &options);
+    assert_eq!(digits.len(), 301);
+    assert!(digits.starts_with(b"1.5000"));
+}
+
 #[test]
 #[cfg(feature = "parse-integers")]
 fn string_to_integer_test() {
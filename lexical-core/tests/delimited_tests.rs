@@ -0,0 +1,98 @@
+#[cfg(feature = "parse")]
+use lexical_core::{Error, FieldError};
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_delimited_integer_test() {
+    let mut values = [0u32; 4];
+    assert_eq!(lexical_core::parse_delimited(b"", b',', &mut values), Ok((0, 0)));
+    assert_eq!(lexical_core::parse_delimited(b"7", b',', &mut values), Ok((1, 1)));
+    assert_eq!(values[0], 7);
+    assert_eq!(lexical_core::parse_delimited(b"1,22,333", b',', &mut values), Ok((3, 8)));
+    assert_eq!(&values[..3], &[1, 22, 333]);
+
+    // The slice is full, so parsing can be resumed.
+    let bytes = b"1,2,3,4,5,6";
+    assert_eq!(lexical_core::parse_delimited(bytes, b',', &mut values), Ok((4, 8)));
+    assert_eq!(values, [1, 2, 3, 4]);
+    assert_eq!(lexical_core::parse_delimited(&bytes[8..], b',', &mut values), Ok((2, 3)));
+    assert_eq!(&values[..2], &[5, 6]);
+
+    // Errors are relative to the field.
+    let error = FieldError {
+        field: 1,
+        offset: 2,
+        error: Error::InvalidDigit(1),
+    };
+    assert_eq!(lexical_core::parse_delimited(b"1,2a,3", b',', &mut values), Err(error));
+    let error = FieldError {
+        field: 2,
+        offset: 4,
+        error: Error::Empty(0),
+    };
+    assert_eq!(lexical_core::parse_delimited(b"1,2,,3", b',', &mut values), Err(error));
+    let error = FieldError {
+        field: 0,
+        offset: 0,
+        error: Error::Overflow(10),
+    };
+    assert_eq!(lexical_core::parse_delimited(b"99999999999", b',', &mut values), Err(error));
+    let error = FieldError {
+        field: 1,
+        offset: 2,
+        error: Error::InvalidDigit(1),
+    };
+    assert_eq!(lexical_core::parse_delimited(b"1;2;3", b';', &mut [0i8; 1]), Ok((1, 2)));
+    assert_eq!(lexical_core::parse_delimited(b"1,2;3", b',', &mut values), Err(error));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_delimited_float_test() {
+    let mut values = [0.0f64; 3];
+    assert_eq!(lexical_core::parse_delimited(b"1.5\t-2\t3e2", b'\t', &mut values), Ok((3, 10)));
+    assert_eq!(values, [1.5, -2.0, 300.0]);
+
+    let options = lexical_core::ParseFloatOptions::builder()
+        .leading_whitespace(lexical_core::WhitespaceMode::Ascii)
+        .trailing_whitespace(lexical_core::WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let bytes = b" 1.5 , 2 ,3\n";
+    let result =
+        lexical_core::parse_delimited_with_options::<_, FORMAT>(bytes, b',', &mut values, &options);
+    assert_eq!(result, Ok((3, 12)));
+    assert_eq!(values, [1.5, 2.0, 3.0]);
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "std"))]
+fn parse_delimited_to_vec_test() {
+    let mut values: Vec<i64> = vec![0];
+    assert_eq!(lexical_core::parse_delimited_to_vec(b"1|-2|3", b'|', &mut values), Ok(3));
+    assert_eq!(values, vec![0, 1, -2, 3]);
+
+    let error = FieldError {
+        field: 1,
+        offset: 2,
+        error: Error::Empty(0),
+    };
+    let mut values: Vec<i64> = Vec::new();
+    assert_eq!(lexical_core::parse_delimited_to_vec(b"4||6", b'|', &mut values), Err(error));
+    assert_eq!(values, vec![4]);
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "std"))]
+fn field_error_display_test() {
+    let error = FieldError {
+        field: 1,
+        offset: 2,
+        error: Error::Empty(0),
+    };
+    assert_eq!(
+        error.to_string(),
+        "lexical parse error: 'the string to parse was empty' at index 0 in field 1 at offset 2"
+    );
+}
@@ -0,0 +1,52 @@
+#[test]
+#[cfg(feature = "parse-integers")]
+fn scan_integer_test() {
+    let numbers = |text: &[u8]| lexical_core::scan::<i32>(text).collect::<Vec<_>>();
+    assert_eq!(numbers(b""), vec![]);
+    assert_eq!(numbers(b"no numbers here"), vec![]);
+    assert_eq!(numbers(b"42"), vec![(0..2, 42)]);
+    assert_eq!(numbers(b"a1b22c333"), vec![(1..2, 1), (3..5, 22), (6..9, 333)]);
+    assert_eq!(numbers(b"took 15ms"), vec![(5..7, 15)]);
+
+    // Sign adjacency.
+    assert_eq!(numbers(b"-1 +2"), vec![(0..2, -1), (3..5, 2)]);
+    assert_eq!(numbers(b"3-4"), vec![(0..1, 3), (2..3, 4)]);
+    assert_eq!(numbers(b"x-4"), vec![(2..3, 4)]);
+    assert_eq!(numbers(b"(-4)"), vec![(1..3, -4)]);
+    assert_eq!(numbers(b"--4"), vec![(1..3, -4)]);
+    assert_eq!(numbers(b"- 4"), vec![(2..3, 4)]);
+
+    // Invalid numbers are skipped entirely.
+    assert_eq!(numbers(b"1 99999999999 2"), vec![(0..1, 1), (14..15, 2)]);
+    assert_eq!(numbers(b"1.5"), vec![(0..1, 1), (2..3, 5)]);
+
+    let numbers = |text: &[u8]| lexical_core::scan::<u8>(text).collect::<Vec<_>>();
+    assert_eq!(numbers(b"-5 6"), vec![(3..4, 6)]);
+    assert_eq!(numbers(b"256 255"), vec![(4..7, 255)]);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn scan_float_test() {
+    let numbers = |text: &[u8]| lexical_core::scan::<f64>(text).collect::<Vec<_>>();
+    assert_eq!(numbers(b"x=1.5, y=-2e3;"), vec![(2..5, 1.5), (9..13, -2000.0)]);
+    assert_eq!(numbers(b"value .5 and 3."), vec![(6..8, 0.5), (13..15, 3.0)]);
+    assert_eq!(numbers(b"NaN inf information"), vec![]);
+    assert_eq!(numbers(b"v1.2.3"), vec![(1..4, 1.2), (4..6, 0.3)]);
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "format"))]
+fn scan_separator_test() {
+    use core::num;
+    use lexical_core::Scanner;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = lexical_core::ParseIntegerOptions::new();
+    let scanner: Scanner<'_, u32, FORMAT> =
+        lexical_core::scan_with_options::<u32, FORMAT>(b"a 1_000 b _2 3_", &options);
+    assert_eq!(scanner.collect::<Vec<_>>(), vec![(2..7, 1000), (11..12, 2), (13..14, 3)]);
+}
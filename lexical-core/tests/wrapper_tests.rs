@@ -0,0 +1,155 @@
+#![cfg(feature = "integers")]
+
+#[cfg(feature = "write-integers")]
+use core::num::NonZeroU64;
+#[cfg(feature = "saturating")]
+use core::num::Saturating;
+use core::num::{NonZeroI8, NonZeroU32, Wrapping};
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+use lexical_core::SuffixMode;
+#[cfg(feature = "parse-integers")]
+use lexical_core::{Error, ParseIntegerOptions, WhitespaceMode};
+#[cfg(feature = "write-integers")]
+use lexical_core::{FormattedSize, BUFFER_SIZE};
+
+#[cfg(feature = "parse-integers")]
+const STANDARD: u128 = lexical_core::format::STANDARD;
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_nonzero_test() {
+    assert_eq!(lexical_core::parse::<NonZeroU32>(b"42"), Ok(NonZeroU32::new(42).unwrap()));
+    assert_eq!(lexical_core::parse::<NonZeroI8>(b"-128"), Ok(NonZeroI8::new(-128).unwrap()));
+    assert_eq!(lexical_core::parse::<NonZeroU32>(b"0"), Err(Error::InvalidZero(0)));
+    assert_eq!(lexical_core::parse::<NonZeroI8>(b"-0"), Err(Error::InvalidZero(0)));
+    assert_eq!(lexical_core::parse::<NonZeroI8>(b"128"), Err(Error::Overflow(2)));
+    assert_eq!(lexical_core::parse::<NonZeroU32>(b"1a"), Err(Error::InvalidDigit(1)));
+    assert_eq!(
+        lexical_core::parse_partial::<NonZeroU32>(b"7 "),
+        Ok((NonZeroU32::new(7).unwrap(), 1))
+    );
+    assert_eq!(lexical_core::parse_partial::<NonZeroU32>(b"00 "), Err(Error::InvalidZero(0)));
+
+    let options = ParseIntegerOptions::builder()
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    let parse = |bytes| lexical_core::parse_with_options::<NonZeroU32, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"  0 "), Err(Error::InvalidZero(2)));
+    assert_eq!(parse(b"  5 "), Ok(NonZeroU32::new(5).unwrap()));
+    let parse =
+        |bytes| lexical_core::parse_partial_with_options::<NonZeroU32, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"\t0,"), Err(Error::InvalidZero(1)));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_wrapping_test() {
+    assert_eq!(lexical_core::parse::<Wrapping<u64>>(b"42"), Ok(Wrapping(42)));
+    assert_eq!(lexical_core::parse::<Wrapping<u8>>(b"256"), Err(Error::Overflow(2)));
+    assert_eq!(lexical_core::parse_partial::<Wrapping<i16>>(b"-3,"), Ok((Wrapping(-3), 2)));
+    assert_eq!(lexical_core::parse_partial::<Wrapping<u8>>(b"999,1"), Err(Error::Overflow(2)));
+    assert_eq!(lexical_core::parse_partial::<Wrapping<i8>>(b"-999,1"), Err(Error::Underflow(2)));
+    let options = ParseIntegerOptions::new();
+    assert_eq!(
+        lexical_core::parse_with_options::<Wrapping<u8>, STANDARD>(b"0", &options),
+        Ok(Wrapping(0))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "saturating"))]
+fn parse_saturating_test() {
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"42"), Ok(Saturating(42)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"256"), Ok(Saturating(255)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"99999"), Ok(Saturating(255)));
+    assert_eq!(lexical_core::parse::<Saturating<i8>>(b"-99999"), Ok(Saturating(-128)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"999x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"-1"), Ok(Saturating(0)));
+    assert_eq!(lexical_core::parse::<Saturating<u32>>(b"-99999999999"), Ok(Saturating(0)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"-5x"), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse::<Saturating<u8>>(b"-"), Err(Error::Empty(1)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<u8>>(b"-5,"), Ok((Saturating(0), 2)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<u8>>(b"99999 1"), Ok((Saturating(255), 5)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<i8>>(b"-300,"), Ok((Saturating(-128), 4)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<u8>>(b"999,1"), Ok((Saturating(255), 3)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<i8>>(b"999,1"), Ok((Saturating(127), 3)));
+    assert_eq!(lexical_core::parse_partial::<Saturating<i8>>(b"-999,1"), Ok((Saturating(-128), 4)));
+
+    let options = ParseIntegerOptions::builder()
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .suffix(SuffixMode::Decimal)
+        .build()
+        .unwrap();
+    let parse =
+        |bytes| lexical_core::parse_with_options::<Saturating<u16>, STANDARD>(bytes, &options);
+    assert_eq!(parse(b"65k"), Ok(Saturating(65_000)));
+    assert_eq!(parse(b"66k "), Ok(Saturating(u16::MAX)));
+    assert_eq!(parse(b"70000k"), Ok(Saturating(u16::MAX)));
+    assert_eq!(parse(b"70000q"), Err(Error::InvalidDigit(5)));
+    let parse = |bytes| {
+        lexical_core::parse_partial_with_options::<Saturating<u16>, STANDARD>(bytes, &options)
+    };
+    assert_eq!(parse(b"66k  x"), Ok((Saturating(u16::MAX), 5)));
+    assert_eq!(parse(b"70000m"), Ok((Saturating(u16::MAX), 5)));
+    assert_eq!(parse(b"-5k "), Ok((Saturating(0), 4)));
+
+    let options =
+        ParseIntegerOptions::builder().leading_whitespace(WhitespaceMode::Ascii).build().unwrap();
+    assert_eq!(
+        lexical_core::parse_with_options::<Saturating<u8>, STANDARD>(b"  -5", &options),
+        Ok(Saturating(0))
+    );
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "saturating", feature = "format"))]
+fn parse_saturating_separator_test() {
+    use core::num;
+
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = ParseIntegerOptions::new();
+    let parse = |bytes| lexical_core::parse_with_options::<Saturating<u8>, FORMAT>(bytes, &options);
+    assert_eq!(parse(b"1_000"), Ok(Saturating(255)));
+    assert_eq!(parse(b"99_999_999"), Ok(Saturating(255)));
+    assert_eq!(parse(b"-1_000"), Ok(Saturating(0)));
+    let parse =
+        |bytes| lexical_core::parse_partial_with_options::<Saturating<u8>, FORMAT>(bytes, &options);
+    assert_eq!(parse(b"1_000 2"), Ok((Saturating(255), 5)));
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_wrapper_test() {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    assert_eq!(lexical_core::write(NonZeroU32::new(42).unwrap(), &mut buffer), b"42");
+    assert_eq!(lexical_core::write(NonZeroI8::new(-128).unwrap(), &mut buffer), b"-128");
+    assert_eq!(lexical_core::write(Wrapping(u64::MAX), &mut buffer), b"18446744073709551615");
+    assert_eq!(lexical_core::write(Wrapping(-5i32) * Wrapping(3), &mut buffer), b"-15");
+    assert_eq!(NonZeroU64::FORMATTED_SIZE_DECIMAL, u64::FORMATTED_SIZE_DECIMAL);
+    assert_eq!(Wrapping::<i8>::FORMATTED_SIZE, i8::FORMATTED_SIZE);
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    let value = NonZeroU32::new(7).unwrap();
+    assert_eq!(
+        lexical_core::write_with_options::<_, { lexical_core::format::STANDARD }>(
+            value,
+            &mut buffer,
+            &options
+        ),
+        b"7"
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "saturating"))]
+fn write_saturating_test() {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let value = Saturating(250u8) + Saturating(10);
+    assert_eq!(lexical_core::write(value, &mut buffer), b"255");
+    assert_eq!(Saturating::<u8>::FORMATTED_SIZE_DECIMAL, u8::FORMATTED_SIZE_DECIMAL);
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "write")]
+use lexical_core::FormattedSize;
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_many_size_test() {
+    assert_eq!(lexical_core::write_many_size::<u32>(&[], false), 0);
+    assert_eq!(lexical_core::write_many_size::<u32>(&[], true), 2);
+    assert_eq!(lexical_core::write_many_size(&[1u32], false), u32::FORMATTED_SIZE_DECIMAL);
+    assert_eq!(
+        lexical_core::write_many_size(&[1u32, 2, 3], true),
+        3 * u32::FORMATTED_SIZE_DECIMAL + 4
+    );
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_many_integer_test() {
+    let mut buffer = [0u8; 128];
+    let mut offsets: [_; 3] = Default::default();
+    assert_eq!(lexical_core::write_many::<u32>(&[], b',', None, &mut buffer, &mut offsets), b"");
+    assert_eq!(
+        lexical_core::write_many::<u32>(&[], b',', Some((b'[', b']')), &mut buffer, &mut []),
+        b"[]"
+    );
+    assert_eq!(lexical_core::write_many(&[7u32], b',', None, &mut buffer, &mut offsets), b"7");
+    assert_eq!(offsets[0], 0..1);
+
+    let values = [1i32, -22, 333];
+    let list = lexical_core::write_many(&values, b',', None, &mut buffer, &mut offsets);
+    assert_eq!(list, b"1,-22,333");
+    assert_eq!(offsets, [0..1, 2..5, 6..9]);
+
+    let list =
+        lexical_core::write_many(&values, b' ', Some((b'(', b')')), &mut buffer, &mut offsets);
+    assert_eq!(list, b"(1 -22 333)");
+    assert_eq!(offsets, [1..2, 3..6, 7..10]);
+
+    // Only as many offsets as fit are stored.
+    let mut offsets: [_; 2] = Default::default();
+    let list = lexical_core::write_many(&[1u8, 2, 3], b';', None, &mut buffer, &mut offsets);
+    assert_eq!(list, b"1;2;3");
+    assert_eq!(offsets, [0..1, 2..3]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "write-integers")]
+fn write_many_overflow_test() {
+    // Large enough for the values, but not for the bound.
+    let mut buffer = [0u8; 8];
+    lexical_core::write_many(&[1u32, 2, 3], b',', None, &mut buffer, &mut []);
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_many_float_test() {
+    let mut buffer = [0u8; 256];
+    let mut offsets: [_; 3] = Default::default();
+    let values = [1.5f64, -2.0, 3e2];
+    let list =
+        lexical_core::write_many(&values, b',', Some((b'[', b']')), &mut buffer, &mut offsets);
+    assert_eq!(list, b"[1.5,-2.0,300.0]");
+    assert_eq!(offsets, [1..4, 5..9, 10..15]);
+    for (offset, value) in offsets.iter().zip(values.iter()) {
+        let parsed: f64 = lexical_core::parse(&list[offset.clone()]).unwrap();
+        assert_eq!(parsed, *value);
+    }
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_many_with_options_test() {
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder()
+        .decimal_point(b',')
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    let values = [1.5f32, 2.0];
+    let size = lexical_core::write_many_size_with_options::<_, FORMAT>(&values, false, &options);
+    let mut buffer = vec![0u8; size];
+    let mut offsets: [_; 2] = Default::default();
+    let list = lexical_core::write_many_with_options::<_, FORMAT>(
+        &values,
+        b';',
+        None,
+        &mut buffer,
+        &mut offsets,
+        &options,
+    );
+    assert_eq!(list, b"1,5;2");
+    assert_eq!(offsets, [0..3, 4..5]);
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn write_many_to_vec_test() {
+    let mut buffer = b"row=".to_vec();
+    let mut offsets: [_; 3] = Default::default();
+    let count =
+        lexical_core::write_many_to_vec(&[1u64, 20, 300], b'\t', None, &mut buffer, &mut offsets);
+    assert_eq!(count, 8);
+    assert_eq!(buffer, b"row=1\t20\t300");
+    assert_eq!(offsets, [4..5, 6..8, 9..12]);
+
+    // Offsets for values that were not written are left untouched.
+    let mut offsets: [_; 3] = Default::default();
+    let count = lexical_core::write_many_to_vec(
+        &[5u8],
+        b',',
+        Some((b'{', b'}')),
+        &mut buffer,
+        &mut offsets,
+    );
+    assert_eq!(count, 3);
+    assert_eq!(buffer, b"row=1\t20\t300{5}");
+    assert_eq!(offsets, [13..14, 0..0, 0..0]);
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteIntegerOptions::new();
+    let mut buffer = Vec::new();
+    let count = lexical_core::write_many_to_vec_with_options::<_, FORMAT>(
+        &[-1i8, 127],
+        b',',
+        None,
+        &mut buffer,
+        &mut [],
+        &options,
+    );
+    assert_eq!(count, 6);
+    assert_eq!(buffer, b"-1,127");
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn write_many_bigint_test() {
+    let values = [lexical_core::Bigint::from(u128::MAX), lexical_core::Bigint::from(-7i64)];
+    let values = [&values[0], &values[1]];
+    let size = lexical_core::write_many_size(&values, true);
+    assert!(size >= 45);
+    let mut buffer = vec![0u8; size];
+    let list = lexical_core::write_many(&values, b',', Some((b'[', b']')), &mut buffer, &mut []);
+    assert_eq!(list, b"[340282366920938463463374607431768211455,-7]");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+fn write_many_decimal_test() {
+    let values = [
+        lexical_core::parse::<lexical_core::Decimal>(b"-0.0").unwrap(),
+        lexical_core::Decimal::new(1, -2000000000),
+    ];
+    let size = lexical_core::write_many_size(&values, false);
+    let mut buffer = vec![0u8; size];
+    let list = lexical_core::write_many(&values, b' ', None, &mut buffer, &mut []);
+    assert_eq!(list, b"-0.0 1e2000000000");
+}
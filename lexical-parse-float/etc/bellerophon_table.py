@@ -82,7 +82,11 @@ def generate_large(base, step):
     '''Generate the large powers for a given base.'''
 
     # Get our starting parameters
-    min_exp = math.floor(math.log(5e-324, base) - math.log(0xFFFFFFFFFFFFFFFF, base))
+    # Decimal strings may have a binary suffix, which scales by up to 2^80.
+    min_value = math.log(5e-324, base)
+    if base == 10:
+        min_value -= math.log(2**80, base)
+    min_exp = math.floor(min_value - math.log(0xFFFFFFFFFFFFFFFF, base))
     max_exp = math.ceil(math.log(1.7976931348623157e+308, base))
     bitshift = calculate_bitshift(base, abs(min_exp - step))
     fps = deque()
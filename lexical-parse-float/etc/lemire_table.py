@@ -52,7 +52,8 @@ def main():
 
 
 def minimum_exponent(base):
-    return ceil(log(5e-324, base) - log(0xFFFFFFFFFFFFFFFF, base))
+    # Include the largest binary suffix, `Yi`, which scales by 2^80.
+    return ceil(log(5e-324, base) - log(2**80, base) - log(0xFFFFFFFFFFFFFFFF, base))
 
 
 def maximum_exponent(base):
@@ -3,9 +3,21 @@
 #![doc(hidden)]
 
 use crate::options::Options;
-use crate::parse::ParseFloat;
+use crate::parse::{parse_decimal_complete, parse_decimal_partial, ParseFloat};
+#[cfg(feature = "ieee-decimal")]
+use crate::parse::{parse_ieee_complete, parse_ieee_partial};
+#[cfg(feature = "std")]
+use crate::parse::{parse_rational_complete, parse_rational_partial};
+#[cfg(feature = "ieee-decimal")]
+use lexical_util::bid::{Decimal128, Decimal64};
+use lexical_util::decimal::Decimal;
 use lexical_util::error::Error;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
+#[cfg(feature = "std")]
+use lexical_util::rational::Rational;
+use lexical_util::whitespace;
 use lexical_util::{from_lexical, from_lexical_with_options};
 
 // API
@@ -51,7 +63,12 @@ macro_rules! float_from_lexical {
                 } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
                     return Err(Error::InvalidPunctuation);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                whitespace::parse_complete(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| Self::parse_complete::<FORMAT>(bytes, options),
+                )
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -60,7 +77,25 @@ macro_rules! float_from_lexical {
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<FORMAT>(bytes, options)
+                let format = NumberFormat::<{ FORMAT }> {};
+                if !format.is_valid() {
+                    return Err(format.error());
+                }
+                Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                whitespace::parse_partial(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| Self::parse_partial::<FORMAT>(bytes, options),
+                )
             }
         }
     )*)
@@ -69,3 +104,214 @@ macro_rules! float_from_lexical {
 from_lexical! {}
 from_lexical_with_options! {}
 float_from_lexical! { f32 f64 }
+
+#[cfg(feature = "half")]
+float_from_lexical! { f16 bf16 }
+
+/// Check if the format is valid for decimals, which must be decimal strings.
+macro_rules! check_decimal_format {
+    ($format:ident) => {{
+        let format = NumberFormat::<{ $format }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        }
+        check_decimal_radix!($format);
+    }};
+}
+
+/// Check if an already validated format uses decimal strings.
+macro_rules! check_decimal_radix {
+    ($format:ident) => {{
+        let format = NumberFormat::<{ $format }> {};
+        if format.mantissa_radix() != 10 || format.exponent_base() != 10 {
+            return Err(Error::InvalidRadix);
+        }
+    }};
+}
+
+impl FromLexical for Decimal {
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self> {
+        parse_decimal_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)> {
+        parse_decimal_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+    }
+}
+
+impl FromLexicalWithOptions for Decimal {
+    type Options = Options;
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<Self> {
+        check_decimal_format!(FORMAT);
+        if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+            return Err(Error::InvalidPunctuation);
+        }
+        whitespace::parse_complete(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_decimal_complete::<FORMAT>(bytes, options),
+        )
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        check_decimal_format!(FORMAT);
+        Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+    }
+
+    #[inline(always)]
+    fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        check_decimal_radix!(FORMAT);
+        whitespace::parse_partial(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_decimal_partial::<FORMAT>(bytes, options),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromLexical for Rational {
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self> {
+        parse_rational_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)> {
+        parse_rational_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromLexicalWithOptions for Rational {
+    type Options = Options;
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point())
+        {
+            return Err(Error::InvalidPunctuation);
+        }
+        whitespace::parse_complete(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_rational_complete::<FORMAT>(bytes, options),
+        )
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        }
+        Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+    }
+
+    #[inline(always)]
+    fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        whitespace::parse_partial(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_rational_partial::<FORMAT>(bytes, options),
+        )
+    }
+}
+
+// Implement FromLexical for decimal floats, which use the same decimal
+// format as exact decimals.
+macro_rules! ieee_decimal_from_lexical {
+    ($($t:ident)*) => ($(
+        #[cfg(feature = "ieee-decimal")]
+        impl FromLexical for $t {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self> {
+                parse_ieee_complete::<Self, STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(
+                bytes: &[u8],
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                parse_ieee_partial::<Self, STANDARD>(bytes, &DEFAULT_OPTIONS)
+            }
+        }
+
+        #[cfg(feature = "ieee-decimal")]
+        impl FromLexicalWithOptions for $t {
+            type Options = Options;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self> {
+                check_decimal_format!(FORMAT);
+                if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+                    return Err(Error::InvalidPunctuation);
+                }
+                whitespace::parse_complete(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| parse_ieee_complete::<Self, FORMAT>(bytes, options),
+                )
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                check_decimal_format!(FORMAT);
+                Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                check_decimal_radix!(FORMAT);
+                whitespace::parse_partial(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| parse_ieee_partial::<Self, FORMAT>(bytes, options),
+                )
+            }
+        }
+    )*)
+}
+
+ieee_decimal_from_lexical! { Decimal64 Decimal128 }
@@ -36,7 +36,12 @@ use lexical_util::format::NumberFormat;
 /// unable to unambiguously round the significant digits.
 ///
 /// This has been modified to return a biased, rather than unbiased exponent.
-pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+/// The float is scaled by `2^binary_exp` before it is rounded.
+pub fn bellerophon<F: RawFloat, const FORMAT: u128>(
+    num: &Number,
+    binary_exp: u32,
+    lossy: bool,
+) -> ExtendedFloat80 {
     let format = NumberFormat::<{ FORMAT }> {};
     debug_assert!(!matches!(format.radix(), 2 | 4 | 8 | 16 | 32));
     debug_assert!(format.mantissa_radix() == format.exponent_base());
@@ -105,7 +110,7 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     // Normalize the floating point (and the errors).
     let shift = normalize(&mut fp);
     errors <<= shift;
-    fp.exp += F::EXPONENT_BIAS;
+    fp.exp += F::EXPONENT_BIAS + binary_exp as i32;
 
     // Check for literal overflow, even with halfway cases.
     if -fp.exp + 1 > 65 {
@@ -0,0 +1,91 @@
+//! Convert the tokenized significant digits to an IEEE decimal float.
+//!
+//! The significant digits are rounded to the precision of the decimal
+//! float, and the exponent of the last digit is kept, preserving the
+//! quantum of the parsed number. Exponents below the minimum round the
+//! coefficient further, and exponents above the maximum append zeros to
+//! the coefficient, if possible, otherwise the value overflows.
+
+#![cfg(feature = "ieee-decimal")]
+#![doc(hidden)]
+
+use crate::decimal::to_digits;
+use crate::number::Number;
+use crate::options::DecimalRounding;
+use lexical_util::bid::IeeeDecimal;
+
+/// Determine if an overflowing value rounds to infinity.
+///
+/// Otherwise, it rounds to the largest finite value.
+#[inline]
+fn overflows_to_infinity(is_negative: bool, rounding: DecimalRounding) -> bool {
+    match rounding {
+        DecimalRounding::HalfEven
+        | DecimalRounding::HalfUp
+        | DecimalRounding::HalfDown
+        | DecimalRounding::Up => true,
+        DecimalRounding::Down => false,
+        DecimalRounding::Ceiling => !is_negative,
+        DecimalRounding::Floor => is_negative,
+    }
+}
+
+/// Calculate the number of digits in the coefficient.
+#[inline]
+fn digit_count(mut coefficient: u128) -> i64 {
+    let mut count = 0;
+    while coefficient != 0 {
+        coefficient /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Convert a parsed, non-special number to a decimal float.
+///
+/// The number is multiplied by `2^shift` before rounding, for binary
+/// magnitude suffixes. Numbers with more significant digits than fit in
+/// a `Decimal` may be rounded twice, with binary magnitude suffixes.
+pub fn to_ieee_decimal<D: IeeeDecimal, const FORMAT: u128>(
+    num: Number,
+    shift: u32,
+    rounding: DecimalRounding,
+) -> D {
+    let is_negative = num.is_negative;
+    let mut digits = to_digits::<FORMAT>(&num);
+    for _ in 0..shift {
+        digits.double();
+    }
+
+    // Round to the precision, or to the minimum exponent.
+    let precision_excess = digit_count(digits.coefficient) - D::PRECISION as i64;
+    let exponent_excess = D::MIN_EXPONENT as i64 - digits.exponent;
+    let excess = precision_excess.max(exponent_excess);
+    if excess > 0 {
+        digits.shift_right(excess);
+    }
+    digits.round(is_negative, rounding);
+    if digits.coefficient > D::MAX_COEFFICIENT {
+        // Rounding carried into a new digit, so the coefficient is `10^PRECISION`.
+        digits.coefficient /= 10;
+        digits.exponent += 1;
+    }
+
+    // Clamp the exponent to the maximum, appending zeros to the coefficient.
+    let mut coefficient = digits.coefficient;
+    let mut exponent = digits.exponent;
+    if coefficient == 0 {
+        exponent = exponent.min(D::MAX_EXPONENT as i64);
+    }
+    while exponent > D::MAX_EXPONENT as i64 && coefficient <= D::MAX_COEFFICIENT / 10 {
+        coefficient *= 10;
+        exponent -= 1;
+    }
+    if exponent <= D::MAX_EXPONENT as i64 {
+        D::from_parts(is_negative, coefficient, exponent as i32)
+    } else if overflows_to_infinity(is_negative, rounding) {
+        D::infinity(is_negative)
+    } else {
+        D::from_parts(is_negative, D::MAX_COEFFICIENT, D::MAX_EXPONENT)
+    }
+}
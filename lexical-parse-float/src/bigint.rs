@@ -12,6 +12,8 @@ use crate::limits::{u32_power_limit, u64_power_limit};
 use crate::table::get_large_int_power;
 use core::{cmp, mem, ops, ptr, slice};
 
+pub use lexical_util::limb::{scalar_add, scalar_mul, Limb, SignedWide, Wide, LIMB_BITS};
+
 // BIGINT
 // ------
 
@@ -912,27 +914,6 @@ pub fn pow<const SIZE: usize>(x: &mut StackVec<SIZE>, base: u32, mut exp: u32) -
     Some(())
 }
 
-// SCALAR
-// ------
-
-/// Add two small integers and return the resulting value and if overflow happens.
-#[inline(always)]
-pub fn scalar_add(x: Limb, y: Limb) -> (Limb, bool) {
-    x.overflowing_add(y)
-}
-
-/// Multiply two small integers (with carry) (and return the overflow contribution).
-///
-/// Returns the (low, high) components.
-#[inline(always)]
-pub fn scalar_mul(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
-    // Cannot overflow, as long as wide is 2x as wide. This is because
-    // the following is always true:
-    // `Wide::MAX - (Narrow::MAX * Narrow::MAX) >= Narrow::MAX`
-    let z: Wide = (x as Wide) * (y as Wide) + (carry as Wide);
-    (z as Limb, (z >> LIMB_BITS) as Limb)
-}
-
 // SMALL
 // -----
 
@@ -1376,93 +1357,3 @@ pub const fn split_radix(radix: u32) -> (u32, u32) {
         _ => (0, 0),
     }
 }
-
-// LIMB
-// ----
-
-//  Type for a single limb of the big integer.
-//
-//  A limb is analogous to a digit in base10, except, it stores 32-bit
-//  or 64-bit numbers instead. We want types where 64-bit multiplication
-//  is well-supported by the architecture, rather than emulated in 3
-//  instructions. The quickest way to check this support is using a
-//  cross-compiler for numerous architectures, along with the following
-//  source file and command:
-//
-//  Compile with `gcc main.c -c -S -O3 -masm=intel`
-//
-//  And the source code is:
-//  ```text
-//  #include <stdint.h>
-//
-//  struct i128 {
-//      uint64_t hi;
-//      uint64_t lo;
-//  };
-//
-//  // Type your code here, or load an example.
-//  struct i128 square(uint64_t x, uint64_t y) {
-//      __int128 prod = (__int128)x * (__int128)y;
-//      struct i128 z;
-//      z.hi = (uint64_t)(prod >> 64);
-//      z.lo = (uint64_t)prod;
-//      return z;
-//  }
-//  ```
-//
-//  If the result contains `call __multi3`, then the multiplication
-//  is emulated by the compiler. Otherwise, it's natively supported.
-//
-//  This should be all-known 64-bit platforms supported by Rust.
-//      https://forge.rust-lang.org/platform-support.html
-//
-//  # Supported
-//
-//  Platforms where native 128-bit multiplication is explicitly supported:
-//      - x86_64 (Supported via `MUL`).
-//      - mips64 (Supported via `DMULTU`, which `HI` and `LO` can be read-from).
-//      - s390x (Supported via `MLGR`).
-//
-//  # Efficient
-//
-//  Platforms where native 64-bit multiplication is supported and
-//  you can extract hi-lo for 64-bit multiplications.
-//      - aarch64 (Requires `UMULH` and `MUL` to capture high and low bits).
-//      - powerpc64 (Requires `MULHDU` and `MULLD` to capture high and low bits).
-//      - riscv64 (Requires `MUL` and `MULH` to capture high and low bits).
-//
-//  # Unsupported
-//
-//  Platforms where native 128-bit multiplication is not supported,
-//  requiring software emulation.
-//      sparc64 (`UMUL` only supports double-word arguments).
-//      sparcv9 (Same as sparc64).
-//
-//  These tests are run via `xcross`, my own library for C cross-compiling,
-//  which supports numerous targets (far in excess of Rust's tier 1 support,
-//  or rust-embedded/cross's list). xcross may be found here:
-//      https://github.com/Alexhuszagh/xcross
-//
-//  To compile for the given target, run:
-//      `xcross gcc main.c -c -S -O3 --target $target`
-//
-//  All 32-bit architectures inherently do not have support. That means
-//  we can essentially look for 64-bit architectures that are not SPARC.
-
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type Limb = u64;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type Wide = u128;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub type SignedWide = i128;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
-pub const LIMB_BITS: usize = 64;
-
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type Limb = u32;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type Wide = u64;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub type SignedWide = i64;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
-pub const LIMB_BITS: usize = 32;
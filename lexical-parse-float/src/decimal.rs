@@ -0,0 +1,191 @@
+//! Convert the tokenized significant digits to an exact decimal.
+//!
+//! Unlike floats, no algorithm is required: the significant digits and
+//! exponent from the tokenized number already represent the decimal
+//! exactly. Only numbers with more significant digits than fit in the
+//! coefficient must process the digits again, to round the digits that
+//! do not fit.
+
+#![doc(hidden)]
+
+use crate::number::Number;
+use crate::options::DecimalRounding;
+use lexical_util::decimal::Decimal;
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::iterator::{AsBytes, BytesIter};
+
+/// Accumulated significant digits, with the truncated digits for rounding.
+///
+/// The value is `(coefficient + 0.ts) * 10^exponent`, for the first
+/// truncated digit `t`, and any further non-zero truncated digits `s`.
+#[derive(Default)]
+pub struct Digits {
+    /// The first significant digits, up to `Decimal::MAX_DIGITS`.
+    pub coefficient: u128,
+    /// The exponent of the last digit in the coefficient.
+    pub exponent: i64,
+    /// The first truncated digit.
+    pub truncated: u32,
+    /// If any truncated digit after the first is non-zero.
+    pub sticky: bool,
+    /// The number of significant digits, including truncated digits.
+    count: usize,
+}
+
+impl Digits {
+    /// Add a significant digit.
+    #[inline(always)]
+    fn add(&mut self, digit: u32) {
+        if self.count < Decimal::MAX_DIGITS {
+            self.coefficient = self.coefficient * 10 + digit as u128;
+        } else if self.count == Decimal::MAX_DIGITS {
+            self.truncated = digit;
+        } else {
+            self.sticky |= digit != 0;
+        }
+        self.count += 1;
+    }
+
+    /// Truncate the last `count` digits of the coefficient.
+    #[inline]
+    pub fn shift_right(&mut self, count: i64) {
+        self.exponent += count;
+        if count > Decimal::MAX_DIGITS as i64 {
+            // All the digits are truncated.
+            self.sticky |= self.coefficient != 0 || self.truncated != 0;
+            self.coefficient = 0;
+            self.truncated = 0;
+        } else {
+            for _ in 0..count {
+                self.sticky |= self.truncated != 0;
+                self.truncated = (self.coefficient % 10) as u32;
+                self.coefficient /= 10;
+            }
+        }
+    }
+
+    /// Multiply the value by 2.
+    ///
+    /// The truncated digit is doubled, so this is exact unless digits
+    /// after the first truncated digit are non-zero, which could carry
+    /// into the truncated digit.
+    #[inline]
+    pub fn double(&mut self) {
+        let truncated = self.truncated * 2;
+        self.coefficient = self.coefficient * 2 + (truncated / 10) as u128;
+        self.truncated = truncated % 10;
+        if self.coefficient >= 10u128.pow(Decimal::MAX_DIGITS as u32) {
+            self.shift_right(1);
+        }
+    }
+
+    /// Round the coefficient, removing the truncated digits.
+    #[inline]
+    pub fn round(&mut self, is_negative: bool, rounding: DecimalRounding) {
+        let is_odd = self.coefficient % 2 == 1;
+        if rounding.round_up(is_negative, is_odd, self.truncated, self.sticky) {
+            // Cannot overflow, since `10^38 < 2^127`.
+            self.coefficient += 1;
+        }
+        self.truncated = 0;
+        self.sticky = false;
+    }
+}
+
+impl DecimalRounding {
+    /// Determine if the truncated coefficient must be rounded up.
+    ///
+    /// * `is_negative` - If the decimal is negative.
+    /// * `is_odd`      - If the last digit of the truncated coefficient is odd.
+    /// * `truncated`   - The first truncated digit.
+    /// * `sticky`      - If any truncated digit after the first is non-zero.
+    #[inline]
+    pub fn round_up(self, is_negative: bool, is_odd: bool, truncated: u32, sticky: bool) -> bool {
+        let is_above = truncated > 5 || (truncated == 5 && sticky);
+        let is_exact = truncated == 0 && !sticky;
+        match self {
+            Self::HalfEven => is_above || (truncated == 5 && is_odd),
+            Self::HalfUp => truncated >= 5,
+            Self::HalfDown => is_above,
+            Self::Up => !is_exact,
+            Self::Down => false,
+            Self::Ceiling => !is_negative && !is_exact,
+            Self::Floor => is_negative && !is_exact,
+        }
+    }
+}
+
+/// Process all the significant digits, which do not fit in a `u64`.
+#[inline]
+fn parse_digits<const FORMAT: u128>(num: &Number) -> Digits {
+    let mut digits = Digits::default();
+    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    for &c in integer_iter {
+        digits.add(char_to_valid_digit_const(c, 10));
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction_iter = fraction.fraction_iter();
+        if digits.count == 0 {
+            fraction_iter.skip_zeros();
+        }
+        for &c in fraction_iter {
+            digits.add(char_to_valid_digit_const(c, 10));
+        }
+    }
+    digits
+}
+
+/// Get the first significant digits of a parsed, non-special number.
+pub fn to_digits<const FORMAT: u128>(num: &Number) -> Digits {
+    if !num.many_digits {
+        // The mantissa has all the significant digits.
+        Digits {
+            coefficient: num.mantissa as u128,
+            exponent: num.exponent,
+            ..Digits::default()
+        }
+    } else {
+        // The value is `0.d1d2d3... * 10^(sci + 1)`, for the significant
+        // digits `d1d2d3...`, so we only need to keep the first digits.
+        let mut sci = num.exponent;
+        let mut mantissa = num.mantissa;
+        while mantissa >= 10 {
+            mantissa /= 10;
+            sci += 1;
+        }
+        let mut digits = parse_digits::<FORMAT>(num);
+        let count = digits.count.min(Decimal::MAX_DIGITS);
+        digits.exponent = sci + 1 - count as i64;
+        digits
+    }
+}
+
+/// Convert a parsed, non-special number to an exact decimal.
+///
+/// The decimal is then multiplied by `2^shift`, for binary magnitude
+/// suffixes. Returns `None` if the scale does not fit in an `i32`, or
+/// the shifted coefficient does not fit in an `i128`.
+pub fn to_decimal<const FORMAT: u128>(
+    num: Number,
+    shift: u32,
+    rounding: DecimalRounding,
+) -> Option<Decimal> {
+    let mut digits = to_digits::<FORMAT>(&num);
+    digits.round(num.is_negative, rounding);
+    let mut coefficient = digits.coefficient;
+    let scale = -digits.exponent;
+    for _ in 0..shift {
+        coefficient = coefficient.checked_mul(2)?;
+    }
+    if coefficient > i128::MAX as u128 || scale < i32::MIN as i64 || scale > i32::MAX as i64 {
+        return None;
+    }
+    Some(match num.is_negative {
+        true if coefficient == 0 => Decimal::negative_zero(scale as i32),
+        true => Decimal::new(-(coefficient as i128), scale as i32),
+        false => Decimal::new(coefficient as i128, scale as i32),
+    })
+}
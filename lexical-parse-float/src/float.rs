@@ -11,6 +11,8 @@ use crate::limits::{ExactFloat, MaxDigits};
 #[cfg(not(feature = "compact"))]
 use crate::table::{get_small_f32_power, get_small_f64_power, get_small_int_power};
 use lexical_util::extended_float::ExtendedFloat;
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 use lexical_util::num::{AsCast, Float};
 
 /// Alias with ~80 bits of precision, 64 for the mantissa and 16 for exponent.
@@ -94,6 +96,24 @@ impl RawFloat for f64 {
     }
 }
 
+// The fast-path powers for the 16-bit floats are exact in `f32`, and are
+// exactly representable, so convert from the `f32` powers.
+#[cfg(feature = "half")]
+macro_rules! half_raw_float_impl {
+    ($($t:ident)*) => ($(
+        impl RawFloat for $t {
+            #[inline(always)]
+            unsafe fn pow_fast_path(exponent: usize, radix: u32) -> Self {
+                // SAFETY: safe as long as the exponent is smaller than the radix table.
+                $t::from_f32(unsafe { f32::pow_fast_path(exponent, radix) })
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "half")]
+half_raw_float_impl! { f16 bf16 }
+
 /// Helper trait to add more float characteristics for the Eisel-Lemire algorithm.
 pub trait LemireFloat: RawFloat {
     // Round-to-even only happens for negative values of q
@@ -143,6 +163,24 @@ impl LemireFloat for f64 {
     const LARGEST_POWER_OF_TEN: i32 = 308;
 }
 
+#[cfg(feature = "half")]
+impl LemireFloat for f16 {
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -22;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 5;
+    const MINIMUM_EXPONENT: i32 = -15;
+    const SMALLEST_POWER_OF_TEN: i32 = -27;
+    const LARGEST_POWER_OF_TEN: i32 = 4;
+}
+
+#[cfg(feature = "half")]
+impl LemireFloat for bf16 {
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = -24;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 3;
+    const MINIMUM_EXPONENT: i32 = -127;
+    const SMALLEST_POWER_OF_TEN: i32 = -60;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
+}
+
 #[inline(always)]
 #[cfg(all(feature = "std", feature = "compact"))]
 pub fn powf(x: f32, y: f32) -> f32 {
@@ -12,21 +12,24 @@ use crate::shared;
 use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 
 /// Ensure truncation of digits doesn't affect our computation, by doing 2 passes.
+///
+/// The float is scaled by `2^binary_exp` before rounding.
 #[inline]
-pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
+pub fn lemire<F: LemireFloat>(num: &Number, binary_exp: u32, lossy: bool) -> ExtendedFloat80 {
     // If significant digits were truncated, then we can have rounding error
     // only if `mantissa + 1` produces a different result. We also avoid
     // redundantly using the Eisel-Lemire algorithm if it was unable to
     // correctly round on the first pass.
-    let mut fp = compute_float::<F>(num.exponent, num.mantissa, lossy);
+    let mut fp = compute_float::<F>(num.exponent, num.mantissa, binary_exp, lossy);
     if !lossy
         && num.many_digits
         && fp.exp >= 0
-        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, false)
+        && fp != compute_float::<F>(num.exponent, num.mantissa + 1, binary_exp, false)
     {
         // Need to re-calculate, since the previous values are rounded
         // when the slow path algorithm expects a normalized extended float.
         fp = compute_error::<F>(num.exponent, num.mantissa);
+        fp.exp += binary_exp as i32;
     }
     fp
 }
@@ -49,7 +52,16 @@ pub fn lemire<F: LemireFloat>(num: &Number, lossy: bool) -> ExtendedFloat80 {
 /// at a Gigabyte per Second" in section 5, "Fast Algorithm", and
 /// section 6, "Exact Numbers And Ties", available online:
 /// <https://arxiv.org/abs/2101.11408.pdf>.
-pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> ExtendedFloat80 {
+///
+/// The binary exponent scales the float by `2^binary_exp` before it is
+/// rounded, so values scaled into or out of the denormal range are
+/// correctly rounded.
+pub fn compute_float<F: LemireFloat>(
+    q: i64,
+    mut w: u64,
+    binary_exp: u32,
+    lossy: bool,
+) -> ExtendedFloat80 {
     let fp_zero = ExtendedFloat80 {
         mant: 0,
         exp: 0,
@@ -60,7 +72,10 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
     };
 
     // Short-circuit if the value can only be a literal 0 or infinity.
-    if w == 0 || q < F::SMALLEST_POWER_OF_TEN as i64 {
+    // Since `2^10 > 10^3`, the binary exponent adds at least 3 non-zero
+    // powers of ten for every 10 powers of two.
+    let smallest_power_of_ten = F::SMALLEST_POWER_OF_TEN as i64 - binary_exp as i64 * 3 / 10;
+    if w == 0 || q < smallest_power_of_ten {
         return fp_zero;
     } else if q > F::LARGEST_POWER_OF_TEN as i64 {
         return fp_inf;
@@ -87,19 +102,32 @@ pub fn compute_float<F: LemireFloat>(q: i64, mut w: u64, lossy: bool) -> Extende
         // <https://arxiv.org/pdf/2101.11408.pdf#section.8>.
         let inside_safe_exponent = (q >= -27) && (q <= 55);
         if !inside_safe_exponent {
-            return compute_error_scaled::<F>(q, hi, lz);
+            let mut fp = compute_error_scaled::<F>(q, hi, lz);
+            fp.exp += binary_exp as i32;
+            return fp;
         }
     }
     let upperbit = (hi >> 63) as i32;
     let mut mantissa = hi >> (upperbit + 64 - F::MANTISSA_SIZE - 3);
-    let mut power2 = power(q as i32) + upperbit - lz - F::MINIMUM_EXPONENT;
+    let mut power2 = power(q as i32) + upperbit - lz - F::MINIMUM_EXPONENT + binary_exp as i32;
     if power2 <= 0 {
         if -power2 + 1 >= 64 {
             // Have more than 64 bits below the minimum exponent, must be 0.
             return fp_zero;
         }
         // Have a subnormal value.
-        mantissa >>= -power2 + 1;
+        let shift = -power2 + 1;
+        mantissa >>= shift;
+        // Need to handle rounding ties, like for normal values. Exactly
+        // halfway subnormals can only have few enough digits when `5^-q`
+        // fits in a 64-bit word, which only occurs for 16-bit floats.
+        if lo <= 1
+            && q >= -27
+            && mantissa & 3 == 1
+            && (mantissa << (upperbit + 64 - F::MANTISSA_SIZE - 3 + shift)) == hi
+        {
+            mantissa &= !1_u64;
+        }
         mantissa += mantissa & 1;
         mantissa >>= 1;
         power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
@@ -25,13 +25,15 @@
 //!
 //! # Features
 //!
-//! * `std` - Use the standard library.
+//! * `std` - Use the standard library, which is required for exact rationals.
 //! * `power-of-two` - Add support for parsing power-of-two integer strings.
 //! * `radix` - Add support for strings of any radix.
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
 //! * `nightly` - Enable assembly instructions to control FPU rounding modes.
+//! * `ieee-decimal` - Add support for parsing IEEE 754-2008 decimal floats.
+//! * `half` - Add support for parsing `f16` and `bf16` from `half`.
 //!
 //! `safe` has a fairly minimal impact, since all parsers are memory-safe
 //! by default except where unsafe functionality can trivially be proven
@@ -76,8 +78,10 @@ mod index;
 pub mod shared;
 
 pub mod bellerophon;
+pub mod bid;
 pub mod bigint;
 pub mod binary;
+pub mod decimal;
 pub mod float;
 pub mod fpu;
 pub mod lemire;
@@ -87,8 +91,11 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod rational;
 pub mod slow;
 pub mod table;
+pub mod token;
+pub mod validate;
 
 mod api;
 mod table_bellerophon_decimal;
@@ -103,8 +110,17 @@ mod table_small;
 // Re-exports
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{DecimalRounding, Options, OptionsBuilder, SuffixMode, WhitespaceMode};
+pub use self::token::{Suffix, Token};
+pub use self::validate::NumberKind;
+#[cfg(feature = "ieee-decimal")]
+pub use lexical_util::bid::{Decimal128, Decimal64};
+pub use lexical_util::decimal::Decimal;
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
+#[cfg(feature = "half")]
+pub use lexical_util::num::{bf16, f16};
 pub use lexical_util::options::ParseOptions;
+#[cfg(feature = "std")]
+pub use lexical_util::rational::Rational;
 pub use lexical_util::result::Result;
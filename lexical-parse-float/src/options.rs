@@ -4,11 +4,37 @@ use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
 use lexical_util::error::Error;
 use lexical_util::options::{self, ParseOptions};
 use lexical_util::result::Result;
+pub use lexical_util::suffix::SuffixMode;
+pub use lexical_util::whitespace::WhitespaceMode;
 use static_assertions::const_assert;
 
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Enumeration for how to round decimals with too many significant digits.
+///
+/// Parsed decimals keep at most [`Decimal::MAX_DIGITS`] significant
+/// digits, and the remaining digits are rounded using the rounding mode.
+///
+/// [`Decimal::MAX_DIGITS`]: lexical_util::decimal::Decimal::MAX_DIGITS
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DecimalRounding {
+    /// Round to the nearest value, with ties to the even digit.
+    HalfEven,
+    /// Round to the nearest value, with ties away from zero.
+    HalfUp,
+    /// Round to the nearest value, with ties toward zero.
+    HalfDown,
+    /// Round away from zero.
+    Up,
+    /// Round toward zero, truncating the remaining digits.
+    Down,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+}
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OptionsBuilder {
@@ -25,6 +51,14 @@ pub struct OptionsBuilder {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Characters to skip as whitespace before the number.
+    leading_whitespace: WhitespaceMode,
+    /// Characters to skip as whitespace after the number.
+    trailing_whitespace: WhitespaceMode,
+    /// Magnitude suffixes to parse after the number.
+    suffix: SuffixMode,
+    /// Rounding mode for decimals with too many significant digits.
+    decimal_rounding: DecimalRounding,
 }
 
 impl OptionsBuilder {
@@ -38,6 +72,10 @@ impl OptionsBuilder {
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
             infinity_string: Some(b"infinity"),
+            leading_whitespace: WhitespaceMode::None,
+            trailing_whitespace: WhitespaceMode::None,
+            suffix: SuffixMode::None,
+            decimal_rounding: DecimalRounding::HalfEven,
         }
     }
 
@@ -79,6 +117,30 @@ impl OptionsBuilder {
         self.infinity_string
     }
 
+    /// Get the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn get_leading_whitespace(&self) -> WhitespaceMode {
+        self.leading_whitespace
+    }
+
+    /// Get the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn get_trailing_whitespace(&self) -> WhitespaceMode {
+        self.trailing_whitespace
+    }
+
+    /// Get the magnitude suffixes to parse after the number.
+    #[inline(always)]
+    pub const fn get_suffix(&self) -> SuffixMode {
+        self.suffix
+    }
+
+    /// Get the rounding mode for decimals with too many significant digits.
+    #[inline(always)]
+    pub const fn get_decimal_rounding(&self) -> DecimalRounding {
+        self.decimal_rounding
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -123,6 +185,37 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(mut self, leading_whitespace: WhitespaceMode) -> Self {
+        self.leading_whitespace = leading_whitespace;
+        self
+    }
+
+    /// Set the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(mut self, trailing_whitespace: WhitespaceMode) -> Self {
+        self.trailing_whitespace = trailing_whitespace;
+        self
+    }
+
+    /// Set the magnitude suffixes to parse after the number.
+    ///
+    /// Suffixes are only parsed for decimal numbers, and are ignored
+    /// for any other radix.
+    #[inline(always)]
+    pub const fn suffix(mut self, suffix: SuffixMode) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Set the rounding mode for decimals with too many significant digits.
+    #[inline(always)]
+    pub const fn decimal_rounding(mut self, decimal_rounding: DecimalRounding) -> Self {
+        self.decimal_rounding = decimal_rounding;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -232,6 +325,10 @@ impl OptionsBuilder {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            leading_whitespace: self.leading_whitespace,
+            trailing_whitespace: self.trailing_whitespace,
+            suffix: self.suffix,
+            decimal_rounding: self.decimal_rounding,
         }
     }
 
@@ -329,6 +426,14 @@ pub struct Options {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// Characters to skip as whitespace before the number.
+    leading_whitespace: WhitespaceMode,
+    /// Characters to skip as whitespace after the number.
+    trailing_whitespace: WhitespaceMode,
+    /// Magnitude suffixes to parse after the number.
+    suffix: SuffixMode,
+    /// Rounding mode for decimals with too many significant digits.
+    decimal_rounding: DecimalRounding,
 }
 
 impl Options {
@@ -385,6 +490,30 @@ impl Options {
         self.infinity_string
     }
 
+    /// Get the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(&self) -> WhitespaceMode {
+        self.leading_whitespace
+    }
+
+    /// Get the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(&self) -> WhitespaceMode {
+        self.trailing_whitespace
+    }
+
+    /// Get the magnitude suffixes to parse after the number.
+    #[inline(always)]
+    pub const fn suffix(&self) -> SuffixMode {
+        self.suffix
+    }
+
+    /// Get the rounding mode for decimals with too many significant digits.
+    #[inline(always)]
+    pub const fn decimal_rounding(&self) -> DecimalRounding {
+        self.decimal_rounding
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -452,6 +581,46 @@ impl Options {
         self.infinity_string = infinity_string
     }
 
+    /// Set the characters to skip as whitespace before the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_leading_whitespace(&mut self, leading_whitespace: WhitespaceMode) {
+        self.leading_whitespace = leading_whitespace;
+    }
+
+    /// Set the characters to skip as whitespace after the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_trailing_whitespace(&mut self, trailing_whitespace: WhitespaceMode) {
+        self.trailing_whitespace = trailing_whitespace;
+    }
+
+    /// Set the magnitude suffixes to parse after the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_suffix(&mut self, suffix: SuffixMode) {
+        self.suffix = suffix;
+    }
+
+    /// Set the rounding mode for decimals with too many significant digits.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_decimal_rounding(&mut self, decimal_rounding: DecimalRounding) {
+        self.decimal_rounding = decimal_rounding;
+    }
+
     // BUILDERS
 
     /// Get OptionsBuilder as a static function.
@@ -470,6 +639,10 @@ impl Options {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            leading_whitespace: self.leading_whitespace,
+            trailing_whitespace: self.trailing_whitespace,
+            suffix: self.suffix,
+            decimal_rounding: self.decimal_rounding,
         }
     }
 }
@@ -536,6 +709,26 @@ pub const CARAT_EXPONENT: Options = unsafe {
 };
 const_assert!(CARAT_EXPONENT.is_valid());
 
+/// Number format that skips surrounding ASCII whitespace.
+#[rustfmt::skip]
+pub const ASCII_WHITESPACE: Options = unsafe {
+    Options::builder()
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build_unchecked()
+};
+const_assert!(ASCII_WHITESPACE.is_valid());
+
+/// Number format that skips surrounding Unicode whitespace.
+#[rustfmt::skip]
+pub const UNICODE_WHITESPACE: Options = unsafe {
+    Options::builder()
+        .leading_whitespace(WhitespaceMode::Unicode)
+        .trailing_whitespace(WhitespaceMode::Unicode)
+        .build_unchecked()
+};
+const_assert!(UNICODE_WHITESPACE.is_valid());
+
 /// Number format for a Rust literal floating-point number.
 #[rustfmt::skip]
 pub const RUST_LITERAL: Options = unsafe {
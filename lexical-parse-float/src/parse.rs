@@ -7,23 +7,38 @@
 
 #[cfg(any(feature = "compact", feature = "radix"))]
 use crate::bellerophon::bellerophon;
+#[cfg(feature = "ieee-decimal")]
+use crate::bid::to_ieee_decimal;
 #[cfg(feature = "power-of-two")]
 use crate::binary::{binary, slow_binary};
+use crate::decimal::to_decimal;
 use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
 use crate::number::Number;
 use crate::options::Options;
+#[cfg(feature = "std")]
+use crate::rational::to_rational;
 use crate::shared;
 use crate::slow::slow_radix;
+use crate::token::Token;
+use crate::validate::NumberKind;
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
+#[cfg(feature = "ieee-decimal")]
+use lexical_util::bid::IeeeDecimal;
+use lexical_util::decimal::Decimal;
 use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
 use lexical_util::error::Error;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, Bytes, BytesIter};
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
+#[cfg(feature = "std")]
+use lexical_util::rational::Rational;
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
+use lexical_util::suffix::{parse_suffix, Suffix};
 
 // API
 // ---
@@ -87,6 +102,9 @@ macro_rules! parse_float_impl {
 
 parse_float_impl! { f32 f64 }
 
+#[cfg(feature = "half")]
+parse_float_impl! { f16 bf16 }
+
 // PARSE
 // -----
 
@@ -179,26 +197,12 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let num = parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special);
-    // Try the fast-path algorithm.
-    if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
-    }
-    // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
-
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
-    if fp.exp < 0 {
-        debug_assert!(!options.lossy());
-        // Undo the invalid extended float biasing.
-        fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
-    }
-
-    // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    let (num, shift) = if has_suffix::<FORMAT>(options) {
+        parse_number!(FORMAT, byte, is_negative, options, parse_suffixed_number, parse_special)
+    } else {
+        (parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_special), 0)
+    };
+    Ok(to_float::<F, FORMAT>(num, shift, options.lossy()))
 }
 
 /// Parse a float from bytes using a partial parser.
@@ -216,33 +220,372 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     }
 
     // Parse our a small representation of our number.
-    let (num, count) = parse_number!(
-        FORMAT,
-        byte,
-        is_negative,
-        options,
-        parse_partial_number,
-        parse_partial_special
-    );
+    let (num, count, shift) = if has_suffix::<FORMAT>(options) {
+        parse_number!(
+            FORMAT,
+            byte,
+            is_negative,
+            options,
+            parse_partial_suffixed_number,
+            parse_partial_special
+        )
+    } else {
+        let (num, count) = parse_number!(
+            FORMAT,
+            byte,
+            is_negative,
+            options,
+            parse_partial_number,
+            parse_partial_special
+        );
+        (num, count, 0)
+    };
+    Ok((to_float::<F, FORMAT>(num, shift, options.lossy()), count))
+}
+
+/// Parse an exact decimal from bytes using a complete parser.
+///
+/// Overflow is reported at the index of the last byte, if the scale does
+/// not fit in an `i32`, or the coefficient does not fit in an `i128`.
+pub fn parse_decimal_complete<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<Decimal> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values cannot be represented as decimals.
+    let (num, shift) = if has_suffix::<FORMAT>(options) {
+        parse_suffixed_number::<FORMAT>(byte, is_negative, options)?
+    } else {
+        (parse_number::<FORMAT>(byte, is_negative, options)?, 0)
+    };
+    to_decimal::<FORMAT>(num, shift, options.decimal_rounding())
+        .ok_or(Error::Overflow(bytes.len() - 1))
+}
+
+/// Parse an exact decimal from bytes using a partial parser.
+///
+/// Overflow is reported at the index of the last processed byte, if the
+/// scale does not fit in an `i32`, or the coefficient does not fit in an
+/// `i128`.
+pub fn parse_decimal_partial<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(Decimal, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values cannot be represented as decimals.
+    let (num, count, shift) = if has_suffix::<FORMAT>(options) {
+        parse_partial_suffixed_number::<FORMAT>(byte, is_negative, options)?
+    } else {
+        let (num, count) = parse_partial_number::<FORMAT>(byte, is_negative, options)?;
+        (num, count, 0)
+    };
+    let decimal = to_decimal::<FORMAT>(num, shift, options.decimal_rounding())
+        .ok_or(Error::Overflow(count - 1))?;
+    Ok((decimal, count))
+}
+
+/// Parse an exact rational from bytes using a complete parser.
+///
+/// Overflow is reported at the index of the last byte, if the magnitude
+/// of the exponent is at least `2^28`.
+#[cfg(feature = "std")]
+pub fn parse_rational_complete<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<Rational> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values cannot be represented as rationals.
+    let (num, shift) = if has_suffix::<FORMAT>(options) {
+        parse_suffixed_number::<FORMAT>(byte, is_negative, options)?
+    } else {
+        (parse_number::<FORMAT>(byte, is_negative, options)?, 0)
+    };
+    to_rational::<FORMAT>(num, shift).ok_or(Error::Overflow(bytes.len() - 1))
+}
+
+/// Parse an exact rational from bytes using a partial parser.
+///
+/// Overflow is reported at the index of the last processed byte, if the
+/// magnitude of the exponent is at least `2^28`.
+#[cfg(feature = "std")]
+pub fn parse_rational_partial<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(Rational, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    // Special values cannot be represented as rationals.
+    let (num, count, shift) = if has_suffix::<FORMAT>(options) {
+        parse_partial_suffixed_number::<FORMAT>(byte, is_negative, options)?
+    } else {
+        let (num, count) = parse_partial_number::<FORMAT>(byte, is_negative, options)?;
+        (num, count, 0)
+    };
+    let rational = to_rational::<FORMAT>(num, shift).ok_or(Error::Overflow(count - 1))?;
+    Ok((rational, count))
+}
+
+/// Parse a decimal float from bytes using a complete parser.
+#[cfg(feature = "ieee-decimal")]
+pub fn parse_ieee_complete<D: IeeeDecimal, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<D> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    let (num, shift) = if has_suffix::<FORMAT>(options) {
+        parse_number!(FORMAT, byte, is_negative, options, parse_suffixed_number, parse_ieee_special)
+    } else {
+        (parse_number!(FORMAT, byte, is_negative, options, parse_number, parse_ieee_special), 0)
+    };
+    Ok(to_ieee_decimal::<D, FORMAT>(num, shift, options.decimal_rounding()))
+}
+
+/// Parse a decimal float from bytes using a partial parser.
+#[cfg(feature = "ieee-decimal")]
+pub fn parse_ieee_partial<D: IeeeDecimal, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(D, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    let (num, count, shift) = if has_suffix::<FORMAT>(options) {
+        parse_number!(
+            FORMAT,
+            byte,
+            is_negative,
+            options,
+            parse_partial_suffixed_number,
+            parse_partial_ieee_special
+        )
+    } else {
+        let (num, count) = parse_number!(
+            FORMAT,
+            byte,
+            is_negative,
+            options,
+            parse_partial_number,
+            parse_partial_ieee_special
+        );
+        (num, count, 0)
+    };
+    Ok((to_ieee_decimal::<D, FORMAT>(num, shift, options.decimal_rounding()), count))
+}
+
+/// Convert a parsed, non-special number, scaled by `2^binary_exp`, to a native float.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn to_float<F: LemireFloat, const FORMAT: u128>(num: Number, binary_exp: u32, lossy: bool) -> F {
     // Try the fast-path algorithm.
     if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+        return scale_binary(value, binary_exp);
     }
     // Now try the moderate path algorithm.
-    let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
+    let mut fp = moderate_path::<F, FORMAT>(&num, binary_exp, lossy);
 
     // Unable to correctly round the float using the fast or moderate algorithms.
     // Fallback to a slower, but always correct algorithm. If we have
     // lossy, we can't be here.
     if fp.exp < 0 {
-        debug_assert!(!options.lossy());
+        debug_assert!(!lossy);
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        fp = slow_path::<F, FORMAT>(num, fp, binary_exp);
     }
 
     // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    let is_negative = num.is_negative;
+    to_native!(F, fp, is_negative)
+}
+
+// VALIDATE
+// --------
+
+/// Validate a float from bytes using a complete parser, without computing its value.
+#[inline]
+pub fn validate_complete<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<NumberKind> {
+    tokenize_complete::<FORMAT>(bytes, options).map(|token| token.kind)
+}
+
+/// Validate a float from bytes using a partial parser, without computing its value.
+#[inline]
+pub fn validate_partial<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(NumberKind, usize)> {
+    tokenize_partial::<FORMAT>(bytes, options).map(|(token, count)| (token.kind, count))
+}
+
+// TOKENIZE
+// --------
+
+/// Decompose a float from bytes using a complete parser, without computing its value.
+#[inline]
+pub fn tokenize_complete<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<Token<'a>> {
+    check_radix!(FORMAT);
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    let start = byte.cursor();
+    let length = byte.length();
+    let error = match tokenize_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok((num, count, exponent, suffix)) if count == length => {
+            return Ok(to_token::<FORMAT>(num, exponent, suffix, bytes));
+        },
+        Ok((_, count, _, _)) => Error::InvalidDigit(count),
+        Err(error) => error,
+    };
+    match parse_positive_special::<f64, FORMAT>(byte, options) {
+        Some((_, count)) if count == length => Ok(special_token(is_negative, &bytes[start..])),
+        _ => Err(error),
+    }
+}
+
+/// Decompose a float from bytes using a partial parser, without computing its value.
+#[inline]
+pub fn tokenize_partial<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(Token<'a>, usize)> {
+    check_radix!(FORMAT);
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let (is_negative, shift) = parse_mantissa_sign!(byte, format);
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { byte.step_by_unchecked(shift) };
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+
+    let start = byte.cursor();
+    match tokenize_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok((num, count, exponent, suffix)) => {
+            Ok((to_token::<FORMAT>(num, exponent, suffix, &bytes[..count]), count))
+        },
+        Err(error) => match parse_positive_special::<f64, FORMAT>(byte, options) {
+            Some((_, count)) => Ok((special_token(is_negative, &bytes[start..count]), count)),
+            None => Err(error),
+        },
+    }
+}
+
+/// Parse the non-special number of a token, with any magnitude suffix.
+#[inline(always)]
+#[allow(clippy::type_complexity)]
+fn tokenize_number<'a, const FORMAT: u128>(
+    byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize, Option<(&'a [u8], i64)>, Option<Suffix>)> {
+    if has_suffix::<FORMAT>(options) {
+        parse_partial_suffixed_number_exponent::<FORMAT>(byte, is_negative, options)
+    } else {
+        let (num, count, exponent) =
+            parse_partial_number_exponent::<FORMAT>(byte, is_negative, options)?;
+        Ok((num, count, exponent, None))
+    }
+}
+
+/// Create the token for a parsed, non-special number.
+///
+/// The number is a float if it has a decimal point, an exponent, or an
+/// SI suffix smaller than 1, like `m`.
+#[inline]
+fn to_token<'a, const FORMAT: u128>(
+    num: Number<'a>,
+    exponent: Option<(&'a [u8], i64)>,
+    suffix: Option<Suffix>,
+    token: &[u8],
+) -> Token<'a> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let separator = format.digit_separator();
+    let is_fraction = matches!(suffix, Some(Suffix::Decimal(exp)) if exp < 0);
+    let kind = if num.fraction.is_some() || exponent.is_some() || is_fraction {
+        NumberKind::Float
+    } else {
+        NumberKind::Integer
+    };
+    Token {
+        kind,
+        is_negative: num.is_negative,
+        integer: num.integer,
+        fraction: num.fraction,
+        exponent: exponent.map(|(digits, _)| digits),
+        explicit_exponent: exponent.map_or(0, |(_, value)| value),
+        suffix,
+        has_separators: separator != 0 && token.contains(&separator),
+    }
+}
+
+/// Create the token for a special value, from the raw special string.
+#[inline]
+fn special_token(is_negative: bool, special: &[u8]) -> Token<'_> {
+    Token {
+        kind: NumberKind::Special,
+        is_negative,
+        integer: special,
+        fraction: None,
+        exponent: None,
+        explicit_exponent: 0,
+        suffix: None,
+        has_separators: false,
+    }
 }
 
 // PATHS
@@ -250,9 +593,13 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
 
 /// Wrapper for different moderate-path algorithms.
 /// A return exponent of `-1` indicates an invalid value.
+///
+/// The float is scaled by `2^binary_exp`, which is always 0 for
+/// power-of-two radixes, since only decimal strings have suffixes.
 #[inline]
 pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
     num: &Number,
+    binary_exp: u32,
     lossy: bool,
 ) -> ExtendedFloat80 {
     #[cfg(feature = "compact")]
@@ -264,13 +611,13 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
                 // Implement the power-of-two backends.
                 binary::<F, FORMAT>(num, lossy)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, FORMAT>(num, binary_exp, lossy)
             }
         }
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            bellerophon::<F, FORMAT>(num, lossy)
+            bellerophon::<F, FORMAT>(num, binary_exp, lossy)
         }
     }
 
@@ -281,12 +628,12 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let format = NumberFormat::<{ FORMAT }> {};
             let radix = format.mantissa_radix();
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, binary_exp, lossy)
             } else if is_power_two!(radix) {
                 // Implement the power-of-two backends.
                 binary::<F, FORMAT>(num, lossy)
             } else {
-                bellerophon::<F, FORMAT>(num, lossy)
+                bellerophon::<F, FORMAT>(num, binary_exp, lossy)
             }
         }
 
@@ -296,7 +643,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
             let radix = format.mantissa_radix();
             debug_assert!(matches!(radix, 2 | 4 | 8 | 10 | 16 | 32));
             if radix == 10 {
-                lemire::<F>(num, lossy)
+                lemire::<F>(num, binary_exp, lossy)
             } else {
                 // Implement the power-of-two backends.
                 binary::<F, FORMAT>(num, lossy)
@@ -305,7 +652,7 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
         #[cfg(not(feature = "power-of-two"))]
         {
-            lemire::<F>(num, lossy)
+            lemire::<F>(num, binary_exp, lossy)
         }
     }
 }
@@ -316,10 +663,11 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    binary_exp: u32,
 ) -> ExtendedFloat80 {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_radix::<F, FORMAT>(num, fp, binary_exp)
     }
 
     #[cfg(feature = "power-of-two")]
@@ -328,7 +676,7 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
         if is_power_two!(format.mantissa_radix()) {
             slow_binary::<F, FORMAT>(num)
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_radix::<F, FORMAT>(num, fp, binary_exp)
         }
     }
 }
@@ -341,12 +689,29 @@ pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
 /// This creates a representation of the float as the
 /// significant digits and the decimal exponent.
 #[inline]
-#[allow(clippy::collapsible_if)]
 pub fn parse_partial_number<'a, const FORMAT: u128>(
-    mut byte: Bytes<'a, FORMAT>,
+    byte: Bytes<'a, FORMAT>,
     is_negative: bool,
     options: &Options,
 ) -> Result<(Number<'a>, usize)> {
+    let (num, count, _) = parse_partial_number_exponent::<FORMAT>(byte, is_negative, options)?;
+    Ok((num, count))
+}
+
+/// Parse a partial, non-special floating point number, and its exponent.
+///
+/// Returns the number, the number of processed bytes, and the raw
+/// exponent digits with the value of the explicit exponent, if the
+/// number has an exponent. Exponents too large to be meaningful for
+/// any float are saturated.
+#[inline(always)]
+#[allow(clippy::collapsible_if)]
+#[allow(clippy::type_complexity)]
+pub fn parse_partial_number_exponent<'a, const FORMAT: u128>(
+    mut byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize, Option<(&'a [u8], i64)>)> {
     //  NOTE:
     //      There are no satisfactory optimizations to reduce the number
     //      of multiplications for very long input strings, but this will
@@ -385,9 +750,11 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     // INTEGER
 
     // Check to see if we have a valid base prefix.
+    // Only consume the leading zero if it's part of the base prefix.
     let base_prefix = format.base_prefix();
     let mut is_prefix = false;
-    let mut iter = byte.integer_iter();
+    let mut prefix = byte.clone();
+    let mut iter = prefix.integer_iter();
     if cfg!(feature = "format") && base_prefix != 0 && iter.peek() == Some(&b'0') {
         // SAFETY: safe since we `byte.len() >= 1`.
         unsafe { iter.step_unchecked() };
@@ -408,6 +775,9 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             }
         }
     }
+    if is_prefix {
+        byte = prefix;
+    }
 
     // Parse our integral digits.
     let mut mantissa = 0_u64;
@@ -422,9 +792,11 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         return Err(Error::EmptyInteger(byte.cursor()));
     }
 
-    // Store the integer digits for slow-path algorithms.
-    // SAFETY: safe, since `n_digits <= start.as_slice().len()`.
-    let integer_digits = unsafe { start.as_slice().get_unchecked(..n_digits) };
+    // Store the integer digits for slow-path algorithms. This includes
+    // any digit separators, so use the bytes processed, not the digits.
+    // SAFETY: safe, since `byte.cursor() - start.cursor() <= start.as_slice().len()`.
+    let n_bytes = byte.cursor() - start.cursor();
+    let integer_digits = unsafe { start.as_slice().get_unchecked(..n_bytes) };
 
     // Check if integer leading zeros are disabled.
     if cfg!(feature = "format") && !is_prefix && format.no_float_leading_zeros() {
@@ -453,8 +825,9 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
         n_after_dot = byte.current_count() - before.current_count();
 
         // Store the fraction digits for slow-path algorithms.
-        // SAFETY: safe, since `n_after_dot <= before.as_slice().len()`.
-        fraction_digits = Some(unsafe { before.as_slice().get_unchecked(..n_after_dot) });
+        // SAFETY: safe, since `byte.cursor() - before.cursor() <= before.as_slice().len()`.
+        let n_bytes = byte.cursor() - before.cursor();
+        fraction_digits = Some(unsafe { before.as_slice().get_unchecked(..n_bytes) });
 
         // Calculate the implicit exponent: the number of digits after the dot.
         implicit_exponent = -(n_after_dot as i64);
@@ -478,6 +851,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
 
     // Handle scientific notation.
     let mut explicit_exponent = 0_i64;
+    let mut exponent_digits = None;
     let is_exponent = if cfg!(feature = "format") && format.case_sensitive_exponent() {
         byte.first_is(exponent_character)
     } else {
@@ -504,16 +878,19 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             return Err(Error::MissingExponentSign(byte.cursor()));
         }
 
-        let before = byte.current_count();
+        let before = byte.clone();
         parse_digits::<_, _, FORMAT>(byte.exponent_iter(), |digit| {
             if explicit_exponent < 0x10000000 {
                 explicit_exponent *= format.radix() as i64;
                 explicit_exponent += digit as i64;
             }
         });
-        if format.required_exponent_digits() && byte.current_count() - before == 0 {
+        if format.required_exponent_digits() && byte.current_count() - before.current_count() == 0 {
             return Err(Error::EmptyExponent(byte.cursor()));
         }
+        // SAFETY: safe, since `byte.cursor() - before.cursor() <= before.as_slice().len()`.
+        let n_bytes = byte.cursor() - before.cursor();
+        exponent_digits = Some(unsafe { before.as_slice().get_unchecked(..n_bytes) });
         // Handle our sign, and get the explicit part of the exponent.
         explicit_exponent = if is_negative {
             -explicit_exponent
@@ -550,6 +927,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
     if cfg!(feature = "format") && !format.required_mantissa_digits() && n_digits == 0 {
         exponent = 0;
     }
+    let exponent_digits = exponent_digits.map(|digits| (digits, explicit_exponent));
     if n_digits <= step {
         return Ok((
             Number {
@@ -561,6 +939,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
                 fraction: fraction_digits,
             },
             end,
+            exponent_digits,
         ));
     }
 
@@ -634,6 +1013,7 @@ pub fn parse_partial_number<'a, const FORMAT: u128>(
             fraction: fraction_digits,
         },
         end,
+        exponent_digits,
     ))
 }
 
@@ -653,6 +1033,119 @@ pub fn parse_number<'a, const FORMAT: u128>(
     }
 }
 
+// SUFFIX
+// ------
+
+/// Determine if magnitude suffixes are parsed after the number.
+///
+/// Suffixes are only parsed for decimal floats, so SI prefixes can be
+/// folded into the exponent.
+#[inline(always)]
+fn has_suffix<const FORMAT: u128>(options: &Options) -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    !options.suffix().is_none() && format.mantissa_radix() == 10 && format.exponent_base() == 10
+}
+
+/// Scale a float from the fast path by `2^exp`, where `exp` is a multiple of 10.
+///
+/// Floats from the fast path are never denormal, so this is exact unless
+/// the result overflows. Every other float has the power of 2 folded into
+/// its binary exponent before it is rounded.
+#[inline(always)]
+fn scale_binary<F: LemireFloat>(mut value: F, exp: u32) -> F {
+    for _ in 0..exp / 10 {
+        value *= F::as_cast(1024u32);
+    }
+    value
+}
+
+/// Parse a partial, non-special floating point number followed by an
+/// optional magnitude suffix.
+///
+/// SI prefixes are folded into the exponent before any algorithm runs,
+/// so `1.1k` is parsed exactly as `1.1e3`. IEC binary prefixes are
+/// returned as the power of 2 to scale the float by. Since `E` is both
+/// an exponent character and the prefix for `1e18`, an `E` without any
+/// exponent digits is parsed as a suffix.
+#[inline]
+pub fn parse_partial_suffixed_number<'a, const FORMAT: u128>(
+    byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize, u32)> {
+    let (mut num, count, _, suffix) =
+        parse_partial_suffixed_number_exponent::<FORMAT>(byte, is_negative, options)?;
+    let shift = match suffix {
+        Some(Suffix::Decimal(exp)) => {
+            num.exponent += exp as i64;
+            0
+        },
+        Some(Suffix::Binary(exp)) => exp,
+        None => 0,
+    };
+    Ok((num, count, shift))
+}
+
+/// Parse a partial, non-special floating point number followed by an
+/// optional magnitude suffix, its exponent, and the suffix.
+///
+/// Returns the number without the suffix applied, the number of processed
+/// bytes, the raw exponent digits with the value of the explicit exponent,
+/// and the suffix, if present.
+#[inline(always)]
+#[allow(clippy::type_complexity)]
+pub fn parse_partial_suffixed_number_exponent<'a, const FORMAT: u128>(
+    byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, usize, Option<(&'a [u8], i64)>, Option<Suffix>)> {
+    let mode = options.suffix();
+    let cursor = byte.cursor();
+    let slc = byte.as_slice();
+    let is_suffix = |index: usize| {
+        index > cursor
+            && slc[index - cursor - 1] == b'E'
+            && parse_suffix(&slc[index - cursor - 1..], mode).is_some()
+    };
+    let (num, count, exponent) =
+        match parse_partial_number_exponent::<FORMAT>(byte.clone(), is_negative, options) {
+            Err(Error::EmptyExponent(index)) if is_suffix(index) => {
+                // Re-parse the number without the suffix.
+                let digits = &slc[..index - cursor - 1];
+                let (num, count, exponent) = parse_partial_number_exponent::<FORMAT>(
+                    digits.bytes::<FORMAT>(),
+                    is_negative,
+                    options,
+                )
+                .map_err(|error| error.shift(cursor))?;
+                (num, count + cursor, exponent)
+            },
+            result => result?,
+        };
+
+    match parse_suffix(&slc[count - cursor..], mode) {
+        Some((suffix, length)) => Ok((num, count + length, exponent, Some(suffix))),
+        None => Ok((num, count, exponent, None)),
+    }
+}
+
+/// Parse a non-special floating point number followed by an optional
+/// magnitude suffix.
+#[inline]
+pub fn parse_suffixed_number<'a, const FORMAT: u128>(
+    byte: Bytes<'a, FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Result<(Number<'a>, u32)> {
+    let length = byte.length();
+    let (num, count, shift) = parse_partial_suffixed_number::<FORMAT>(byte, is_negative, options)?;
+    if count == length {
+        Ok((num, shift))
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
+
 // DIGITS
 // ------
 
@@ -825,6 +1318,44 @@ where
     Some((float, count))
 }
 
+/// Parse a partial representation of a special, non-finite decimal float.
+#[cfg(feature = "ieee-decimal")]
+#[inline]
+pub fn parse_partial_ieee_special<D, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<(D, usize)>
+where
+    D: IeeeDecimal,
+{
+    let (float, count) = parse_positive_special::<f64, FORMAT>(byte, options)?;
+    let value = if float.is_nan() {
+        D::nan(is_negative)
+    } else {
+        D::infinity(is_negative)
+    };
+    Some((value, count))
+}
+
+/// Try to parse a special, non-finite decimal float.
+#[cfg(feature = "ieee-decimal")]
+#[inline]
+pub fn parse_ieee_special<D, const FORMAT: u128>(
+    byte: Bytes<FORMAT>,
+    is_negative: bool,
+    options: &Options,
+) -> Option<D>
+where
+    D: IeeeDecimal,
+{
+    let length = byte.length();
+    match parse_partial_ieee_special::<D, FORMAT>(byte, is_negative, options) {
+        Some((value, count)) if count == length => Some(value),
+        _ => None,
+    }
+}
+
 /// Try to parse a special, non-finite float.
 #[inline]
 pub fn parse_special<F, const FORMAT: u128>(
@@ -0,0 +1,175 @@
+//! Convert the tokenized significant digits to an exact rational.
+//!
+//! Every significant digit is processed again into a big integer, like
+//! the slow-path algorithms, but without any limit on the number of
+//! digits. The exponent of the tokenized number is relative to the
+//! digits in the truncated mantissa, so it is adjusted by the number of
+//! digits after the mantissa. Trailing zeros are folded into the
+//! exponent, rather than scaling the numerator just to reduce it again.
+
+#![cfg(feature = "std")]
+#![doc(hidden)]
+
+use crate::number::Number;
+use crate::shared;
+use lexical_parse_integer::bigint::combine_chunks;
+use lexical_util::bigint::Bigint;
+use lexical_util::digit::char_to_valid_digit_const;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, BytesIter};
+use lexical_util::limb::Limb;
+use lexical_util::rational::Rational;
+use lexical_util::step::u64_step;
+use lexical_util::wide::limb_power;
+
+/// The maximum magnitude of the exponent.
+///
+/// Explicit exponents saturate at this value when tokenizing the number,
+/// so larger exponents are not exact.
+const MAX_EXPONENT: i64 = 0x10000000;
+
+/// Accumulated significant digits, in limbs of digits.
+struct Digits {
+    /// The radix of the digits.
+    radix: u32,
+    /// The largest power of the radix that fits in a limb.
+    power: Limb,
+    /// The number of digits in `power`.
+    step: usize,
+    /// Limbs of digits, from most to least significant.
+    chunks: Vec<Limb>,
+    /// The digits in the current limb.
+    value: Limb,
+    /// The number of digits in the current limb.
+    digits: usize,
+    /// The number of trailing zeros, which are not yet accumulated.
+    zeros: usize,
+    /// The number of significant digits, including trailing zeros.
+    count: usize,
+}
+
+impl Digits {
+    /// Create an empty accumulator for digits of the radix.
+    #[inline]
+    fn new(radix: u32) -> Self {
+        let (power, step) = limb_power(radix);
+        Self {
+            radix,
+            power,
+            step,
+            chunks: Vec::new(),
+            value: 0,
+            digits: 0,
+            zeros: 0,
+            count: 0,
+        }
+    }
+
+    /// Accumulate a digit into the current limb.
+    #[inline(always)]
+    fn push(&mut self, digit: u32) {
+        self.value = self.value * self.radix as Limb + digit as Limb;
+        self.digits += 1;
+        if self.digits == self.step {
+            self.chunks.push(self.value);
+            self.value = 0;
+            self.digits = 0;
+        }
+    }
+
+    /// Add a significant digit.
+    #[inline(always)]
+    fn add(&mut self, digit: u32) {
+        self.count += 1;
+        if digit == 0 {
+            self.zeros += 1;
+        } else {
+            for _ in 0..self.zeros {
+                self.push(0);
+            }
+            self.zeros = 0;
+            self.push(digit);
+        }
+    }
+
+    /// Get the significant digits, without the trailing zeros.
+    #[inline]
+    fn into_bigint(self) -> Bigint {
+        let mut value = combine_chunks(&self.chunks, self.power);
+        if self.digits != 0 {
+            value.mul_small_add((self.radix as Limb).pow(self.digits as u32), self.value);
+        }
+        value
+    }
+}
+
+/// Process all the significant digits.
+#[inline]
+fn parse_digits<const FORMAT: u128>(num: &Number) -> Digits {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let mut digits = Digits::new(radix);
+    let mut integer = num.integer.bytes::<FORMAT>();
+    let mut integer_iter = integer.integer_iter();
+    integer_iter.skip_zeros();
+    for &c in integer_iter {
+        digits.add(char_to_valid_digit_const(c, radix));
+    }
+    if let Some(fraction) = num.fraction {
+        let mut fraction = fraction.bytes::<FORMAT>();
+        let mut fraction_iter = fraction.fraction_iter();
+        if digits.count == 0 {
+            fraction_iter.skip_zeros();
+        }
+        for &c in fraction_iter {
+            digits.add(char_to_valid_digit_const(c, radix));
+        }
+    }
+    digits
+}
+
+/// Convert a parsed, non-special number to an exact rational.
+///
+/// The denominator is a power of the exponent base, and the rational is
+/// then multiplied by `2^shift`, for binary magnitude suffixes. Returns
+/// `None` if the magnitude of the exponent is at least `2^28`, since the
+/// exponent may have saturated.
+pub fn to_rational<const FORMAT: u128>(num: Number, shift: u32) -> Option<Rational> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let base = format.exponent_base();
+    // The number of exponent base digits per digit.
+    let ratio = if radix == base {
+        1
+    } else {
+        (shared::log2(radix) / shared::log2(base)) as i64
+    };
+
+    // With many digits, the exponent is relative to the first digits
+    // that fit in the mantissa, so adjust it by the remaining digits.
+    let digits = parse_digits::<FORMAT>(&num);
+    let truncated = if num.many_digits {
+        digits.count - digits.count.min(u64_step(radix))
+    } else {
+        0
+    };
+    let exponent = num.exponent + (digits.zeros as i64 - truncated as i64) * ratio;
+    if exponent.abs() >= MAX_EXPONENT {
+        return None;
+    }
+
+    let mut numerator = digits.into_bigint();
+    if num.is_negative {
+        numerator = -numerator;
+    }
+    if shift != 0 {
+        numerator <<= shift as usize;
+    }
+    if exponent >= 0 {
+        if exponent != 0 && !numerator.is_zero() {
+            numerator *= Bigint::from(base).pow(exponent as u32);
+        }
+        Some(Rational::new(numerator, base, 0))
+    } else {
+        Some(Rational::new(numerator, base, (-exponent) as u32))
+    }
+}
@@ -42,10 +42,15 @@ use lexical_util::num::{AsPrimitive, Integer};
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// The float is scaled by `2^binary_exp`, which is only used by magnitude
+/// suffixes, and therefore is always 0 for radixes without a finite
+/// number of digits.
 #[inline]
 pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
+    binary_exp: u32,
 ) -> ExtendedFloat80 {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
@@ -71,9 +76,10 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
-            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits, binary_exp)
         } else {
             // Fallback to infinite digits.
+            debug_assert!(binary_exp == 0);
             byte_comp::<F, FORMAT>(num, fp, sci_exp)
         }
     }
@@ -82,7 +88,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         // Can use our finite number of digit algorithm.
         let max_digits = F::max_digits(format.radix()).unwrap();
-        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits, binary_exp)
     }
 }
 
@@ -92,15 +98,23 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
 /// is just a multiplication by an exponent power. For a negative
 /// exponent relative to the significant digits, we scale the real
 /// digits to the theoretical digits for `b` and determine if we
-/// need to round-up.
+/// need to round-up. Scaling the float by `2^binary_exp` is just a
+/// multiplication of the real digits.
 #[inline]
 pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
     num: Number,
     fp: ExtendedFloat80,
     sci_exp: i32,
     max_digits: usize,
+    binary_exp: u32,
 ) -> ExtendedFloat80 {
-    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    // Each power of 2 the float is scaled by can add a digit to the
+    // halfway representation, so we need to parse that many more digits.
+    let max_digits = max_digits + binary_exp as usize;
+    let (mut bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    if binary_exp != 0 {
+        bigmant.pow(2, binary_exp).unwrap();
+    }
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
@@ -12,7 +12,7 @@
 //!
 //! Total array storage:
 //!  With radix: ~20 KB:
-//!     2534 u64
+//!     2536 u64
 //!
 //! DO NOT MODIFY: Generated by `etc/bellerophon_table.py`
 
@@ -56,7 +56,9 @@ const BASE10_SMALL_MANTISSA: [u64; 10] = [
     13743895347200000000, // 10^8
     17179869184000000000, // 10^9
 ];
-const BASE10_LARGE_MANTISSA: [u64; 66] = [
+const BASE10_LARGE_MANTISSA: [u64; 68] = [
+    17052356107738151455, // 10^-370
+    9925777620949172098,  // 10^-360
     11555125961253852697, // 10^-350
     13451937075301367670, // 10^-340
     15660115838168849784, // 10^-330
@@ -127,6 +129,6 @@ const BASE10_LARGE_MANTISSA: [u64; 66] = [
 const BASE10_SMALL_INT_POWERS: [u64; 10] =
     [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000];
 const BASE10_STEP: i32 = 10;
-const BASE10_BIAS: i32 = 350;
+const BASE10_BIAS: i32 = 370;
 const BASE10_LOG2_MULT: i64 = 217706;
 const BASE10_LOG2_SHIFT: i32 = 16;
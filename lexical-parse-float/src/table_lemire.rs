@@ -12,7 +12,7 @@
 #![doc(hidden)]
 #![cfg(not(feature = "compact"))]
 
-pub const SMALLEST_POWER_OF_FIVE: i32 = -342;
+pub const SMALLEST_POWER_OF_FIVE: i32 = -366;
 pub const LARGEST_POWER_OF_FIVE: i32 = 308;
 pub const N_POWERS_OF_FIVE: usize = (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_FIVE + 1) as usize;
 
@@ -22,6 +22,30 @@ pub const N_POWERS_OF_FIVE: usize = (LARGEST_POWER_OF_FIVE - SMALLEST_POWER_OF_F
 // the final binary.
 #[rustfmt::skip]
 pub static POWER_OF_FIVE_128: [(u64, u64); N_POWERS_OF_FIVE] = [
+    (0x9070672ffd52c0e9, 0xecdc8cd2f815b5d7), // 5^-366
+    (0xb48c80fbfca77124, 0x6813b007b61b234d), // 5^-365
+    (0xe1afa13afbd14d6d, 0x82189c09a3a1ec21), // 5^-364
+    (0x8d0dc4c4dd62d064, 0x714f618606453394), // 5^-363
+    (0xb05135f614bb847d, 0x8da339e787d6807a), // 5^-362
+    (0xdc65837399ea659c, 0xf10c086169cc2098), // 5^-361
+    (0x89bf722840327f82, 0x16a7853ce21f945f), // 5^-360
+    (0xac2f4eb2503f1f62, 0x9c51668c1aa77977), // 5^-359
+    (0xd73b225ee44ee73b, 0x4365c02f215157d5), // 5^-358
+    (0x8684f57b4eb15085, 0xa1f981d74d2d6e5),  // 5^-357
+    (0xa82632da225da4a6, 0x4ca77e24d2078c9e), // 5^-356
+    (0xd22fbf90aaf50dcf, 0xdfd15dae06896fc6), // 5^-355
+    (0x835dd7ba6ad928a1, 0xebe2da8cc415e5db), // 5^-354
+    (0xa4354da9058f72ca, 0x66db912ff51b5f52), // 5^-353
+    (0xcd42a11346f34f7d, 0x92757bf2623727),   // 5^-352
+    (0x8049a4ac0c5811ae, 0x205b896d777d6278), // 5^-351
+    (0xa05c0dd70f6e1619, 0xa8726bc8d55cbb16), // 5^-350
+    (0xc873114cd3499ba0, 0x128f06bb0ab3e9dc), // 5^-349
+    (0xfa8fd5a0081c0288, 0x1732c869cd60e453), // 5^-348
+    (0x9c99e58405118195, 0xe7fbd42205c8eb4),  // 5^-347
+    (0xc3c05ee50655e1fa, 0x521fac92a873b261), // 5^-346
+    (0xf4b0769e47eb5a78, 0xe6a797b752909ef9), // 5^-345
+    (0x98ee4a22ecf3188b, 0x9028bed2939a635c), // 5^-344
+    (0xbf29dcaba82fdeae, 0x7432ee873880fc33), // 5^-343
     (0xeef453d6923bd65a, 0x113faa2906a13b3f), // 5^-342
     (0x9558b4661b6565f8, 0x4ac7ca59a424c507), // 5^-341
     (0xbaaee17fa23ebf76, 0x5d79bcf00d2df649), // 5^-340
@@ -0,0 +1,153 @@
+//! Decompose numbers into their components without computing their value.
+//!
+//! This uses the same tokenizer as the float parsers, and therefore
+//! accepts the exact same inputs, but rather than converting the number
+//! to a native float, it returns the raw components of the number. This
+//! allows other numeric types, such as decimals or arbitrary-precision
+//! floats, to use lexical's number grammar with their own conversion.
+
+use crate::options::Options;
+use crate::parse;
+use crate::validate::NumberKind;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+pub use lexical_util::suffix::Suffix;
+use lexical_util::whitespace;
+
+/// Decomposed representation of a valid number.
+///
+/// All digit slices are subslices of the parsed input, and contain the
+/// digits exactly as they were written, including any digit separators.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    /// The classification of the number.
+    pub kind: NumberKind,
+    /// If the number is negative.
+    pub is_negative: bool,
+    /// The raw integer digits, after any sign or base prefix.
+    ///
+    /// For special values, this is the raw special string, like `"NaN"`.
+    pub integer: &'a [u8],
+    /// The raw fraction digits, if a decimal point was present.
+    pub fraction: Option<&'a [u8]>,
+    /// The raw exponent digits, after any sign, if an exponent was present.
+    pub exponent: Option<&'a [u8]>,
+    /// The value of the explicit exponent, or 0 if not present.
+    ///
+    /// Exponents too large to be meaningful for any float are saturated,
+    /// so this value may not exactly match the exponent digits.
+    pub explicit_exponent: i64,
+    /// The magnitude suffix, if suffixes are enabled and one was present.
+    ///
+    /// The suffix is not applied to any other field, so `"1.5k"` has the
+    /// same digits and explicit exponent as `"1.5"`.
+    pub suffix: Option<Suffix>,
+    /// If any digit separators were present in the number.
+    pub has_separators: bool,
+}
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Decompose a complete number using the standard format.
+///
+/// # Example
+///
+/// ```rust
+/// use lexical_parse_float::token::tokenize;
+///
+/// let token = tokenize(b"-1.25e-3").unwrap();
+/// assert!(token.is_negative);
+/// assert_eq!(token.integer, b"1");
+/// assert_eq!(token.fraction, Some(&b"25"[..]));
+/// assert_eq!(token.exponent, Some(&b"3"[..]));
+/// assert_eq!(token.explicit_exponent, -3);
+/// ```
+#[inline]
+pub fn tokenize(bytes: &[u8]) -> Result<Token<'_>> {
+    parse::tokenize_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Decompose a number until an invalid digit is found, using the standard format.
+///
+/// Returns the decomposed number and the number of processed bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use lexical_parse_float::token::tokenize_partial;
+///
+/// let (token, count) = tokenize_partial(b"12.5, 3").unwrap();
+/// assert_eq!(count, 4);
+/// assert_eq!(token.integer, b"12");
+/// assert_eq!(token.fraction, Some(&b"5"[..]));
+/// ```
+#[inline]
+pub fn tokenize_partial(bytes: &[u8]) -> Result<(Token<'_>, usize)> {
+    parse::tokenize_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Decompose a complete number using custom formats and options.
+///
+/// Whitespace is skipped as specified by the options.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "format")] {
+/// use lexical_parse_float::format::{self, NumberFormatBuilder};
+/// use lexical_parse_float::token::tokenize_with_options;
+/// use lexical_parse_float::Options;
+///
+/// const FORMAT: u128 = NumberFormatBuilder::rebuild(format::PERMISSIVE)
+///     .internal_digit_separator(true)
+///     .digit_separator(std::num::NonZeroU8::new(b'_'))
+///     .build();
+/// let options = Options::new();
+/// let token = tokenize_with_options::<FORMAT>(b"1_000.5", &options).unwrap();
+/// assert_eq!(token.integer, b"1_000");
+/// assert!(token.has_separators);
+/// # }
+/// ```
+#[inline]
+pub fn tokenize_with_options<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<Token<'a>> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    whitespace::parse_complete(
+        bytes,
+        options.leading_whitespace(),
+        options.trailing_whitespace(),
+        |bytes| parse::tokenize_complete::<FORMAT>(bytes, options),
+    )
+}
+
+/// Decompose a number until an invalid digit is found, using custom formats and options.
+///
+/// Returns the decomposed number and the number of processed bytes.
+/// Whitespace is skipped as specified by the options, and is included
+/// in the number of processed bytes.
+#[inline]
+pub fn tokenize_partial_with_options<'a, const FORMAT: u128>(
+    bytes: &'a [u8],
+    options: &Options,
+) -> Result<(Token<'a>, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    whitespace::parse_partial(
+        bytes,
+        options.leading_whitespace(),
+        options.trailing_whitespace(),
+        |bytes| parse::tokenize_partial::<FORMAT>(bytes, options),
+    )
+}
@@ -0,0 +1,145 @@
+//! Validate the syntax of numbers without computing their value.
+//!
+//! These use the same tokenizer as the float parsers, and therefore
+//! accept the exact same inputs, but skip all numeric conversion: no
+//! fast, moderate, or slow path algorithms are ever run. This is useful
+//! when only the validity or length of a token is required.
+
+use crate::options::Options;
+use crate::parse;
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+use lexical_util::whitespace;
+
+/// Classification of a valid numeric token.
+///
+/// Invalid tokens are reported as an [`Error`], which contains the
+/// index of the invalid character.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NumberKind {
+    /// A number without a decimal point or exponent, like `"-12"`.
+    Integer,
+    /// A number with a decimal point or exponent, like `"1.5"` or `"1e5"`.
+    Float,
+    /// A special, non-finite value, like `"NaN"` or `"inf"`.
+    Special,
+}
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Validate a complete number using the standard format.
+///
+/// Returns the classification of the number, or an error containing the
+/// index of the first invalid character.
+///
+/// # Example
+///
+/// ```rust
+/// # use lexical_parse_float::Error;
+/// use lexical_parse_float::validate::{validate, NumberKind};
+///
+/// assert_eq!(validate(b"-12"), Ok(NumberKind::Integer));
+/// assert_eq!(validate(b"1.5e10"), Ok(NumberKind::Float));
+/// assert_eq!(validate(b"NaN"), Ok(NumberKind::Special));
+/// assert_eq!(validate(b"1.5x"), Err(Error::InvalidDigit(3)));
+/// ```
+#[inline]
+pub fn validate(bytes: &[u8]) -> Result<NumberKind> {
+    parse::validate_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Validate a number until an invalid digit is found, using the standard format.
+///
+/// Returns the classification of the number and the number of processed
+/// bytes, or an error containing the index of the invalid character.
+///
+/// # Example
+///
+/// ```rust
+/// use lexical_parse_float::validate::{validate_partial, NumberKind};
+///
+/// assert_eq!(validate_partial(b"1.5e10, 2"), Ok((NumberKind::Float, 6)));
+/// assert_eq!(validate_partial(b"-12]"), Ok((NumberKind::Integer, 3)));
+/// ```
+#[inline]
+pub fn validate_partial(bytes: &[u8]) -> Result<(NumberKind, usize)> {
+    parse::validate_partial::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Validate a complete number using custom formats and options.
+///
+/// Returns the classification of the number, or an error containing the
+/// index of the first invalid character. Whitespace is skipped as
+/// specified by the options.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "format")] {
+/// use lexical_parse_float::format::JSON;
+/// use lexical_parse_float::validate::{validate_with_options, NumberKind};
+/// use lexical_parse_float::Options;
+///
+/// let options = Options::new();
+/// assert_eq!(validate_with_options::<JSON>(b"1e5", &options), Ok(NumberKind::Float));
+/// assert!(validate_with_options::<JSON>(b"NaN", &options).is_err());
+/// # }
+/// ```
+#[inline]
+pub fn validate_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<NumberKind> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    whitespace::parse_complete(
+        bytes,
+        options.leading_whitespace(),
+        options.trailing_whitespace(),
+        |bytes| parse::validate_complete::<FORMAT>(bytes, options),
+    )
+}
+
+/// Validate a number until an invalid digit is found, using custom formats and options.
+///
+/// Returns the classification of the number and the number of processed
+/// bytes, or an error containing the index of the invalid character.
+/// Whitespace is skipped as specified by the options, and is included
+/// in the number of processed bytes.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "format")] {
+/// use lexical_parse_float::format::JSON;
+/// use lexical_parse_float::validate::{validate_partial_with_options, NumberKind};
+/// use lexical_parse_float::Options;
+///
+/// let options = Options::new();
+/// let result = validate_partial_with_options::<JSON>(b"-12.5,", &options);
+/// assert_eq!(result, Ok((NumberKind::Float, 5)));
+/// # }
+/// ```
+#[inline]
+pub fn validate_partial_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(NumberKind, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    whitespace::parse_partial(
+        bytes,
+        options.leading_whitespace(),
+        options.trailing_whitespace(),
+        |bytes| parse::validate_partial::<FORMAT>(bytes, options),
+    )
+}
@@ -1,6 +1,6 @@
 #[cfg(feature = "format")]
 use core::num;
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options, WhitespaceMode};
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format;
@@ -29,6 +29,39 @@ fn special_bytes_test() {
     assert!(f32::from_lexical_with_options::<FORMAT>(b"Infinity", &options).unwrap().is_infinite());
 }
 
+#[test]
+fn whitespace_test() {
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::builder()
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b" 1.5e3\n", &options), Ok(1500.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"\t-inf ", &options),
+        Ok(f64::NEG_INFINITY)
+    );
+    assert_eq!(f64::from_lexical_partial_with_options::<FORMAT>(b" 1.5 x", &options), Ok((1.5, 5)));
+    assert_eq!(f64::from_lexical(b" 1.5"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"  1.5a ", &options),
+        Err(Error::InvalidDigit(5))
+    );
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b" \r\n", &options), Err(Error::Empty(3)));
+
+    let options = Options::builder().trailing_whitespace(WhitespaceMode::Unicode).build().unwrap();
+    let bytes = "1.5\u{2028}\u{a0}".as_bytes();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(bytes, &options), Ok(1.5));
+    assert_eq!(f64::from_lexical_partial_with_options::<FORMAT>(bytes, &options), Ok((1.5, 8)));
+    let bytes = "\u{a0}1.5".as_bytes();
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(bytes, &options),
+        Err(Error::EmptyMantissa(0))
+    );
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn invalid_format_test() {
@@ -37,6 +70,20 @@ fn invalid_format_test() {
     let res = f32::from_lexical_with_options::<FORMAT>(b"inf", &options);
     assert!(res.is_err());
     assert_eq!(res, Err(Error::InvalidMantissaRadix));
+    let res = f32::from_lexical_partial_with_options::<FORMAT>(b"inf", &options);
+    assert_eq!(res, Err(Error::InvalidMantissaRadix));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn f64_hex_test() {
+    // Must not use the 8-digit fast path for non-decimal digits.
+    const FORMAT: u128 = NumberFormatBuilder::from_radix(16);
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    let result = f64::from_lexical_with_options::<FORMAT>(b"DEADBEEFCAFE", &options);
+    assert_eq!(result, Ok(0xDEADBEEFCAFEu64 as f64));
+    let result = f64::from_lexical_with_options::<FORMAT>(b"DEADBEEF.CAFEBABE", &options);
+    assert_eq!(result, Ok(0xDEADBEEFCAFEBABEu64 as f64 / 65536.0 / 65536.0));
 }
 
 #[test]
@@ -21,7 +21,7 @@ pub fn bellerophon_test<F: RawFloat, const FORMAT: u128>(
         integer: &[],
         fraction: None,
     };
-    let xfp = bellerophon::<F, FORMAT>(&num, false);
+    let xfp = bellerophon::<F, FORMAT>(&num, 0, false);
     let yfp = ExtendedFloat80 {
         mant: ymant,
         exp: yexp,
@@ -50,7 +50,7 @@ pub fn compute_float32(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f32, { STANDARD }>(&num, false);
+    let fp = bellerophon::<f32, { STANDARD }>(&num, 0, false);
     (fp.exp, fp.mant)
 }
 
@@ -63,6 +63,6 @@ pub fn compute_float64(q: i64, w: u64) -> (i32, u64) {
         integer: &[],
         fraction: None,
     };
-    let fp = bellerophon::<f64, { STANDARD }>(&num, false);
+    let fp = bellerophon::<f64, { STANDARD }>(&num, 0, false);
     (fp.exp, fp.mant)
 }
@@ -0,0 +1,132 @@
+#![cfg(feature = "ieee-decimal")]
+
+use lexical_parse_float::{
+    Decimal128,
+    Decimal64,
+    DecimalRounding,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    SuffixMode,
+};
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+
+fn d64(bytes: &[u8]) -> Decimal64 {
+    Decimal64::from_lexical(bytes).unwrap()
+}
+
+fn d128(bytes: &[u8]) -> Decimal128 {
+    Decimal128::from_lexical(bytes).unwrap()
+}
+
+fn new64(is_negative: bool, coefficient: u64, exponent: i32) -> Decimal64 {
+    Decimal64::new(is_negative, coefficient, exponent).unwrap()
+}
+
+fn new128(is_negative: bool, coefficient: u128, exponent: i32) -> Decimal128 {
+    Decimal128::new(is_negative, coefficient, exponent).unwrap()
+}
+
+#[test]
+fn parse_quantum_test() {
+    assert_eq!(d64(b"1"), new64(false, 1, 0));
+    assert_eq!(d64(b"1.50"), new64(false, 150, -2));
+    assert_eq!(d64(b"1.5"), new64(false, 15, -1));
+    assert_eq!(d64(b"12e3"), new64(false, 12, 3));
+    assert_eq!(d64(b"-0.001"), new64(true, 1, -3));
+    assert_eq!(d64(b"0.00"), new64(false, 0, -2));
+    assert_eq!(d64(b"-0"), new64(true, 0, 0));
+    assert_eq!(d128(b"1.50"), new128(false, 150, -2));
+    assert_eq!(d128(b"-12345.6789e-10"), new128(true, 123456789, -14));
+}
+
+#[test]
+fn parse_round_test() {
+    // Ties round to even.
+    assert_eq!(d64(b"12345678901234565"), new64(false, 1234567890123456, 1));
+    assert_eq!(d64(b"12345678901234575"), new64(false, 1234567890123458, 1));
+    assert_eq!(d64(b"123456789012345650001"), new64(false, 1234567890123457, 5));
+    assert_eq!(d64(b"1.2345678901234564999"), new64(false, 1234567890123456, -15));
+
+    // Rounding may carry into a new digit.
+    assert_eq!(d64(b"9999999999999999.5"), new64(false, 1000000000000000, 1));
+
+    let digits = b"1234567890123456789012345678901234567890";
+    assert_eq!(d128(digits), new128(false, 1234567890123456789012345678901235, 6));
+    let digits = b"0.000000000012345678901234567890123456789012345000000000000000000001";
+    assert_eq!(d128(digits), new128(false, 1234567890123456789012345678901235, -44));
+}
+
+#[test]
+fn parse_range_test() {
+    // Large exponents are clamped, if zeros can be appended to the coefficient.
+    assert_eq!(d64(b"1e384"), new64(false, 1000000000000000, 369));
+    assert_eq!(d64(b"1e385"), Decimal64::INFINITY);
+    assert_eq!(d64(b"-1e400"), Decimal64::NEG_INFINITY);
+    assert_eq!(d64(b"9.999999999999999e384"), new64(false, 9999999999999999, 369));
+    assert_eq!(d64(b"0e400"), new64(false, 0, 369));
+    assert_eq!(d128(b"1e6144"), new128(false, 1000000000000000000000000000000000, 6111));
+    assert_eq!(d128(b"1e6145"), Decimal128::INFINITY);
+
+    // Small exponents round the coefficient further.
+    assert_eq!(d64(b"1e-398"), new64(false, 1, -398));
+    assert_eq!(d64(b"1e-399"), new64(false, 0, -398));
+    assert_eq!(d64(b"5e-399"), new64(false, 0, -398));
+    assert_eq!(d64(b"15e-399"), new64(false, 2, -398));
+    assert_eq!(d64(b"-1.23456e-395"), new64(true, 1235, -398));
+    assert_eq!(d64(b"0e-500"), new64(false, 0, -398));
+    assert_eq!(d64(b"1e-300000000"), new64(false, 0, -398));
+    assert_eq!(d128(b"1e-6177"), new128(false, 0, -6176));
+}
+
+#[test]
+fn parse_rounding_test() {
+    let options = Options::builder().decimal_rounding(DecimalRounding::Down).build().unwrap();
+    let parse = |bytes: &[u8]| Decimal64::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"12345678901234569"), Ok(new64(false, 1234567890123456, 1)));
+    assert_eq!(parse(b"1e385"), Ok(new64(false, 9999999999999999, 369)));
+
+    let options = Options::builder().decimal_rounding(DecimalRounding::Floor).build().unwrap();
+    let parse = |bytes: &[u8]| Decimal64::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1e385"), Ok(new64(false, 9999999999999999, 369)));
+    assert_eq!(parse(b"-1e385"), Ok(Decimal64::NEG_INFINITY));
+    assert_eq!(parse(b"-1e-399"), Ok(new64(true, 1, -398)));
+}
+
+#[test]
+fn parse_special_test() {
+    assert!(d64(b"NaN").is_nan());
+    assert!(d64(b"-nan").is_sign_negative());
+    assert_eq!(d64(b"inf"), Decimal64::INFINITY);
+    assert_eq!(d64(b"-Infinity"), Decimal64::NEG_INFINITY);
+    assert!(d128(b"NaN").is_nan());
+    assert_eq!(Decimal64::from_lexical_partial(b"infx"), Ok((Decimal64::INFINITY, 3)));
+    assert_eq!(Decimal64::from_lexical(b"infx"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(Decimal64::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(Decimal64::from_lexical(b"1.2a"), Err(Error::InvalidDigit(3)));
+    assert_eq!(Decimal128::from_lexical(b"1e"), Err(Error::EmptyExponent(2)));
+}
+
+#[test]
+fn parse_partial_test() {
+    assert_eq!(Decimal64::from_lexical_partial(b"1.5e3x"), Ok((new64(false, 15, 2), 5)));
+    assert_eq!(Decimal128::from_lexical_partial(b"-2.50 EUR"), Ok((new128(true, 250, -2), 5)));
+}
+
+#[test]
+fn parse_options_test() {
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let result = Decimal64::from_lexical_with_options::<STANDARD>(b"1.5k", &options);
+    assert_eq!(result, Ok(new64(false, 15, 2)));
+    let result = Decimal64::from_lexical_with_options::<STANDARD>(b"1.5Ki", &options);
+    assert_eq!(result, Ok(new64(false, 15360, -1)));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let result = Decimal128::from_lexical_with_options::<STANDARD>(b"1,50", &options);
+    assert_eq!(result, Ok(new128(false, 150, -2)));
+}
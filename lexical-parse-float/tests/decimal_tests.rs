@@ -0,0 +1,153 @@
+use lexical_parse_float::{
+    Decimal,
+    DecimalRounding,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    SuffixMode,
+};
+use lexical_util::error::Error;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+
+fn parse(bytes: &[u8]) -> Result<Decimal, Error> {
+    Decimal::from_lexical(bytes)
+}
+
+fn parse_rounding(bytes: &[u8], rounding: DecimalRounding) -> Decimal {
+    let options = Options::builder().decimal_rounding(rounding).build().unwrap();
+    Decimal::from_lexical_with_options::<STANDARD>(bytes, &options).unwrap()
+}
+
+#[test]
+fn parse_simple_test() {
+    assert_eq!(parse(b"1.50"), Ok(Decimal::new(150, 2)));
+    assert_eq!(parse(b"1.5"), Ok(Decimal::new(15, 1)));
+    assert_eq!(parse(b"12e3"), Ok(Decimal::new(12, -3)));
+    assert_eq!(parse(b"1.2e-3"), Ok(Decimal::new(12, 4)));
+    assert_eq!(parse(b"0.00"), Ok(Decimal::new(0, 2)));
+    assert_eq!(parse(b"0"), Ok(Decimal::new(0, 0)));
+    assert_eq!(parse(b"-0.1"), Ok(Decimal::new(-1, 1)));
+    assert_eq!(parse(b"+7"), Ok(Decimal::new(7, 0)));
+    assert_eq!(parse(b"000123.4500"), Ok(Decimal::new(1234500, 4)));
+    assert!(parse(b"-0.00").unwrap().is_zero());
+    assert_eq!(parse(b"-0.00"), Ok(Decimal::negative_zero(2)));
+    assert!(parse(b"-0.00").unwrap().is_negative());
+    assert!(!parse(b"0.00").unwrap().is_negative());
+    assert!(parse(b"-1").unwrap().is_negative());
+}
+
+#[test]
+fn parse_many_digits_test() {
+    // Up to 38 significant digits are exact.
+    let digits = b"12345678901234567890.123456789012345678";
+    assert_eq!(parse(digits), Ok(Decimal::new(12345678901234567890123456789012345678, 18)));
+    let digits = b"0.00012345678901234567890123456789012345678";
+    assert_eq!(parse(digits), Ok(Decimal::new(12345678901234567890123456789012345678, 41)));
+    let digits = b"-99999999999999999999999999999999999999";
+    assert_eq!(parse(digits), Ok(Decimal::new(-99999999999999999999999999999999999999, 0)));
+
+    // More significant digits are rounded, adjusting the scale.
+    let digits = b"123456789012345678901234567890123456785";
+    assert_eq!(parse(digits), Ok(Decimal::new(12345678901234567890123456789012345678, -1)));
+    let digits = b"1234567890123456789012345678901234567.855e2";
+    assert_eq!(parse(digits), Ok(Decimal::new(12345678901234567890123456789012345679, -1)));
+    let digits = b"0.000999999999999999999999999999999999999999";
+    assert_eq!(parse(digits), Ok(Decimal::new(100000000000000000000000000000000000000, 41)));
+}
+
+#[test]
+fn parse_rounding_test() {
+    use DecimalRounding::*;
+
+    let tie = b"123456789012345678901234567890123456785";
+    let tie_even = b"123456789012345678901234567890123456775";
+    let above = b"1234567890123456789012345678901234567851";
+    let below = b"123456789012345678901234567890123456781";
+    let exact = b"123456789012345678901234567890123456780";
+    let base = 12345678901234567890123456789012345678;
+
+    let cases = [
+        (HalfEven, [base, base, base + 1, base, base]),
+        (HalfUp, [base + 1, base, base + 1, base, base]),
+        (HalfDown, [base, base - 1, base + 1, base, base]),
+        (Up, [base + 1, base, base + 1, base + 1, base]),
+        (Down, [base, base - 1, base, base, base]),
+        (Ceiling, [base + 1, base, base + 1, base + 1, base]),
+        (Floor, [base, base - 1, base, base, base]),
+    ];
+    for &(rounding, expected) in cases.iter() {
+        let inputs = [&tie[..], &tie_even[..], &above[..], &below[..], &exact[..]];
+        for (input, &coefficient) in inputs.iter().zip(expected.iter()) {
+            let scale = if input.len() == 40 {
+                -2
+            } else {
+                -1
+            };
+            let expected = Decimal::new(coefficient, scale);
+            assert_eq!(parse_rounding(input, rounding), expected, "{:?}", rounding);
+        }
+    }
+
+    // The direction of ceiling and floor depend on the sign.
+    let input = b"-123456789012345678901234567890123456781";
+    assert_eq!(parse_rounding(input, Ceiling), Decimal::new(-base, -1));
+    assert_eq!(parse_rounding(input, Floor), Decimal::new(-base - 1, -1));
+    assert_eq!(parse_rounding(input, Up), Decimal::new(-base - 1, -1));
+    assert_eq!(parse_rounding(input, Down), Decimal::new(-base, -1));
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse(b"1.2a"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.2e"), Err(Error::EmptyExponent(4)));
+    assert!(parse(b"NaN").is_err());
+    assert!(parse(b"inf").is_err());
+    assert_eq!(parse(b"1e-3000000000"), Ok(Decimal::new(1, 300000000)));
+}
+
+#[test]
+fn parse_partial_test() {
+    assert_eq!(Decimal::from_lexical_partial(b"1.50"), Ok((Decimal::new(150, 2), 4)));
+    assert_eq!(Decimal::from_lexical_partial(b"-2.5e1 USD"), Ok((Decimal::new(-25, 0), 6)));
+    assert_eq!(Decimal::from_lexical_partial(b"3.0.1"), Ok((Decimal::new(30, 1), 3)));
+}
+
+#[test]
+fn parse_options_test() {
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let result = Decimal::from_lexical_with_options::<STANDARD>(b"1,25", &options);
+    assert_eq!(result, Ok(Decimal::new(125, 2)));
+
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let result = Decimal::from_lexical_with_options::<STANDARD>(b"1.5k", &options);
+    assert_eq!(result, Ok(Decimal::new(15, -2)));
+    let result = Decimal::from_lexical_with_options::<STANDARD>(b"2.5m", &options);
+    assert_eq!(result, Ok(Decimal::new(25, 4)));
+    let result = Decimal::from_lexical_with_options::<STANDARD>(b"1.5Ki", &options);
+    assert_eq!(result, Ok(Decimal::new(15360, 1)));
+    let result = Decimal::from_lexical_partial_with_options::<STANDARD>(b"3Mi!", &options);
+    assert_eq!(result, Ok((Decimal::new(3145728, 0), 3)));
+
+    // The scaled coefficient must fit in an `i128`.
+    let max = b"170141183460469231731687303715884105727Ki";
+    let result = Decimal::from_lexical_with_options::<STANDARD>(max, &options);
+    assert_eq!(result, Err(Error::Overflow(40)));
+    let result = Decimal::from_lexical_partial_with_options::<STANDARD>(
+        b"99999999999999999999Yi ",
+        &options,
+    );
+    assert_eq!(result, Err(Error::Overflow(21)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_radix_test() {
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    let options = Options::new();
+    let result = Decimal::from_lexical_with_options::<BINARY>(b"1.1", &options);
+    assert_eq!(result, Err(Error::InvalidRadix));
+}
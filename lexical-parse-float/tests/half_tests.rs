@@ -0,0 +1,124 @@
+#![cfg(feature = "half")]
+
+use lexical_parse_float::{bf16, f16, FromLexical, FromLexicalWithOptions, Options};
+use lexical_util::error::Error;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+
+fn parse16(bytes: &[u8]) -> f16 {
+    f16::from_lexical(bytes).unwrap()
+}
+
+fn parse_b16(bytes: &[u8]) -> bf16 {
+    bf16::from_lexical(bytes).unwrap()
+}
+
+#[test]
+fn f16_test() {
+    assert_eq!(parse16(b"0"), f16::ZERO);
+    assert_eq!(parse16(b"1"), f16::ONE);
+    assert_eq!(parse16(b"-1.5"), f16::from_f32(-1.5));
+    assert_eq!(parse16(b"0.1"), f16::from_bits(0x2E66));
+    assert_eq!(parse16(b"3.14159"), f16::from_bits(0x4248));
+    assert_eq!(parse16(b"2047"), f16::from_f32(2047.0));
+    assert_eq!(parse16(b"2049"), f16::from_f32(2048.0));
+    assert_eq!(parse16(b"2051"), f16::from_f32(2052.0));
+    assert_eq!(parse16(b"65504"), f16::MAX);
+    assert_eq!(parse16(b"65519.99"), f16::MAX);
+    assert_eq!(parse16(b"65520"), f16::INFINITY);
+    assert_eq!(parse16(b"1e5"), f16::INFINITY);
+    assert_eq!(parse16(b"-1e5"), f16::NEG_INFINITY);
+}
+
+#[test]
+fn f16_subnormal_test() {
+    assert_eq!(parse16(b"6.103515625e-5"), f16::MIN_POSITIVE);
+    assert_eq!(parse16(b"5.960464477539063e-8"), f16::from_bits(1));
+    assert_eq!(parse16(b"1e-7"), f16::from_bits(2));
+
+    // Exactly halfway subnormals have few digits, and round to even.
+    assert_eq!(parse16(b"2.98023223876953125e-8"), f16::ZERO);
+    assert_eq!(parse16(b"2.980232238769531250000000000000000001e-8"), f16::from_bits(1));
+    assert_eq!(parse16(b"2.98023223876953124999999999999999999e-8"), f16::ZERO);
+    assert_eq!(parse16(b"8.94069671630859375e-8"), f16::from_bits(2));
+    assert_eq!(parse16(b"1.490116119384765625e-7"), f16::from_bits(2));
+    assert_eq!(parse16(b"1e-8"), f16::ZERO);
+}
+
+#[test]
+fn bf16_test() {
+    assert_eq!(parse_b16(b"0"), bf16::ZERO);
+    assert_eq!(parse_b16(b"1"), bf16::ONE);
+    assert_eq!(parse_b16(b"0.1"), bf16::from_bits(0x3DCD));
+    assert_eq!(parse_b16(b"257"), bf16::from_f32(256.0));
+    assert_eq!(parse_b16(b"259"), bf16::from_f32(260.0));
+
+    // Ties round to even.
+    assert_eq!(parse_b16(b"1.00390625"), bf16::ONE);
+    assert_eq!(parse_b16(b"1.0039062500000000000000001"), bf16::from_bits(0x3F81));
+    assert_eq!(parse_b16(b"1.01171875"), bf16::from_bits(0x3F82));
+
+    assert_eq!(parse_b16(b"3.3895314e38"), bf16::MAX);
+    assert_eq!(parse_b16(b"3.4e38"), bf16::INFINITY);
+    assert_eq!(parse_b16(b"1.1754944e-38"), bf16::MIN_POSITIVE);
+    assert_eq!(parse_b16(b"9.1835e-41"), bf16::from_bits(1));
+    assert_eq!(parse_b16(b"1e-42"), bf16::ZERO);
+}
+
+#[test]
+fn special_test() {
+    assert!(parse16(b"NaN").is_nan());
+    assert!(parse_b16(b"NaN").is_nan());
+    assert_eq!(parse16(b"inf"), f16::INFINITY);
+    assert_eq!(parse_b16(b"-inf"), bf16::NEG_INFINITY);
+    assert_eq!(parse16(b"-0"), f16::NEG_ZERO);
+}
+
+#[test]
+fn error_test() {
+    assert_eq!(f16::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(f16::from_lexical(b"1.0x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(bf16::from_lexical(b"e5"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(f16::from_lexical_partial(b"1.5x"), Ok((f16::from_f32(1.5), 3)));
+    assert_eq!(bf16::from_lexical_partial(b"-2e2,"), Ok((bf16::from_f32(-200.0), 4)));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let value =
+        f16::from_lexical_with_options::<{ lexical_util::format::STANDARD }>(b"0,25", &options);
+    assert_eq!(value, Ok(f16::from_f32(0.25)));
+}
+
+#[test]
+fn roundtrip_test() {
+    // Every finite value is exact in `f32`, so its shortest `f32` string
+    // must parse back to the same value.
+    for bits in 0..0x7C00 {
+        let value = f16::from_bits(bits);
+        let string = format!("{}", value.to_f32());
+        assert_eq!(parse16(string.as_bytes()), value, "{}", string);
+    }
+    for bits in 0..0x7F80 {
+        let value = bf16::from_bits(bits);
+        let string = format!("{}", value.to_f32());
+        assert_eq!(parse_b16(string.as_bytes()), value, "{}", string);
+    }
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn power_of_two_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    let parse = |bytes: &[u8]| f16::from_lexical_with_options::<BINARY>(bytes, &options);
+    assert_eq!(parse(b"11111111111"), Ok(f16::from_f32(2047.0)));
+    assert_eq!(parse(b"111111111111"), Ok(f16::from_f32(4096.0)));
+    assert_eq!(parse(b"100000000001"), Ok(f16::from_f32(2048.0)));
+    assert_eq!(parse(b"100000000011"), Ok(f16::from_f32(2052.0)));
+    assert_eq!(parse(b"0.000000000000000000000001"), Ok(f16::from_bits(1)));
+    assert_eq!(parse(b"0.0000000000000000000000001"), Ok(f16::ZERO));
+
+    let parse = |bytes: &[u8]| bf16::from_lexical_with_options::<BINARY>(bytes, &options);
+    assert_eq!(parse(b"1.00000001"), Ok(bf16::ONE));
+    assert_eq!(parse(b"1.000000010000001"), Ok(bf16::from_bits(0x3F81)));
+    assert_eq!(parse(b"1.00000011"), Ok(bf16::from_bits(0x3F82)));
+}
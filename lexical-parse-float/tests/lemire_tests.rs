@@ -26,12 +26,12 @@ fn compute_error_scaled64(q: i64, w: u64, lz: i32) -> (i32, u64) {
 }
 
 fn compute_float32(q: i64, w: u64) -> (i32, u64) {
-    let fp = lemire::compute_float::<f32>(q, w, false);
+    let fp = lemire::compute_float::<f32>(q, w, 0, false);
     (fp.exp, fp.mant)
 }
 
 fn compute_float64(q: i64, w: u64) -> (i32, u64) {
-    let fp = lemire::compute_float::<f64>(q, w, false);
+    let fp = lemire::compute_float::<f64>(q, w, 0, false);
     (fp.exp, fp.mant)
 }
 
@@ -307,3 +307,24 @@ fn compute_float_f64_rounding() {
     assert_eq!(compute_float64(-3, 9007199254740995000), (1076, 2));
     assert_eq!(compute_float64(-3, 9007199254740996000), (1076, 2));
 }
+
+#[test]
+#[cfg(feature = "half")]
+fn compute_float16_test() {
+    use lexical_parse_float::f16;
+
+    let compute_float16 = |q: i64, w: u64| {
+        let fp = lemire::compute_float::<f16>(q, w, 0, false);
+        (fp.exp, fp.mant)
+    };
+
+    // Halfway subnormals are exact with few digits, so round to even.
+    assert_eq!(compute_float16(-25, 298023223876953125), (0, 0));
+    assert_eq!(compute_float16(-25, 298023223876953126), (0, 1));
+    assert_eq!(compute_float16(-25, 894069671630859375), (0, 2));
+    assert_eq!(compute_float16(-25, 894069671630859374), (0, 1));
+
+    // Halfway between the largest normal value and infinity.
+    assert_eq!(compute_float16(0, 65519), (30, 1023));
+    assert_eq!(compute_float16(0, 65520), (31, 0));
+}
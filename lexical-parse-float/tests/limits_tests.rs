@@ -25,3 +25,16 @@ fn max_digit_test() {
     assert_eq!(f32::max_digits(10), Some(114));
     assert_eq!(f64::max_digits(10), Some(769));
 }
+
+#[test]
+#[cfg(feature = "half")]
+fn half_limit_test() {
+    use lexical_parse_float::{bf16, f16};
+
+    assert_eq!(f16::mantissa_limit(10), 3);
+    assert_eq!(bf16::mantissa_limit(10), 2);
+    assert_eq!(f16::exponent_limit(10), (-4, 4));
+    assert_eq!(bf16::exponent_limit(10), (-3, 3));
+    assert_eq!(f16::max_digits(10), Some(23));
+    assert_eq!(bf16::max_digits(10), Some(98));
+}
@@ -1,4 +1,10 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+use lexical_parse_float::options::{
+    DecimalRounding,
+    Options,
+    OptionsBuilder,
+    SuffixMode,
+    WhitespaceMode,
+};
 
 #[test]
 fn invalid_exponent_test() {
@@ -98,6 +104,10 @@ fn builder_test() {
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.leading_whitespace(WhitespaceMode::Ascii);
+    builder = builder.trailing_whitespace(WhitespaceMode::Unicode);
+    builder = builder.suffix(SuffixMode::Decimal);
+    builder = builder.decimal_rounding(DecimalRounding::Floor);
 
     assert_eq!(builder.get_lossy(), true);
     assert_eq!(builder.get_exponent(), b'^');
@@ -105,6 +115,10 @@ fn builder_test() {
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_leading_whitespace(), WhitespaceMode::Ascii);
+    assert_eq!(builder.get_trailing_whitespace(), WhitespaceMode::Unicode);
+    assert_eq!(builder.get_suffix(), SuffixMode::Decimal);
+    assert_eq!(builder.get_decimal_rounding(), DecimalRounding::Floor);
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
@@ -121,6 +135,10 @@ fn options_test() {
         opts.set_nan_string(Some(b"nan"));
         opts.set_inf_string(Some(b"Infinity"));
         opts.set_infinity_string(Some(b"Infiniiiiiity"));
+        opts.set_leading_whitespace(WhitespaceMode::Unicode);
+        opts.set_trailing_whitespace(WhitespaceMode::Ascii);
+        opts.set_suffix(SuffixMode::Both);
+        opts.set_decimal_rounding(DecimalRounding::HalfUp);
     }
 
     assert_eq!(opts.lossy(), true);
@@ -129,6 +147,10 @@ fn options_test() {
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
     assert_eq!(opts.infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(opts.leading_whitespace(), WhitespaceMode::Unicode);
+    assert_eq!(opts.trailing_whitespace(), WhitespaceMode::Ascii);
+    assert_eq!(opts.suffix(), SuffixMode::Both);
+    assert_eq!(opts.decimal_rounding(), DecimalRounding::HalfUp);
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
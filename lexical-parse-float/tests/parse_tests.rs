@@ -1,5 +1,6 @@
-use lexical_parse_float::options::Options;
+use lexical_parse_float::options::{Options, SuffixMode};
 use lexical_parse_float::parse;
+use lexical_util::error::Error;
 use lexical_util::format::STANDARD;
 use lexical_util::iterator::AsBytes;
 use lexical_util::step::u64_step;
@@ -231,3 +232,65 @@ fn parse_parse_special_test() {
     let result = parse::parse_special::<f64, FORMAT>(byte, true, &options);
     assert_eq!(result, None);
 }
+
+#[test]
+fn parse_suffix_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let parse = |string: &[u8]| parse::parse_complete::<f64, FORMAT>(string, &options);
+    assert_eq!(parse(b"1.1k"), Ok(1100.0));
+    assert_eq!(parse(b"2.5M"), Ok(2.5e6));
+    assert_eq!(parse(b"-3\xc2\xb5"), Ok(-3e-6));
+    assert_eq!(parse(b"3u"), Ok(3e-6));
+    assert_eq!(parse(b"0.1m"), Ok(1e-4));
+    assert_eq!(parse(b"1.5e3k"), Ok(1.5e6));
+    assert_eq!(parse(b"512Mi"), Ok(536870912.0));
+    assert_eq!(parse(b"0.5Ki"), Ok(512.0));
+    assert_eq!(parse(b"2E"), Ok(2e18));
+    assert_eq!(parse(b"2E3"), Ok(2e3));
+    assert_eq!(parse(b"1Ei"), Ok(1152921504606846976.0));
+    assert_eq!(parse(b"1.7976931348623157Q"), Ok(1.7976931348623157e30));
+    assert_eq!(parse(b"1e300Q"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"1e-300q"), Ok(0.0));
+    assert_eq!(parse(b"1k5"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"1.5e"), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse(b"1E+"), Err(Error::EmptyExponent(3)));
+    assert_eq!(parse(b"inf"), Ok(f64::INFINITY));
+
+    // Decimal suffixes are correctly rounded, unlike scaling the float.
+    assert_eq!(parse(b"9.007199254740993P"), Ok(9.007199254740993e15));
+    assert_eq!(parse(b"0.3k"), Ok(300.0));
+    assert_eq!(parse(b"1.1n"), Ok(1.1e-9));
+    assert_eq!(parse(b"1.0000000000000000000000001G"), Ok(1e9));
+
+    // Binary suffixes are folded into the binary exponent, so denormal
+    // floats are rounded only once.
+    assert_eq!(parse(b"1e-320Ki"), Ok(1.024e-317));
+    assert_eq!(parse(b"2.5e-324Ki"), Ok(2.56e-321));
+    assert_eq!(parse(b"1e-330Yi"), Ok(1.2089258196146292e-306));
+    assert_eq!(parse(b"1.5e-343Yi"), Ok(1.81337e-319));
+    assert_eq!(parse(b"1e-367Yi"), Ok(0.0));
+    assert_eq!(parse(b"1e308Ki"), Ok(f64::INFINITY));
+
+    // Exactly halfway between 2 denormal floats after scaling, with
+    // more digits than are required for an unscaled float.
+    let halfway = b"1.0217038089209238665207116449264120202690596429114850347645852882792220124450795539305499276850593315471274891481879460834375803013264153111915583432988551104847882536913723247122395110468894321118952135712729570910858534088680343532146641347399638951251124401582327849249489284638027959570566759163252096058182433718778559675021827829413609872456119146296644505544542285004039656497304273962171496421665230649414254017389079221194611978781122167934608994126622246556489040676599648589931415725577048848636163102448418105547040740174827154481538497505610115696198729317732354294209751807552154270389905794005896522099420648474586170883714097902251227368008289656429434318734568445009825844777247085698272890373935752004590620534932274765600969753269336687130555341358230381221261495738872326910495758056640625e-347Yi";
+    assert_eq!(parse(halfway), Ok(1e-323));
+
+    let result = parse::parse_partial::<f64, FORMAT>(b"1.1ks", &options);
+    assert_eq!(result, Ok((1100.0, 4)));
+    let result = parse::parse_partial::<f64, FORMAT>(b"-1E ", &options);
+    assert_eq!(result, Ok((-1e18, 3)));
+    let result = parse::parse_partial::<f64, FORMAT>(b"1.5e", &options);
+    assert_eq!(result, Err(Error::EmptyExponent(4)));
+
+    let options = Options::builder().suffix(SuffixMode::Decimal).build().unwrap();
+    let result = parse::parse_complete::<f64, FORMAT>(b"1Ki", &options);
+    assert_eq!(result, Err(Error::InvalidDigit(1)));
+    let result = parse::parse_partial::<f64, FORMAT>(b"1Mi", &options);
+    assert_eq!(result, Ok((1e6, 2)));
+
+    let options = Options::new();
+    let result = parse::parse_complete::<f64, FORMAT>(b"1k", &options);
+    assert_eq!(result, Err(Error::InvalidDigit(1)));
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "power-of-two")]
+use core::num;
+use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options, Rational, SuffixMode};
+use lexical_parse_integer::Bigint;
+use lexical_util::error::Error;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+
+fn parse(bytes: &[u8]) -> Result<Rational, Error> {
+    Rational::from_lexical(bytes)
+}
+
+fn big(digits: &str) -> Bigint {
+    <Bigint as lexical_parse_integer::FromLexical>::from_lexical(digits.as_bytes()).unwrap()
+}
+
+fn rational(numerator: i128, base: u32, scale: u32) -> Rational {
+    Rational::new(Bigint::from(numerator), base, scale)
+}
+
+#[test]
+fn parse_simple_test() {
+    assert_eq!(parse(b"0.1"), Ok(rational(1, 10, 1)));
+    assert_eq!(parse(b"1.50"), Ok(rational(15, 10, 1)));
+    assert_eq!(parse(b"1.5"), Ok(rational(15, 10, 1)));
+    assert_eq!(parse(b"12e3"), Ok(rational(12000, 10, 0)));
+    assert_eq!(parse(b"1.2e-3"), Ok(rational(12, 10, 4)));
+    assert_eq!(parse(b"-0.5"), Ok(rational(-5, 10, 1)));
+    assert_eq!(parse(b"+7"), Ok(rational(7, 10, 0)));
+    assert_eq!(parse(b"000123.4500"), Ok(rational(12345, 10, 2)));
+    assert_eq!(parse(b"1200"), Ok(rational(1200, 10, 0)));
+    assert_eq!(parse(b"1200e-2"), Ok(rational(12, 10, 0)));
+    assert_eq!(parse(b"1200e-3"), Ok(rational(12, 10, 1)));
+
+    let value = parse(b"-0.00").unwrap();
+    assert!(value.is_zero() && !value.is_negative());
+    assert_eq!(value.scale(), 0);
+    assert_eq!(parse(b"0e-20"), Ok(rational(0, 10, 0)));
+
+    let value = parse(b"-0.125").unwrap();
+    assert!(value.is_negative() && !value.is_integer());
+    assert_eq!(value.into_parts(), (Bigint::from(-125), Bigint::from(1000)));
+}
+
+#[test]
+fn parse_many_digits_test() {
+    let digits = "1234567890123456789012345678901234567890123456789";
+    let string = format!("0.{}", digits);
+    assert_eq!(parse(string.as_bytes()), Ok(Rational::new(big(digits), 10, 49)));
+    let string = format!("{}.{}e-5", digits, digits);
+    assert_eq!(parse(string.as_bytes()), Ok(Rational::new(big(&digits.repeat(2)), 10, 54)));
+    let string = format!("-{}00000000000000000000e-25", digits);
+    assert_eq!(parse(string.as_bytes()), Ok(Rational::new(-big(digits), 10, 5)));
+
+    // Trailing zeros, which are not significant for the float parsers.
+    let string = format!("1{}", "0".repeat(40));
+    assert_eq!(parse(string.as_bytes()), Ok(Rational::new(big(&string), 10, 0)));
+    let string = format!("1{}e-45", "0".repeat(40));
+    assert_eq!(parse(string.as_bytes()), Ok(rational(1, 10, 5)));
+    let string = format!("0.{}1{}", "0".repeat(30), "0".repeat(30));
+    assert_eq!(parse(string.as_bytes()), Ok(rational(1, 10, 31)));
+
+    // Far more digits than any float, which are never rounded.
+    let digits = "3".repeat(2000);
+    let string = format!("0.{}", digits);
+    let value = parse(string.as_bytes()).unwrap();
+    assert_eq!(value, Rational::new(big(&digits), 10, 2000));
+    assert_eq!(value.denominator(), Bigint::from(10).pow(2000));
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(parse(b""), Err(Error::Empty(0)));
+    assert_eq!(parse(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse(b"1.2a"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.2e"), Err(Error::EmptyExponent(4)));
+    assert!(parse(b"NaN").is_err());
+    assert!(parse(b"inf").is_err());
+    assert_eq!(parse(b"1e300000000"), Err(Error::Overflow(10)));
+    assert_eq!(parse(b"1e-300000000"), Err(Error::Overflow(11)));
+}
+
+#[test]
+fn parse_partial_test() {
+    assert_eq!(Rational::from_lexical_partial(b"1.50"), Ok((rational(15, 10, 1), 4)));
+    assert_eq!(Rational::from_lexical_partial(b"-2.5e1 USD"), Ok((rational(-25, 10, 0), 6)));
+    assert_eq!(Rational::from_lexical_partial(b"3.0.1"), Ok((rational(3, 10, 0), 3)));
+    assert_eq!(Rational::from_lexical_partial(b"1e300000000 "), Err(Error::Overflow(10)));
+}
+
+#[test]
+fn parse_options_test() {
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let result = Rational::from_lexical_with_options::<STANDARD>(b"1,25", &options);
+    assert_eq!(result, Ok(rational(125, 10, 2)));
+
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let result = Rational::from_lexical_with_options::<STANDARD>(b"1.5k", &options);
+    assert_eq!(result, Ok(rational(1500, 10, 0)));
+    let result = Rational::from_lexical_with_options::<STANDARD>(b"2.5m", &options);
+    assert_eq!(result, Ok(rational(25, 10, 4)));
+    let result = Rational::from_lexical_with_options::<STANDARD>(b"0.1Ki", &options);
+    assert_eq!(result, Ok(rational(1024, 10, 1)));
+    let result = Rational::from_lexical_partial_with_options::<STANDARD>(b"3Mi!", &options);
+    assert_eq!(result, Ok((rational(3145728, 10, 0), 3)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_radix_test() {
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    let options = Options::new();
+    let result = Rational::from_lexical_with_options::<BINARY>(b"1.1", &options);
+    assert_eq!(result, Ok(rational(3, 2, 1)));
+    let result = Rational::from_lexical_with_options::<BINARY>(b"-0.011e1", &options);
+    assert_eq!(result, Ok(rational(-3, 2, 2)));
+
+    // Hexadecimal digits with a binary exponent are scaled by powers of 2.
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let result = Rational::from_lexical_with_options::<HEX>(b"1.8p3", &options);
+    assert_eq!(result, Ok(rational(12, 2, 0)));
+    let result = Rational::from_lexical_with_options::<HEX>(b"1.8", &options);
+    assert_eq!(result, Ok(rational(3, 2, 1)));
+    let result = Rational::from_lexical_with_options::<HEX>(b"0.01p-1", &options);
+    assert_eq!(result, Ok(rational(1, 2, 9)));
+    let result = Rational::from_lexical_with_options::<HEX>(b"A0p0", &options);
+    assert_eq!(result, Ok(rational(160, 2, 0)));
+
+    // Many digits, with a truncated mantissa.
+    let digits = "F".repeat(40);
+    let string = format!("0.{}p4", digits);
+    let result = Rational::from_lexical_with_options::<HEX>(string.as_bytes(), &options);
+    let numerator = (Bigint::from(1) << 160) - Bigint::from(1);
+    assert_eq!(result, Ok(Rational::new(numerator, 2, 156)));
+    let string = format!("{}0p-8", digits);
+    let result = Rational::from_lexical_with_options::<HEX>(string.as_bytes(), &options);
+    let numerator = (Bigint::from(1) << 160) - Bigint::from(1);
+    assert_eq!(result, Ok(Rational::new(numerator, 2, 4)));
+}
+
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn parse_base_prefix_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let result = Rational::from_lexical_with_options::<HEX>(b"0x1.8p3", &options);
+    assert_eq!(result, Ok(rational(12, 2, 0)));
+    let result = Rational::from_lexical_with_options::<HEX>(b"-0x0.1p0", &options);
+    assert_eq!(result, Ok(rational(-1, 2, 4)));
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "format")]
+use core::num;
+use lexical_parse_float::token::{
+    tokenize,
+    tokenize_partial,
+    tokenize_partial_with_options,
+    tokenize_with_options,
+};
+use lexical_parse_float::{NumberKind, Options, Suffix, SuffixMode, Token, WhitespaceMode};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format::{self, NumberFormatBuilder};
+use lexical_util::format::STANDARD;
+
+#[test]
+fn tokenize_test() {
+    assert_eq!(
+        tokenize(b"-1.25e-3"),
+        Ok(Token {
+            kind: NumberKind::Float,
+            is_negative: true,
+            integer: b"1",
+            fraction: Some(b"25"),
+            exponent: Some(b"3"),
+            explicit_exponent: -3,
+            suffix: None,
+            has_separators: false,
+        })
+    );
+    assert_eq!(
+        tokenize(b"+0012"),
+        Ok(Token {
+            kind: NumberKind::Integer,
+            is_negative: false,
+            integer: b"0012",
+            fraction: None,
+            exponent: None,
+            explicit_exponent: 0,
+            suffix: None,
+            has_separators: false,
+        })
+    );
+    assert_eq!(
+        tokenize(b".5E10"),
+        Ok(Token {
+            kind: NumberKind::Float,
+            is_negative: false,
+            integer: b"",
+            fraction: Some(b"5"),
+            exponent: Some(b"10"),
+            explicit_exponent: 10,
+            suffix: None,
+            has_separators: false,
+        })
+    );
+    assert_eq!(
+        tokenize(b"-NaN"),
+        Ok(Token {
+            kind: NumberKind::Special,
+            is_negative: true,
+            integer: b"NaN",
+            fraction: None,
+            exponent: None,
+            explicit_exponent: 0,
+            suffix: None,
+            has_separators: false,
+        })
+    );
+
+    // Large exponents are saturated, but the digits are preserved.
+    let token = tokenize(b"1e999999999999").unwrap();
+    assert_eq!(token.exponent, Some(&b"999999999999"[..]));
+    assert!(token.explicit_exponent >= 0x10000000);
+
+    // Many digits are preserved.
+    let token = tokenize(b"1234567890123456789012345.5").unwrap();
+    assert_eq!(token.integer, b"1234567890123456789012345");
+
+    assert_eq!(tokenize(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(tokenize(b"-"), Err(Error::Empty(1)));
+}
+
+#[test]
+fn tokenize_partial_test() {
+    let (token, count) = tokenize_partial(b"12.5, 3").unwrap();
+    assert_eq!(count, 4);
+    assert_eq!(token.kind, NumberKind::Float);
+    assert_eq!(token.integer, b"12");
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+    assert_eq!(token.exponent, None);
+
+    let (token, count) = tokenize_partial(b"inf]").unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(token.kind, NumberKind::Special);
+    assert_eq!(token.integer, b"inf");
+
+    assert_eq!(tokenize_partial(b"1e+"), Err(Error::EmptyExponent(3)));
+}
+
+#[test]
+fn tokenize_with_options_test() {
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::builder()
+        .exponent(b'^')
+        .decimal_point(b',')
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    let token = tokenize_with_options::<FORMAT>(b" 3,5^-2 ", &options).unwrap();
+    assert_eq!(token.integer, b"3");
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+    assert_eq!(token.exponent, Some(&b"2"[..]));
+    assert_eq!(token.explicit_exponent, -2);
+
+    let (token, count) = tokenize_partial_with_options::<FORMAT>(b" 3,5 ;", &options).unwrap();
+    assert_eq!(count, 5);
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+}
+
+#[test]
+fn tokenize_suffix_test() {
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let token = tokenize_with_options::<FORMAT>(b"-1.5e3k", &options).unwrap();
+    assert_eq!(token.kind, NumberKind::Float);
+    assert!(token.is_negative);
+    assert_eq!(token.integer, b"1");
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+    assert_eq!(token.exponent, Some(&b"3"[..]));
+    assert_eq!(token.explicit_exponent, 3);
+    assert_eq!(token.suffix, Some(Suffix::Decimal(3)));
+
+    let token = tokenize_with_options::<FORMAT>(b"512Mi", &options).unwrap();
+    assert_eq!(token.kind, NumberKind::Integer);
+    assert_eq!(token.integer, b"512");
+    assert_eq!(token.suffix, Some(Suffix::Binary(20)));
+
+    let token = tokenize_with_options::<FORMAT>(b"2E", &options).unwrap();
+    assert_eq!(token.exponent, None);
+    assert_eq!(token.suffix, Some(Suffix::Decimal(18)));
+
+    let token = tokenize_with_options::<FORMAT>(b"3u", &options).unwrap();
+    assert_eq!(token.kind, NumberKind::Float);
+    assert_eq!(token.suffix, Some(Suffix::Decimal(-6)));
+
+    let (token, count) = tokenize_partial_with_options::<FORMAT>(b"10k, 3", &options).unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(token.integer, b"10");
+    assert_eq!(token.suffix, Some(Suffix::Decimal(3)));
+
+    let token = tokenize_with_options::<FORMAT>(b"inf", &options).unwrap();
+    assert_eq!(token.suffix, None);
+    assert_eq!(tokenize_with_options::<FORMAT>(b"1k5", &options), Err(Error::InvalidDigit(2)));
+
+    let token = tokenize_with_options::<FORMAT>(b"1.5", &Options::new()).unwrap();
+    assert_eq!(token.suffix, None);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn tokenize_separators_test() {
+    const FORMAT: u128 = NumberFormatBuilder::rebuild(format::PERMISSIVE)
+        .internal_digit_separator(true)
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .build();
+
+    let options = Options::new();
+    let token = tokenize_with_options::<FORMAT>(b"1_000.2_5e1_0", &options).unwrap();
+    assert_eq!(token.integer, b"1_000");
+    assert_eq!(token.fraction, Some(&b"2_5"[..]));
+    assert_eq!(token.exponent, Some(&b"1_0"[..]));
+    assert_eq!(token.explicit_exponent, 10);
+    assert!(token.has_separators);
+
+    let token = tokenize_with_options::<FORMAT>(b"1000.25", &options).unwrap();
+    assert!(!token.has_separators);
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn tokenize_base_prefix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().base_prefix(num::NonZeroU8::new(b'x')).build();
+
+    let options = Options::new();
+    let token = tokenize_with_options::<FORMAT>(b"-0x12.5", &options).unwrap();
+    assert!(token.is_negative);
+    assert_eq!(token.integer, b"12");
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+
+    let token = tokenize_with_options::<FORMAT>(b"0.5", &options).unwrap();
+    assert_eq!(token.integer, b"0");
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn tokenize_base_suffix_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().base_suffix(num::NonZeroU8::new(b'd')).build();
+
+    let options = Options::new();
+    let token = tokenize_with_options::<FORMAT>(b"12.5e3d", &options).unwrap();
+    assert_eq!(token.integer, b"12");
+    assert_eq!(token.fraction, Some(&b"5"[..]));
+    assert_eq!(token.exponent, Some(&b"3"[..]));
+    assert_eq!(token.explicit_exponent, 3);
+
+    let token = tokenize_with_options::<FORMAT>(b"12d", &options).unwrap();
+    assert_eq!(token.kind, NumberKind::Integer);
+    assert_eq!(token.integer, b"12");
+    assert_eq!(token.exponent, None);
+}
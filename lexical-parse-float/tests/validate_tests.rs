@@ -0,0 +1,105 @@
+use lexical_parse_float::validate::{
+    validate,
+    validate_partial,
+    validate_partial_with_options,
+    validate_with_options,
+};
+use lexical_parse_float::{NumberKind, Options, SuffixMode, WhitespaceMode};
+use lexical_util::error::Error;
+#[cfg(feature = "format")]
+use lexical_util::format;
+use lexical_util::format::STANDARD;
+
+#[test]
+fn validate_test() {
+    assert_eq!(validate(b"0"), Ok(NumberKind::Integer));
+    assert_eq!(validate(b"-12"), Ok(NumberKind::Integer));
+    assert_eq!(validate(b"+12"), Ok(NumberKind::Integer));
+    assert_eq!(validate(b"1."), Ok(NumberKind::Float));
+    assert_eq!(validate(b".5"), Ok(NumberKind::Float));
+    assert_eq!(validate(b"1.5"), Ok(NumberKind::Float));
+    assert_eq!(validate(b"1e5"), Ok(NumberKind::Float));
+    assert_eq!(validate(b"1E-5"), Ok(NumberKind::Float));
+    assert_eq!(validate(b"12345678901234567890123456789"), Ok(NumberKind::Integer));
+    assert_eq!(validate(b"1.2345678901234567890123456789e400"), Ok(NumberKind::Float));
+    assert_eq!(validate(b"NaN"), Ok(NumberKind::Special));
+    assert_eq!(validate(b"-inf"), Ok(NumberKind::Special));
+    assert_eq!(validate(b"Infinity"), Ok(NumberKind::Special));
+
+    assert_eq!(validate(b""), Err(Error::Empty(0)));
+    assert_eq!(validate(b"-"), Err(Error::Empty(1)));
+    assert_eq!(validate(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(validate(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(validate(b"NaNa"), Err(Error::EmptyMantissa(0)));
+}
+
+#[test]
+fn validate_partial_test() {
+    assert_eq!(validate_partial(b"12,"), Ok((NumberKind::Integer, 2)));
+    assert_eq!(validate_partial(b"-12.5]"), Ok((NumberKind::Float, 5)));
+    assert_eq!(validate_partial(b"1e5 "), Ok((NumberKind::Float, 3)));
+    assert_eq!(validate_partial(b"NaN,"), Ok((NumberKind::Special, 3)));
+    assert_eq!(validate_partial(b"-inf "), Ok((NumberKind::Special, 4)));
+
+    assert_eq!(validate_partial(b"x"), Err(Error::EmptyMantissa(0)));
+    assert_eq!(validate_partial(b"1e+"), Err(Error::EmptyExponent(3)));
+}
+
+#[test]
+fn validate_with_options_test() {
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::builder()
+        .exponent(b'^')
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    assert_eq!(validate_with_options::<FORMAT>(b"  1^5 ", &options), Ok(NumberKind::Float));
+    assert_eq!(validate_with_options::<FORMAT>(b" 15 ", &options), Ok(NumberKind::Integer));
+    assert_eq!(validate_with_options::<FORMAT>(b" 1e5", &options), Err(Error::InvalidDigit(2)));
+    assert_eq!(
+        validate_partial_with_options::<FORMAT>(b" 1^5 , 2", &options),
+        Ok((NumberKind::Float, 5))
+    );
+}
+
+#[test]
+fn validate_suffix_test() {
+    const FORMAT: u128 = STANDARD;
+
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    assert_eq!(validate_with_options::<FORMAT>(b"10k", &options), Ok(NumberKind::Integer));
+    assert_eq!(validate_with_options::<FORMAT>(b"512Mi", &options), Ok(NumberKind::Integer));
+    assert_eq!(validate_with_options::<FORMAT>(b"2E", &options), Ok(NumberKind::Integer));
+    assert_eq!(validate_with_options::<FORMAT>(b"1.5k", &options), Ok(NumberKind::Float));
+    assert_eq!(validate_with_options::<FORMAT>(b"3m", &options), Ok(NumberKind::Float));
+    assert_eq!(validate_with_options::<FORMAT>(b"1k5", &options), Err(Error::InvalidDigit(2)));
+    assert_eq!(
+        validate_partial_with_options::<FORMAT>(b"1.1ks", &options),
+        Ok((NumberKind::Float, 4))
+    );
+
+    let options = Options::new();
+    assert_eq!(validate_with_options::<FORMAT>(b"10k", &options), Err(Error::InvalidDigit(2)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn validate_json_test() {
+    const FORMAT: u128 = format::JSON;
+
+    let options = Options::new();
+    assert_eq!(validate_with_options::<FORMAT>(b"-12", &options), Ok(NumberKind::Integer));
+    assert_eq!(validate_with_options::<FORMAT>(b"1.5e10", &options), Ok(NumberKind::Float));
+    assert_eq!(validate_with_options::<FORMAT>(b"1.", &options), Err(Error::EmptyFraction(2)));
+    assert_eq!(
+        validate_with_options::<FORMAT>(b"01", &options),
+        Err(Error::InvalidLeadingZeros(0))
+    );
+    assert!(validate_with_options::<FORMAT>(b"NaN", &options).is_err());
+    assert_eq!(
+        validate_partial_with_options::<FORMAT>(b"12.5,", &options),
+        Ok((NumberKind::Float, 4))
+    );
+}
@@ -24,7 +24,7 @@ use lexical_util::step::min_step;
 /// Check if we can try to parse 8 digits.
 macro_rules! can_try_parse_8digits {
     ($iter:expr, $radix:expr) => {
-        $iter.is_contiguous() && (cfg!(not(feature = "radix")) || $radix <= 10)
+        $iter.is_contiguous() && (cfg!(not(feature = "power-of-two")) || $radix <= 10)
     };
 }
 
@@ -37,6 +37,7 @@ macro_rules! can_try_parse_8digits {
 macro_rules! parse_8digits {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $t:ident
@@ -48,8 +49,7 @@ macro_rules! parse_8digits {
 
         // Try our fast, 8-digit at a time optimizations.
         while let Some(val8) = try_parse_8digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix8);
-            $value = $value.wrapping_add(val8);
+            add_digits!($value, $overflow, radix8, val8);
         }
     }};
 }
@@ -63,6 +63,7 @@ macro_rules! parse_8digits {
 macro_rules! parse_4digits {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $t:ident
@@ -73,8 +74,7 @@ macro_rules! parse_4digits {
 
         // Try our fast, 4-digit at a time optimizations.
         while let Some(val4) = try_parse_4digits::<$t, _, $format>(&mut $iter) {
-            $value = $value.wrapping_mul(radix4);
-            $value = $value.wrapping_add(val4);
+            add_digits!($value, $overflow, radix4, val4);
         }
     }};
 }
@@ -85,6 +85,7 @@ macro_rules! parse_4digits {
 macro_rules! parse_digits {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $is_negative:ident,
@@ -121,20 +122,30 @@ macro_rules! parse_digits {
         // Makes no sense to do 8 digits at a time for 32-bit values,
         // since it can only hold 8 digits for base 10.
         if <$t>::BITS == 128 && can_try_parse_8digits!($iter, radix) {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $overflow, $iter, $format, $u);
         }
         if <$t>::BITS == 64 && can_try_parse_8digits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_8digits!($value, $iter, $format, $u);
+            parse_8digits!($value, $overflow, $iter, $format, $u);
         }
 
         // Optimizations for reading 4-digits at a time.
         // 36^4 is larger than a 16-bit integer. Likewise, 10^4 is almost
         // the limit of u16, so it's not worth it.
         if <$t>::BITS == 32 && can_try_parse_8digits!($iter, radix) && !<$t>::IS_SIGNED {
-            parse_4digits!($value, $iter, $format, $u);
+            parse_4digits!($value, $overflow, $iter, $format, $u);
         }
 
-        parse_1digit!($value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit)
+        parse_1digit!(
+            $value,
+            $overflow,
+            $iter,
+            $format,
+            $is_negative,
+            $start_index,
+            $t,
+            $u,
+            $invalid_digit
+        )
     }};
 }
 
@@ -2,9 +2,17 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "std")]
+use crate::bigint::parse_bigint;
 use crate::options::Options;
-use crate::parse::ParseInteger;
+use crate::parse::{parse_complete_suffixed, parse_partial_suffixed, ParseInteger};
+use crate::wide::{parse_signed_wide, parse_unsigned_wide};
+#[cfg(feature = "std")]
+use lexical_util::bigint::Bigint;
 use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::suffix::SuffixMode;
+use lexical_util::whitespace;
+use lexical_util::wide::{Int, Uint};
 use lexical_util::{from_lexical, from_lexical_with_options};
 
 /// Implement FromLexical for numeric type.
@@ -40,28 +48,61 @@ macro_rules! integer_from_lexical {
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_with_options<const FORMAT: u128>(
                 bytes: &[u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> lexical_util::result::Result<Self>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_complete::<$unsigned, FORMAT>(bytes)
+                whitespace::parse_complete(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| {
+                        if options.suffix().is_none() || format.radix() != 10 {
+                            Self::parse_complete::<$unsigned, FORMAT>(bytes)
+                        } else {
+                            parse_complete_suffixed::<_, $unsigned, FORMAT>(bytes, options.suffix())
+                        }
+                    },
+                )
             }
 
             $(#[$meta:meta])?
             #[cfg_attr(not(feature = "compact"), inline)]
             fn from_lexical_partial_with_options<const FORMAT: u128>(
                 bytes: &[u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
                 let format = NumberFormat::<{ FORMAT }> {};
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_partial::<$unsigned, FORMAT>(bytes)
+                Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+            }
+
+            $(#[$meta:meta])?
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)>
+            {
+                let format = NumberFormat::<{ FORMAT }> {};
+                whitespace::parse_partial(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| {
+                        if options.suffix().is_none() || format.radix() != 10 {
+                            Self::parse_partial::<$unsigned, FORMAT>(bytes)
+                        } else {
+                            parse_partial_suffixed::<_, $unsigned, FORMAT>(bytes, options.suffix())
+                        }
+                    },
+                )
             }
         }
     )*)
@@ -83,3 +124,146 @@ integer_from_lexical! {
     i128 u128 ;
     isize usize ;
 }
+
+// Implement FromLexical for wide integer types.
+macro_rules! wide_from_lexical {
+    ($($t:ident $cb:ident ; )*) => ($(
+        impl<const N: usize> FromLexical for $t<N> {
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self> {
+                $cb::<N, STANDARD>(bytes, true, SuffixMode::None).map(|(value, _)| value)
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)> {
+                $cb::<N, STANDARD>(bytes, false, SuffixMode::None)
+            }
+        }
+
+        impl<const N: usize> FromLexicalWithOptions for $t<N> {
+            type Options = Options;
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<Self> {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if !format.is_valid() {
+                    return Err(format.error());
+                }
+                let mode = wide_suffix::<FORMAT>(options);
+                whitespace::parse_complete(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| $cb::<N, FORMAT>(bytes, true, mode).map(|(value, _)| value),
+                )
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline)]
+            fn from_lexical_partial_with_options<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                let format = NumberFormat::<{ FORMAT }> {};
+                if !format.is_valid() {
+                    return Err(format.error());
+                }
+                Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+            }
+
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                let mode = wide_suffix::<FORMAT>(options);
+                whitespace::parse_partial(
+                    bytes,
+                    options.leading_whitespace(),
+                    options.trailing_whitespace(),
+                    |bytes| $cb::<N, FORMAT>(bytes, false, mode),
+                )
+            }
+        }
+    )*)
+}
+
+/// Get the suffix mode for wide integers, which only applies to decimal strings.
+#[inline(always)]
+fn wide_suffix<const FORMAT: u128>(options: &Options) -> SuffixMode {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.radix() == 10 {
+        options.suffix()
+    } else {
+        SuffixMode::None
+    }
+}
+
+wide_from_lexical! {
+    Uint parse_unsigned_wide ;
+    Int parse_signed_wide ;
+}
+
+#[cfg(feature = "std")]
+impl FromLexical for Bigint {
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical(bytes: &[u8]) -> lexical_util::result::Result<Self> {
+        parse_bigint::<STANDARD>(bytes, true, SuffixMode::None).map(|(value, _)| value)
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial(bytes: &[u8]) -> lexical_util::result::Result<(Self, usize)> {
+        parse_bigint::<STANDARD>(bytes, false, SuffixMode::None)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromLexicalWithOptions for Bigint {
+    type Options = Options;
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<Self> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        }
+        let mode = wide_suffix::<FORMAT>(options);
+        whitespace::parse_complete(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_bigint::<FORMAT>(bytes, true, mode).map(|(value, _)| value),
+        )
+    }
+
+    #[cfg_attr(not(feature = "compact"), inline)]
+    fn from_lexical_partial_with_options<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        let format = NumberFormat::<{ FORMAT }> {};
+        if !format.is_valid() {
+            return Err(format.error());
+        }
+        Self::from_lexical_partial_with_valid_format::<FORMAT>(bytes, options)
+    }
+
+    #[inline(always)]
+    fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Self::Options,
+    ) -> lexical_util::result::Result<(Self, usize)> {
+        let mode = wide_suffix::<FORMAT>(options);
+        whitespace::parse_partial(
+            bytes,
+            options.leading_whitespace(),
+            options.trailing_whitespace(),
+            |bytes| parse_bigint::<FORMAT>(bytes, false, mode),
+        )
+    }
+}
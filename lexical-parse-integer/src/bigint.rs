@@ -0,0 +1,137 @@
+//! Radix-generic string-to-integer conversion routines for big integers.
+//!
+//! The digits are parsed a limb at a time, like the wide integers, and
+//! then combined using subquadratic, divide-and-conquer base conversion:
+//! the high and low halves of the limbs of digits are converted
+//! recursively, and the high half is scaled by a power of the radix, so
+//! the cost is dominated by the fast multiplication of the large halves.
+
+#![cfg(feature = "std")]
+#![doc(hidden)]
+
+use crate::wide::parse_chunks;
+use lexical_util::bigint::Bigint;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::limb::Limb;
+use lexical_util::result::Result;
+use lexical_util::suffix::{parse_suffix, Suffix, SuffixMode};
+use lexical_util::wide::limb_power;
+
+/// The number of limbs of digits where divide-and-conquer conversion is faster.
+const DIVIDE_CONQUER_CUTOFF: usize = 32;
+
+/// Convert limbs of digits, from most to least significant, to a big integer.
+///
+/// Each power is the square of the previous, and the first is the
+/// largest power of the radix that fits in a limb, so `powers[level]`
+/// scales the value by `2^level` limbs of digits.
+fn combine(chunks: &[Limb], powers: &mut Vec<Bigint>) -> Bigint {
+    if chunks.len() <= DIVIDE_CONQUER_CUTOFF {
+        let power = powers[0].as_limbs()[0];
+        let mut value = Bigint::new();
+        for &chunk in chunks.iter() {
+            value.mul_small_add(power, chunk);
+        }
+        return value;
+    }
+
+    // Split the chunks so the low half has a power-of-two number of limbs.
+    let mut level = 0;
+    while 2 << level < chunks.len() {
+        level += 1;
+    }
+    while powers.len() <= level {
+        let last = &powers[powers.len() - 1];
+        let next = last * last;
+        powers.push(next);
+    }
+    let (hi, lo) = chunks.split_at(chunks.len() - (1 << level));
+    let hi = combine(hi, powers);
+    let lo = combine(lo, powers);
+    &hi * &powers[level] + lo
+}
+
+/// Convert limbs of digits, from most to least significant, to a big integer.
+///
+/// Each limb has the digits of `power`, the largest power of the radix
+/// that fits in a limb, from [`limb_power`].
+#[inline]
+pub fn combine_chunks(chunks: &[Limb], power: Limb) -> Bigint {
+    let mut powers = vec![Bigint::from(power)];
+    combine(chunks, &mut powers)
+}
+
+/// Parse the magnitude and sign of a big integer.
+///
+/// Returns the magnitude, if the value is negative, and the number of
+/// processed bytes.
+fn parse_magnitude<const FORMAT: u128>(
+    bytes: &[u8],
+    is_complete: bool,
+) -> Result<(Bigint, bool, usize)> {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let (power, step) = limb_power(radix);
+    let mut chunks = Vec::new();
+    let mut last: (Limb, usize) = (0, 0);
+    let (is_negative, count) =
+        parse_chunks::<FORMAT, _>(bytes, true, is_complete, |limb, digits| {
+            // Only the last limb can have fewer digits.
+            if digits == step {
+                chunks.push(limb);
+            } else {
+                last = (limb, digits);
+            }
+        })?;
+
+    let mut value = combine_chunks(&chunks, power);
+    if last.1 != 0 {
+        value.mul_small_add((radix as Limb).pow(last.1 as u32), last.0);
+    }
+    Ok((value, is_negative, count))
+}
+
+/// Scale the magnitude by a magnitude suffix, if present.
+///
+/// Returns the number of processed bytes.
+fn parse_magnitude_suffix(
+    value: &mut Bigint,
+    bytes: &[u8],
+    count: usize,
+    mode: SuffixMode,
+) -> usize {
+    match parse_suffix(&bytes[count..], mode) {
+        Some((Suffix::Decimal(exp), length)) if exp > 0 => {
+            *value *= Bigint::from(10u32).pow(exp as u32);
+            count + length
+        },
+        Some((Suffix::Binary(exp), length)) => {
+            *value <<= exp as usize;
+            count + length
+        },
+        _ => count,
+    }
+}
+
+/// Parse a big integer, with an optional suffix.
+#[inline]
+pub fn parse_bigint<const FORMAT: u128>(
+    bytes: &[u8],
+    is_complete: bool,
+    mode: SuffixMode,
+) -> Result<(Bigint, usize)> {
+    let complete_digits = is_complete && mode.is_none();
+    let (mut value, is_negative, count) = parse_magnitude::<FORMAT>(bytes, complete_digits)?;
+    let count = if mode.is_none() {
+        count
+    } else {
+        parse_magnitude_suffix(&mut value, bytes, count, mode)
+    };
+    if is_complete && count != bytes.len() {
+        return Err(Error::InvalidDigit(count));
+    }
+    if is_negative {
+        value = -value;
+    }
+    Ok((value, count))
+}
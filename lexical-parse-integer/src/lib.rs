@@ -64,17 +64,22 @@
 mod shared;
 
 pub mod algorithm;
+pub mod bigint;
 pub mod compact;
 pub mod options;
 pub mod parse;
+pub mod wide;
 
 mod api;
 
 // Re-exports
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, SuffixMode, WhitespaceMode};
+#[cfg(feature = "std")]
+pub use lexical_util::bigint::Bigint;
 pub use lexical_util::error::Error;
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
+pub use lexical_util::wide::{Int, Uint, I256, I512, U256, U512};
@@ -2,17 +2,76 @@
 
 use lexical_util::options::ParseOptions;
 use lexical_util::result::Result;
+pub use lexical_util::suffix::SuffixMode;
+pub use lexical_util::whitespace::WhitespaceMode;
 use static_assertions::const_assert;
 
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Characters to skip as whitespace before the number.
+    leading_whitespace: WhitespaceMode,
+    /// Characters to skip as whitespace after the number.
+    trailing_whitespace: WhitespaceMode,
+    /// Magnitude suffixes to parse after the number.
+    suffix: SuffixMode,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            leading_whitespace: WhitespaceMode::None,
+            trailing_whitespace: WhitespaceMode::None,
+            suffix: SuffixMode::None,
+        }
+    }
+
+    // GETTERS
+
+    /// Get the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn get_leading_whitespace(&self) -> WhitespaceMode {
+        self.leading_whitespace
+    }
+
+    /// Get the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn get_trailing_whitespace(&self) -> WhitespaceMode {
+        self.trailing_whitespace
+    }
+
+    /// Get the magnitude suffixes to parse after the number.
+    #[inline(always)]
+    pub const fn get_suffix(&self) -> SuffixMode {
+        self.suffix
+    }
+
+    // SETTERS
+
+    /// Set the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(mut self, leading_whitespace: WhitespaceMode) -> Self {
+        self.leading_whitespace = leading_whitespace;
+        self
+    }
+
+    /// Set the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(mut self, trailing_whitespace: WhitespaceMode) -> Self {
+        self.trailing_whitespace = trailing_whitespace;
+        self
+    }
+
+    /// Set the magnitude suffixes to parse after the number.
+    ///
+    /// Suffixes are only parsed for decimal numbers, and are ignored
+    /// for any other radix.
+    #[inline(always)]
+    pub const fn suffix(mut self, suffix: SuffixMode) -> Self {
+        self.suffix = suffix;
+        self
     }
 
     // BUILDERS
@@ -30,7 +89,11 @@ impl OptionsBuilder {
     /// Safe as long as`is_valid` is true.
     #[inline(always)]
     pub const unsafe fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            leading_whitespace: self.leading_whitespace,
+            trailing_whitespace: self.trailing_whitespace,
+            suffix: self.suffix,
+        }
     }
 
     /// Build the Options struct.
@@ -53,22 +116,31 @@ impl Default for OptionsBuilder {
 /// # Examples
 ///
 /// ```rust
-/// use lexical_parse_integer::options::Options;
+/// use lexical_parse_integer::options::{Options, WhitespaceMode};
 ///
 /// # pub fn main() {
 /// let options = Options::builder()
+///     .leading_whitespace(WhitespaceMode::Ascii)
 ///     .build()
 ///     .unwrap();
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Characters to skip as whitespace before the number.
+    leading_whitespace: WhitespaceMode,
+    /// Characters to skip as whitespace after the number.
+    trailing_whitespace: WhitespaceMode,
+    /// Magnitude suffixes to parse after the number.
+    suffix: SuffixMode,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        // SAFETY: always safe since it uses the default arguments.
+        unsafe { Self::builder().build_unchecked() }
     }
 
     /// Check if the options state is valid.
@@ -77,6 +149,58 @@ impl Options {
         true
     }
 
+    // GETTERS
+
+    /// Get the characters to skip as whitespace before the number.
+    #[inline(always)]
+    pub const fn leading_whitespace(&self) -> WhitespaceMode {
+        self.leading_whitespace
+    }
+
+    /// Get the characters to skip as whitespace after the number.
+    #[inline(always)]
+    pub const fn trailing_whitespace(&self) -> WhitespaceMode {
+        self.trailing_whitespace
+    }
+
+    /// Get the magnitude suffixes to parse after the number.
+    #[inline(always)]
+    pub const fn suffix(&self) -> SuffixMode {
+        self.suffix
+    }
+
+    // SETTERS
+
+    /// Set the characters to skip as whitespace before the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_leading_whitespace(&mut self, leading_whitespace: WhitespaceMode) {
+        self.leading_whitespace = leading_whitespace;
+    }
+
+    /// Set the characters to skip as whitespace after the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_trailing_whitespace(&mut self, trailing_whitespace: WhitespaceMode) {
+        self.trailing_whitespace = trailing_whitespace;
+    }
+
+    /// Set the magnitude suffixes to parse after the number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_suffix(&mut self, suffix: SuffixMode) {
+        self.suffix = suffix;
+    }
+
     // BUILDERS
 
     /// Get OptionsBuilder as a static function.
@@ -88,7 +212,11 @@ impl Options {
     /// Create OptionsBuilder using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            leading_whitespace: self.leading_whitespace,
+            trailing_whitespace: self.trailing_whitespace,
+            suffix: self.suffix,
+        }
     }
 }
 
@@ -113,3 +241,23 @@ impl ParseOptions for Options {
 #[rustfmt::skip]
 pub const STANDARD: Options = Options::new();
 const_assert!(STANDARD.is_valid());
+
+/// Number format that skips surrounding ASCII whitespace.
+#[rustfmt::skip]
+pub const ASCII_WHITESPACE: Options = unsafe {
+    Options::builder()
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build_unchecked()
+};
+const_assert!(ASCII_WHITESPACE.is_valid());
+
+/// Number format that skips surrounding Unicode whitespace.
+#[rustfmt::skip]
+pub const UNICODE_WHITESPACE: Options = unsafe {
+    Options::builder()
+        .leading_whitespace(WhitespaceMode::Unicode)
+        .trailing_whitespace(WhitespaceMode::Unicode)
+        .build_unchecked()
+};
+const_assert!(UNICODE_WHITESPACE.is_valid());
@@ -8,8 +8,10 @@ use crate::algorithm::{algorithm_complete, algorithm_partial};
 #[cfg(feature = "compact")]
 use crate::compact::{algorithm_complete, algorithm_partial};
 
+use lexical_util::error::Error;
 use lexical_util::num::{Integer, UnsignedInteger};
 use lexical_util::result::Result;
+use lexical_util::suffix::{parse_suffix, Suffix, SuffixMode};
 
 /// Parse integer trait, implemented in terms of the optimized back-end.
 pub trait ParseInteger: Integer {
@@ -36,3 +38,62 @@ macro_rules! parse_integer_impl {
 
 parse_integer_impl! { u8 u16 u32 u64 u128 usize }
 parse_integer_impl! { i8 i16 i32 i64 i128 isize }
+
+// SUFFIX
+// ------
+
+/// Scale an integer by `radix^exp`, erroring at `index` on overflow.
+#[inline]
+fn scale<T: Integer>(mut value: T, radix: u32, exp: u32, index: usize) -> Result<T> {
+    let radix = T::from_u32(radix);
+    for _ in 0..exp {
+        value = match value.checked_mul(radix) {
+            Some(value) => value,
+            None if value < T::ZERO => return Err(Error::Underflow(index)),
+            None => return Err(Error::Overflow(index)),
+        };
+    }
+    Ok(value)
+}
+
+/// Parse a partial integer followed by an optional magnitude suffix.
+///
+/// SI prefixes smaller than 1, such as `m`, are not valid suffixes for
+/// integers, and are left unprocessed. Any overflow from scaling the
+/// integer is reported at the index of the suffix.
+#[inline]
+pub fn parse_partial_suffixed<T, Unsigned, const FORMAT: u128>(
+    bytes: &[u8],
+    mode: SuffixMode,
+) -> Result<(T, usize)>
+where
+    T: ParseInteger,
+    Unsigned: UnsignedInteger,
+{
+    let (value, count) = T::parse_partial::<Unsigned, FORMAT>(bytes)?;
+    match parse_suffix(&bytes[count..], mode) {
+        Some((Suffix::Decimal(exp), length)) if exp > 0 => {
+            Ok((scale(value, 10, exp as u32, count)?, count + length))
+        },
+        Some((Suffix::Binary(exp), length)) => Ok((scale(value, 2, exp, count)?, count + length)),
+        _ => Ok((value, count)),
+    }
+}
+
+/// Parse a complete integer followed by an optional magnitude suffix.
+#[inline]
+pub fn parse_complete_suffixed<T, Unsigned, const FORMAT: u128>(
+    bytes: &[u8],
+    mode: SuffixMode,
+) -> Result<T>
+where
+    T: ParseInteger,
+    Unsigned: UnsignedInteger,
+{
+    let (value, count) = parse_partial_suffixed::<T, Unsigned, FORMAT>(bytes, mode)?;
+    if count == bytes.len() {
+        Ok(value)
+    } else {
+        Err(Error::InvalidDigit(count))
+    }
+}
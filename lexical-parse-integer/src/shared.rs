@@ -13,9 +13,10 @@
 //! 2. Overflow checking on invalid digits for partial parsers, while
 //!     just returning invalid digits for complete parsers.
 //! 3. A format-aware sign parser.
-//! 4. Digit parsing algorithms which explicitly wrap on overflow, for no
-//!     additional overhead. This has major performance wins for **most**
-//!     real-world integers, so most valid input will be substantially faster.
+//! 4. Digit parsing algorithms which explicitly wrap on overflow, and only
+//!     record if the value wrapped, without branching. This has major
+//!     performance wins for **most** real-world integers, so most valid
+//!     input will be substantially faster.
 //! 5. An algorithm to detect if overflow occurred. This is comprehensive,
 //!     and short-circuits for common cases.
 //! 6. A parsing algorithm for unsigned integers, always producing positive
@@ -53,6 +54,7 @@ macro_rules! into_ok_partial {
 macro_rules! invalid_digit_complete {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $is_negative:ident,
@@ -70,6 +72,7 @@ macro_rules! invalid_digit_complete {
 macro_rules! invalid_digit_partial {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $is_negative:ident,
@@ -77,17 +80,56 @@ macro_rules! invalid_digit_partial {
         $t:ident,
         $u:ident
     ) => {{
-        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
         let count = $iter.current_count() - $start_index - 1;
-        if is_overflow::<$t, $u, $format>($value, count, $is_negative) {
+        let index = $iter.cursor() - 1;
+        into_value!($value, $overflow, count, $format, $is_negative, $t, $u, into_ok_partial, index)
+    }};
+}
+
+/// Add digits to the value, wrapping on overflow and recording if it wrapped.
+///
+/// A wrapped value may be indistinguishable from a valid one with the
+/// same number of digits, such as `999` for `u8`, which wraps to `231`,
+/// so the overflow can't be detected from the final value alone.
+macro_rules! add_digits {
+    ($value:ident, $overflow:ident, $radix:expr, $digits:expr) => {{
+        let (value, mul_overflow) = $value.overflowing_mul($radix);
+        let (value, add_overflow) = value.overflowing_add($digits);
+        $value = value;
+        $overflow |= mul_overflow | add_overflow;
+    }};
+}
+
+/// Return the signed value from the parsed digits, or an error on overflow.
+///
+/// This is shared by the complete and partial parsers, so a value is
+/// negated the same way whether or not parsing stops at an invalid digit.
+macro_rules! into_value {
+    (
+        $value:ident,
+        $overflow:ident,
+        $count:ident,
+        $format:ident,
+        $is_negative:ident,
+        $t:ident,
+        $u:ident,
+        $into_ok:ident,
+        $index:expr
+    ) => {{
+        let radix = NumberFormat::<{ $format }>::MANTISSA_RADIX;
+        if $overflow || is_overflow::<$t, $u, $format>($value, $count, $is_negative) {
             let min = min_step(radix, <$t as Integer>::BITS, <$t>::IS_SIGNED);
             if <$t>::IS_SIGNED && $is_negative {
-                into_error!(Underflow, (count - 1).min(min + 1))
+                into_error!(Underflow, ($count - 1).min(min + 1))
             } else {
-                into_error!(Overflow, (count - 1).min(min + 1))
+                into_error!(Overflow, ($count - 1).min(min + 1))
             }
+        } else if <$t>::IS_SIGNED && $is_negative {
+            // Need to cast it to the signed type first, so we don't
+            // get an invalid representation for i128 if it's widened.
+            $into_ok!(as_cast::<$t, _>($value.wrapping_neg()), $index)
         } else {
-            into_ok_partial!($value, $iter.cursor() - 1)
+            $into_ok!($value, $index)
         }
     }};
 }
@@ -194,24 +236,20 @@ macro_rules! parse_value {
         // and cast the value over, which is fast. Leads to substantial
         // improvements due to decreased branching for all but `i8`.
         let mut value = <$u>::ZERO;
-        let format = NumberFormat::<{ $format }> {};
-        $parser!(value, $iter, $format, $is_negative, $start_index, $t, $u, $invalid_digit);
+        let mut overflow = false;
+        $parser!(
+            value,
+            overflow,
+            $iter,
+            $format,
+            $is_negative,
+            $start_index,
+            $t,
+            $u,
+            $invalid_digit
+        );
         let count = $iter.current_count() - $start_index;
-
-        if is_overflow::<$t, $u, $format>(value, count, $is_negative) {
-            let min = min_step(format.radix(), <$t as Integer>::BITS, <$t>::IS_SIGNED);
-            if <$t>::IS_SIGNED && $is_negative {
-                into_error!(Underflow, (count - 1).min(min + 1))
-            } else {
-                into_error!(Overflow, (count - 1).min(min + 1))
-            }
-        } else if <$t>::IS_SIGNED && $is_negative {
-            // Need to cast it to the signed type first, so we don't
-            // get an invalid representation for i128 if it's widened.
-            $into_ok!(as_cast::<$t, _>(value.wrapping_neg()), $iter.length())
-        } else {
-            $into_ok!(value, $iter.length())
-        }
+        into_value!(value, overflow, count, $format, $is_negative, $t, $u, $into_ok, $iter.length())
     }};
 }
 
@@ -221,6 +259,7 @@ macro_rules! parse_value {
 macro_rules! parse_1digit {
     (
         $value:ident,
+        $overflow:ident,
         $iter:ident,
         $format:ident,
         $is_negative:ident,
@@ -261,6 +300,7 @@ macro_rules! parse_1digit {
                     // Might have handled our base-prefix here.
                     return $invalid_digit!(
                         $value,
+                        $overflow,
                         $iter,
                         $format,
                         $is_negative,
@@ -270,8 +310,7 @@ macro_rules! parse_1digit {
                     );
                 },
             };
-            $value = $value.wrapping_mul(as_cast(radix));
-            $value = $value.wrapping_add(as_cast(digit));
+            add_digits!($value, $overflow, as_cast(radix), as_cast(digit));
         }
     }};
 }
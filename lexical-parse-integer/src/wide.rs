@@ -0,0 +1,255 @@
+//! Radix-generic string-to-integer conversion routines for wide integers.
+//!
+//! Wide integers are parsed by limb multiply-add: digits are accumulated
+//! into a native limb until the limb would overflow, and each limb of
+//! digits is then added to the wide integer, scaled by the power of the
+//! radix for the number of digits. The syntax is otherwise identical to
+//! the native integer parsers.
+
+#![doc(hidden)]
+
+use lexical_util::digit::char_to_digit_const;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::iterator::{AsBytes, BytesIter};
+use lexical_util::limb::Limb;
+use lexical_util::num::AsPrimitive;
+use lexical_util::result::Result;
+use lexical_util::suffix::{parse_suffix, Suffix, SuffixMode};
+use lexical_util::wide::{limb_power, Int, Uint};
+
+/// Parse the sign and digits of an integer, a limb of digits at a time.
+///
+/// Each limb of digits is passed to the callback with the number of
+/// digits it contains, which is the number of digits in the largest
+/// power of the radix that fits in a limb for all but the last limb.
+/// Returns if the value is negative and the number of processed bytes.
+/// A complete parser errors on the first invalid digit, while a partial
+/// parser stops.
+pub(crate) fn parse_chunks<const FORMAT: u128, Cb: FnMut(Limb, usize)>(
+    bytes: &[u8],
+    is_signed: bool,
+    is_complete: bool,
+    mut cb: Cb,
+) -> Result<(bool, usize)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let mut iter = byte.integer_iter();
+    let (is_negative, shift) = match iter.peek() {
+        Some(&b'+') if !format.no_positive_mantissa_sign() => (false, 1),
+        Some(&b'+') => return Err(Error::InvalidPositiveSign(0)),
+        Some(&b'-') if is_signed => (true, 1),
+        Some(&b'-') => return Err(Error::InvalidNegativeSign(0)),
+        Some(_) if format.required_mantissa_sign() => return Err(Error::MissingSign(0)),
+        _ => (false, 0),
+    };
+    // SAFETY: safe since we shift at most one for a parsed sign byte.
+    unsafe { iter.step_by_unchecked(shift) };
+    if iter.is_done() {
+        return Err(Error::Empty(shift));
+    }
+    // Skip any leading zeros. These may contain digit separators, so
+    // indexes are taken from the cursor rather than the number of zeros.
+    let zeros_index = iter.cursor();
+    let zeros = iter.skip_zeros();
+    let mut start_index = iter.cursor();
+
+    // Now, check to see if we have a valid base prefix.
+    let base_prefix = format.base_prefix();
+    let mut is_prefix = false;
+    if cfg!(feature = "format") && base_prefix != 0 && zeros == 1 {
+        if let Some(&c) = iter.peek() {
+            is_prefix = if format.case_sensitive_base_prefix() {
+                c == base_prefix
+            } else {
+                c.eq_ignore_ascii_case(&base_prefix)
+            };
+            if is_prefix {
+                // SAFETY: safe since `byte.len() >= 1`.
+                unsafe { iter.step_unchecked() };
+                if iter.is_done() {
+                    return Err(Error::Empty(iter.cursor()));
+                } else {
+                    start_index = iter.cursor();
+                }
+            }
+        }
+    }
+
+    // Check for invalid leading zeros, if a prefix was not found.
+    if cfg!(feature = "format") && !is_prefix && format.no_integer_leading_zeros() && zeros != 0 {
+        let index = zeros_index;
+        if zeros > 1 {
+            return Err(Error::InvalidLeadingZeros(index));
+        }
+        return match iter.peek().map(|&c| char_to_digit_const(c, radix)) {
+            Some(Some(_)) => Err(Error::InvalidLeadingZeros(index)),
+            _ => Ok((false, iter.cursor())),
+        };
+    }
+
+    // Parse the digits a limb at a time.
+    let (_, step) = limb_power(radix);
+    let mut limb: Limb = 0;
+    let mut digits = 0;
+    let mut count = iter.length();
+    while let Some(&c) = iter.next() {
+        let digit = match char_to_digit_const(c, radix) {
+            Some(v) => v,
+            None => {
+                // Check for a base suffix, which requires at least 1 digit.
+                let base_suffix = format.base_suffix();
+                if cfg!(feature = "format") && base_suffix != 0 && iter.cursor() - start_index > 1 {
+                    let is_suffix = if format.case_sensitive_base_suffix() {
+                        c == base_suffix
+                    } else {
+                        c.eq_ignore_ascii_case(&base_suffix)
+                    };
+                    if is_suffix && iter.is_done() {
+                        break;
+                    } else if is_suffix {
+                        // SAFETY: safe since the iterator is not empty.
+                        unsafe { iter.step_unchecked() };
+                    }
+                }
+                if is_complete {
+                    return Err(Error::InvalidDigit(iter.cursor() - 1));
+                }
+                count = iter.cursor() - 1;
+                break;
+            },
+        };
+        limb = limb * radix as Limb + digit as Limb;
+        digits += 1;
+        if digits == step {
+            cb(limb, digits);
+            limb = 0;
+            digits = 0;
+        }
+    }
+    if digits != 0 {
+        cb(limb, digits);
+    }
+
+    Ok((is_negative, count))
+}
+
+/// Parse the magnitude and sign of a wide integer.
+///
+/// Returns the magnitude, if the value is negative, the number of
+/// processed bytes, and if the magnitude overflowed.
+fn parse_magnitude<const N: usize, const FORMAT: u128>(
+    bytes: &[u8],
+    is_signed: bool,
+    is_complete: bool,
+) -> Result<(Uint<N>, bool, usize, bool)> {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let (power, step) = limb_power(radix);
+    let mut value = Uint::<N>::ZERO;
+    let mut overflow = false;
+    let (is_negative, count) =
+        parse_chunks::<FORMAT, _>(bytes, is_signed, is_complete, |limb, digits| {
+            let power = if digits == step {
+                power
+            } else {
+                (radix as Limb).pow(digits as u32)
+            };
+            overflow |= value.mul_small_add(power.as_u64(), limb.as_u64()) != 0;
+        })?;
+    Ok((value, is_negative, count, overflow))
+}
+
+/// Scale the magnitude by a magnitude suffix, if present.
+///
+/// Returns if the magnitude overflowed, and the number of processed bytes.
+fn parse_magnitude_suffix<const N: usize>(
+    value: &mut Uint<N>,
+    bytes: &[u8],
+    count: usize,
+    mode: SuffixMode,
+) -> (bool, usize) {
+    let mut overflow = false;
+    match parse_suffix(&bytes[count..], mode) {
+        Some((Suffix::Decimal(exp), length)) if exp > 0 => {
+            for _ in 0..exp {
+                overflow |= value.mul_small_add(10, 0) != 0;
+            }
+            (overflow, count + length)
+        },
+        Some((Suffix::Binary(exp), length)) => {
+            let mut exp = exp as usize;
+            while exp != 0 {
+                let shift = exp.min(63);
+                overflow |= value.mul_small_add(1 << shift, 0) != 0;
+                exp -= shift;
+            }
+            (overflow, count + length)
+        },
+        _ => (false, count),
+    }
+}
+
+/// Parse a wide integer into its magnitude, with an optional suffix.
+#[inline]
+fn parse_wide<const N: usize, const FORMAT: u128>(
+    bytes: &[u8],
+    is_signed: bool,
+    is_complete: bool,
+    mode: SuffixMode,
+) -> Result<(Uint<N>, bool, usize, bool)> {
+    let complete_digits = is_complete && mode.is_none();
+    let (mut value, is_negative, count, overflow) =
+        parse_magnitude::<N, FORMAT>(bytes, is_signed, complete_digits)?;
+    let (overflow, count) = if mode.is_none() {
+        (overflow, count)
+    } else {
+        let (suffix_overflow, count) = parse_magnitude_suffix(&mut value, bytes, count, mode);
+        (overflow || suffix_overflow, count)
+    };
+    if is_complete && count != bytes.len() {
+        return Err(Error::InvalidDigit(count));
+    }
+    Ok((value, is_negative, count, overflow))
+}
+
+/// Parse an unsigned, wide integer.
+///
+/// Overflow is reported at the index of the last processed byte.
+#[inline]
+pub fn parse_unsigned_wide<const N: usize, const FORMAT: u128>(
+    bytes: &[u8],
+    is_complete: bool,
+    mode: SuffixMode,
+) -> Result<(Uint<N>, usize)> {
+    let (value, _, count, overflow) = parse_wide::<N, FORMAT>(bytes, false, is_complete, mode)?;
+    if overflow {
+        return Err(Error::Overflow(count.saturating_sub(1)));
+    }
+    Ok((value, count))
+}
+
+/// Parse a signed, wide integer.
+///
+/// Overflow and underflow are reported at the index of the last processed byte.
+#[inline]
+pub fn parse_signed_wide<const N: usize, const FORMAT: u128>(
+    bytes: &[u8],
+    is_complete: bool,
+    mode: SuffixMode,
+) -> Result<(Int<N>, usize)> {
+    let (value, is_negative, count, overflow) =
+        parse_wide::<N, FORMAT>(bytes, true, is_complete, mode)?;
+    if is_negative {
+        if overflow || value > Int::<N>::MIN.unsigned_abs() {
+            return Err(Error::Underflow(count.saturating_sub(1)));
+        }
+        Ok((Int::from_bits(value.wrapping_neg()), count))
+    } else {
+        if overflow || value > Int::<N>::MAX.to_bits() {
+            return Err(Error::Overflow(count.saturating_sub(1)));
+        }
+        Ok((Int::from_bits(value), count))
+    }
+}
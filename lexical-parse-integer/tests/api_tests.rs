@@ -136,6 +136,22 @@ fn i128_decimal_test() {
     assert_eq!(Err(Error::InvalidDigit(1)), i128::from_lexical(b"1a"));
 }
 
+#[test]
+fn wrapped_overflow_test() {
+    // These wrap to values with the same number of digits, such as `231`.
+    assert_eq!(Err(Error::Overflow(2)), u8::from_lexical(b"999"));
+    assert_eq!(Err(Error::Overflow(2)), i8::from_lexical(b"999"));
+    assert_eq!(Err(Error::Underflow(2)), i8::from_lexical(b"-999"));
+    assert_eq!(Err(Error::Overflow(4)), u16::from_lexical(b"99999"));
+    assert_eq!(Err(Error::Overflow(9)), u32::from_lexical(b"9999999999"));
+    assert_eq!(Err(Error::Overflow(9)), i32::from_lexical(b"9999999999"));
+    assert_eq!(Err(Error::Overflow(19)), u64::from_lexical(b"99999999999999999999"));
+    assert_eq!(
+        Err(Error::Overflow(38)),
+        u128::from_lexical(b"999999999999999999999999999999999999999")
+    );
+}
+
 #[test]
 fn options_test() {
     let options = Options::new();
@@ -151,6 +167,16 @@ fn i32_binary_test() {
     assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-11", &options), Ok(-3));
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn u64_hex_test() {
+    // Must not use the 8-digit fast path for non-decimal digits.
+    let options = Options::new();
+    const FORMAT: u128 = from_radix(16);
+    let result = u64::from_lexical_with_options::<FORMAT>(b"DEADBEEFCAFE", &options);
+    assert_eq!(result, Ok(0xDEADBEEFCAFE));
+}
+
 #[cfg(feature = "radix")]
 fn radix_to_u32<const FORMAT: u128>(bytes: &[u8], expected: u32) {
     let options = Options::new();
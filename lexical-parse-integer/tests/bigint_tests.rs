@@ -0,0 +1,133 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "power-of-two")]
+mod util;
+
+use lexical_parse_integer::{
+    Bigint,
+    Error,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    SuffixMode,
+};
+use lexical_util::format::STANDARD;
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+/// Parse the digits a single digit at a time, in quadratic time.
+fn parse_slow(digits: &[u8], radix: u32) -> Bigint {
+    let mut value = Bigint::new();
+    for &digit in digits.iter() {
+        value.mul_small_add(radix as _, (digit as char).to_digit(radix).unwrap() as _);
+    }
+    value
+}
+
+/// Generate `count` pseudo-random decimal digits.
+fn random_digits(count: usize) -> Vec<u8> {
+    let mut seed: u64 = 1;
+    (0..count)
+        .map(|_| {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            b'0' + ((seed >> 33) % 10) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(Bigint::from_lexical(b"0"), Ok(Bigint::new()));
+    assert_eq!(Bigint::from_lexical(b"-0"), Ok(Bigint::new()));
+    assert_eq!(Bigint::from_lexical(b"+12"), Ok(Bigint::from(12u32)));
+    assert_eq!(Bigint::from_lexical(b"-12"), Ok(Bigint::from(-12i32)));
+    assert_eq!(
+        Bigint::from_lexical(b"-170141183460469231731687303715884105728"),
+        Ok(Bigint::from(i128::MIN))
+    );
+    assert_eq!(
+        Bigint::from_lexical(b"340282366920938463463374607431768211456"),
+        Ok(Bigint::from(u128::MAX) + Bigint::from(1u32))
+    );
+    assert_eq!(
+        Bigint::from_lexical(b"0000000000000000000000000000000000000000001"),
+        Ok(Bigint::from(1u32))
+    );
+}
+
+#[test]
+fn parse_large_test() {
+    // Large enough to use divide-and-conquer conversion.
+    let mut digits = vec![b'1'];
+    digits.extend_from_slice(&[b'0'; 10000]);
+    assert_eq!(Bigint::from_lexical(&digits), Ok(Bigint::from(10u32).pow(10000)));
+
+    for &count in [100, 1000, 4321, 10000].iter() {
+        let digits = random_digits(count);
+        assert_eq!(Bigint::from_lexical(&digits), Ok(parse_slow(&digits, 10)));
+        let mut negative = vec![b'-'];
+        negative.extend_from_slice(&digits);
+        assert_eq!(Bigint::from_lexical(&negative), Ok(-parse_slow(&digits, 10)));
+    }
+}
+
+#[test]
+fn parse_error_test() {
+    assert_eq!(Bigint::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(Bigint::from_lexical(b"-"), Err(Error::Empty(1)));
+    assert_eq!(Bigint::from_lexical(b"12a45"), Err(Error::InvalidDigit(2)));
+    assert_eq!(Bigint::from_lexical_partial(b"12a45"), Ok((Bigint::from(12u32), 2)));
+    assert_eq!(Bigint::from_lexical_partial(b"-12-"), Ok((Bigint::from(-12i32), 3)));
+
+    let mut digits = random_digits(1000);
+    digits.push(b'a');
+    assert_eq!(Bigint::from_lexical(&digits), Err(Error::InvalidDigit(1000)));
+    assert_eq!(Bigint::from_lexical_partial(&digits), Ok((parse_slow(&digits[..1000], 10), 1000)));
+}
+
+#[test]
+fn parse_options_test() {
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let parse = |bytes: &[u8]| Bigint::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1k"), Ok(Bigint::from(1000u32)));
+    assert_eq!(parse(b"-2Ki"), Ok(Bigint::from(-2048i32)));
+    assert_eq!(parse(b"3Q"), Ok(Bigint::from(3u32) * Bigint::from(10u32).pow(30)));
+    assert_eq!(parse(b"1Yi"), Ok(Bigint::from(1u32) << 80));
+    assert_eq!(parse(b"1m"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_radix_test() {
+    let options = Options::new();
+    const HEX: u128 = from_radix(16);
+    let mut digits = vec![b'1'];
+    digits.extend_from_slice(&[b'0'; 2500]);
+    let expected = Bigint::from(1u32) << 10000;
+    assert_eq!(Bigint::from_lexical_with_options::<HEX>(&digits, &options), Ok(expected));
+
+    let digits = [b'F'; 3000];
+    let expected = (Bigint::from(1u32) << 12000) - Bigint::from(1u32);
+    assert_eq!(Bigint::from_lexical_with_options::<HEX>(&digits, &options), Ok(expected));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_radix36_test() {
+    let options = Options::new();
+    const BASE36: u128 = from_radix(36);
+    let digits: Vec<u8> = random_digits(5000)
+        .iter()
+        .map(|&c| {
+            if c == b'7' {
+                b'Z'
+            } else {
+                c
+            }
+        })
+        .collect();
+    assert_eq!(
+        Bigint::from_lexical_with_options::<BASE36>(&digits, &options),
+        Ok(parse_slow(&digits, 36))
+    );
+}
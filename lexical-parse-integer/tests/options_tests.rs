@@ -1,4 +1,4 @@
-use lexical_parse_integer::options::{Options, OptionsBuilder};
+use lexical_parse_integer::options::{Options, OptionsBuilder, SuffixMode, WhitespaceMode};
 
 #[test]
 fn options_tests() {
@@ -15,3 +15,36 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn builder_test() {
+    let mut builder = OptionsBuilder::default();
+
+    builder = builder.leading_whitespace(WhitespaceMode::Ascii);
+    builder = builder.trailing_whitespace(WhitespaceMode::Unicode);
+    builder = builder.suffix(SuffixMode::Binary);
+
+    assert_eq!(builder.get_leading_whitespace(), WhitespaceMode::Ascii);
+    assert_eq!(builder.get_trailing_whitespace(), WhitespaceMode::Unicode);
+    assert_eq!(builder.get_suffix(), SuffixMode::Binary);
+
+    assert!(builder.is_valid());
+    assert_eq!(builder.build(), Ok(unsafe { builder.build_unchecked() }));
+}
+
+#[test]
+fn options_test() {
+    let mut opts = Options::new();
+
+    unsafe {
+        opts.set_leading_whitespace(WhitespaceMode::Unicode);
+        opts.set_trailing_whitespace(WhitespaceMode::Ascii);
+        opts.set_suffix(SuffixMode::Both);
+    }
+
+    assert_eq!(opts.leading_whitespace(), WhitespaceMode::Unicode);
+    assert_eq!(opts.trailing_whitespace(), WhitespaceMode::Ascii);
+    assert_eq!(opts.suffix(), SuffixMode::Both);
+    assert!(opts.is_valid());
+    assert_eq!(opts.rebuild().build(), Ok(opts));
+}
@@ -1,4 +1,10 @@
-use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_integer::{
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    SuffixMode,
+    WhitespaceMode,
+};
 use lexical_util::error::Error;
 #[cfg(all(feature = "format", feature = "power-of-two"))]
 use lexical_util::format::NumberFormatBuilder;
@@ -17,6 +23,39 @@ fn u8_decimal_test() {
     assert_eq!(Ok((0, 1)), u8::from_lexical_partial_with_options::<{ STANDARD }>(b"0", &options));
 }
 
+#[test]
+fn wrapped_overflow_test() {
+    assert_eq!(Err(Error::Overflow(2)), u8::from_lexical_partial(b"999,1"));
+    assert_eq!(Err(Error::Overflow(2)), i8::from_lexical_partial(b"999,1"));
+    assert_eq!(Err(Error::Underflow(2)), i8::from_lexical_partial(b"-999,1"));
+    assert_eq!(Err(Error::Overflow(9)), u32::from_lexical_partial(b"9999999999 "));
+}
+
+#[test]
+fn i32_decimal_test() {
+    assert_eq!(Ok((-1, 2)), i32::from_lexical_partial(b"-1"));
+    assert_eq!(Ok((-1, 2)), i32::from_lexical_partial(b"-1 "));
+    assert_eq!(Ok((-123456789, 10)), i32::from_lexical_partial(b"-123456789a"));
+    assert_eq!(Ok((i32::MIN, 11)), i32::from_lexical_partial(b"-2147483648,"));
+    assert_eq!(Err(Error::Underflow(9)), i32::from_lexical_partial(b"-2147483649,"));
+}
+
+#[test]
+fn negative_invalid_digit_test() {
+    // Negative values must be negated even if an invalid digit is found.
+    assert_eq!(Ok((-5, 2)), i8::from_lexical_partial(b"-5x"));
+    assert_eq!(Ok((i8::MIN, 4)), i8::from_lexical_partial(b"-128x"));
+    assert_eq!(Ok((-12345, 6)), i16::from_lexical_partial(b"-12345;"));
+    assert_eq!(Ok((-1234567890123, 14)), i64::from_lexical_partial(b"-1234567890123."));
+    assert_eq!(Ok((i64::MIN, 20)), i64::from_lexical_partial(b"-9223372036854775808 "));
+    assert_eq!(
+        Ok((-123456789012345678901234567890, 31)),
+        i128::from_lexical_partial(b"-123456789012345678901234567890e")
+    );
+    assert_eq!(Err(Error::Underflow(2)), i8::from_lexical_partial(b"-129x"));
+    assert_eq!(Ok((5, 2)), i8::from_lexical_partial(b"+5x"));
+}
+
 #[test]
 #[cfg(all(feature = "format", feature = "power-of-two"))]
 fn u8_decimal_format_test() {
@@ -32,3 +71,97 @@ fn u8_decimal_format_test() {
         u8::from_lexical_partial_with_options::<FORMAT>(b"0", &options)
     );
 }
+
+#[test]
+fn whitespace_test() {
+    let options = Options::builder()
+        .leading_whitespace(WhitespaceMode::Ascii)
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .build()
+        .unwrap();
+    assert_eq!(Ok(42), i32::from_lexical_with_options::<STANDARD>(b" 42\n", &options));
+    assert_eq!(Ok(-42), i32::from_lexical_with_options::<STANDARD>(b"\t-42", &options));
+    assert_eq!(Ok((42, 4)), i32::from_lexical_partial_with_options::<STANDARD>(b" 42\n", &options));
+    assert_eq!(Ok((42, 4)), i32::from_lexical_partial_with_options::<STANDARD>(b" 42 a", &options));
+    assert_eq!(Err(Error::InvalidDigit(0)), i32::from_lexical(b" 42\n"));
+    assert_eq!(
+        Err(Error::InvalidDigit(3)),
+        i32::from_lexical_with_options::<STANDARD>(b"  4a2 ", &options)
+    );
+    assert_eq!(
+        Err(Error::Overflow(4)),
+        u8::from_lexical_with_options::<STANDARD>(b"  256 ", &options)
+    );
+    assert_eq!(Err(Error::Empty(3)), u8::from_lexical_with_options::<STANDARD>(b" \r\n", &options));
+
+    let options = Options::builder().leading_whitespace(WhitespaceMode::Unicode).build().unwrap();
+    let bytes = "\u{3000}\u{a0}42".as_bytes();
+    assert_eq!(Ok(42), u64::from_lexical_with_options::<STANDARD>(bytes, &options));
+    assert_eq!(Ok((42, 7)), u64::from_lexical_partial_with_options::<STANDARD>(bytes, &options));
+    let bytes = "\u{3000}4\u{a0}2".as_bytes();
+    assert_eq!(
+        Err(Error::InvalidDigit(4)),
+        u64::from_lexical_with_options::<STANDARD>(bytes, &options)
+    );
+}
+
+#[test]
+fn suffix_test() {
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    assert_eq!(Ok(10_000), u32::from_lexical_with_options::<STANDARD>(b"10k", &options));
+    assert_eq!(Ok(-3_000_000), i32::from_lexical_with_options::<STANDARD>(b"-3M", &options));
+    assert_eq!(Ok(512 << 20), u64::from_lexical_with_options::<STANDARD>(b"512Mi", &options));
+    assert_eq!(Ok(1 << 80), u128::from_lexical_with_options::<STANDARD>(b"1Yi", &options));
+    assert_eq!(Ok(0), u8::from_lexical_with_options::<STANDARD>(b"0Q", &options));
+    assert_eq!(Ok(42), u8::from_lexical_with_options::<STANDARD>(b"42", &options));
+    assert_eq!(
+        Ok((10_000, 3)),
+        u32::from_lexical_partial_with_options::<STANDARD>(b"10k ", &options)
+    );
+    assert_eq!(Ok((5, 1)), u32::from_lexical_partial_with_options::<STANDARD>(b"5m", &options));
+
+    // Fractional prefixes and unknown suffixes are invalid digits.
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        u32::from_lexical_with_options::<STANDARD>(b"5m", &options)
+    );
+    assert_eq!(
+        Err(Error::InvalidDigit(2)),
+        u32::from_lexical_with_options::<STANDARD>(b"5ks", &options)
+    );
+    assert_eq!(Err(Error::InvalidDigit(1)), u32::from_lexical(b"5k"));
+
+    // Overflow is reported at the suffix.
+    assert_eq!(Ok(250_000), u32::from_lexical_with_options::<STANDARD>(b"250k", &options));
+    assert_eq!(
+        Err(Error::Overflow(3)),
+        u16::from_lexical_with_options::<STANDARD>(b"256k", &options)
+    );
+    assert_eq!(Err(Error::Overflow(1)), u8::from_lexical_with_options::<STANDARD>(b"1k", &options));
+    assert_eq!(
+        Err(Error::Overflow(1)),
+        u64::from_lexical_with_options::<STANDARD>(b"1Z", &options)
+    );
+    assert_eq!(
+        Err(Error::Underflow(2)),
+        i8::from_lexical_with_options::<STANDARD>(b"-1k", &options)
+    );
+    assert_eq!(Ok(-128), i16::from_lexical_with_options::<STANDARD>(b"-128", &options));
+    assert_eq!(Ok(i64::MIN), i64::from_lexical_with_options::<STANDARD>(b"-8Ei", &options));
+    assert_eq!(
+        Err(Error::Overflow(1)),
+        i64::from_lexical_with_options::<STANDARD>(b"8Ei", &options)
+    );
+
+    // Whitespace is skipped after the suffix.
+    let options = Options::builder()
+        .trailing_whitespace(WhitespaceMode::Ascii)
+        .suffix(SuffixMode::Decimal)
+        .build()
+        .unwrap();
+    assert_eq!(Ok(2_000), u16::from_lexical_with_options::<STANDARD>(b"2k ", &options));
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        u16::from_lexical_with_options::<STANDARD>(b"2Ki", &options)
+    );
+}
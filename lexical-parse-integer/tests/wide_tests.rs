@@ -0,0 +1,185 @@
+#[cfg(feature = "power-of-two")]
+mod util;
+
+use lexical_parse_integer::{
+    Error,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+    SuffixMode,
+    I256,
+    I512,
+    U256,
+    U512,
+};
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+const U256_MAX: &[u8] =
+    b"115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const U256_OVERFLOW: &[u8] =
+    b"115792089237316195423570985008687907853269984665640564039457584007913129639936";
+const I256_MIN: &[u8] =
+    b"-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_UNDERFLOW: &[u8] =
+    b"-57896044618658097711785492504343953926634992332820282019728792003956564819969";
+const I256_MAX: &[u8] =
+    b"57896044618658097711785492504343953926634992332820282019728792003956564819967";
+const I256_OVERFLOW: &[u8] =
+    b"57896044618658097711785492504343953926634992332820282019728792003956564819968";
+
+#[test]
+fn parse_unsigned_test() {
+    assert_eq!(U256::from_lexical(b"0"), Ok(U256::ZERO));
+    assert_eq!(U256::from_lexical(b"+12345"), Ok(U256::from_u128(12345)));
+    assert_eq!(U256::from_lexical(b"000012345"), Ok(U256::from_u128(12345)));
+    assert_eq!(
+        U256::from_lexical(b"340282366920938463463374607431768211455"),
+        Ok(U256::from_u128(u128::MAX))
+    );
+    assert_eq!(U256::from_lexical(U256_MAX), Ok(U256::MAX));
+    assert_eq!(U256::from_lexical(U256_OVERFLOW), Err(Error::Overflow(77)));
+    assert_eq!(U512::from_lexical(U256_OVERFLOW).map(|x| x > U512::ZERO), Ok(true));
+
+    let mut value = U256::from_u128(1);
+    for _ in 0..60 {
+        value.mul_small_add(10, 0);
+    }
+    let mut digits = vec![b'1'];
+    digits.extend_from_slice(&[b'0'; 60]);
+    assert_eq!(U256::from_lexical(&digits), Ok(value));
+}
+
+#[test]
+fn parse_unsigned_error_test() {
+    assert_eq!(U256::from_lexical(b""), Err(Error::Empty(0)));
+    assert_eq!(U256::from_lexical(b"+"), Err(Error::Empty(1)));
+    assert_eq!(U256::from_lexical(b"-1"), Err(Error::InvalidNegativeSign(0)));
+    assert_eq!(U256::from_lexical(b"12a45"), Err(Error::InvalidDigit(2)));
+    assert_eq!(U256::from_lexical_partial(b"12a45"), Ok((U256::from_u128(12), 2)));
+    assert_eq!(U256::from_lexical_partial(b"-1"), Err(Error::InvalidNegativeSign(0)));
+
+    let mut digits = U256_OVERFLOW.to_vec();
+    digits.push(b'a');
+    assert_eq!(U256::from_lexical(&digits), Err(Error::InvalidDigit(78)));
+    assert_eq!(U256::from_lexical_partial(&digits), Err(Error::Overflow(77)));
+    assert_eq!(U256::from_lexical_partial(U256_MAX), Ok((U256::MAX, 78)));
+}
+
+#[test]
+fn parse_signed_test() {
+    assert_eq!(I256::from_lexical(b"0"), Ok(I256::ZERO));
+    assert_eq!(I256::from_lexical(b"-0"), Ok(I256::ZERO));
+    assert_eq!(I256::from_lexical(b"-1"), Ok(I256::from_i128(-1)));
+    assert_eq!(
+        I256::from_lexical(b"-170141183460469231731687303715884105728"),
+        Ok(I256::from_i128(i128::MIN))
+    );
+    assert_eq!(I256::from_lexical(I256_MIN), Ok(I256::MIN));
+    assert_eq!(I256::from_lexical(I256_MAX), Ok(I256::MAX));
+    assert_eq!(I256::from_lexical(I256_UNDERFLOW), Err(Error::Underflow(77)));
+    assert_eq!(I256::from_lexical(I256_OVERFLOW), Err(Error::Overflow(76)));
+    assert_eq!(I256::from_lexical(U256_MAX), Err(Error::Overflow(77)));
+    assert_eq!(I512::from_lexical(I256_UNDERFLOW).map(|x| x < I512::ZERO), Ok(true));
+    assert_eq!(I256::from_lexical(b"-"), Err(Error::Empty(1)));
+    assert_eq!(I256::from_lexical_partial(b"-12-"), Ok((I256::from_i128(-12), 3)));
+}
+
+#[test]
+fn parse_options_test() {
+    let options = Options::builder().suffix(SuffixMode::Both).build().unwrap();
+    let parse = |bytes: &[u8]| U256::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(parse(b"1k"), Ok(U256::from_u128(1000)));
+    assert_eq!(parse(b"1Yi"), Ok(U256::from_u128(1 << 80)));
+    assert_eq!(parse(b"1Q"), Ok(U256::from_u128(10u128.pow(30))));
+    assert_eq!(parse(b"1m"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(U256_MAX), Ok(U256::MAX));
+
+    let mut digits = U256_MAX.to_vec();
+    digits.push(b'k');
+    assert_eq!(parse(&digits), Err(Error::Overflow(78)));
+    let signed = |bytes: &[u8]| I256::from_lexical_with_options::<STANDARD>(bytes, &options);
+    assert_eq!(signed(b"-2Ki"), Ok(I256::from_i128(-2048)));
+    let mut digits = I256_MIN.to_vec();
+    digits.push(b'k');
+    assert_eq!(signed(&digits), Err(Error::Underflow(78)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_radix_test() {
+    let options = Options::new();
+    const HEX: u128 = from_radix(16);
+    const BINARY: u128 = from_radix(2);
+    let hex = [b'F'; 64];
+    assert_eq!(U256::from_lexical_with_options::<HEX>(&hex, &options), Ok(U256::MAX));
+    let binary = [b'1'; 256];
+    assert_eq!(U256::from_lexical_with_options::<BINARY>(&binary, &options), Ok(U256::MAX));
+    let mut digits = vec![b'1'];
+    digits.extend_from_slice(&[b'0'; 64]);
+    assert_eq!(U256::from_lexical_with_options::<HEX>(&digits, &options), Err(Error::Overflow(64)));
+
+    let mut digits = vec![b'-', b'8'];
+    digits.extend_from_slice(&[b'0'; 63]);
+    assert_eq!(I256::from_lexical_with_options::<HEX>(&digits, &options), Ok(I256::MIN));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn parse_radix36_test() {
+    let options = Options::new();
+    const BASE36: u128 = from_radix(36);
+    assert_eq!(
+        U256::from_lexical_with_options::<BASE36>(
+            b"6DP5QCB22IM238NR3WVP0IC7Q99W035JMY2IW7I6N43D37JTOF",
+            &options
+        ),
+        Ok(U256::MAX)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_format_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .required_mantissa_sign(true)
+        .build();
+    assert_eq!(
+        U256::from_lexical_with_options::<FORMAT>(b"+1_000_000", &options),
+        Ok(U256::from_u128(1_000_000))
+    );
+    assert_eq!(
+        U256::from_lexical_with_options::<FORMAT>(b"1", &options),
+        Err(Error::MissingSign(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_separator_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b'_'))
+        .integer_internal_digit_separator(true)
+        .integer_leading_digit_separator(true)
+        .integer_consecutive_digit_separator(true)
+        .no_integer_leading_zeros(true)
+        .build();
+    let parse = |bytes: &[u8]| U256::from_lexical_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(parse(b"1_2__3"), Ok(U256::from_u128(123)));
+    assert_eq!(parse(b"_12"), Ok(U256::from_u128(12)));
+    assert_eq!(parse(b"_0"), Ok(U256::ZERO));
+    assert_eq!(parse(b"0"), Ok(U256::ZERO));
+    assert_eq!(parse(b"0_0_1"), Err(Error::InvalidLeadingZeros(0)));
+    assert_eq!(parse(b"_0_1"), Err(Error::InvalidLeadingZeros(1)));
+    assert_eq!(parse(b"1_2a"), Err(Error::InvalidDigit(3)));
+    let signed = |bytes: &[u8]| I256::from_lexical_with_options::<FORMAT>(bytes, &options);
+    assert_eq!(signed(b"-1_000"), Ok(I256::from_i128(-1000)));
+    assert_eq!(signed(b"-0_1"), Err(Error::InvalidLeadingZeros(1)));
+}
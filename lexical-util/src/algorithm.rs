@@ -1,7 +1,9 @@
 //! Simple, shared algorithms for slices and iterators.
 
 #[cfg(feature = "write")]
-use core::ptr;
+use core::mem::MaybeUninit;
+#[cfg(feature = "write")]
+use core::{ptr, slice};
 
 /// Copy bytes from source to destination.
 ///
@@ -23,6 +25,20 @@ pub unsafe fn copy_to_dst<Bytes: AsRef<[u8]>>(dst: &mut [u8], src: Bytes) -> usi
     src.len()
 }
 
+/// Initialize a possibly uninitialized buffer with zeros.
+///
+/// Returns the entire buffer, which is now initialized.
+#[inline]
+#[cfg(feature = "write")]
+pub fn zero_uninit(dst: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    for byte in dst.iter_mut() {
+        *byte = MaybeUninit::new(0);
+    }
+    // SAFETY: safe since `MaybeUninit<u8>` has the same layout as `u8`,
+    // and every byte in `dst` has been initialized.
+    unsafe { slice::from_raw_parts_mut(dst.as_mut_ptr() as *mut u8, dst.len()) }
+}
+
 /// Count the number of trailing characters equal to a given value.
 #[inline]
 #[cfg(feature = "write")]
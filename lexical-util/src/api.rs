@@ -13,7 +13,10 @@
 macro_rules! from_lexical {
     () => {
         /// Trait for numerical types that can be parsed from bytes.
-        pub trait FromLexical: lexical_util::num::Number {
+        ///
+        /// This is not limited to primitive numbers, so it does not imply
+        /// `Number`: wrapper types such as `NonZeroU32` also implement it.
+        pub trait FromLexical: Sized {
             /// Checked parser for a string-to-number conversion.
             ///
             /// This method parses the entire string, returning an error if
@@ -47,7 +50,10 @@ macro_rules! from_lexical_with_options {
         /// Trait for numerical types that can be parsed from bytes with custom options.
         ///
         /// The `Options` type specifies the configurable options to provide.
-        pub trait FromLexicalWithOptions: lexical_util::num::Number {
+        ///
+        /// This is not limited to primitive numbers, so it does not imply
+        /// `Number`: wrapper types such as `NonZeroU32` also implement it.
+        pub trait FromLexicalWithOptions: Sized {
             /// Custom formatting options for parsing a number.
             type Options: lexical_util::options::ParseOptions;
 
@@ -101,6 +107,23 @@ macro_rules! from_lexical_with_options {
                 bytes: &[u8],
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>;
+
+            /// Checked parser for a string-to-number conversion, where the
+            /// caller has already validated `FORMAT`.
+            ///
+            /// This is identical to `from_lexical_partial_with_options`,
+            /// but parsers may skip validating the format, so callers
+            /// parsing many numbers only need to validate it once.
+            /// Passing an invalid format is not unsafe, but the results
+            /// are unspecified.
+            #[doc(hidden)]
+            #[inline(always)]
+            fn from_lexical_partial_with_valid_format<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<(Self, usize)> {
+                Self::from_lexical_partial_with_options::<FORMAT>(bytes, options)
+            }
         }
     };
 }
@@ -119,11 +142,16 @@ macro_rules! to_lexical {
         /// - [`FORMATTED_SIZE`]
         /// - [`FORMATTED_SIZE_DECIMAL`]
         ///
+        /// Arbitrary-precision numbers have no maximum size, so use
+        /// [`formatted_len`] to get the size for a given value.
+        ///
+        /// This is not limited to primitive numbers, so it does not imply
+        /// `Number`: wrapper types such as `NonZeroU32` also implement it.
+        ///
+        /// [`formatted_len`]: Self::formatted_len
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexical:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexical: lexical_util::constants::FormattedSize + Sized {
             /// Serializer for a number-to-string conversion.
             ///
             /// Returns a subslice of the input buffer containing the written bytes,
@@ -135,10 +163,11 @@ macro_rules! to_lexical {
             /// # Safety
             ///
             /// Safe as long as the caller has provided a buffer of at least
-            /// [`FORMATTED_SIZE_DECIMAL`] elements. If a smaller buffer is
-            /// provided, a buffer overflow is very likely.
+            /// [`formatted_len`] or [`written_len`] elements. If a smaller
+            /// buffer is provided, a buffer overflow is very likely.
             ///
-            /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+            /// [`formatted_len`]: Self::formatted_len
+            /// [`written_len`]: Self::written_len
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
 
             /// Serializer for a number-to-string conversion.
@@ -154,10 +183,65 @@ macro_rules! to_lexical {
             /// Panics if the buffer is not of sufficient size. The caller
             /// must provide a slice of sufficient size. In order to ensure
             /// the function will not panic, ensure the buffer has at least
-            /// [`FORMATTED_SIZE_DECIMAL`] elements.
+            /// [`formatted_len`] elements.
             ///
-            /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+            /// [`formatted_len`]: Self::formatted_len
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+            /// Get the number of bytes required to serialize the value to a decimal string.
+            ///
+            /// This is [`FORMATTED_SIZE_DECIMAL`] by default, and is only
+            /// overridden by numbers where the size depends on the value.
+            ///
+            /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+            #[inline(always)]
+            fn formatted_len(&self) -> usize {
+                Self::FORMATTED_SIZE_DECIMAL
+            }
+
+            /// Get the exact number of bytes written when serializing the value to a decimal string.
+            ///
+            /// Unlike [`formatted_len`], which is the size of a buffer that
+            /// can hold any value of the type, this is the length of the
+            /// subslice returned by [`to_lexical`].
+            ///
+            /// [`formatted_len`]: Self::formatted_len
+            /// [`to_lexical`]: Self::to_lexical
+            fn written_len(&self) -> usize;
+
+            /// Serializer for a number-to-string conversion to an uninitialized buffer.
+            ///
+            /// Returns a subslice of the input buffer containing the written,
+            /// initialized bytes, starting from the same address in memory as
+            /// the input slice. Since the buffer does not need to be initialized,
+            /// it may be the spare capacity of a vector.
+            ///
+            /// The writers require an initialized buffer, so the bytes that
+            /// may be written are zeroed first: this avoids undefined behavior,
+            /// but not the cost of initializing them.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size. The caller
+            /// must provide a slice of sufficient size. In order to ensure
+            /// the function will not panic, ensure the buffer has at least
+            /// [`formatted_len`] elements.
+            ///
+            /// [`formatted_len`]: Self::formatted_len
+            #[inline]
+            fn to_lexical_uninit<'a>(
+                self,
+                bytes: &'a mut [core::mem::MaybeUninit<u8>],
+            ) -> &'a mut [u8] {
+                // Only initialize the bytes we may write, so we never create
+                // a reference to uninitialized memory.
+                let size = self.formatted_len();
+                let bytes = lexical_util::algorithm::zero_uninit(&mut bytes[..size]);
+                self.to_lexical(bytes)
+            }
         }
     };
 }
@@ -174,13 +258,18 @@ macro_rules! to_lexical_with_options {
         /// - [`FORMATTED_SIZE`]
         /// - [`FORMATTED_SIZE_DECIMAL`]
         ///
+        /// Arbitrary-precision numbers have no maximum size, so use
+        /// [`formatted_len_with_options`] to get the size for a given value.
+        ///
         /// The `Options` type specifies the configurable options to provide.
         ///
+        /// This is not limited to primitive numbers, so it does not imply
+        /// `Number`: wrapper types such as `NonZeroU32` also implement it.
+        ///
+        /// [`formatted_len_with_options`]: Self::formatted_len_with_options
         /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
         /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
-        pub trait ToLexicalWithOptions:
-            lexical_util::constants::FormattedSize + lexical_util::num::Number
-        {
+        pub trait ToLexicalWithOptions: lexical_util::constants::FormattedSize + Sized {
             /// Custom formatting options for writing a number.
             type Options: lexical_util::options::WriteOptions;
 
@@ -197,8 +286,9 @@ macro_rules! to_lexical_with_options {
             /// # Safety
             ///
             /// Safe as long as the caller has provided a buffer of at least
-            /// [`FORMATTED_SIZE`] elements. If a smaller buffer is
-            /// provided, a buffer overflow is very likely.
+            /// [`formatted_len_with_options`] or [`written_len_with_options`]
+            /// elements. If a smaller buffer is provided, a buffer overflow
+            /// is very likely.
             ///
             /// # Panics
             ///
@@ -222,7 +312,8 @@ macro_rules! to_lexical_with_options {
             /// Panics as well if the NaN or Inf string provided to the writer
             /// is disabled, but the value provided is NaN or Inf, respectively.
             ///
-            /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
+            /// [`formatted_len_with_options`]: Self::formatted_len_with_options
+            /// [`written_len_with_options`]: Self::written_len_with_options
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
@@ -244,7 +335,7 @@ macro_rules! to_lexical_with_options {
             /// Panics if the buffer is not of sufficient size. The caller
             /// must provide a slice of sufficient size. In order to ensure
             /// the function will not panic, ensure the buffer has at least
-            /// [`FORMATTED_SIZE`] elements.
+            /// [`formatted_len_with_options`] elements.
             ///
             /// **Floats Only**
             ///
@@ -266,12 +357,89 @@ macro_rules! to_lexical_with_options {
             /// Panics as well if the NaN or Inf string provided to the writer
             /// is disabled, but the value provided is NaN or Inf, respectively.
             ///
-            /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
+            /// [`formatted_len_with_options`]: Self::formatted_len_with_options
             fn to_lexical_with_options<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8];
+
+            /// Get the number of bytes required to serialize the value with custom options.
+            ///
+            /// This is the [`buffer_size`] of the options by default, and is
+            /// only overridden by numbers where the size depends on the value.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `options` - Options for number formatting.
+            ///
+            /// [`buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+            #[inline(always)]
+            fn formatted_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> usize {
+                lexical_util::options::WriteOptions::buffer_size::<Self, FORMAT>(options)
+            }
+
+            /// Get the exact number of bytes written when serializing the value with custom options.
+            ///
+            /// This is the length of the subslice returned by
+            /// [`to_lexical_with_options`]. Since this is only known once the
+            /// format and options are validated, it returns an error in the
+            /// cases where [`to_lexical_with_options`] panics for an invalid
+            /// format, options that conflict with the format, or a NaN or Inf
+            /// value when the NaN or Inf string is disabled.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `options` - Options for number formatting.
+            ///
+            /// [`to_lexical_with_options`]: Self::to_lexical_with_options
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> lexical_util::result::Result<usize>;
+
+            /// Serializer for a number-to-string conversion to an uninitialized buffer.
+            ///
+            /// Returns a subslice of the input buffer containing the written,
+            /// initialized bytes, starting from the same address in memory as
+            /// the input slice. Since the buffer does not need to be initialized,
+            /// it may be the spare capacity of a vector.
+            ///
+            /// The writers require an initialized buffer, so the bytes that
+            /// may be written are zeroed first: this avoids undefined behavior,
+            /// but not the cost of initializing them.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `options` - Options for number formatting.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size. The caller
+            /// must provide a slice of sufficient size. In order to ensure
+            /// the function will not panic, ensure the buffer has at least
+            /// [`formatted_len_with_options`] elements, which is [`buffer_size`]
+            /// elements for fixed-width numbers.
+            ///
+            /// Also panics in the same cases as [`to_lexical_with_options`].
+            ///
+            /// [`buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+            /// [`to_lexical_with_options`]: Self::to_lexical_with_options
+            /// [`formatted_len_with_options`]: Self::formatted_len_with_options
+            #[inline]
+            fn to_lexical_with_options_uninit<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [core::mem::MaybeUninit<u8>],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // Only initialize the bytes we may write, so we never create
+                // a reference to uninitialized memory.
+                let size = self.formatted_len_with_options::<FORMAT>(options);
+                let bytes = lexical_util::algorithm::zero_uninit(&mut bytes[..size]);
+                self.to_lexical_with_options::<FORMAT>(bytes, options)
+            }
         }
     };
 }
@@ -0,0 +1,269 @@
+//! IEEE 754-2008 decimal floats, in the binary integer decimal encoding.
+//!
+//! Decimal floats store a sign, an integer coefficient and a decimal
+//! exponent, so every decimal string with few enough significant digits
+//! is represented exactly. Unlike binary floats, the coefficient is not
+//! normalized: `1.50` and `1.5` are different members of the same cohort,
+//! with exponents of `-2` and `-1`, so the quantum of a value is preserved.
+//!
+//! In the binary integer decimal (BID) encoding, the coefficient is stored
+//! as a binary integer. Small coefficients follow the biased exponent
+//! directly, while large coefficients, which are only used by `Decimal64`,
+//! have an implicit `100` prefix after the `11` combination bits.
+
+#![cfg(all(feature = "floats", feature = "ieee-decimal"))]
+
+/// Encoding parameters and operations shared by the decimal floats.
+pub trait IeeeDecimal: Copy {
+    /// The number of bits in the encoding.
+    const BITS: u32;
+    /// The number of bits in the biased exponent.
+    const EXPONENT_BITS: u32;
+    /// The maximum number of significant digits in the coefficient.
+    const PRECISION: usize;
+    /// The minimum exponent, of the last digit in the coefficient.
+    const MIN_EXPONENT: i32;
+    /// The maximum exponent, of the last digit in the coefficient.
+    const MAX_EXPONENT: i32;
+    /// The maximum coefficient, with all `PRECISION` digits set to `9`.
+    const MAX_COEFFICIENT: u128;
+
+    /// Create the decimal float from the encoded bits.
+    fn from_u128_bits(bits: u128) -> Self;
+
+    /// Get the encoded bits of the decimal float.
+    fn to_u128_bits(self) -> u128;
+
+    /// The number of bits in a small coefficient.
+    const COEFFICIENT_BITS: u32 = Self::BITS - 1 - Self::EXPONENT_BITS;
+
+    /// Create a canonical, finite decimal float from its parts.
+    ///
+    /// The coefficient must be at most `MAX_COEFFICIENT`, and the exponent
+    /// must be in the range `[MIN_EXPONENT, MAX_EXPONENT]`.
+    #[inline]
+    fn from_parts(is_negative: bool, coefficient: u128, exponent: i32) -> Self {
+        debug_assert!(coefficient <= Self::MAX_COEFFICIENT);
+        debug_assert!((Self::MIN_EXPONENT..=Self::MAX_EXPONENT).contains(&exponent));
+        let biased = (exponent - Self::MIN_EXPONENT) as u128;
+        let mut bits = (is_negative as u128) << (Self::BITS - 1);
+        if coefficient >> Self::COEFFICIENT_BITS == 0 {
+            bits |= biased << Self::COEFFICIENT_BITS | coefficient;
+        } else {
+            let low = coefficient & ((1 << (Self::COEFFICIENT_BITS - 2)) - 1);
+            bits |= 0b11 << (Self::BITS - 3) | biased << (Self::COEFFICIENT_BITS - 2) | low;
+        }
+        Self::from_u128_bits(bits)
+    }
+
+    /// Create a quiet NaN.
+    #[inline]
+    fn nan(is_negative: bool) -> Self {
+        let sign = (is_negative as u128) << (Self::BITS - 1);
+        Self::from_u128_bits(sign | 0b11111 << (Self::BITS - 6))
+    }
+
+    /// Create an infinity.
+    #[inline]
+    fn infinity(is_negative: bool) -> Self {
+        let sign = (is_negative as u128) << (Self::BITS - 1);
+        Self::from_u128_bits(sign | 0b11110 << (Self::BITS - 6))
+    }
+
+    /// Get the 5 combination bits following the sign.
+    #[inline(always)]
+    fn combination(self) -> u128 {
+        (self.to_u128_bits() >> (Self::BITS - 6)) & 0b11111
+    }
+
+    /// Get if the sign bit is set, including for zeros and NaNs.
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.to_u128_bits() >> (Self::BITS - 1) != 0
+    }
+
+    /// Get if the decimal float is a NaN.
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.combination() == 0b11111
+    }
+
+    /// Get if the decimal float is infinite.
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.combination() == 0b11110
+    }
+
+    /// Get if the decimal float is neither infinite nor a NaN.
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.combination() >> 1 != 0b1111
+    }
+
+    /// Get the biased exponent and coefficient of a finite decimal float.
+    #[inline]
+    fn decode(self) -> (u128, u128) {
+        let bits = self.to_u128_bits();
+        let exponent_mask = (1 << Self::EXPONENT_BITS) - 1;
+        if (bits >> (Self::BITS - 3)) & 0b11 == 0b11 {
+            let shift = Self::COEFFICIENT_BITS - 2;
+            let low = bits & ((1 << shift) - 1);
+            ((bits >> shift) & exponent_mask, 0b100 << shift | low)
+        } else {
+            let shift = Self::COEFFICIENT_BITS;
+            ((bits >> shift) & exponent_mask, bits & ((1 << shift) - 1))
+        }
+    }
+
+    /// Get the coefficient of a finite decimal float.
+    ///
+    /// Non-canonical coefficients, which are larger than the maximum
+    /// coefficient, are zero. Infinities and NaNs have a zero coefficient.
+    #[inline]
+    fn coefficient(self) -> u128 {
+        let coefficient = self.decode().1;
+        if !self.is_finite() || coefficient > Self::MAX_COEFFICIENT {
+            0
+        } else {
+            coefficient
+        }
+    }
+
+    /// Get the exponent of a finite decimal float.
+    ///
+    /// Infinities and NaNs have a zero exponent.
+    #[inline]
+    fn exponent(self) -> i32 {
+        if self.is_finite() {
+            self.decode().0 as i32 + Self::MIN_EXPONENT
+        } else {
+            0
+        }
+    }
+}
+
+macro_rules! ieee_decimal_impl {
+    (
+        $(#[$meta:meta])*
+        $name:ident,
+        $uint:ty,
+        $exponent_bits:literal,
+        $precision:literal,
+        $min_exponent:literal,
+        $max_exponent:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub struct $name($uint);
+
+        impl IeeeDecimal for $name {
+            const BITS: u32 = <$uint>::MAX.count_ones();
+            const EXPONENT_BITS: u32 = $exponent_bits;
+            const PRECISION: usize = $precision;
+            const MIN_EXPONENT: i32 = $min_exponent;
+            const MAX_EXPONENT: i32 = $max_exponent;
+            const MAX_COEFFICIENT: u128 = 10u128.pow($precision) - 1;
+
+            #[inline(always)]
+            fn from_u128_bits(bits: u128) -> Self {
+                Self(bits as $uint)
+            }
+
+            #[inline(always)]
+            fn to_u128_bits(self) -> u128 {
+                self.0 as u128
+            }
+        }
+
+        impl $name {
+            /// Quiet, positive NaN.
+            pub const NAN: Self = Self(0b11111 << (<Self as IeeeDecimal>::BITS - 6));
+            /// Positive infinity.
+            pub const INFINITY: Self = Self(0b11110 << (<Self as IeeeDecimal>::BITS - 6));
+            /// Negative infinity.
+            pub const NEG_INFINITY: Self = Self(0b111110 << (<Self as IeeeDecimal>::BITS - 6));
+
+            /// Create the decimal float from the encoded bits.
+            #[inline(always)]
+            pub const fn from_bits(bits: $uint) -> Self {
+                Self(bits)
+            }
+
+            /// Get the encoded bits of the decimal float.
+            #[inline(always)]
+            pub const fn to_bits(self) -> $uint {
+                self.0
+            }
+
+            /// Create a finite decimal float, with the value
+            /// `coefficient * 10^exponent`.
+            ///
+            /// Returns `None` if the coefficient has too many digits,
+            /// or the exponent is out of range.
+            #[inline]
+            pub fn new(is_negative: bool, coefficient: $uint, exponent: i32) -> Option<Self> {
+                let coefficient = coefficient as u128;
+                let exponents = Self::MIN_EXPONENT..=Self::MAX_EXPONENT;
+                if coefficient <= Self::MAX_COEFFICIENT && exponents.contains(&exponent) {
+                    Some(Self::from_parts(is_negative, coefficient, exponent))
+                } else {
+                    None
+                }
+            }
+
+            /// Get if the sign bit is set, including for zeros and NaNs.
+            #[inline]
+            pub fn is_sign_negative(self) -> bool {
+                IeeeDecimal::is_sign_negative(self)
+            }
+
+            /// Get if the decimal float is a NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                IeeeDecimal::is_nan(self)
+            }
+
+            /// Get if the decimal float is infinite.
+            #[inline]
+            pub fn is_infinite(self) -> bool {
+                IeeeDecimal::is_infinite(self)
+            }
+
+            /// Get if the decimal float is neither infinite nor a NaN.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                IeeeDecimal::is_finite(self)
+            }
+
+            /// Get the coefficient, which is zero for infinities and NaNs.
+            #[inline]
+            pub fn coefficient(self) -> $uint {
+                IeeeDecimal::coefficient(self) as $uint
+            }
+
+            /// Get the exponent, which is zero for infinities and NaNs.
+            #[inline]
+            pub fn exponent(self) -> i32 {
+                IeeeDecimal::exponent(self)
+            }
+        }
+    };
+}
+
+ieee_decimal_impl! {
+    /// IEEE 754-2008 `decimal64` float, in the BID encoding.
+    ///
+    /// The coefficient has up to 16 digits, and the exponent is in the
+    /// range `[-398, 369]`. Equality compares the encodings, so `1.50`
+    /// and `1.5` are not equal, but identical NaNs are.
+    Decimal64, u64, 10, 16, -398, 369
+}
+
+ieee_decimal_impl! {
+    /// IEEE 754-2008 `decimal128` float, in the BID encoding.
+    ///
+    /// The coefficient has up to 34 digits, and the exponent is in the
+    /// range `[-6176, 6111]`. Equality compares the encodings, so `1.50`
+    /// and `1.5` are not equal, but identical NaNs are.
+    Decimal128, u128, 14, 34, -6176, 6111
+}
@@ -0,0 +1,792 @@
+//! Arbitrary-precision, heap-allocated integer type.
+//!
+//! Unlike the stack-allocated big integers used in the float parsers'
+//! slow-path algorithms, which have a fixed capacity, this has no upper
+//! bound, so it can store integers of any size. This provides the
+//! arithmetic required for subquadratic, divide-and-conquer base
+//! conversion: Karatsuba multiplication and Burnikel-Ziegler division,
+//! which are used over the schoolbook algorithms for large integers.
+
+#![cfg(all(feature = "integers", feature = "std"))]
+
+use crate::limb::{scalar_add, scalar_div, scalar_mul, Limb, Wide, LIMB_BITS};
+use core::{cmp, ops};
+
+// BIGINT
+// ------
+
+/// Arbitrary-precision, signed integer.
+///
+/// The integer is stored as a sign and a magnitude, with the magnitude
+/// stored as little-endian, [`Limb`]-sized limbs without any trailing
+/// zero limbs. Zero has no limbs, and is never negative.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bigint {
+    /// If the value is negative.
+    negative: bool,
+    /// Little-endian limbs of the magnitude.
+    limbs: Vec<Limb>,
+}
+
+impl Bigint {
+    /// Create a big integer representing 0.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            negative: false,
+            limbs: Vec::new(),
+        }
+    }
+
+    /// Create a non-negative big integer from little-endian limbs.
+    #[inline]
+    pub fn from_limbs(mut limbs: Vec<Limb>) -> Self {
+        normalize(&mut limbs);
+        Self {
+            negative: false,
+            limbs,
+        }
+    }
+
+    /// Create a big integer from an unsigned, 128-bit integer.
+    #[inline]
+    pub fn from_u128(value: u128) -> Self {
+        let mut limbs = Vec::with_capacity(128 / LIMB_BITS);
+        let mut value = value;
+        while value != 0 {
+            limbs.push(value as Limb);
+            value >>= LIMB_BITS;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Create a big integer from a signed, 128-bit integer.
+    #[inline]
+    pub fn from_i128(value: i128) -> Self {
+        let mut result = Self::from_u128(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+
+    /// Get the little-endian limbs of the magnitude.
+    #[inline(always)]
+    pub fn as_limbs(&self) -> &[Limb] {
+        &self.limbs
+    }
+
+    /// Get the little-endian limbs of the magnitude.
+    #[inline(always)]
+    pub fn into_limbs(self) -> Vec<Limb> {
+        self.limbs
+    }
+
+    /// Get the value as an unsigned, 128-bit integer, if it fits.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.negative || self.limbs.len() * LIMB_BITS > 128 {
+            return None;
+        }
+        Some(self.limbs.iter().rev().fold(0, |acc, &x| (acc << LIMB_BITS) | x as u128))
+    }
+
+    /// Get the value as a signed, 128-bit integer, if it fits.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.unsigned_abs().to_u128()?;
+        if self.negative && magnitude <= i128::MIN.unsigned_abs() {
+            Some((magnitude as i128).wrapping_neg())
+        } else if !self.negative && magnitude <= i128::MAX as u128 {
+            Some(magnitude as i128)
+        } else {
+            None
+        }
+    }
+
+    /// Determine if the integer is zero.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Determine if the integer is negative.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Get the absolute value of the integer.
+    #[inline]
+    pub fn unsigned_abs(&self) -> Self {
+        Self::from_limbs(self.limbs.clone())
+    }
+
+    /// Calculate the number of bits in the magnitude.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        match self.limbs.last() {
+            Some(&hi) => self.limbs.len() * LIMB_BITS - hi.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Multiply the magnitude by a small integer and then add another.
+    #[inline]
+    pub fn mul_small_add(&mut self, y: Limb, z: Limb) {
+        let mut carry = z;
+        for xi in self.limbs.iter_mut() {
+            let result = scalar_mul(*xi, y, carry);
+            *xi = result.0;
+            carry = result.1;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+        normalize(&mut self.limbs);
+        self.negative &= !self.limbs.is_empty();
+    }
+
+    /// Divide the magnitude by a small integer, returning the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    #[inline]
+    pub fn div_small(&mut self, y: Limb) -> Limb {
+        let rem = div_small(&mut self.limbs, y);
+        self.negative &= !self.limbs.is_empty();
+        rem
+    }
+
+    /// Raise the integer to a power, using exponentiation by squaring.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from_u128(1);
+        while exp != 0 {
+            if exp & 1 != 0 {
+                result *= &base;
+            }
+            exp >>= 1;
+            if exp != 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Calculate the quotient and remainder, truncating towards zero.
+    ///
+    /// The remainder has the same sign as the dividend, like the
+    /// primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let (quo, rem) = div_rem(&self.limbs, &other.limbs);
+        (Self::from_sign(self.negative != other.negative, quo), Self::from_sign(self.negative, rem))
+    }
+
+    /// Create the integer from a sign and little-endian limbs.
+    #[inline]
+    fn from_sign(negative: bool, limbs: Vec<Limb>) -> Self {
+        let mut result = Self::from_limbs(limbs);
+        result.negative = negative && !result.is_zero();
+        result
+    }
+}
+
+impl cmp::PartialOrd for Bigint {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for Bigint {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => compare(&self.limbs, &other.limbs),
+            (true, true) => compare(&other.limbs, &self.limbs),
+            (false, true) => cmp::Ordering::Greater,
+            (true, false) => cmp::Ordering::Less,
+        }
+    }
+}
+
+impl ops::Neg for Bigint {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.negative = !self.negative && !self.is_zero();
+        self
+    }
+}
+
+impl ops::Neg for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn neg(self) -> Bigint {
+        -self.clone()
+    }
+}
+
+impl ops::Add for &Bigint {
+    type Output = Bigint;
+
+    fn add(self, rhs: &Bigint) -> Bigint {
+        if self.negative == rhs.negative {
+            Bigint::from_sign(self.negative, add(&self.limbs, &rhs.limbs))
+        } else if compare(&self.limbs, &rhs.limbs) != cmp::Ordering::Less {
+            Bigint::from_sign(self.negative, sub(&self.limbs, &rhs.limbs))
+        } else {
+            Bigint::from_sign(rhs.negative, sub(&rhs.limbs, &self.limbs))
+        }
+    }
+}
+
+impl ops::Sub for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn sub(self, rhs: &Bigint) -> Bigint {
+        self + &-rhs
+    }
+}
+
+impl ops::Mul for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn mul(self, rhs: &Bigint) -> Bigint {
+        Bigint::from_sign(self.negative != rhs.negative, mul(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl ops::Div for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn div(self, rhs: &Bigint) -> Bigint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl ops::Rem for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn rem(self, rhs: &Bigint) -> Bigint {
+        self.div_rem(rhs).1
+    }
+}
+
+impl ops::Shl<usize> for &Bigint {
+    type Output = Bigint;
+
+    #[inline]
+    fn shl(self, rhs: usize) -> Bigint {
+        let mut limbs = vec![0; rhs / LIMB_BITS];
+        limbs.extend_from_slice(&shl_bits(&self.limbs, (rhs % LIMB_BITS) as u32));
+        Bigint::from_sign(self.negative, limbs)
+    }
+}
+
+// Implement the operators for owned values and assignment from references.
+macro_rules! bigint_ops {
+    ($($trait:ident $fn:ident $assign:ident $assign_fn:ident ; )*) => ($(
+        impl ops::$trait for Bigint {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self {
+                ops::$trait::$fn(&self, &rhs)
+            }
+        }
+
+        impl ops::$trait<&Bigint> for Bigint {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: &Self) -> Self {
+                ops::$trait::$fn(&self, rhs)
+            }
+        }
+
+        impl ops::$trait<Bigint> for &Bigint {
+            type Output = Bigint;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Bigint) -> Bigint {
+                ops::$trait::$fn(self, &rhs)
+            }
+        }
+
+        impl ops::$assign<&Bigint> for Bigint {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: &Self) {
+                *self = ops::$trait::$fn(&*self, rhs);
+            }
+        }
+
+        impl ops::$assign for Bigint {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = ops::$trait::$fn(&*self, &rhs);
+            }
+        }
+    )*);
+}
+
+bigint_ops! {
+    Add add AddAssign add_assign ;
+    Sub sub SubAssign sub_assign ;
+    Mul mul MulAssign mul_assign ;
+    Div div DivAssign div_assign ;
+    Rem rem RemAssign rem_assign ;
+}
+
+impl ops::Shl<usize> for Bigint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: usize) -> Self {
+        &self << rhs
+    }
+}
+
+impl ops::ShlAssign<usize> for Bigint {
+    #[inline(always)]
+    fn shl_assign(&mut self, rhs: usize) {
+        *self = &*self << rhs;
+    }
+}
+
+// Implement conversions from the primitive integers.
+macro_rules! bigint_from {
+    ($($t:ty, $cb:ident, $wide:ty ; )*) => ($(
+        impl From<$t> for Bigint {
+            #[inline(always)]
+            fn from(value: $t) -> Self {
+                Self::$cb(value as $wide)
+            }
+        }
+    )*);
+}
+
+bigint_from! {
+    u8, from_u128, u128 ;
+    u16, from_u128, u128 ;
+    u32, from_u128, u128 ;
+    u64, from_u128, u128 ;
+    u128, from_u128, u128 ;
+    usize, from_u128, u128 ;
+    i8, from_i128, i128 ;
+    i16, from_i128, i128 ;
+    i32, from_i128, i128 ;
+    i64, from_i128, i128 ;
+    i128, from_i128, i128 ;
+    isize, from_i128, i128 ;
+}
+
+// HELPERS
+// -------
+
+/// Remove the trailing zero limbs from a buffer.
+#[inline]
+fn normalize(x: &mut Vec<Limb>) {
+    while let Some(&0) = x.last() {
+        x.pop();
+    }
+}
+
+/// Get a slice without any trailing zero limbs.
+#[inline]
+fn trim(x: &[Limb]) -> &[Limb] {
+    let length = x.iter().rposition(|&xi| xi != 0).map_or(0, |index| index + 1);
+    &x[..length]
+}
+
+/// Get the limbs in `start..end`, where missing limbs are zero.
+#[inline]
+fn limb_range(x: &[Limb], start: usize, end: usize) -> &[Limb] {
+    let end = end.min(x.len());
+    &x[start.min(end)..end]
+}
+
+/// Create `hi * B^n + lo`, where `B` is the limb radix and `lo < B^n`.
+#[inline]
+fn join(lo: &[Limb], n: usize, hi: &[Limb]) -> Vec<Limb> {
+    debug_assert!(trim(lo).len() <= n);
+    let mut x = Vec::with_capacity(n + hi.len());
+    x.extend_from_slice(trim(lo));
+    x.resize(n, 0);
+    x.extend_from_slice(hi);
+    normalize(&mut x);
+    x
+}
+
+/// Compare `x` to `y`, in little-endian order.
+#[inline]
+fn compare(x: &[Limb], y: &[Limb]) -> cmp::Ordering {
+    let x = trim(x);
+    let y = trim(y);
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+// ADD/SUB
+// -------
+
+/// Add `y` to `x`, starting at the limb `start`.
+fn add_from(x: &mut Vec<Limb>, y: &[Limb], start: usize) {
+    if x.len() < start + y.len() {
+        x.resize(start + y.len(), 0);
+    }
+    let mut carry = false;
+    for (xi, &yi) in x[start..].iter_mut().zip(y.iter()) {
+        let (value, c1) = scalar_add(*xi, yi);
+        let (value, c2) = scalar_add(value, carry as Limb);
+        *xi = value;
+        carry = c1 | c2;
+    }
+    let mut index = start + y.len();
+    while carry {
+        if index == x.len() {
+            x.push(1);
+            break;
+        }
+        let (value, c) = scalar_add(x[index], 1);
+        x[index] = value;
+        carry = c;
+        index += 1;
+    }
+}
+
+/// Add two magnitudes.
+#[inline]
+fn add(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let mut z = x.to_vec();
+    add_from(&mut z, y, 0);
+    normalize(&mut z);
+    z
+}
+
+/// Subtract `y` from `x`, where `x >= y`.
+fn sub_assign(x: &mut Vec<Limb>, y: &[Limb]) {
+    let y = trim(y);
+    debug_assert!(compare(x, y) != cmp::Ordering::Less);
+    let mut borrow = false;
+    for (index, xi) in x.iter_mut().enumerate() {
+        if index >= y.len() && !borrow {
+            break;
+        }
+        let yi = y.get(index).copied().unwrap_or(0);
+        let (value, b1) = xi.overflowing_sub(yi);
+        let (value, b2) = value.overflowing_sub(borrow as Limb);
+        *xi = value;
+        borrow = b1 | b2;
+    }
+    debug_assert!(!borrow);
+    normalize(x);
+}
+
+/// Subtract two magnitudes, where `x >= y`.
+#[inline]
+fn sub(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let mut z = x.to_vec();
+    sub_assign(&mut z, y);
+    z
+}
+
+// MUL
+// ---
+
+/// The number of limbs where Karatsuba multiplication is faster.
+const KARATSUBA_CUTOFF: usize = 32;
+
+/// Grade-school multiplication algorithm, in `O(n*m)` time.
+fn long_mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let mut z = vec![0; x.len() + y.len()];
+    for (j, &yj) in y.iter().enumerate() {
+        if yj == 0 {
+            continue;
+        }
+        let mut carry: Limb = 0;
+        for (i, &xi) in x.iter().enumerate() {
+            let (lo, hi) = scalar_mul(xi, yj, carry);
+            let (value, c) = scalar_add(z[i + j], lo);
+            z[i + j] = value;
+            // Cannot overflow, since `hi <= Limb::MAX - 1`.
+            carry = hi + c as Limb;
+        }
+        z[j + x.len()] = carry;
+    }
+    normalize(&mut z);
+    z
+}
+
+/// Karatsuba multiplication algorithm, in `O(n^1.58)` time.
+///
+/// Requires `x.len() >= y.len() > x.len() / 2`, so both halves of `y`
+/// are non-empty.
+fn karatsuba_mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let m = x.len() / 2;
+    debug_assert!(y.len() > m);
+    let (x0, x1) = x.split_at(m);
+    let (y0, y1) = y.split_at(m);
+    let z0 = mul(x0, y0);
+    let z2 = mul(x1, y1);
+    let mut z1 = mul(&add(x0, x1), &add(y0, y1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut z = z0;
+    add_from(&mut z, &z1, m);
+    add_from(&mut z, &z2, 2 * m);
+    normalize(&mut z);
+    z
+}
+
+/// Multiply two magnitudes.
+///
+/// This uses Karatsuba multiplication for large, balanced inputs, and
+/// splits unbalanced inputs into balanced products.
+fn mul(x: &[Limb], y: &[Limb]) -> Vec<Limb> {
+    let (x, y) = (trim(x), trim(y));
+    let (x, y) = if x.len() >= y.len() {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    if y.len() < KARATSUBA_CUTOFF {
+        return long_mul(x, y);
+    } else if x.len() < 2 * y.len() {
+        return karatsuba_mul(x, y);
+    }
+
+    let mut z = Vec::with_capacity(x.len() + y.len());
+    for (index, chunk) in x.chunks(y.len()).enumerate() {
+        add_from(&mut z, &mul(chunk, y), index * y.len());
+    }
+    normalize(&mut z);
+    z
+}
+
+// DIV
+// ---
+
+/// The number of limbs where Burnikel-Ziegler division is faster.
+const BURNIKEL_ZIEGLER_CUTOFF: usize = 32;
+
+/// Divide a magnitude by a small integer in-place, returning the remainder.
+fn div_small(x: &mut Vec<Limb>, y: Limb) -> Limb {
+    assert!(y != 0, "attempt to divide by zero");
+    let mut rem: Limb = 0;
+    for xi in x.iter_mut().rev() {
+        let (quo, r) = scalar_div(rem, *xi, y);
+        *xi = quo;
+        rem = r;
+    }
+    normalize(x);
+    rem
+}
+
+/// Shift-left `n` bits, where `n < LIMB_BITS`.
+fn shl_bits(x: &[Limb], n: u32) -> Vec<Limb> {
+    debug_assert!((n as usize) < LIMB_BITS);
+    let mut z = Vec::with_capacity(x.len() + 1);
+    if n == 0 {
+        z.extend_from_slice(x);
+        return z;
+    }
+    let mut prev: Limb = 0;
+    for &xi in x.iter() {
+        z.push((xi << n) | (prev >> (LIMB_BITS as u32 - n)));
+        prev = xi;
+    }
+    z.push(prev >> (LIMB_BITS as u32 - n));
+    normalize(&mut z);
+    z
+}
+
+/// Shift-right `n` bits in-place, where `n < LIMB_BITS`.
+fn shr_bits(x: &mut Vec<Limb>, n: u32) {
+    debug_assert!((n as usize) < LIMB_BITS);
+    if n == 0 {
+        return;
+    }
+    let mut prev: Limb = 0;
+    for xi in x.iter_mut().rev() {
+        let tmp = *xi;
+        *xi = (tmp >> n) | (prev << (LIMB_BITS as u32 - n));
+        prev = tmp;
+    }
+    normalize(x);
+}
+
+/// Schoolbook division, from Knuth's Algorithm D.
+///
+/// Requires a normalized divisor of at least 2 limbs, that is, with the
+/// most-significant bit set, so each estimate of the quotient limb is at
+/// most 2 larger than the true value.
+fn schoolbook_div_rem(a: &[Limb], b: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    let a = trim(a);
+    let n = b.len();
+    debug_assert!(n >= 2 && b[n - 1].leading_zeros() == 0);
+    if compare(a, b) == cmp::Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+
+    let m = a.len() - n;
+    let mut u = Vec::with_capacity(a.len() + 1);
+    u.extend_from_slice(a);
+    u.push(0);
+    let mut q = vec![0; m + 1];
+    let b_hi = b[n - 1] as Wide;
+    let b_lo = b[n - 2] as Wide;
+    for j in (0..=m).rev() {
+        // Estimate the quotient limb from the top 2 limbs, and correct
+        // it using the next limb, so it's at most 1 too large.
+        let num = ((u[j + n] as Wide) << LIMB_BITS) | u[j + n - 1] as Wide;
+        let mut qhat = num / b_hi;
+        let mut rhat = num % b_hi;
+        while qhat >> LIMB_BITS != 0 || qhat * b_lo > ((rhat << LIMB_BITS) | u[j + n - 2] as Wide) {
+            qhat -= 1;
+            rhat += b_hi;
+            if rhat >> LIMB_BITS != 0 {
+                break;
+            }
+        }
+
+        // Multiply and subtract.
+        let mut carry: Limb = 0;
+        let mut borrow = false;
+        for (i, &bi) in b.iter().enumerate() {
+            let (lo, hi) = scalar_mul(bi, qhat as Limb, carry);
+            carry = hi;
+            let (value, b1) = u[i + j].overflowing_sub(lo);
+            let (value, b2) = value.overflowing_sub(borrow as Limb);
+            u[i + j] = value;
+            borrow = b1 | b2;
+        }
+        let (value, b1) = u[j + n].overflowing_sub(carry);
+        let (value, b2) = value.overflowing_sub(borrow as Limb);
+        u[j + n] = value;
+
+        // The estimate was 1 too large: add back the divisor.
+        if b1 | b2 {
+            qhat -= 1;
+            let mut carry = false;
+            for (i, &bi) in b.iter().enumerate() {
+                let (value, c1) = scalar_add(u[i + j], bi);
+                let (value, c2) = scalar_add(value, carry as Limb);
+                u[i + j] = value;
+                carry = c1 | c2;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as Limb);
+        }
+        q[j] = qhat as Limb;
+    }
+
+    u.truncate(n);
+    normalize(&mut u);
+    normalize(&mut q);
+    (q, u)
+}
+
+/// Divide a `2n`-limb integer by an `n`-limb integer.
+///
+/// Requires a normalized divisor, and `a < b * B^n`, where `B` is the
+/// limb radix, so the quotient has at most `n` limbs. Adapted from
+/// Burnikel and Ziegler's "Fast Recursive Division".
+fn div_2n_1n(a: &[Limb], b: &[Limb], n: usize) -> (Vec<Limb>, Vec<Limb>) {
+    debug_assert!(b.len() == n);
+    if n < BURNIKEL_ZIEGLER_CUTOFF {
+        return schoolbook_div_rem(a, b);
+    } else if n % 2 == 1 {
+        // Pad both by a limb, which does not change the quotient, and
+        // the remainder has a zero low limb.
+        let a = join(&[], 1, a);
+        let b = join(&[], 1, b);
+        let (q, r) = div_2n_1n(&a, &b, n + 1);
+        return (q, limb_range(&r, 1, r.len()).to_vec());
+    }
+
+    let half = n / 2;
+    let (b2, b1) = b.split_at(half);
+    let (q1, r) = div_3n_2n(limb_range(a, n, a.len()), limb_range(a, half, n), b, b1, b2, half);
+    let (q2, r) = div_3n_2n(&r, limb_range(a, 0, half), b, b1, b2, half);
+    (join(&q2, half, &q1), r)
+}
+
+/// Divide a `3n`-limb integer by an `2n`-limb integer.
+///
+/// `a12` is the top `2n` limbs and `a3` the bottom `n` limbs of the
+/// dividend, and `b1` and `b2` are the top and bottom halves of the
+/// normalized divisor `b`.
+fn div_3n_2n(
+    a12: &[Limb],
+    a3: &[Limb],
+    b: &[Limb],
+    b1: &[Limb],
+    b2: &[Limb],
+    n: usize,
+) -> (Vec<Limb>, Vec<Limb>) {
+    let (mut q, r) = if compare(limb_range(a12, n, a12.len()), b1) == cmp::Ordering::Equal {
+        // The quotient would overflow, so use `B^n - 1` as the estimate.
+        let q = vec![Limb::MAX; n];
+        let r = add(limb_range(a12, 0, n), b1);
+        (q, r)
+    } else {
+        div_2n_1n(a12, b1, n)
+    };
+
+    // The quotient is at most 2 too large, so correct it.
+    let mut r = join(a3, n, &r);
+    let qb2 = mul(&q, b2);
+    while compare(&r, &qb2) == cmp::Ordering::Less {
+        sub_assign(&mut q, &[1]);
+        add_from(&mut r, b, 0);
+    }
+    sub_assign(&mut r, &qb2);
+    (q, r)
+}
+
+/// Divide two magnitudes, returning the quotient and remainder.
+fn div_rem(a: &[Limb], b: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    let a = trim(a);
+    let b = trim(b);
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if compare(a, b) == cmp::Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    } else if b.len() == 1 {
+        let mut q = a.to_vec();
+        let r = div_small(&mut q, b[0]);
+        return (q, join(&[r], 1, &[]));
+    }
+
+    // Normalize the divisor, and then divide the dividend in base `B^n`,
+    // where `B` is the limb radix, so each step divides `2n` limbs by `n`.
+    let shift = b[b.len() - 1].leading_zeros();
+    let a = shl_bits(a, shift);
+    let b = shl_bits(b, shift);
+    let n = b.len();
+    let mut q = vec![0; a.len()];
+    let mut r = Vec::new();
+    let mut count = a.len() / n;
+    if count * n < a.len() {
+        count += 1;
+    }
+    for index in (0..count).rev() {
+        let start = index * n;
+        let t = join(limb_range(&a, start, start + n), n, &r);
+        let (qi, ri) = div_2n_1n(&t, &b, n);
+        q[start..start + qi.len()].copy_from_slice(&qi);
+        r = ri;
+    }
+    normalize(&mut q);
+    shr_bits(&mut r, shift);
+    (q, r)
+}
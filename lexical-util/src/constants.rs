@@ -2,6 +2,34 @@
 
 #![cfg(feature = "write")]
 
+#[cfg(all(feature = "floats", feature = "ieee-decimal"))]
+use crate::bid::{Decimal128, Decimal64};
+#[cfg(all(feature = "integers", feature = "std"))]
+use crate::bigint::Bigint;
+#[cfg(feature = "floats")]
+use crate::decimal::Decimal;
+#[cfg(feature = "half")]
+use crate::num::{bf16, f16};
+#[cfg(feature = "integers")]
+use crate::wide::{Int, Uint};
+#[cfg(feature = "saturating")]
+use core::num::Saturating;
+use core::num::{
+    NonZeroI128,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI8,
+    NonZeroIsize,
+    NonZeroU128,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU8,
+    NonZeroUsize,
+    Wrapping,
+};
+
 /// The size, in bytes, of formatted values.
 pub trait FormattedSize {
     /// Maximum number of bytes required to serialize a number to string.
@@ -49,14 +77,18 @@ formatted_size_impl! {
     // Use 256 fir non-decimal values, actually, since we seem to have memory
     // issues with f64. Clearly not sufficient memory allocated for non-decimal
     // values.
-    //bf16 64 256 ;
-    //f16 64 256 ;
     f32 64 256 ;
     f64 64 256 ;
     //f128 128 512 ;
     //f256 256 1024 ;
 }
 
+#[cfg(feature = "half")]
+formatted_size_impl! {
+    bf16 64 256 ;
+    f16 64 256 ;
+}
+
 #[cfg(target_pointer_width = "16")]
 formatted_size_impl! { isize 6 32 ; }
 #[cfg(target_pointer_width = "16")]
@@ -72,8 +104,102 @@ formatted_size_impl! { isize 20 128 ; }
 #[cfg(target_pointer_width = "64")]
 formatted_size_impl! { usize 20 128 ; }
 
+// Integer wrappers are formatted identically to the wrapped integer.
+macro_rules! nonzero_formatted_size_impl {
+    ($($t:ident $int:ident ; )*) => ($(
+        impl FormattedSize for $t {
+            const FORMATTED_SIZE: usize = $int::FORMATTED_SIZE;
+            const FORMATTED_SIZE_DECIMAL: usize = $int::FORMATTED_SIZE_DECIMAL;
+        }
+    )*)
+}
+
+nonzero_formatted_size_impl! {
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+}
+
+impl<T: FormattedSize> FormattedSize for Wrapping<T> {
+    const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
+    const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
+}
+
+#[cfg(feature = "saturating")]
+impl<T: FormattedSize> FormattedSize for Saturating<T> {
+    const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
+    const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
+}
+
+// Wide integers scale with their width, so the sizes are calculated
+// from the number of bits: `1233 / 4096` slightly underestimates
+// `log10(2)`, so an extra digit is added to the decimal size.
+#[cfg(feature = "integers")]
+impl<const N: usize> FormattedSize for Uint<N> {
+    #[cfg(feature = "power-of-two")]
+    const FORMATTED_SIZE: usize = 2 * Self::BITS;
+    #[cfg(not(feature = "power-of-two"))]
+    const FORMATTED_SIZE: usize = Self::FORMATTED_SIZE_DECIMAL;
+    const FORMATTED_SIZE_DECIMAL: usize = Self::BITS * 1233 / 4096 + 2;
+}
+
+#[cfg(feature = "integers")]
+impl<const N: usize> FormattedSize for Int<N> {
+    const FORMATTED_SIZE: usize = Uint::<N>::FORMATTED_SIZE + 1;
+    const FORMATTED_SIZE_DECIMAL: usize = Uint::<N>::FORMATTED_SIZE_DECIMAL + 1;
+}
+
+// Arbitrary-precision integers have no maximum size, so this is only a
+// sentinel: the size must be calculated from the value, using
+// `ToLexical::formatted_len`.
+#[cfg(all(feature = "integers", feature = "std"))]
+impl FormattedSize for Bigint {
+    const FORMATTED_SIZE: usize = usize::MAX;
+    const FORMATTED_SIZE_DECIMAL: usize = usize::MAX;
+}
+
+#[cfg(all(feature = "integers", feature = "std"))]
+impl FormattedSize for &Bigint {
+    const FORMATTED_SIZE: usize = usize::MAX;
+    const FORMATTED_SIZE_DECIMAL: usize = usize::MAX;
+}
+
+// Decimals with more than 64 leading or trailing zeros are written in
+// scientific notation, so the longest value is the sign, `0.`, 64 zeros
+// and 39 digits. Group separators may require more, which is accounted
+// for by `ToLexicalWithOptions::formatted_len_with_options`.
+#[cfg(feature = "floats")]
+impl FormattedSize for Decimal {
+    const FORMATTED_SIZE: usize = 106;
+    const FORMATTED_SIZE_DECIMAL: usize = 106;
+}
+
+// Decimal floats are written with at most the precision in significant
+// digits, so the size is the sign, the digits, `0.00000` or the decimal
+// point, exponent symbol and exponent sign, and 4 exponent digits. Padding
+// the exponent or long special strings may require more, which is
+// accounted for when writing with options.
+#[cfg(all(feature = "floats", feature = "ieee-decimal"))]
+formatted_size_impl! {
+    Decimal64 24 24 ;
+    Decimal128 42 42 ;
+}
+
 /// Maximum number of bytes required to serialize any number to string.
 ///
+/// Wide integers, such as `U256`, decimals and decimal floats may require
+/// more. Arbitrary-precision integers do not have a maximum size, so the
+/// size must be calculated from the value.
+///
 /// Note that this value may be insufficient if digit precision control,
 /// exponent break points, or disabling exponent notation is used.
 /// Please read the documentation in [`lexical_write_float`] for more information.
@@ -0,0 +1,74 @@
+//! Exact, fixed-point decimal numbers.
+//!
+//! A decimal stores a number as an integer coefficient and a decimal
+//! scale, so values such as `0.1` or currency amounts can be parsed and
+//! written exactly, without a round-trip through a binary float.
+
+#![cfg(feature = "floats")]
+
+/// Exact decimal number, with the value `coefficient * 10^-scale`.
+///
+/// The representation is preserved from the parsed string, so `1.50`
+/// has a coefficient of `150` and a scale of `2`, and is not equal to
+/// `1.5`, with a coefficient of `15` and a scale of `1`. A negative scale
+/// designates trailing zeros, so `12e3` has a coefficient of `12` and a
+/// scale of `-3`. Like floats, the sign of zero is also preserved, so
+/// `-0.0` is not equal to `0.0`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    coefficient: i128,
+    scale: i32,
+    is_negative: bool,
+}
+
+impl Decimal {
+    /// The maximum number of significant digits in the coefficient.
+    ///
+    /// Every integer with this many digits fits in an `i128`, so numbers
+    /// with more significant digits are rounded.
+    pub const MAX_DIGITS: usize = 38;
+
+    /// Create the decimal from the coefficient and scale.
+    #[inline(always)]
+    pub const fn new(coefficient: i128, scale: i32) -> Self {
+        Self {
+            coefficient,
+            scale,
+            is_negative: coefficient < 0,
+        }
+    }
+
+    /// Create a negative zero with the scale, such as `-0.00`.
+    #[inline(always)]
+    pub const fn negative_zero(scale: i32) -> Self {
+        Self {
+            coefficient: 0,
+            scale,
+            is_negative: true,
+        }
+    }
+
+    /// Get the integer coefficient.
+    #[inline(always)]
+    pub const fn coefficient(&self) -> i128 {
+        self.coefficient
+    }
+
+    /// Get the number of digits after the decimal point.
+    #[inline(always)]
+    pub const fn scale(&self) -> i32 {
+        self.scale
+    }
+
+    /// Get if the decimal is negative, including negative zero.
+    #[inline(always)]
+    pub const fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    /// Get if the decimal is zero, for any scale.
+    #[inline(always)]
+    pub const fn is_zero(&self) -> bool {
+        self.coefficient == 0
+    }
+}
@@ -51,6 +51,8 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Zero was found for a non-zero integer type.
+    InvalidZero(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -160,6 +162,7 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::InvalidZero(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -199,6 +202,43 @@ impl Error {
         }
     }
 
+    /// Shift the index for the parsing error by `offset` bytes.
+    ///
+    /// Errors without an index, such as number format errors,
+    /// are returned unchanged.
+    pub const fn shift(self, offset: usize) -> Self {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(index) => Self::Overflow(index + offset),
+            Self::Underflow(index) => Self::Underflow(index + offset),
+            Self::InvalidDigit(index) => Self::InvalidDigit(index + offset),
+            Self::Empty(index) => Self::Empty(index + offset),
+            Self::EmptyMantissa(index) => Self::EmptyMantissa(index + offset),
+            Self::EmptyExponent(index) => Self::EmptyExponent(index + offset),
+            Self::EmptyInteger(index) => Self::EmptyInteger(index + offset),
+            Self::EmptyFraction(index) => Self::EmptyFraction(index + offset),
+            Self::InvalidPositiveMantissaSign(index) => {
+                Self::InvalidPositiveMantissaSign(index + offset)
+            },
+            Self::MissingMantissaSign(index) => Self::MissingMantissaSign(index + offset),
+            Self::InvalidExponent(index) => Self::InvalidExponent(index + offset),
+            Self::InvalidPositiveExponentSign(index) => {
+                Self::InvalidPositiveExponentSign(index + offset)
+            },
+            Self::MissingExponentSign(index) => Self::MissingExponentSign(index + offset),
+            Self::ExponentWithoutFraction(index) => Self::ExponentWithoutFraction(index + offset),
+            Self::InvalidLeadingZeros(index) => Self::InvalidLeadingZeros(index + offset),
+            Self::MissingExponent(index) => Self::MissingExponent(index + offset),
+            Self::MissingSign(index) => Self::MissingSign(index + offset),
+            Self::InvalidPositiveSign(index) => Self::InvalidPositiveSign(index + offset),
+            Self::InvalidNegativeSign(index) => Self::InvalidNegativeSign(index + offset),
+            Self::InvalidZero(index) => Self::InvalidZero(index + offset),
+
+            // NUMBER FORMAT ERRORS, OPTION ERRORS, NOT AN ERROR
+            _ => self,
+        }
+    }
+
     is_error_type!(is_overflow, Overflow(_));
     is_error_type!(is_underflow, Underflow(_));
     is_error_type!(is_invalid_digit, InvalidDigit(_));
@@ -218,6 +258,7 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_invalid_zero, InvalidZero(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -303,6 +344,7 @@ impl fmt::Display for Error {
             Self::MissingSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for integer'", index),
             Self::InvalidPositiveSign(index) => write_parse_error!(formatter, "'invalid `+` sign for an integer was found'", index),
             Self::InvalidNegativeSign(index) => write_parse_error!(formatter, "'invalid `-` sign for an unsigned type was found'", index),
+            Self::InvalidZero(index) => write_parse_error!(formatter, "'invalid zero for a non-zero type was found'", index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => format_message!(formatter, "'invalid radix for mantissa digits'"),
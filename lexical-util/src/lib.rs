@@ -15,6 +15,7 @@
 //! * `parse-integers` - Add support for parsing integers.
 //! * `parse-floats` - Add support for parsing floats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `half` - Add support for the `f16` and `bf16` types from `half`.
 //!
 //! # Note
 //!
@@ -44,18 +45,26 @@
 pub mod algorithm;
 pub mod ascii;
 pub mod assert;
+pub mod bid;
+pub mod bigint;
 pub mod constants;
+pub mod decimal;
 pub mod digit;
 pub mod div128;
 pub mod error;
 pub mod extended_float;
 pub mod format;
 pub mod iterator;
+pub mod limb;
 pub mod mul;
 pub mod num;
 pub mod options;
+pub mod rational;
 pub mod result;
 pub mod step;
+pub mod suffix;
+pub mod whitespace;
+pub mod wide;
 
 mod api;
 mod feature_format;
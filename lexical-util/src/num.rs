@@ -6,6 +6,10 @@
 
 use core::{fmt, mem, ops};
 
+/// The 16-bit floats from the `half` crate.
+#[cfg(feature = "half")]
+pub use half::{bf16, f16};
+
 // AS PRIMITIVE
 // ------------
 
@@ -111,6 +115,92 @@ macro_rules! as_primitive {
 
 as_primitive! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
 
+// The 16-bit floats have no `as` casts, so convert through `f32` and `f64`.
+#[cfg(feature = "half")]
+macro_rules! half_as_primitive {
+    ($($t:ty)*) => ($(
+        impl AsPrimitive for $t {
+            #[inline(always)]
+            fn as_u8(self) -> u8 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_u16(self) -> u16 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_u32(self) -> u32 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_u64(self) -> u64 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_u128(self) -> u128 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_usize(self) -> usize {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_i8(self) -> i8 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_i16(self) -> i16 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_i32(self) -> i32 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_i64(self) -> i64 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_i128(self) -> i128 {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_isize(self) -> isize {
+                self.to_f32() as _
+            }
+
+            #[inline(always)]
+            fn as_f32(self) -> f32 {
+                self.to_f32()
+            }
+
+            #[inline(always)]
+            fn as_f64(self) -> f64 {
+                self.to_f64()
+            }
+
+            #[inline(always)]
+            fn from_u32(value: u32) -> Self {
+                Self::from_f64(value as _)
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "half")]
+half_as_primitive! { f16 bf16 }
+
 // AS CAST
 // -------
 
@@ -155,6 +245,21 @@ as_cast!(
     f64, as_f64 ;
 );
 
+#[cfg(feature = "half")]
+macro_rules! half_as_cast {
+    ($($t:ty)*) => ($(
+        impl AsCast for $t {
+            #[inline]
+            fn as_cast<N: AsPrimitive>(n: N) -> $t {
+                <$t>::from_f64(n.as_f64())
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "half")]
+half_as_cast! { f16 bf16 }
+
 // PRIMITIVE
 // ---------
 
@@ -169,6 +274,9 @@ macro_rules! primitive {
 
 primitive! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64 }
 
+#[cfg(feature = "half")]
+primitive! { f16 bf16 }
+
 // NUMBER
 // ------
 
@@ -213,13 +321,17 @@ number_impl! {
     i64 true ;
     i128 true ;
     isize true ;
-    // f16 true
-    // bf16 true
     f32 true ;
     f64 true ;
     // f128 true
 }
 
+#[cfg(feature = "half")]
+number_impl! {
+    f16 true ;
+    bf16 true ;
+}
+
 // INTEGER
 // -------
 
@@ -680,45 +792,99 @@ macro_rules! float_masks {
 
 //  Due to missing specifics or types for the following float types,
 //  `Float` is not yet fully implemented for:
-//      - f16
-//      - bf16
 //      - f128
 
-// #[cfg(all(feature = "f16", feature = "floats"))]
-// impl Float for f16 {
-//     type Unsigned = u16;
-//     float_literals!(f16);
-//     float_masks!(
-//         float => Self,
-//         sign_mask => 0x8000,
-//         exponent_mask => 0x7C00,
-//         hidden_bit_mask => 0x0400,
-//         mantissa_mask => 0x03FF,
-//     );
-//     const EXPONENT_SIZE: i32 = 5;
-//     const MANTISSA_SIZE: i32 = 10;
-//     const EXPONENT_BIAS: i32 = 15 + Self::MANTISSA_SIZE;
-//     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
-//     const MAX_EXPONENT: i32 = 0x1F - Self::EXPONENT_BIAS;
-// }
+/// Define the float literals for the 16-bit floats, which lack float literals.
+#[cfg(all(feature = "floats", feature = "half"))]
+macro_rules! half_float_literals {
+    ($float:ty) => {
+        const ZERO: $float = <$float>::ZERO;
+        const ONE: $float = <$float>::ONE;
+        const TWO: $float = <$float>::from_bits(0x4000);
+        const MAX: $float = <$float>::MAX;
+        const MIN: $float = <$float>::MIN;
+        const INFINITY: $float = <$float>::INFINITY;
+        const NEG_INFINITY: $float = <$float>::NEG_INFINITY;
+        const NAN: $float = <$float>::NAN;
+        const BITS: usize = mem::size_of::<$float>() * 8;
+    };
+}
 
-// #[cfg(all(feature = "f16", feature = "floats"))]
-// impl Float for bf16 {
-//     type Unsigned = u16;
-//     float_literals!(bf16);
-//     float_masks!(
-//         float => Self,
-//         sign_mask => 0x8000,
-//         exponent_mask => 0x7F80,
-//         hidden_bit_mask => 0x0080,
-//         mantissa_mask => 0x007F,
-//     );
-//     const EXPONENT_SIZE: i32 = 8;
-//     const MANTISSA_SIZE: i32 = 7;
-//     const EXPONENT_BIAS: i32 = 127 + Self::MANTISSA_SIZE;
-//     const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
-//     const MAX_EXPONENT: i32 = 0xFF - Self::EXPONENT_BIAS;
-// }
+/// Define the float functions for the 16-bit floats, which use `f32` math.
+#[cfg(all(feature = "floats", feature = "half"))]
+macro_rules! half_float_functions {
+    ($float:ty) => {
+        #[inline]
+        fn to_bits(self) -> u16 {
+            <$float>::to_bits(self)
+        }
+
+        #[inline]
+        fn from_bits(u: u16) -> $float {
+            <$float>::from_bits(u)
+        }
+
+        #[inline]
+        fn ln(self) -> $float {
+            <$float>::from_f32(Float::ln(self.to_f32()))
+        }
+
+        #[inline]
+        fn floor(self) -> $float {
+            <$float>::from_f32(Float::floor(self.to_f32()))
+        }
+
+        #[inline]
+        fn is_sign_positive(self) -> bool {
+            <$float>::is_sign_positive(self)
+        }
+
+        #[inline]
+        fn is_sign_negative(self) -> bool {
+            <$float>::is_sign_negative(self)
+        }
+    };
+}
+
+#[cfg(all(feature = "floats", feature = "half"))]
+impl Float for f16 {
+    type Unsigned = u16;
+    half_float_literals!(f16);
+    float_masks!(
+        float => Self,
+        sign_mask => 0x8000,
+        exponent_mask => 0x7C00,
+        hidden_bit_mask => 0x0400,
+        mantissa_mask => 0x03FF,
+    );
+    const EXPONENT_SIZE: i32 = 5;
+    const MANTISSA_SIZE: i32 = 10;
+    const EXPONENT_BIAS: i32 = 15 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0x1F - Self::EXPONENT_BIAS;
+
+    half_float_functions!(f16);
+}
+
+#[cfg(all(feature = "floats", feature = "half"))]
+impl Float for bf16 {
+    type Unsigned = u16;
+    half_float_literals!(bf16);
+    float_masks!(
+        float => Self,
+        sign_mask => 0x8000,
+        exponent_mask => 0x7F80,
+        hidden_bit_mask => 0x0080,
+        mantissa_mask => 0x007F,
+    );
+    const EXPONENT_SIZE: i32 = 8;
+    const MANTISSA_SIZE: i32 = 7;
+    const EXPONENT_BIAS: i32 = 127 + Self::MANTISSA_SIZE;
+    const DENORMAL_EXPONENT: i32 = 1 - Self::EXPONENT_BIAS;
+    const MAX_EXPONENT: i32 = 0xFF - Self::EXPONENT_BIAS;
+
+    half_float_functions!(bf16);
+}
 
 #[cfg(feature = "floats")]
 impl Float for f32 {
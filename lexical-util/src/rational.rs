@@ -0,0 +1,101 @@
+//! Exact rational numbers, with a power-of-base denominator.
+//!
+//! Every finite float string is a rational number, where the denominator
+//! is a power of the exponent base: `0.1` is `1/10`, and `0x1.8p3` is
+//! `12/1`. Unlike floats and decimals, the numerator is an
+//! arbitrary-precision integer, so no digits are ever rounded.
+
+#![cfg(all(feature = "floats", feature = "integers", feature = "std"))]
+
+use crate::bigint::Bigint;
+use crate::limb::Limb;
+
+/// Exact rational number, with the value `numerator / base^scale`.
+///
+/// The rational is reduced so the numerator is not divisible by the
+/// base, unless the scale is zero, so `1.50` and `1.5` are both `15/10`.
+/// The rational is not reduced to lowest terms: `0.5` is `5/10` rather
+/// than `1/2`, since the denominator must be a power of the base.
+/// Zero always has a scale of zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: Bigint,
+    base: u32,
+    scale: u32,
+}
+
+impl Rational {
+    /// Create the reduced rational `numerator / base^scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is less than 2.
+    pub fn new(mut numerator: Bigint, base: u32, mut scale: u32) -> Self {
+        assert!(base >= 2, "The base must be at least 2.");
+        if numerator.is_zero() {
+            scale = 0;
+        }
+        while scale > 0 {
+            let mut quotient = numerator.clone();
+            if quotient.div_small(base as Limb) != 0 {
+                break;
+            }
+            numerator = quotient;
+            scale -= 1;
+        }
+        Self {
+            numerator,
+            base,
+            scale,
+        }
+    }
+
+    /// Get the signed numerator.
+    #[inline(always)]
+    pub fn numerator(&self) -> &Bigint {
+        &self.numerator
+    }
+
+    /// Calculate the denominator, `base^scale`.
+    #[inline]
+    pub fn denominator(&self) -> Bigint {
+        Bigint::from(self.base).pow(self.scale)
+    }
+
+    /// Get the base of the denominator.
+    #[inline(always)]
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Get the power of the base in the denominator.
+    #[inline(always)]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Get if the rational is negative.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    /// Get if the rational is zero.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    /// Get if the rational is an integer.
+    #[inline(always)]
+    pub fn is_integer(&self) -> bool {
+        self.scale == 0
+    }
+
+    /// Split the rational into the numerator and denominator.
+    #[inline]
+    pub fn into_parts(self) -> (Bigint, Bigint) {
+        let denominator = self.denominator();
+        (self.numerator, denominator)
+    }
+}
@@ -0,0 +1,126 @@
+//! Utilities to parse magnitude suffixes following numeric strings.
+//!
+//! Magnitude suffixes scale the number they follow, such as `10k`,
+//! `2.5M`, `512Mi` or `3µ`. The parsers only recognize the suffixes:
+//! scaling the number is left to the parser, so floats can fold decimal
+//! suffixes into their exponent, and integers can detect overflow.
+
+#![cfg(feature = "parse")]
+
+/// Enumeration for which magnitude suffixes are parsed after the number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SuffixMode {
+    /// No suffixes are parsed: a suffix is an invalid digit.
+    None,
+    /// SI prefixes, which scale the number by a power of 10.
+    ///
+    /// These are `q`, `r`, `y`, `z`, `a`, `f`, `p`, `n`, `µ` (or `μ`
+    /// and `u`), `m`, `k`, `M`, `G`, `T`, `P`, `E`, `Z`, `Y`, `R` and
+    /// `Q`, from `1e-30` to `1e30`.
+    Decimal,
+    /// IEC binary prefixes, which scale the number by a power of 2.
+    ///
+    /// These are `Ki`, `Mi`, `Gi`, `Ti`, `Pi`, `Ei`, `Zi` and `Yi`,
+    /// from `2^10` to `2^80`.
+    Binary,
+    /// Both SI and IEC binary prefixes.
+    Both,
+}
+
+impl SuffixMode {
+    /// Determine if no suffixes are parsed.
+    #[inline(always)]
+    pub const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Determine if SI prefixes are parsed.
+    #[inline(always)]
+    pub const fn is_decimal(&self) -> bool {
+        matches!(self, Self::Decimal | Self::Both)
+    }
+
+    /// Determine if IEC binary prefixes are parsed.
+    #[inline(always)]
+    pub const fn is_binary(&self) -> bool {
+        matches!(self, Self::Binary | Self::Both)
+    }
+}
+
+/// The scale applied to a number by a magnitude suffix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Suffix {
+    /// Scale the number by `10^exp`.
+    Decimal(i32),
+    /// Scale the number by `2^exp`.
+    Binary(u32),
+}
+
+/// Get the power of 10 for an SI prefix at the start of the slice,
+/// and the number of bytes in the prefix.
+#[inline]
+const fn decimal_prefix(slc: &[u8]) -> Option<(i32, usize)> {
+    let exp = match slc {
+        // U+00B5 (MICRO SIGN) and U+03BC (GREEK SMALL LETTER MU).
+        [0xC2, 0xB5, ..] | [0xCE, 0xBC, ..] => return Some((-6, 2)),
+        [b'q', ..] => -30,
+        [b'r', ..] => -27,
+        [b'y', ..] => -24,
+        [b'z', ..] => -21,
+        [b'a', ..] => -18,
+        [b'f', ..] => -15,
+        [b'p', ..] => -12,
+        [b'n', ..] => -9,
+        [b'u', ..] => -6,
+        [b'm', ..] => -3,
+        [b'k', ..] => 3,
+        [b'M', ..] => 6,
+        [b'G', ..] => 9,
+        [b'T', ..] => 12,
+        [b'P', ..] => 15,
+        [b'E', ..] => 18,
+        [b'Z', ..] => 21,
+        [b'Y', ..] => 24,
+        [b'R', ..] => 27,
+        [b'Q', ..] => 30,
+        _ => return None,
+    };
+    Some((exp, 1))
+}
+
+/// Get the power of 2 for an IEC binary prefix at the start of the slice.
+#[inline]
+const fn binary_prefix(slc: &[u8]) -> Option<u32> {
+    let exp = match slc {
+        [b'K', b'i', ..] => 10,
+        [b'M', b'i', ..] => 20,
+        [b'G', b'i', ..] => 30,
+        [b'T', b'i', ..] => 40,
+        [b'P', b'i', ..] => 50,
+        [b'E', b'i', ..] => 60,
+        [b'Z', b'i', ..] => 70,
+        [b'Y', b'i', ..] => 80,
+        _ => return None,
+    };
+    Some(exp)
+}
+
+/// Parse the magnitude suffix at the start of the slice.
+///
+/// Returns the scale of the suffix and the number of bytes it spans,
+/// or `None` if the slice does not start with a suffix for the mode.
+/// Binary prefixes are matched first, so `Mi` is never read as `M`.
+#[inline]
+pub fn parse_suffix(bytes: &[u8], mode: SuffixMode) -> Option<(Suffix, usize)> {
+    if mode.is_binary() {
+        if let Some(exp) = binary_prefix(bytes) {
+            return Some((Suffix::Binary(exp), 2));
+        }
+    }
+    if mode.is_decimal() {
+        if let Some((exp, count)) = decimal_prefix(bytes) {
+            return Some((Suffix::Decimal(exp), count));
+        }
+    }
+    None
+}
@@ -0,0 +1,153 @@
+//! Utilities to skip whitespace surrounding numeric strings.
+//!
+//! The parsers themselves never accept whitespace: these helpers trim
+//! the input before it is handed to the parser, and then shift any
+//! error indexes or processed counts so they are relative to the
+//! untrimmed input.
+
+#![cfg(feature = "parse")]
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// Enumeration for which characters are treated as whitespace.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WhitespaceMode {
+    /// No characters are whitespace: whitespace is an invalid digit.
+    None,
+    /// ASCII whitespace, identical to C's `isspace` in the "C" locale.
+    ///
+    /// This is `' '`, `'\t'`, `'\n'`, `'\v'`, `'\f'`, and `'\r'`.
+    Ascii,
+    /// Unicode whitespace, with the `White_Space` property, in UTF-8.
+    ///
+    /// This is all ASCII whitespace, as well as `U+0085`, `U+00A0`,
+    /// `U+1680`, `U+2000` to `U+200A`, `U+2028`, `U+2029`, `U+202F`,
+    /// `U+205F`, and `U+3000`.
+    Unicode,
+}
+
+impl WhitespaceMode {
+    /// Determine if no characters are skipped as whitespace.
+    #[inline(always)]
+    pub const fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+/// Determine if a character is ASCII whitespace.
+#[inline(always)]
+pub const fn is_ascii_whitespace(c: u8) -> bool {
+    c == b' ' || (c >= b'\t' && c <= b'\r')
+}
+
+/// Get the length of the UTF-8 encoded, non-ASCII whitespace character
+/// at the start of the slice, or 0 if there is none.
+#[inline]
+const fn unicode_whitespace_len(slc: &[u8]) -> usize {
+    // All non-ASCII whitespace is encoded in 2 or 3 bytes.
+    //  U+0085, U+00A0:             C2 85, C2 A0
+    //  U+1680:                     E1 9A 80
+    //  U+2000..=U+200A:            E2 80 80..=8A
+    //  U+2028, U+2029, U+202F:     E2 80 A8, E2 80 A9, E2 80 AF
+    //  U+205F:                     E2 81 9F
+    //  U+3000:                     E3 80 80
+    match slc {
+        [0xC2, 0x85, ..] | [0xC2, 0xA0, ..] => 2,
+        [0xE1, 0x9A, 0x80, ..] => 3,
+        [0xE2, 0x80, 0x80..=0x8A, ..] => 3,
+        [0xE2, 0x80, 0xA8, ..] | [0xE2, 0x80, 0xA9, ..] | [0xE2, 0x80, 0xAF, ..] => 3,
+        [0xE2, 0x81, 0x9F, ..] => 3,
+        [0xE3, 0x80, 0x80, ..] => 3,
+        _ => 0,
+    }
+}
+
+/// Get the number of leading whitespace bytes in the slice.
+#[inline]
+pub fn leading_whitespace(bytes: &[u8], mode: WhitespaceMode) -> usize {
+    let mut index = 0;
+    while index < bytes.len() {
+        let c = bytes[index];
+        if mode != WhitespaceMode::None && is_ascii_whitespace(c) {
+            index += 1;
+        } else if mode == WhitespaceMode::Unicode && c >= 0x80 {
+            match unicode_whitespace_len(&bytes[index..]) {
+                0 => break,
+                count => index += count,
+            }
+        } else {
+            break;
+        }
+    }
+    index
+}
+
+/// Get the number of trailing whitespace bytes in the slice.
+#[inline]
+pub fn trailing_whitespace(bytes: &[u8], mode: WhitespaceMode) -> usize {
+    let mut end = bytes.len();
+    'outer: while end > 0 {
+        let c = bytes[end - 1];
+        if mode != WhitespaceMode::None && is_ascii_whitespace(c) {
+            end -= 1;
+        } else if mode == WhitespaceMode::Unicode && c >= 0x80 {
+            // Whitespace is at most 3 bytes, so try each possible start.
+            for count in 2..=3 {
+                if end >= count && unicode_whitespace_len(&bytes[end - count..end]) == count {
+                    end -= count;
+                    continue 'outer;
+                }
+            }
+            break;
+        } else {
+            break;
+        }
+    }
+    bytes.len() - end
+}
+
+/// Parse a complete number, skipping any surrounding whitespace.
+///
+/// Any errors are shifted so their index is relative to `bytes`.
+#[inline(always)]
+pub fn parse_complete<'a, T, Cb>(
+    bytes: &'a [u8],
+    leading: WhitespaceMode,
+    trailing: WhitespaceMode,
+    cb: Cb,
+) -> Result<T>
+where
+    Cb: FnOnce(&'a [u8]) -> Result<T>,
+{
+    if leading.is_none() && trailing.is_none() {
+        return cb(bytes);
+    }
+    let start = leading_whitespace(bytes, leading);
+    let bytes = &bytes[start..];
+    let end = bytes.len() - trailing_whitespace(bytes, trailing);
+    cb(&bytes[..end]).map_err(|error| error.shift(start))
+}
+
+/// Parse a partial number, skipping any surrounding whitespace.
+///
+/// The number of processed bytes includes any whitespace skipped, and
+/// any errors are shifted so their index is relative to `bytes`.
+#[inline(always)]
+pub fn parse_partial<'a, T, Cb>(
+    bytes: &'a [u8],
+    leading: WhitespaceMode,
+    trailing: WhitespaceMode,
+    cb: Cb,
+) -> Result<(T, usize)>
+where
+    Cb: FnOnce(&'a [u8]) -> Result<(T, usize)>,
+{
+    if leading.is_none() && trailing.is_none() {
+        return cb(bytes);
+    }
+    let start = leading_whitespace(bytes, leading);
+    let (value, count) = cb(&bytes[start..]).map_err(|error: Error| error.shift(start))?;
+    let end = start + count;
+    Ok((value, end + leading_whitespace(&bytes[end..], trailing)))
+}
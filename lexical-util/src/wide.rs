@@ -0,0 +1,296 @@
+//! Fixed-width, wide integer types.
+//!
+//! These store an integer as an array of 64-bit limbs, so integers wider
+//! than 128 bits, such as the 256-bit and 512-bit integers common in
+//! cryptography, can be parsed and written. The types are intentionally
+//! minimal: they provide the limb-wise multiply-add and division used by
+//! the number parsers and writers, and conversions to and from the limbs,
+//! rather than full arithmetic.
+
+#![cfg(feature = "integers")]
+
+use crate::limb::Limb;
+use core::cmp;
+
+/// Number of bits in a wide integer limb.
+///
+/// This is fixed, rather than the native [`Limb`], so the width of a
+/// wide integer does not depend on the target.
+const WIDE_LIMB_BITS: usize = 64;
+
+/// Fixed-width, unsigned integer with `N` 64-bit limbs.
+///
+/// The limbs are stored in little-endian order, so `Uint<4>` is a
+/// 256-bit integer on every target, with the alias [`U256`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Uint<const N: usize> {
+    limbs: [u64; N],
+}
+
+impl<const N: usize> Uint<N> {
+    /// The size of the integer, in bits.
+    pub const BITS: usize = N * WIDE_LIMB_BITS;
+    /// The smallest value, `0`.
+    pub const ZERO: Self = Self {
+        limbs: [0; N],
+    };
+    /// The largest value, `2^BITS - 1`.
+    pub const MAX: Self = Self {
+        limbs: [u64::MAX; N],
+    };
+
+    /// Create the integer from little-endian limbs.
+    #[inline(always)]
+    pub const fn from_limbs(limbs: [u64; N]) -> Self {
+        Self {
+            limbs,
+        }
+    }
+
+    /// Get the little-endian limbs of the integer.
+    #[inline(always)]
+    pub const fn into_limbs(self) -> [u64; N] {
+        self.limbs
+    }
+
+    /// Get a reference to the little-endian limbs of the integer.
+    #[inline(always)]
+    pub const fn as_limbs(&self) -> &[u64; N] {
+        &self.limbs
+    }
+
+    /// Get a mutable reference to the little-endian limbs of the integer.
+    #[inline(always)]
+    pub fn as_limbs_mut(&mut self) -> &mut [u64; N] {
+        &mut self.limbs
+    }
+
+    /// Create the integer from a `u128`, truncating it if it does not fit.
+    #[inline]
+    pub fn from_u128(value: u128) -> Self {
+        let mut limbs = [0; N];
+        let mut value = value;
+        for limb in limbs.iter_mut() {
+            *limb = value as u64;
+            value = value.checked_shr(WIDE_LIMB_BITS as u32).unwrap_or(0);
+        }
+        Self::from_limbs(limbs)
+    }
+
+    /// Determine if the integer is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&x| x == 0)
+    }
+
+    /// Get the value as a single limb, if it fits in one.
+    #[inline]
+    pub fn to_limb(self) -> Option<u64> {
+        match self.limbs.split_first() {
+            Some((&lo, hi)) if hi.iter().all(|&x| x == 0) => Some(lo),
+            Some(_) => None,
+            None => Some(0),
+        }
+    }
+
+    /// Calculate `self * y + z` in place, returning the carried limb.
+    ///
+    /// The value has overflowed if the returned carry is non-zero.
+    #[inline]
+    pub fn mul_small_add(&mut self, y: u64, z: u64) -> u64 {
+        let mut carry = z;
+        for xi in self.limbs.iter_mut() {
+            // Cannot overflow, since `(2^64 - 1)^2 + 2^64 - 1 < 2^128`.
+            let z = (*xi as u128) * (y as u128) + (carry as u128);
+            *xi = z as u64;
+            carry = (z >> WIDE_LIMB_BITS) as u64;
+        }
+        carry
+    }
+
+    /// Divide the integer in place by a small, non-zero integer, returning the remainder.
+    #[inline]
+    pub fn div_small(&mut self, y: u64) -> u64 {
+        let mut rem = 0;
+        for xi in self.limbs.iter_mut().rev() {
+            let x = ((rem as u128) << WIDE_LIMB_BITS) | (*xi as u128);
+            *xi = (x / y as u128) as u64;
+            rem = (x % y as u128) as u64;
+        }
+        rem
+    }
+
+    /// Calculate the two's complement negation, wrapping around the width.
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        let mut limbs = self.limbs;
+        let mut carry = true;
+        for xi in limbs.iter_mut() {
+            let result = (!*xi).overflowing_add(carry as u64);
+            *xi = result.0;
+            carry = result.1;
+        }
+        Self::from_limbs(limbs)
+    }
+}
+
+impl<const N: usize> Default for Uint<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> PartialOrd for Uint<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Uint<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+/// Fixed-width, signed, two's complement integer with `N` 64-bit limbs.
+///
+/// The limbs are stored in little-endian order, so `Int<4>` is a
+/// 256-bit integer on every target, with the alias [`I256`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Int<const N: usize> {
+    bits: Uint<N>,
+}
+
+impl<const N: usize> Int<N> {
+    /// The size of the integer, in bits.
+    pub const BITS: usize = N * WIDE_LIMB_BITS;
+    /// The zero value.
+    pub const ZERO: Self = Self::from_bits(Uint::ZERO);
+    /// The largest value, `2^(BITS - 1) - 1`.
+    pub const MAX: Self = Self::from_bits(Uint::from_limbs(top_limb([u64::MAX; N], u64::MAX >> 1)));
+    /// The smallest value, `-2^(BITS - 1)`.
+    pub const MIN: Self = Self::from_bits(Uint::from_limbs(top_limb([0; N], !(u64::MAX >> 1))));
+
+    /// Create the integer from its two's complement representation.
+    #[inline(always)]
+    pub const fn from_bits(bits: Uint<N>) -> Self {
+        Self {
+            bits,
+        }
+    }
+
+    /// Get the two's complement representation of the integer.
+    #[inline(always)]
+    pub const fn to_bits(self) -> Uint<N> {
+        self.bits
+    }
+
+    /// Create the integer from little-endian, two's complement limbs.
+    #[inline(always)]
+    pub const fn from_limbs(limbs: [u64; N]) -> Self {
+        Self::from_bits(Uint::from_limbs(limbs))
+    }
+
+    /// Get the little-endian, two's complement limbs of the integer.
+    #[inline(always)]
+    pub const fn into_limbs(self) -> [u64; N] {
+        self.bits.into_limbs()
+    }
+
+    /// Create the integer from an `i128`, truncating it if it does not fit.
+    #[inline]
+    pub fn from_i128(value: i128) -> Self {
+        let bits = Uint::from_u128(value.unsigned_abs());
+        if value < 0 {
+            Self::from_bits(bits.wrapping_neg())
+        } else {
+            Self::from_bits(bits)
+        }
+    }
+
+    /// Determine if the integer is negative.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        match self.bits.limbs.last() {
+            Some(&hi) => hi >> (WIDE_LIMB_BITS - 1) != 0,
+            None => false,
+        }
+    }
+
+    /// Calculate the two's complement negation, wrapping around the width.
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        Self::from_bits(self.bits.wrapping_neg())
+    }
+
+    /// Get the absolute value of the integer, as an unsigned integer.
+    #[inline]
+    pub fn unsigned_abs(self) -> Uint<N> {
+        if self.is_negative() {
+            self.bits.wrapping_neg()
+        } else {
+            self.bits
+        }
+    }
+}
+
+impl<const N: usize> Default for Int<N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> PartialOrd for Int<N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Int<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => cmp::Ordering::Less,
+            (false, true) => cmp::Ordering::Greater,
+            _ => self.bits.cmp(&other.bits),
+        }
+    }
+}
+
+/// Replace the most-significant limb, if there are any limbs.
+const fn top_limb<const N: usize>(mut limbs: [u64; N], hi: u64) -> [u64; N] {
+    if N != 0 {
+        limbs[N - 1] = hi;
+    }
+    limbs
+}
+
+/// Get the largest power of the radix that fits in a limb, and its exponent.
+///
+/// This is the number of digits processed at a time when parsing
+/// or writing wide integers.
+#[inline]
+pub fn limb_power(radix: u32) -> (Limb, usize) {
+    let radix = radix as Limb;
+    let mut power = radix;
+    let mut step = 1;
+    while let Some(next) = power.checked_mul(radix) {
+        power = next;
+        step += 1;
+    }
+    (power, step)
+}
+
+/// 256-bit unsigned integer.
+pub type U256 = Uint<4>;
+/// 512-bit unsigned integer.
+pub type U512 = Uint<8>;
+/// 256-bit signed integer.
+pub type I256 = Int<4>;
+/// 512-bit signed integer.
+pub type I512 = Int<8>;
@@ -1,4 +1,6 @@
 #[cfg(feature = "write")]
+use core::mem::MaybeUninit;
+#[cfg(feature = "write")]
 use lexical_util::algorithm;
 
 #[test]
@@ -11,6 +13,13 @@ fn copy_to_dest_test() {
     assert_eq!(&dst[..5], src);
 }
 
+#[test]
+#[cfg(feature = "write")]
+fn zero_uninit_test() {
+    let mut dst = [MaybeUninit::<u8>::uninit(); 4];
+    assert_eq!(algorithm::zero_uninit(&mut dst), &[0, 0, 0, 0]);
+}
+
 #[test]
 #[cfg(feature = "write")]
 fn ltrim_char_test() {
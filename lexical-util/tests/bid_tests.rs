@@ -0,0 +1,63 @@
+#![cfg(all(feature = "floats", feature = "ieee-decimal"))]
+
+use lexical_util::bid::{Decimal128, Decimal64, IeeeDecimal};
+
+#[test]
+fn decimal64_test() {
+    let one = Decimal64::new(false, 1, 0).unwrap();
+    assert_eq!(one.to_bits(), 0x31C0000000000001);
+    assert_eq!(one.coefficient(), 1);
+    assert_eq!(one.exponent(), 0);
+
+    // The largest coefficients use the `11` combination bits.
+    let max = Decimal64::new(false, 9999999999999999, 369).unwrap();
+    assert_eq!(max.to_bits(), 0x77FB86F26FC0FFFF);
+    assert_eq!(max.coefficient(), 9999999999999999);
+    assert_eq!(max.exponent(), 369);
+
+    let min = Decimal64::new(true, 1, -398).unwrap();
+    assert_eq!(min.to_bits(), 0x8000000000000001);
+    assert!(min.is_sign_negative());
+    assert_eq!(min.exponent(), -398);
+
+    assert_eq!(Decimal64::new(false, 10000000000000000, 0), None);
+    assert_eq!(Decimal64::new(false, 1, 370), None);
+    assert_eq!(Decimal64::new(false, 1, -399), None);
+
+    // Non-canonical coefficients are zero.
+    let non_canonical = Decimal64::from_bits(0x6C7FFFFFFFFFFFFF);
+    assert!(non_canonical.is_finite());
+    assert_eq!(non_canonical.coefficient(), 0);
+}
+
+#[test]
+fn decimal128_test() {
+    let one = Decimal128::new(false, 1, 0).unwrap();
+    assert_eq!(one.to_bits(), 0x30400000000000000000000000000001);
+    let max = Decimal128::new(false, Decimal128::MAX_COEFFICIENT, 6111).unwrap();
+    assert_eq!(max.to_bits(), 0x5FFFED09BEAD87C0378D8E63FFFFFFFF);
+    assert_eq!(max.coefficient(), 9999999999999999999999999999999999);
+    assert_eq!(max.exponent(), 6111);
+    let min = Decimal128::new(true, 1, -6176).unwrap();
+    assert_eq!(min.to_bits(), 0x80000000000000000000000000000001);
+    assert_eq!(Decimal128::new(false, 1, 6112), None);
+}
+
+#[test]
+fn special_test() {
+    assert_eq!(Decimal64::NAN.to_bits(), 0x7C00000000000000);
+    assert_eq!(Decimal64::INFINITY.to_bits(), 0x7800000000000000);
+    assert_eq!(Decimal64::NEG_INFINITY.to_bits(), 0xF800000000000000);
+    assert_eq!(Decimal128::NAN.to_bits(), 0x7C000000000000000000000000000000);
+    assert_eq!(<Decimal64 as IeeeDecimal>::nan(false), Decimal64::NAN);
+    assert_eq!(<Decimal128 as IeeeDecimal>::infinity(true), Decimal128::NEG_INFINITY);
+
+    assert!(Decimal64::NAN.is_nan());
+    assert!(!Decimal64::NAN.is_infinite());
+    assert!(!Decimal64::NAN.is_finite());
+    assert!(Decimal64::NEG_INFINITY.is_infinite());
+    assert!(Decimal64::NEG_INFINITY.is_sign_negative());
+    assert!(!Decimal128::INFINITY.is_nan());
+    assert_eq!(Decimal128::INFINITY.coefficient(), 0);
+    assert_eq!(Decimal128::INFINITY.exponent(), 0);
+}
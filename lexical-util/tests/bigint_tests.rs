@@ -0,0 +1,143 @@
+#![cfg(all(feature = "integers", feature = "std"))]
+
+use lexical_util::bigint::Bigint;
+use lexical_util::limb::{Limb, LIMB_BITS};
+
+/// Generate a pseudo-random big integer with `count` limbs.
+fn random(count: usize, seed: &mut u64) -> Bigint {
+    let limbs = (0..count)
+        .map(|_| {
+            // Use a simple LCG, since we only need reproducible values.
+            *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (*seed >> 16) as Limb | ((*seed as Limb) << (LIMB_BITS - 16))
+        })
+        .collect();
+    Bigint::from_limbs(limbs)
+}
+
+#[test]
+fn convert_test() {
+    assert!(Bigint::new().is_zero());
+    assert_eq!(Bigint::from_u128(0), Bigint::new());
+    assert_eq!(Bigint::from_u128(u128::MAX).to_u128(), Some(u128::MAX));
+    assert_eq!(Bigint::from_i128(i128::MIN).to_i128(), Some(i128::MIN));
+    assert_eq!(Bigint::from_i128(i128::MAX).to_i128(), Some(i128::MAX));
+    assert_eq!(Bigint::from_i128(-1).to_u128(), None);
+    assert_eq!(Bigint::from(-5i8).to_i128(), Some(-5));
+    assert_eq!(Bigint::from(5usize).to_i128(), Some(5));
+    assert_eq!(Bigint::from_u128(u128::MAX).to_i128(), None);
+    assert_eq!((Bigint::from_u128(u128::MAX) << 1).to_u128(), None);
+    assert_eq!(Bigint::from_limbs(vec![1, 0, 0]).as_limbs(), &[1]);
+    assert_eq!(Bigint::from_u128(1 << 100).bit_length(), 101);
+    assert_eq!(Bigint::new().bit_length(), 0);
+    assert!(!(-Bigint::new()).is_negative());
+}
+
+#[test]
+fn ord_test() {
+    let values = [i128::MIN, -1 << 70, -2, -1, 0, 1, 2, 1 << 70, i128::MAX];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            assert_eq!(Bigint::from(x).cmp(&Bigint::from(y)), x.cmp(&y));
+        }
+    }
+}
+
+#[test]
+fn arithmetic_test() {
+    let values = [-(1i128 << 100), -(1 << 64) - 7, -12345, -1, 0, 1, 3, 1 << 64, (1 << 90) + 17];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            let (bx, by) = (Bigint::from(x), Bigint::from(y));
+            assert_eq!((&bx + &by).to_i128(), Some(x + y));
+            assert_eq!((&bx - &by).to_i128(), Some(x - y));
+            if x.unsigned_abs().leading_zeros() + y.unsigned_abs().leading_zeros() >= 130 {
+                assert_eq!((&bx * &by).to_i128(), Some(x * y));
+            }
+            if y != 0 {
+                assert_eq!((&bx / &by).to_i128(), Some(x / y));
+                assert_eq!((&bx % &by).to_i128(), Some(x % y));
+            }
+        }
+    }
+
+    let mut x = Bigint::from(10u32);
+    x += Bigint::from(5u32);
+    x *= &Bigint::from(-3i32);
+    x -= Bigint::from(1u32);
+    assert_eq!(x.to_i128(), Some(-46));
+    x /= Bigint::from(4u32);
+    assert_eq!(x.to_i128(), Some(-11));
+    x %= Bigint::from(4u32);
+    assert_eq!(x.to_i128(), Some(-3));
+    x <<= 70;
+    assert_eq!(x.to_i128(), Some(-3 << 70));
+    assert_eq!(Bigint::from(3u32).pow(80), Bigint::from(3u128.pow(40)).pow(2));
+    assert_eq!(Bigint::from(-2i32).pow(3).to_i128(), Some(-8));
+}
+
+#[test]
+fn small_test() {
+    let mut x = Bigint::from_u128(u128::MAX);
+    x.mul_small_add(3, 2);
+    assert_eq!(x.div_small(3), 2);
+    assert_eq!(x, Bigint::from_u128(u128::MAX));
+    let mut x = Bigint::from(-7i32);
+    assert_eq!(x.div_small(7), 0);
+    assert_eq!(x, Bigint::from(-1i32));
+    assert_eq!(x.div_small(2), 1);
+    assert!(!x.is_negative());
+}
+
+#[test]
+#[should_panic]
+fn div_zero_test() {
+    let _ = Bigint::from(1u32) / Bigint::new();
+}
+
+#[test]
+fn large_mul_test() {
+    // Check the Karatsuba results against the distributive law,
+    // using a split at a size below the Karatsuba cutoff.
+    let mut seed = 1;
+    for &(xn, yn) in [(40, 40), (64, 33), (100, 64), (300, 70), (257, 256)].iter() {
+        let x = random(xn, &mut seed);
+        let y = random(yn, &mut seed);
+        let y_hi = Bigint::from_limbs(y.as_limbs()[5..].to_vec());
+        let y_lo = Bigint::from_limbs(y.as_limbs()[..5].to_vec());
+        let expected = ((&x * &y_hi) << (5 * LIMB_BITS)) + &x * &y_lo;
+        assert_eq!(&x * &y, expected);
+        assert_eq!(&y * &x, expected);
+    }
+}
+
+#[test]
+fn large_div_test() {
+    // Check the Burnikel-Ziegler results satisfy `x = q * y + r`,
+    // with `r < y`.
+    let mut seed = 2;
+    for &(xn, yn) in [(80, 40), (200, 33), (64, 64), (513, 101), (1000, 300), (300, 2)].iter() {
+        let x = random(xn, &mut seed);
+        let y = random(yn, &mut seed);
+        let (q, r) = x.div_rem(&y);
+        assert!(r < y);
+        assert_eq!(&q * &y + &r, x);
+
+        // Exact division, where the remainder is zero.
+        let (q, r) = (&x * &y).div_rem(&y);
+        assert!(r.is_zero());
+        assert_eq!(q, x);
+    }
+
+    // Divisors with all bits set, which require the quotient estimates
+    // to be corrected.
+    for &(xn, yn) in [(128, 64), (300, 100), (97, 33)].iter() {
+        let x = Bigint::from_limbs(vec![Limb::MAX; xn]);
+        let y = Bigint::from_limbs(vec![Limb::MAX; yn]);
+        let (q, r) = x.div_rem(&y);
+        assert!(r < y);
+        assert_eq!(&q * &y + &r, x);
+        let (q, r) = (&x - &Bigint::from(1u32)).div_rem(&(&y - &Bigint::from(1u32)));
+        assert_eq!(&q * &(&y - &Bigint::from(1u32)) + &r, &x - &Bigint::from(1u32));
+    }
+}
@@ -252,3 +252,39 @@ fn float_test() {
     assert_eq!(f.prev_positive().next_positive(), f);
     assert_ne!(f.round_positive_even(), f.next());
 }
+
+#[test]
+#[cfg(all(feature = "floats", feature = "half"))]
+fn half_test() {
+    use lexical_util::num::{bf16, f16, AsPrimitive, Float};
+
+    as_primitive(f16::ONE);
+    as_primitive(bf16::ONE);
+    as_cast(f16::ONE);
+    as_cast(bf16::ONE);
+    check_number(f16::ONE, f16::from_f32(5.0));
+    check_number(bf16::ONE, bf16::from_f32(5.0));
+    check_float(f16::from_f32(123.0));
+    check_float(bf16::from_f32(123.0));
+
+    assert_eq!(<f16 as Float>::TWO, f16::from_f32(2.0));
+    assert_eq!(<bf16 as Float>::TWO, bf16::from_f32(2.0));
+    assert_eq!(num::as_cast::<f16, _>(2049u32), f16::from_f32(2048.0));
+    assert_eq!(num::as_cast::<bf16, _>(257u32), bf16::from_f32(256.0));
+    assert_eq!(f16::from_f32(-1.5).as_i32(), -1);
+
+    // b0000000000000001
+    let f = f16::from_bits(1);
+    assert!(f.is_denormal());
+    assert_eq!(f.exponent(), -24);
+    assert_eq!(f.mantissa(), 1);
+    assert_eq!(f.next(), f16::from_bits(2));
+
+    // b0011110000000000
+    assert_eq!(f16::ONE.exponent(), -10);
+    assert_eq!(f16::ONE.mantissa(), 0x0400);
+    assert_eq!(bf16::ONE.exponent(), -7);
+    assert_eq!(bf16::ONE.mantissa(), 0x0080);
+    assert!(bf16::from_bits(bf16::INFINITY_BITS).is_inf());
+    assert!(<f16 as Float>::NAN.is_nan());
+}
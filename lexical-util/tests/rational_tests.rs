@@ -0,0 +1,34 @@
+#![cfg(all(feature = "floats", feature = "integers", feature = "std"))]
+
+use lexical_util::bigint::Bigint;
+use lexical_util::rational::Rational;
+
+#[test]
+fn new_test() {
+    let value = Rational::new(Bigint::from(1500), 10, 3);
+    assert_eq!(value.numerator(), &Bigint::from(15));
+    assert_eq!(value.scale(), 1);
+    assert_eq!(value.base(), 10);
+    assert_eq!(value.denominator(), Bigint::from(10));
+
+    // Only factors of the base are removed.
+    let value = Rational::new(Bigint::from(-12), 2, 4);
+    assert_eq!(value.clone().into_parts(), (Bigint::from(-3), Bigint::from(4)));
+    assert!(value.is_negative());
+    assert!(!value.is_integer());
+    assert_eq!(Rational::new(Bigint::from(5), 10, 1).denominator(), Bigint::from(10));
+
+    // Integers are never scaled.
+    let value = Rational::new(Bigint::from(1500), 10, 0);
+    assert_eq!(value.numerator(), &Bigint::from(1500));
+    assert!(value.is_integer());
+    let value = Rational::new(Bigint::new(), 10, 5);
+    assert!(value.is_zero() && value.is_integer());
+    assert_eq!(value.denominator(), Bigint::from(1));
+}
+
+#[test]
+#[should_panic]
+fn new_invalid_base_test() {
+    Rational::new(Bigint::from(1), 1, 1);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::suffix::{self, Suffix, SuffixMode};
+
+#[test]
+fn suffix_mode_test() {
+    assert!(SuffixMode::None.is_none());
+    assert!(!SuffixMode::None.is_decimal());
+    assert!(!SuffixMode::None.is_binary());
+    assert!(SuffixMode::Decimal.is_decimal());
+    assert!(!SuffixMode::Decimal.is_binary());
+    assert!(!SuffixMode::Binary.is_decimal());
+    assert!(SuffixMode::Binary.is_binary());
+    assert!(SuffixMode::Both.is_decimal());
+    assert!(SuffixMode::Both.is_binary());
+}
+
+#[test]
+fn parse_suffix_test() {
+    let parse = suffix::parse_suffix;
+    assert_eq!(parse(b"k", SuffixMode::None), None);
+    assert_eq!(parse(b"k", SuffixMode::Decimal), Some((Suffix::Decimal(3), 1)));
+    assert_eq!(parse(b"k", SuffixMode::Binary), None);
+    assert_eq!(parse(b"K", SuffixMode::Both), None);
+    assert_eq!(parse(b"Ki", SuffixMode::Binary), Some((Suffix::Binary(10), 2)));
+    assert_eq!(parse(b"Mi", SuffixMode::Decimal), Some((Suffix::Decimal(6), 1)));
+    assert_eq!(parse(b"Mi", SuffixMode::Both), Some((Suffix::Binary(20), 2)));
+    assert_eq!(parse(b"Yi", SuffixMode::Both), Some((Suffix::Binary(80), 2)));
+    assert_eq!(parse(b"Q", SuffixMode::Decimal), Some((Suffix::Decimal(30), 1)));
+    assert_eq!(parse(b"q", SuffixMode::Decimal), Some((Suffix::Decimal(-30), 1)));
+    assert_eq!(parse(b"m", SuffixMode::Decimal), Some((Suffix::Decimal(-3), 1)));
+    assert_eq!(parse(b"u", SuffixMode::Decimal), Some((Suffix::Decimal(-6), 1)));
+    assert_eq!(parse("\u{b5}".as_bytes(), SuffixMode::Decimal), Some((Suffix::Decimal(-6), 2)));
+    assert_eq!(parse("\u{3bc}".as_bytes(), SuffixMode::Decimal), Some((Suffix::Decimal(-6), 2)));
+    assert_eq!(parse(b"ks", SuffixMode::Decimal), Some((Suffix::Decimal(3), 1)));
+    assert_eq!(parse(b"\xc2", SuffixMode::Decimal), None);
+    assert_eq!(parse(b"", SuffixMode::Both), None);
+}
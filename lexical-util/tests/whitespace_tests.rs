@@ -0,0 +1,102 @@
+#![cfg(feature = "parse")]
+
+use lexical_util::error::Error;
+use lexical_util::whitespace::{self, WhitespaceMode};
+
+#[test]
+fn is_ascii_whitespace_test() {
+    assert!(whitespace::is_ascii_whitespace(b' '));
+    assert!(whitespace::is_ascii_whitespace(b'\t'));
+    assert!(whitespace::is_ascii_whitespace(b'\n'));
+    assert!(whitespace::is_ascii_whitespace(b'\x0b'));
+    assert!(whitespace::is_ascii_whitespace(b'\x0c'));
+    assert!(whitespace::is_ascii_whitespace(b'\r'));
+    assert!(!whitespace::is_ascii_whitespace(b'\x08'));
+    assert!(!whitespace::is_ascii_whitespace(b'0'));
+    assert!(!whitespace::is_ascii_whitespace(b'\xa0'));
+}
+
+#[test]
+fn leading_whitespace_test() {
+    let ws = whitespace::leading_whitespace;
+    assert_eq!(ws(b" \t\n1 ", WhitespaceMode::None), 0);
+    assert_eq!(ws(b" \t\n1 ", WhitespaceMode::Ascii), 3);
+    assert_eq!(ws(b" \t\n1 ", WhitespaceMode::Unicode), 3);
+    assert_eq!(ws("\u{a0} \u{3000}1".as_bytes(), WhitespaceMode::Ascii), 0);
+    assert_eq!(ws("\u{a0} \u{3000}1".as_bytes(), WhitespaceMode::Unicode), 6);
+    assert_eq!(ws("\u{2000}\u{200a}\u{200b}".as_bytes(), WhitespaceMode::Unicode), 6);
+    assert_eq!(ws(b"   ", WhitespaceMode::Ascii), 3);
+    assert_eq!(ws(b"", WhitespaceMode::Unicode), 0);
+    // Truncated UTF-8 sequences are not whitespace.
+    assert_eq!(ws(b" \xe2\x80", WhitespaceMode::Unicode), 1);
+}
+
+#[test]
+fn trailing_whitespace_test() {
+    let ws = whitespace::trailing_whitespace;
+    assert_eq!(ws(b" 1 \r\n", WhitespaceMode::None), 0);
+    assert_eq!(ws(b" 1 \r\n", WhitespaceMode::Ascii), 3);
+    assert_eq!(ws(b" 1 \r\n", WhitespaceMode::Unicode), 3);
+    assert_eq!(ws("1\u{85} \u{1680}".as_bytes(), WhitespaceMode::Ascii), 0);
+    assert_eq!(ws("1\u{85} \u{1680}".as_bytes(), WhitespaceMode::Unicode), 6);
+    assert_eq!(ws("1\u{205f}\u{2029}".as_bytes(), WhitespaceMode::Unicode), 6);
+    assert_eq!(ws("1\u{2030}".as_bytes(), WhitespaceMode::Unicode), 0);
+    assert_eq!(ws(b"\x80\x80", WhitespaceMode::Unicode), 0);
+    assert_eq!(ws(b"   ", WhitespaceMode::Ascii), 3);
+}
+
+#[test]
+fn parse_complete_test() {
+    let parse = |bytes: &[u8]| -> Result<usize, Error> {
+        match bytes.iter().position(|&c| c != b'1') {
+            Some(index) => Err(Error::InvalidDigit(index)),
+            None if bytes.is_empty() => Err(Error::Empty(0)),
+            None => Ok(bytes.len()),
+        }
+    };
+    let ascii = WhitespaceMode::Ascii;
+    let none = WhitespaceMode::None;
+    assert_eq!(
+        whitespace::parse_complete(b" 11\n", none, none, parse),
+        Err(Error::InvalidDigit(0))
+    );
+    assert_eq!(whitespace::parse_complete(b" 11\n", ascii, ascii, parse), Ok(2));
+    assert_eq!(
+        whitespace::parse_complete(b" 11\n", ascii, none, parse),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(
+        whitespace::parse_complete(b" 11\n", none, ascii, parse),
+        Err(Error::InvalidDigit(0))
+    );
+    assert_eq!(
+        whitespace::parse_complete(b"  1a1 ", ascii, ascii, parse),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(whitespace::parse_complete(b"   ", ascii, ascii, parse), Err(Error::Empty(3)));
+}
+
+#[test]
+fn parse_partial_test() {
+    let parse = |bytes: &[u8]| -> Result<(usize, usize), Error> {
+        match bytes.iter().position(|&c| c != b'1').unwrap_or(bytes.len()) {
+            0 => Err(Error::Empty(0)),
+            count => Ok((count, count)),
+        }
+    };
+    let ascii = WhitespaceMode::Ascii;
+    let none = WhitespaceMode::None;
+    assert_eq!(whitespace::parse_partial(b" 11\n", none, none, parse), Err(Error::Empty(0)));
+    assert_eq!(whitespace::parse_partial(b" 11\n", ascii, ascii, parse), Ok((2, 4)));
+    assert_eq!(whitespace::parse_partial(b" 11\n", ascii, none, parse), Ok((2, 3)));
+    assert_eq!(whitespace::parse_partial(b" 11 a", ascii, ascii, parse), Ok((2, 4)));
+    assert_eq!(whitespace::parse_partial(b"  a", ascii, ascii, parse), Err(Error::Empty(2)));
+}
+
+#[test]
+fn error_shift_test() {
+    assert_eq!(Error::InvalidDigit(1).shift(2), Error::InvalidDigit(3));
+    assert_eq!(Error::Overflow(0).shift(5), Error::Overflow(5));
+    assert_eq!(Error::InvalidMantissaRadix.shift(5), Error::InvalidMantissaRadix);
+    assert_eq!(Error::Success.shift(5), Error::Success);
+}
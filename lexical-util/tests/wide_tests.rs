@@ -0,0 +1,112 @@
+#![cfg(feature = "integers")]
+
+use core::cmp::Ordering;
+use lexical_util::limb::{self, Limb, LIMB_BITS};
+use lexical_util::wide::{self, Int, Uint, I256, U256};
+
+#[test]
+fn scalar_div_test() {
+    assert_eq!(limb::scalar_div(0, 25, 5), (5, 0));
+    assert_eq!(limb::scalar_div(0, 27, 5), (5, 2));
+    assert_eq!(limb::scalar_div(1, 0, 2), (1 << (LIMB_BITS - 1), 0));
+    assert_eq!(limb::scalar_div(4, Limb::MAX, 5), (Limb::MAX, 4));
+}
+
+#[test]
+fn limb_power_test() {
+    if LIMB_BITS == 64 {
+        assert_eq!(wide::limb_power(10), (10_000_000_000_000_000_000, 19));
+        assert_eq!(wide::limb_power(2), (1 << 63, 63));
+        assert_eq!(wide::limb_power(16), (1 << 60, 15));
+    } else {
+        assert_eq!(wide::limb_power(10), (1_000_000_000, 9));
+        assert_eq!(wide::limb_power(2), (1 << 31, 31));
+        assert_eq!(wide::limb_power(16), (1 << 28, 7));
+    }
+}
+
+#[test]
+fn uint_test() {
+    assert_eq!(U256::BITS, 256);
+    assert!(U256::ZERO.is_zero());
+    assert!(!U256::MAX.is_zero());
+    assert_eq!(U256::default(), U256::ZERO);
+    assert_eq!(U256::from_u128(5).to_limb(), Some(5));
+    assert_eq!(U256::MAX.to_limb(), None);
+    assert_eq!(Uint::<0>::ZERO.to_limb(), Some(0));
+
+    // The limbs are always 64-bit, independent of the target.
+    assert_eq!(Uint::<4>::BITS, 256);
+    assert_eq!(Int::<8>::BITS, 512);
+    let x = U256::from_u128(u128::MAX);
+    let limbs = [u64::MAX, u64::MAX, 0, 0];
+    assert_eq!(x.into_limbs(), limbs);
+    assert_eq!(U256::from_limbs(limbs), x);
+    assert_eq!(x.as_limbs(), &limbs);
+}
+
+#[test]
+fn uint_mul_small_add_test() {
+    let mut x = U256::from_u128(u128::MAX);
+    assert_eq!(x.mul_small_add(2, 1), 0);
+    assert!(x > U256::from_u128(u128::MAX));
+    assert_eq!(x.div_small(2), 1);
+    assert_eq!(x, U256::from_u128(u128::MAX));
+
+    let mut x = U256::MAX;
+    assert_eq!(x.mul_small_add(1, 0), 0);
+    assert_eq!(x, U256::MAX);
+    assert_eq!(x.mul_small_add(2, 0), 1);
+    assert_eq!(x.mul_small_add(1, 1), 0);
+    assert_eq!(x, U256::MAX);
+}
+
+#[test]
+fn uint_div_small_test() {
+    let mut x = U256::from_u128(1_000_000_007);
+    assert_eq!(x.div_small(10), 7);
+    assert_eq!(x, U256::from_u128(100_000_000));
+    let mut x = U256::MAX;
+    assert_eq!(x.div_small(u64::MAX), 0);
+    assert_eq!(x, U256::from_limbs([1; 4]));
+    let mut x = U256::MAX;
+    assert_eq!(x.div_small(3), 0);
+}
+
+#[test]
+fn uint_ord_test() {
+    let x = U256::from_u128(1);
+    let y = U256::from_u128(u128::MAX);
+    assert_eq!(x.cmp(&y), Ordering::Less);
+    assert_eq!(y.cmp(&U256::MAX), Ordering::Less);
+    assert_eq!(U256::MAX.cmp(&U256::MAX), Ordering::Equal);
+    assert_eq!(U256::ZERO.wrapping_neg(), U256::ZERO);
+    assert_eq!(x.wrapping_neg(), U256::MAX);
+}
+
+#[test]
+fn int_test() {
+    assert_eq!(I256::BITS, 256);
+    assert!(!I256::ZERO.is_negative());
+    assert!(!I256::MAX.is_negative());
+    assert!(I256::MIN.is_negative());
+    assert!(I256::MIN < I256::ZERO);
+    assert!(I256::ZERO < I256::MAX);
+    assert_eq!(I256::MIN.wrapping_neg(), I256::MIN);
+    assert_eq!(I256::MAX.wrapping_neg().unsigned_abs(), I256::MAX.to_bits());
+    assert!(!Int::<0>::ZERO.is_negative());
+    assert_eq!(I256::from_i128(-1).to_bits(), U256::MAX);
+    assert_eq!(I256::from_i128(-1).unsigned_abs(), U256::from_u128(1));
+    assert_eq!(I256::from_i128(i128::MIN).unsigned_abs(), U256::from_u128(1 << 127));
+    assert!(I256::from_i128(-2) < I256::from_i128(-1));
+    assert!(I256::from_i128(-1) < I256::from_i128(1));
+
+    let mut max = U256::MAX;
+    assert_eq!(max.div_small(2), 1);
+    assert_eq!(I256::MAX.to_bits(), max);
+    assert_eq!(I256::MIN.unsigned_abs(), {
+        let mut min = max;
+        min.mul_small_add(1, 1);
+        min
+    });
+}
@@ -27,10 +27,12 @@
 #![doc(hidden)]
 
 use crate::float::{ExtendedFloat80, RawFloat};
-use crate::options::{Options, RoundMode};
-use crate::shared;
+use crate::options::{Notation, Options, RoundMode};
+use crate::shared::{self, Layout};
 use crate::table::*;
 use lexical_util::format::{NumberFormat, STANDARD};
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 use lexical_util::num::{AsPrimitive, Float, Integer};
 use lexical_write_integer::decimal::DigitCount;
 use lexical_write_integer::write::WriteInteger;
@@ -50,27 +52,113 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
 
-    let fp = to_decimal(float);
+    let mut fp = to_decimal(float);
     let digit_count = F::digit_count(fp.mant);
     let sci_exp = fp.exp + digit_count as i32 - 1;
+    fp.mant = shared::round_exact_halfway(float, fp.mant, fp.exp, digit_count, options);
 
     // Note that for performance reasons, we write the significant digits
     // later into the algorithms, since we can determine the right path
     // and write the significant digits without using an intermediate buffer
-    // in most cases.
+    // in most cases. The general notation depends on the rounded exponent,
+    // however, so we need to know if rounding carries beforehand.
+    let carried = options.notation() == Notation::General && round_digits(fp.mant, options).1;
 
     write_float!(
         FORMAT,
         sci_exp,
         options,
         write_float_scientific,
+        write_float_engineering,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => F,
+        carried => carried,
         args => bytes, fp, sci_exp, options,
     )
 }
 
+/// Get the number of bytes written by the optimized float-to-string algorithm.
+///
+/// This assumes the float is not special (NaN or Infinity), and
+/// is non-negative.
+#[inline]
+pub fn written_len<F: RawFloat, const FORMAT: u128>(float: F, options: &Options) -> usize {
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    let mut fp = to_decimal(float);
+    let digit_count = F::digit_count(fp.mant);
+    let sci_exp = fp.exp + digit_count as i32 - 1;
+    fp.mant = shared::round_exact_halfway(float, fp.mant, fp.exp, digit_count, options);
+
+    let (mant, carried) = round_digits(fp.mant, options);
+    let digit_count = F::digit_count(mant);
+    let notation_exp = sci_exp + (options.notation() == Notation::General && carried) as i32;
+    match shared::layout::<FORMAT>(sci_exp, notation_exp, options) {
+        Layout::Scientific => {
+            shared::scientific_len::<FORMAT>(digit_count, sci_exp + carried as i32, options)
+        },
+        Layout::Engineering => {
+            shared::engineering_len::<FORMAT>(digit_count, sci_exp + carried as i32, options)
+        },
+        Layout::PositiveExponent => {
+            let leading_digits = sci_exp as usize + 1 + carried as usize;
+            shared::positive_exponent_len(digit_count, leading_digits, options)
+        },
+        Layout::NegativeExponent => {
+            let cursor = sci_exp.wrapping_neg() as usize + 1;
+            if carried && cursor == 2 {
+                // Rounded-up to `1`, which is written as an integer.
+                if options.trim_floats() {
+                    1
+                } else {
+                    3 + shared::min_exact_digits(digit_count, options) - digit_count
+                }
+            } else if carried {
+                shared::negative_exponent_len(digit_count, cursor - 1, options)
+            } else {
+                shared::negative_exponent_len(digit_count, cursor, options)
+            }
+        },
+    }
+}
+
+/// Round the significant digits to the maximum number of significant digits.
+///
+/// Returns the rounded digits, and if rounding carried to a new digit, in
+/// which case the digits are `1`. The digits are rounded before they are
+/// written, so no digits are written past the end of the float. Truncated
+/// digits may have trailing zeros, but the digits rounded-up do not.
+#[inline]
+fn round_digits(mant: u64, options: &Options) -> (u64, bool) {
+    let digit_count = mant.digit_count();
+    let max_digits = match options.max_significant_digits() {
+        Some(max_digits) if max_digits.get() < digit_count => max_digits.get(),
+        _ => return (mant, false),
+    };
+
+    // Both powers must fit in a u64, since `digit_count <= 20`.
+    let pow = 10u64.pow((digit_count - max_digits) as u32);
+    let (quotient, remainder) = (mant / pow, mant % pow);
+    if options.round_mode() == RoundMode::Truncate {
+        return (quotient, false);
+    }
+    let halfway = pow / 2;
+    let round_up = remainder > halfway || (remainder == halfway && quotient % 2 == 1);
+    if !round_up {
+        (quotient, false)
+    } else if quotient + 1 == 10u64.pow(max_digits as u32) {
+        (1, true)
+    } else {
+        let mut rounded = quotient + 1;
+        while rounded % 10 == 0 {
+            rounded /= 10;
+        }
+        (rounded, false)
+    }
+}
+
 /// Write float to string in scientific notation.
 ///
 /// # Safety
@@ -84,21 +172,18 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
     options: &Options,
 ) -> usize {
     // Config options.
-    debug_assert_eq!(count_factors_u64(10, fp.mant), 0);
     let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
     let decimal_point = options.decimal_point();
 
+    // Round the significant digits, so we only write the digits we keep.
+    let (mant, carried) = round_digits(fp.mant, options);
+    let sci_exp = sci_exp + carried as i32;
+
     // Write the significant digits
     // SAFETY: safe, if we have enough bytes to write the significant digits.
-    let mut digits = unsafe { &mut index_unchecked_mut!(bytes[1..]) };
-    let digit_count = unsafe { F::write_digits(digits, fp.mant) };
-
-    // Truncate and round the significant digits.
-    // SAFETY: safe since `digit_count < digits.len()`.
-    let (digit_count, carried) =
-        unsafe { shared::truncate_and_round_decimal(&mut digits, digit_count, options) };
-    let sci_exp = sci_exp + carried as i32;
+    let digits = unsafe { &mut index_unchecked_mut!(bytes[1..]) };
+    let digit_count = unsafe { F::write_digits(digits, mant) };
 
     // Determine the exact number of digits to write.
     let exact_count = shared::min_exact_digits(digit_count, options);
@@ -130,11 +215,33 @@ pub unsafe fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
 
+/// Write float to string in engineering notation.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of digits,
+/// the trailing zeros, and the engineering notation's exponent digits.
+pub unsafe fn write_float_engineering<F: DragonboxFloat, const FORMAT: u128>(
+    bytes: &mut [u8],
+    fp: ExtendedFloat80,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    // Round and write the significant digits.
+    // SAFETY: safe, if we have enough bytes to write the significant digits.
+    let (mant, carried) = round_digits(fp.mant, options);
+    let digit_count = unsafe { F::write_digits(bytes, mant) };
+    let sci_exp = sci_exp + carried as i32;
+
+    // SAFETY: safe since bytes must be large enough to store all digits.
+    unsafe { shared::write_engineering::<FORMAT>(bytes, digit_count, sci_exp, options) }
+}
+
 /// Write negative float to string without scientific notation.
 /// Has a negative exponent (shift right) and no scientific notation.
 ///
@@ -149,38 +256,17 @@ pub unsafe fn write_float_negative_exponent<F: DragonboxFloat, const FORMAT: u12
     options: &Options,
 ) -> usize {
     debug_assert!(sci_exp < 0);
-    debug_assert_eq!(count_factors_u64(10, fp.mant), 0);
 
     // Config options.
     let decimal_point = options.decimal_point();
     let sci_exp = sci_exp.wrapping_neg() as usize;
 
-    // Write our 0 digits.
-    // SAFETY: safe if `bytes.len() < BUFFER_SIZE - 2`.
+    // Round the significant digits, so we only write the digits we keep.
+    let (mant, carried) = round_digits(fp.mant, options);
+    let digit_count = F::digit_count(mant);
+
     let mut cursor = sci_exp + 1;
     debug_assert!(cursor >= 2);
-    unsafe {
-        // We write 0 digits even over the decimal point, since we might have
-        // to round carry over. This is rare, but it could happen, and would
-        // require a shift after. The good news is: if we have a shift, we
-        // only need to move 1 digit.
-        let digits = &mut index_unchecked_mut!(bytes[..cursor]);
-        slice_fill_unchecked!(digits, b'0');
-    }
-
-    // Write out our significant digits.
-    // SAFETY: safe, if we have enough bytes to write the significant digits.
-    let digits = unsafe { &mut index_unchecked_mut!(bytes[cursor..]) };
-    let digit_count = unsafe { F::write_digits(digits, fp.mant) };
-
-    // Truncate and round the significant digits.
-    // SAFETY: safe since `cursor > 0 && cursor < digits.len()`.
-    // We use an extra leading digit so that way can detect carries efficiently.
-    debug_assert!(cursor > 0);
-    let (digit_count, carried) =
-        unsafe { shared::truncate_and_round_decimal(digits, digit_count, options) };
-
-    // Handle any trailing digits.
     let mut trimmed = false;
     if carried && cursor == 2 {
         // Rounded-up, and carried to the first byte, so instead of having
@@ -197,17 +283,25 @@ pub unsafe fn write_float_negative_exponent<F: DragonboxFloat, const FORMAT: u12
                 cursor = 3;
             }
         }
-    } else if carried {
-        // Carried, so we need to remove 1 zero before our digits.
+    } else {
+        // Write our 0 digits.
         // SAFETY: safe if `bytes.len() > cursor`.
         unsafe {
+            let digits = &mut index_unchecked_mut!(bytes[..cursor]);
+            slice_fill_unchecked!(digits, b'0');
             index_unchecked_mut!(bytes[1]) = decimal_point;
-            index_unchecked_mut!(bytes[cursor - 1] = bytes[cursor]);
         }
-    } else {
-        // SAFETY: safe if `bytes.len() >= 2`.
-        unsafe { index_unchecked_mut!(bytes[1]) = decimal_point };
-        cursor += digit_count;
+        if carried {
+            // Carried, so we need to remove 1 zero before our digits.
+            // SAFETY: safe if `bytes.len() > cursor`.
+            unsafe { index_unchecked_mut!(bytes[cursor - 1]) = b'1' };
+        } else {
+            // Write out our significant digits.
+            // SAFETY: safe, if we have enough bytes to write the significant digits.
+            let digits = unsafe { &mut index_unchecked_mut!(bytes[cursor..]) };
+            unsafe { F::write_digits(digits, mant) };
+            cursor += digit_count;
+        }
     }
 
     // Determine the exact number of digits to write.
@@ -242,19 +336,17 @@ pub unsafe fn write_float_positive_exponent<F: DragonboxFloat, const FORMAT: u12
 ) -> usize {
     // Config options.
     debug_assert!(sci_exp >= 0);
-    debug_assert_eq!(count_factors_u64(10, fp.mant), 0);
     let decimal_point = options.decimal_point();
 
     // Write out our significant digits.
     // Let's be optimistic and try to write without needing to move digits.
     // This only works if the if the resulting leading digits, or `sci_exp + 1`,
     // is greater than the written digits. If not, we have to move digits after
-    // and then adjust the decimal point. However, with truncating and remove
-    // trailing zeros, we **don't** know the exact digit count **yet**.
+    // and then adjust the decimal point. The digits are rounded first, so
+    // we only write the digits we keep.
     // SAFETY: safe, if we have enough bytes to write the significant digits.
-    let digit_count = unsafe { F::write_digits(bytes, fp.mant) };
-    let (mut digit_count, carried) =
-        unsafe { shared::truncate_and_round_decimal(bytes, digit_count, options) };
+    let (mant, carried) = round_digits(fp.mant, options);
+    let mut digit_count = unsafe { F::write_digits(bytes, mant) };
 
     // Now, check if we have shift digits.
     let leading_digits = sci_exp as usize + 1 + carried as usize;
@@ -424,7 +516,7 @@ pub fn compute_nearest_shorter<F: RawFloat>(float: F) -> ExtendedFloat80 {
     }
 
     // If the left endpoint is not an integer, increase it.
-    if !interval_type.include_left_endpoint() && is_left_endpoint::<F>(exponent) {
+    if !interval_type.include_left_endpoint() || !is_left_endpoint::<F>(exponent) {
         xi += 1;
     }
 
@@ -1593,3 +1685,98 @@ impl DragonboxFloat for f64 {
         small_div_pow10!(n, SMALL_F64_DIV10_INFO)
     }
 }
+
+// The 16-bit floats use the 64-bit implementation, since the 128-bit powers
+// cover the full exponent range of `bf16`, and the 64-bit significand has
+// more than enough room for `KAPPA` digits beyond the mantissa.
+#[cfg(feature = "half")]
+macro_rules! half_dragonbox_impl {
+    ($($t:ident $digits:literal ;)*) => ($(
+        impl DragonboxFloat for $t {
+            const KAPPA: u32 = f64::KAPPA;
+            const DECIMAL_DIGITS: usize = $digits;
+            const DIV5_TABLE: Self::Table = div64_table::<{ Self::TABLE_SIZE }>(5);
+
+            type Power = (u64, u64);
+            type Table = Div64Table<{ Self::TABLE_SIZE }>;
+
+            #[inline(always)]
+            fn digit_count(mantissa: u64) -> usize {
+                f64::digit_count(mantissa)
+            }
+
+            #[inline(always)]
+            unsafe fn write_digits(bytes: &mut [u8], mantissa: u64) -> usize {
+                // SAFETY: safe if `bytes` is large enough to hold the digits.
+                unsafe { f64::write_digits(bytes, mantissa) }
+            }
+
+            #[inline(always)]
+            unsafe fn dragonbox_power(exponent: i32) -> Self::Power {
+                // SAFETY: safe since the exponent range is a subset of `f64`.
+                unsafe { f64::dragonbox_power(exponent) }
+            }
+
+            #[inline(always)]
+            fn compute_left_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_left_endpoint_u64::<Self>(high(pow5), beta_minus_1)
+            }
+
+            #[inline(always)]
+            fn compute_right_endpoint(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_right_endpoint_u64::<Self>(high(pow5), beta_minus_1)
+            }
+
+            #[inline(always)]
+            fn compute_round_up(pow5: &Self::Power, beta_minus_1: i32) -> u64 {
+                compute_round_up_u64::<Self>(high(pow5), beta_minus_1)
+            }
+
+            #[inline(always)]
+            fn compute_mul(u: u64, pow5: &Self::Power) -> u64 {
+                f64::compute_mul(u, pow5)
+            }
+
+            #[inline(always)]
+            fn compute_mul_parity(two_f: u64, pow5: &Self::Power, beta_minus_1: i32) -> bool {
+                f64::compute_mul_parity(two_f, pow5, beta_minus_1)
+            }
+
+            #[inline(always)]
+            fn compute_delta(pow5: &Self::Power, beta_minus_1: i32) -> u32 {
+                f64::compute_delta(pow5, beta_minus_1)
+            }
+
+            #[inline(always)]
+            fn process_trailing_zeros(mantissa: u64, exponent: i32) -> (u64, i32) {
+                f64::process_trailing_zeros(mantissa, exponent)
+            }
+
+            #[inline(always)]
+            fn remove_trailing_zeros(mantissa: u64) -> (u64, i32) {
+                f64::remove_trailing_zeros(mantissa)
+            }
+
+            #[inline(always)]
+            unsafe fn divisible_by_pow5(x: u64, exp: u32) -> bool {
+                divisible_by_pow5!(Self::DIV5_TABLE, x, exp)
+            }
+
+            #[inline(always)]
+            fn check_div_pow10(n: u32) -> (u32, bool) {
+                f64::check_div_pow10(n)
+            }
+
+            #[inline(always)]
+            fn small_div_pow10(n: u32) -> u32 {
+                f64::small_div_pow10(n)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "half")]
+half_dragonbox_impl! {
+    f16 5 ;
+    bf16 4 ;
+}
@@ -2,11 +2,22 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "ieee-decimal")]
+use crate::bid::{self, write_ieee_decimal};
+use crate::decimal::{formatted_len, write_decimal, written_len};
 use crate::options::Options;
 use crate::write::WriteFloat;
+#[cfg(feature = "ieee-decimal")]
+use lexical_util::bid::{Decimal128, Decimal64};
 use lexical_util::constants::FormattedSize;
+use lexical_util::decimal::Decimal;
+use lexical_util::error::Error;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::num::Float;
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 use lexical_util::options::WriteOptions;
+use lexical_util::result::Result;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
 /// Check if a buffer is sufficiently large.
@@ -19,6 +30,38 @@ where
     len >= size
 }
 
+/// Check if the format and options can be used to write a number.
+#[inline]
+fn check_options<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        Err(Error::InvalidPunctuation)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check if the format and options can be used to write a float.
+#[inline]
+fn check_float_options<F: Float, const FORMAT: u128>(float: F, options: &Options) -> Result<()> {
+    check_options::<FORMAT>(options)?;
+    let format = NumberFormat::<{ FORMAT }> {};
+    let (radix, exponent_base) = (format.radix(), format.exponent_base());
+    if radix != exponent_base
+        && !matches!((radix, exponent_base), (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4))
+    {
+        Err(Error::InvalidExponentBase)
+    } else if float.is_nan() && options.nan_string().is_none() {
+        Err(Error::InvalidNanString)
+    } else if float.is_inf() && options.inf_string().is_none() {
+        Err(Error::InvalidInfString)
+    } else {
+        Ok(())
+    }
+}
+
 // API
 
 const DEFAULT_OPTIONS: Options = Options::new();
@@ -31,8 +74,12 @@ macro_rules! float_to_lexical {
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                debug_assert!(check_buffer::<Self, { STANDARD }>(bytes.len(), &DEFAULT_OPTIONS));
-                // SAFETY: safe if `check_buffer::<STANDARD>(bytes.len(), &options)`.
+                debug_assert!(
+                    check_buffer::<Self, { STANDARD }>(bytes.len(), &DEFAULT_OPTIONS)
+                        || bytes.len() >= self.written_len()
+                );
+                // SAFETY: safe if `check_buffer::<STANDARD>(bytes.len(), &options)`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = self.write_float::<{ STANDARD }>(bytes, &DEFAULT_OPTIONS);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -47,6 +94,11 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe since `check_buffer::<STANDARD>(bytes.len(), &options)`.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len(&self) -> usize {
+                WriteFloat::written_float_len::<{ STANDARD }>(*self, &DEFAULT_OPTIONS)
+            }
         }
 
         impl ToLexicalWithOptions for $t {
@@ -61,8 +113,12 @@ macro_rules! float_to_lexical {
             {
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
                 assert!(is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()));
-                debug_assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), &options));
-                // SAFETY: safe if `check_buffer::<FORMAT>(bytes.len(), &options)`.
+                debug_assert!(
+                    check_buffer::<Self, { FORMAT }>(bytes.len(), &options)
+                        || bytes.len() >= WriteFloat::written_float_len::<FORMAT>(self, options)
+                );
+                // SAFETY: safe if `check_buffer::<FORMAT>(bytes.len(), &options)`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = self.write_float::<{ FORMAT }>(bytes, &options);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -80,6 +136,15 @@ macro_rules! float_to_lexical {
                 // SAFETY: safe since `check_buffer::<FORMAT>(bytes.len(), &options)`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> Result<usize> {
+                check_float_options::<_, FORMAT>(*self, options)?;
+                Ok(WriteFloat::written_float_len::<FORMAT>(*self, options))
+            }
         }
     )*)
 }
@@ -90,3 +155,151 @@ float_to_lexical! {
     f32 ;
     f64 ;
 }
+
+#[cfg(feature = "half")]
+float_to_lexical! {
+    f16 ;
+    bf16 ;
+}
+
+// Implement ToLexical for decimals, which are written in fixed-point
+// notation unless the scale requires too many zeros, so the size of the
+// buffer depends on the scale.
+impl ToLexical for Decimal {
+    unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+        // The size of the buffer must always be checked, since it depends on the value.
+        self.to_lexical(bytes)
+    }
+
+    fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+        let len = write_decimal::<{ STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
+        &mut bytes[..len]
+    }
+
+    fn formatted_len(&self) -> usize {
+        formatted_len(*self, &DEFAULT_OPTIONS)
+    }
+
+    fn written_len(&self) -> usize {
+        written_len::<{ STANDARD }>(*self, &DEFAULT_OPTIONS)
+    }
+}
+
+impl ToLexicalWithOptions for Decimal {
+    type Options = Options;
+
+    unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        // The size of the buffer must always be checked, since it depends on the value.
+        self.to_lexical_with_options::<FORMAT>(bytes, options)
+    }
+
+    fn to_lexical_with_options<'a, const FORMAT: u128>(
+        self,
+        bytes: &'a mut [u8],
+        options: &Self::Options,
+    ) -> &'a mut [u8] {
+        let format = NumberFormat::<{ FORMAT }> {};
+        assert!(format.is_valid());
+        assert!(format.mantissa_radix() == 10);
+        assert!(is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()));
+        let len = write_decimal::<FORMAT>(self, bytes, options);
+        &mut bytes[..len]
+    }
+
+    fn formatted_len_with_options<const FORMAT: u128>(&self, options: &Self::Options) -> usize {
+        formatted_len(*self, options)
+    }
+
+    fn written_len_with_options<const FORMAT: u128>(
+        &self,
+        options: &Self::Options,
+    ) -> Result<usize> {
+        check_options::<FORMAT>(options)?;
+        if NumberFormat::<{ FORMAT }>::MANTISSA_RADIX != 10 {
+            return Err(Error::InvalidMantissaRadix);
+        }
+        Ok(written_len::<FORMAT>(*self, options))
+    }
+}
+
+// Implement ToLexical for decimal floats, which may be written with the
+// special strings, so the size of the buffer depends on the value.
+macro_rules! ieee_decimal_to_lexical {
+    ($($t:ident)*) => ($(
+        #[cfg(feature = "ieee-decimal")]
+        impl ToLexical for $t {
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                // The size of the buffer must always be checked, since it depends on the value.
+                self.to_lexical(bytes)
+            }
+
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                let len = write_ieee_decimal::<_, { STANDARD }>(self, bytes, &DEFAULT_OPTIONS);
+                &mut bytes[..len]
+            }
+
+            fn written_len(&self) -> usize {
+                bid::written_len::<_, { STANDARD }>(*self, &DEFAULT_OPTIONS)
+            }
+        }
+
+        #[cfg(feature = "ieee-decimal")]
+        impl ToLexicalWithOptions for $t {
+            type Options = Options;
+
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // The size of the buffer must always be checked, since it depends on the value.
+                self.to_lexical_with_options::<FORMAT>(bytes, options)
+            }
+
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                let format = NumberFormat::<{ FORMAT }> {};
+                assert!(format.is_valid());
+                assert!(format.mantissa_radix() == 10);
+                assert!(format.exponent_base() == 10 && format.exponent_radix() == 10);
+                assert!(is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()));
+                let len = write_ieee_decimal::<_, FORMAT>(self, bytes, options);
+                &mut bytes[..len]
+            }
+
+            fn formatted_len_with_options<const FORMAT: u128>(&self, options: &Self::Options) -> usize {
+                bid::buffer_size::<Self>(options)
+            }
+
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                options: &Self::Options,
+            ) -> Result<usize> {
+                check_options::<FORMAT>(options)?;
+                let format = NumberFormat::<{ FORMAT }> {};
+                if format.mantissa_radix() != 10 {
+                    Err(Error::InvalidMantissaRadix)
+                } else if format.exponent_base() != 10 {
+                    Err(Error::InvalidExponentBase)
+                } else if format.exponent_radix() != 10 {
+                    Err(Error::InvalidExponentRadix)
+                } else if self.is_nan() && options.nan_string().is_none() {
+                    Err(Error::InvalidNanString)
+                } else if self.is_infinite() && options.inf_string().is_none() {
+                    Err(Error::InvalidInfString)
+                } else {
+                    Ok(bid::written_len::<_, FORMAT>(*self, options))
+                }
+            }
+        }
+    )*)
+}
+
+ieee_decimal_to_lexical! { Decimal64 Decimal128 }
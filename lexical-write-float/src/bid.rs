@@ -0,0 +1,199 @@
+//! Write IEEE decimal floats in their canonical string form.
+//!
+//! The coefficient of a decimal float is exact, so the shortest string
+//! that round-trips is the coefficient digits, laid out around the
+//! exponent. Like the IEEE 754-2008 conversion to a string, values with
+//! a non-positive exponent and an adjusted exponent, of the first digit,
+//! of at least `-6` are written in fixed-point notation, and all other
+//! values in scientific notation. This preserves the quantum, so `1.50`
+//! is written as `1.50`, and `12e2` as `1.2e3`, while `1200` with an
+//! exponent of `0` is written as `1200`.
+
+#![cfg(feature = "ieee-decimal")]
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::shared::{exponent_len, write_exponent};
+use lexical_util::bid::IeeeDecimal;
+use lexical_util::format::NumberFormat;
+use lexical_write_integer::write::{digit_count, WriteInteger};
+
+/// The maximum number of digits in the coefficient.
+const MAX_DIGITS: usize = 39;
+
+/// The minimum adjusted exponent written in fixed-point notation.
+const MIN_FIXED_EXPONENT: i32 = -6;
+
+/// The panic message when writing a NaN without a NaN string.
+const NAN_MESSAGE: &str = "NaN explicitly disabled but asked to write NaN as string.";
+
+/// The panic message when writing an infinity without an Inf string.
+const INF_MESSAGE: &str = "Inf explicitly disabled but asked to write Inf as string.";
+
+/// Calculate the number of bytes required to write the decimal float.
+///
+/// This is an upper bound, including the sign.
+#[inline]
+pub fn formatted_len<D: IeeeDecimal>(value: D, options: &Options) -> usize {
+    let length = if value.is_nan() {
+        options.nan_string().map_or(0, |x| x.len())
+    } else if value.is_infinite() {
+        options.inf_string().map_or(0, |x| x.len())
+    } else {
+        // Fixed-point notation has up to `0.00000` before the digits, and
+        // scientific notation has the decimal point, exponent symbol, sign
+        // and up to 4 exponent digits after the digits.
+        let exponent_digits = options.min_exponent_digits().map_or(4, |x| x.get().max(4));
+        D::PRECISION + 3 + exponent_digits
+    };
+    1 + length
+}
+
+/// Calculate the number of bytes required to write any decimal float.
+///
+/// This is an upper bound for [`formatted_len`] over all values,
+/// including the sign.
+#[inline]
+pub fn buffer_size<D: IeeeDecimal>(options: &Options) -> usize {
+    let exponent_digits = options.min_exponent_digits().map_or(4, |x| x.get().max(4));
+    let nan = options.nan_string().map_or(0, |x| x.len());
+    let inf = options.inf_string().map_or(0, |x| x.len());
+    1 + (D::PRECISION + 3 + exponent_digits).max(nan).max(inf)
+}
+
+/// Calculate the exact number of bytes written for the decimal float.
+///
+/// This includes the sign.
+///
+/// # Panics
+///
+/// Panics if the value is a NaN or infinity and `options.nan_string`
+/// or `options.inf_string` is `None`.
+#[inline]
+pub fn written_len<D: IeeeDecimal, const FORMAT: u128>(value: D, options: &Options) -> usize {
+    if value.is_nan() {
+        return options.nan_string().expect(NAN_MESSAGE).len();
+    }
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    let sign =
+        value.is_sign_negative() || (cfg!(feature = "format") && format.required_mantissa_sign());
+    if value.is_infinite() {
+        return sign as usize + options.inf_string().expect(INF_MESSAGE).len();
+    }
+
+    let count = digit_count(value.coefficient(), 10);
+    let exponent = value.exponent();
+    let adjusted = exponent + count as i32 - 1;
+    let length = if exponent <= 0 && adjusted >= MIN_FIXED_EXPONENT {
+        // Fixed-point notation, with the decimal point if there are fraction digits.
+        let point = count as i32 + exponent;
+        if point <= 0 {
+            2 + (-point) as usize + count
+        } else if (point as usize) < count {
+            count + 1
+        } else {
+            count
+        }
+    } else {
+        // Scientific notation, with the decimal point if there are multiple digits.
+        let digits = if count > 1 {
+            count + 1
+        } else {
+            count
+        };
+        digits + exponent_len::<FORMAT>(adjusted, options)
+    };
+    sign as usize + length
+}
+
+/// Write the special string, returning the number of bytes written.
+#[inline]
+fn write_special(string: Option<&[u8]>, buffer: &mut [u8], message: &str) -> usize {
+    let string = string.expect(message);
+    buffer[..string.len()].copy_from_slice(string);
+    string.len()
+}
+
+/// Write a decimal float to the buffer, returning the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`formatted_len`] and [`written_len`],
+/// or if asked to write a NaN or infinity when `options.nan_string` or
+/// `options.inf_string` is `None`.
+pub fn write_ieee_decimal<D: IeeeDecimal, const FORMAT: u128>(
+    value: D,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize {
+    // Only calculate the exact length if the upper bound does not fit.
+    assert!(
+        buffer.len() >= formatted_len(value, options)
+            || buffer.len() >= written_len::<D, FORMAT>(value, options),
+        "Buffer is too small: may overwrite buffer, panicking!"
+    );
+    if value.is_nan() {
+        return write_special(options.nan_string(), buffer, NAN_MESSAGE);
+    }
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut index = if value.is_sign_negative() {
+        buffer[0] = b'-';
+        1
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        buffer[0] = b'+';
+        1
+    } else {
+        0
+    };
+    if value.is_infinite() {
+        return index + write_special(options.inf_string(), &mut buffer[index..], INF_MESSAGE);
+    }
+
+    let mut digits = [b'0'; MAX_DIGITS];
+    // SAFETY: safe since the buffer can hold every `u128`.
+    let count = unsafe { value.coefficient().write_mantissa::<u128, FORMAT>(&mut digits) };
+    let digits = &digits[..count];
+    let exponent = value.exponent();
+    let adjusted = exponent + count as i32 - 1;
+
+    if exponent <= 0 && adjusted >= MIN_FIXED_EXPONENT {
+        // Fixed-point notation, where `point` is the number of integer
+        // digits, which is negative for leading zeros.
+        let point = count as i32 + exponent;
+        if point > 0 {
+            let (integer, fraction) = digits.split_at(point as usize);
+            buffer[index..index + integer.len()].copy_from_slice(integer);
+            index += integer.len();
+            if !fraction.is_empty() {
+                buffer[index] = options.decimal_point();
+                index += 1;
+                buffer[index..index + fraction.len()].copy_from_slice(fraction);
+                index += fraction.len();
+            }
+        } else {
+            let zeros = (-point) as usize;
+            buffer[index] = b'0';
+            buffer[index + 1] = options.decimal_point();
+            index += 2;
+            buffer[index..index + zeros].fill(b'0');
+            index += zeros;
+            buffer[index..index + count].copy_from_slice(digits);
+            index += count;
+        }
+    } else {
+        // Scientific notation, with the exponent of the first digit.
+        buffer[index] = digits[0];
+        index += 1;
+        if count > 1 {
+            buffer[index] = options.decimal_point();
+            index += 1;
+            buffer[index..index + count - 1].copy_from_slice(&digits[1..]);
+            index += count - 1;
+        }
+        // SAFETY: safe since the buffer can hold the exponent.
+        unsafe { write_exponent::<FORMAT>(buffer, &mut index, adjusted, options) };
+    }
+    index
+}
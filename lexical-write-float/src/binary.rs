@@ -8,7 +8,7 @@
 #![doc(hidden)]
 
 use crate::options::{Options, RoundMode};
-use crate::shared;
+use crate::shared::{self, Layout};
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::format::NumberFormat;
@@ -89,6 +89,7 @@ where
         sci_exp,
         options,
         write_float_scientific,
+        write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => _,
@@ -96,6 +97,39 @@ where
     )
 }
 
+/// Get the number of bytes written by the power-of-two float-to-string algorithm.
+///
+/// This assumes the float is not special (NaN or Infinity), and
+/// is non-negative.
+pub fn written_len<F: Float, const FORMAT: u128>(float: F, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let (mantissa, mantissa_bits) = truncate_and_round(float.mantissa(), radix, options);
+    let exp = float.exponent();
+    let mut sci_exp = exp + mantissa_bits as i32 - 1;
+    if mantissa == <F as Float>::Unsigned::ZERO {
+        sci_exp = 0;
+    }
+
+    let bits_per_digit = fast_log2(radix);
+    let value = trim_zero_digits(mantissa << calculate_shl(exp, bits_per_digit), bits_per_digit);
+    let digit_count = fast_ceildiv(significant_bits(value) as i32, bits_per_digit) as usize;
+    match shared::layout::<FORMAT>(sci_exp, sci_exp, options) {
+        Layout::Scientific | Layout::Engineering => {
+            let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
+            shared::scientific_len::<FORMAT>(digit_count.max(1), scaled_sci_exp, options)
+        },
+        Layout::PositiveExponent => {
+            let leading_digits = (sci_exp / bits_per_digit) as usize + 1;
+            shared::positive_exponent_len(digit_count, leading_digits, options)
+        },
+        Layout::NegativeExponent => {
+            let zero_digits = fast_ceildiv(sci_exp.wrapping_neg(), bits_per_digit) as usize;
+            shared::negative_exponent_len(digit_count, zero_digits + 1, options)
+        },
+    }
+}
+
 /// Write float to string in scientific notation.
 ///
 /// # Safety
@@ -121,7 +155,6 @@ where
 {
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -134,7 +167,7 @@ where
     // Write our value, then trim trailing zeros, before we check the exact
     // bounds of the digits, to avoid accidentally choosing too many digits.
     let shl = calculate_shl(exp, bits_per_digit);
-    let value = mantissa << shl;
+    let value = trim_zero_digits(mantissa << shl, bits_per_digit);
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let digit_count = unsafe {
@@ -174,9 +207,7 @@ where
 
     // Now, write our scientific notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
@@ -207,7 +238,6 @@ where
 
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -236,7 +266,7 @@ where
     // Generate our digits after the shift. Store the number of written
     // digits, so we can adjust the end-point accordingly.
     let shl = calculate_shl(exp, bits_per_digit);
-    let value = mantissa << shl;
+    let value = trim_zero_digits(mantissa << shl, bits_per_digit);
 
     // SAFETY: both are safe, if the buffer is large enough to hold the significant digits.
     let digit_count = unsafe {
@@ -293,7 +323,7 @@ where
     // Write our value, then trim trailing zeros, before we check the exact
     // bounds of the digits, to avoid accidentally choosing too many digits.
     let shl = calculate_shl(exp, bits_per_digit);
-    let value = mantissa << shl;
+    let value = trim_zero_digits(mantissa << shl, bits_per_digit);
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let mut digit_count = unsafe {
@@ -321,6 +351,7 @@ where
             slice_fill_unchecked!(digits, b'0');
         }
         cursor = leading_digits;
+        digit_count = leading_digits;
         // Only write decimal point if we're not trimming floats.
         if !options.trim_floats() {
             unsafe { index_unchecked_mut!(bytes[cursor]) = decimal_point };
@@ -730,6 +761,19 @@ pub fn calculate_shl(exp: i32, bits_per_digit: i32) -> i32 {
     }
 }
 
+/// Remove the trailing zero digits from the significant digits.
+///
+/// This avoids writing digits that are then trimmed, so the digits
+/// never extend past the written float.
+#[inline(always)]
+pub fn trim_zero_digits<M: UnsignedInteger>(value: M, bits_per_digit: i32) -> M {
+    if value == M::ZERO {
+        return value;
+    }
+    let zero_digits = value.trailing_zeros() as i32 / bits_per_digit;
+    value >> (zero_digits * bits_per_digit)
+}
+
 /// We need to scale the scientific exponent for writing.
 ///
 /// If we have a negative exp, then when scaling that,
@@ -793,7 +837,7 @@ where
             let initial_bits = shifted_mantissa.leading_zeros();
             shifted_mantissa += as_cast((above_halfway || (is_odd & is_halfway)) as u32);
             let final_bits = shifted_mantissa.leading_zeros();
-            mantissa_bits += (final_bits - initial_bits) as usize;
+            mantissa_bits += (initial_bits - final_bits) as usize;
         }
     }
 
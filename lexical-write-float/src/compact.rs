@@ -23,12 +23,14 @@
 
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::options::Options;
-use crate::shared;
+use crate::shared::{self, Layout};
 use crate::table::GRISU_POWERS_OF_TEN;
 use core::mem;
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::digit::digit_to_char_const;
 use lexical_util::format::NumberFormat;
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 use lexical_util::num::{AsPrimitive, Float};
 
 /// Compact float-to-string algorithm for decimal strings.
@@ -63,6 +65,26 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     let digits: mem::MaybeUninit<[u8; 32]> = mem::MaybeUninit::uninit();
     // SAFETY: safe, since we never read bytes that weren't written.
     let mut digits = unsafe { digits.assume_init() };
+    let (digit_count, sci_exp) = write_digits(float, &mut digits, options);
+
+    write_float!(
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_engineering,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        args => bytes, &mut digits, digit_count, sci_exp, options,
+    )
+}
+
+/// Write the rounded significant digits to a temporary buffer.
+///
+/// Returns the number of digits, without trailing zeros, and the
+/// scientific exponent.
+#[inline]
+fn write_digits<F: RawFloat>(float: F, digits: &mut [u8; 32], options: &Options) -> (usize, i32) {
     let (digit_count, kappa, carried) = if float == F::ZERO {
         // SAFETY: safe since `digits.len() == 32`.
         unsafe { index_unchecked_mut!(digits[0]) = b'0' };
@@ -70,22 +92,45 @@ pub unsafe fn write_float<F: RawFloat, const FORMAT: u128>(
     } else {
         // SAFETY: safe since `digits.len()` is large enough to always hold enough digits.
         unsafe {
-            let (start, k) = grisu(float, &mut digits);
-            let (end, carried) = shared::truncate_and_round_decimal(&mut digits, start, options);
+            let (start, k) = grisu(float, digits);
+            // Round like the exact value if the digits are exactly halfway.
+            let mant = index_unchecked!(digits[..start])
+                .iter()
+                .fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64);
+            let mant = shared::round_exact_halfway(float, mant, k, start, options);
+            index_unchecked_mut!(digits[start - 1]) = b'0' + (mant % 10) as u8;
+            let (end, carried) = shared::truncate_and_round_decimal(digits, start, options);
+            // Rounding may leave trailing zeros, which are not significant.
+            let end = end - rtrim_char_count(&index_unchecked!(digits[1..end]), b'0');
             (end, k + start as i32 - end as i32, carried)
         }
     };
 
-    let sci_exp = kappa + digit_count as i32 - 1 + carried as i32;
-    write_float!(
-        FORMAT,
-        sci_exp,
-        options,
-        write_float_scientific,
-        write_float_positive_exponent,
-        write_float_negative_exponent,
-        args => bytes, &mut digits, digit_count, sci_exp, options,
-    )
+    (digit_count, kappa + digit_count as i32 - 1 + carried as i32)
+}
+
+/// Get the number of bytes written by the compact float-to-string algorithm.
+///
+/// This assumes the float is not special (NaN or Infinity), and
+/// is non-negative.
+pub fn written_len<F: RawFloat, const FORMAT: u128>(float: F, options: &Options) -> usize {
+    let digits: mem::MaybeUninit<[u8; 32]> = mem::MaybeUninit::uninit();
+    // SAFETY: safe, since we never read bytes that weren't written.
+    let mut digits = unsafe { digits.assume_init() };
+    let (digit_count, sci_exp) = write_digits(float, &mut digits, options);
+
+    match shared::layout::<FORMAT>(sci_exp, sci_exp, options) {
+        Layout::Scientific => shared::scientific_len::<FORMAT>(digit_count, sci_exp, options),
+        Layout::Engineering => shared::engineering_len::<FORMAT>(digit_count, sci_exp, options),
+        Layout::PositiveExponent => {
+            let leading_digits = sci_exp as usize + 1;
+            shared::positive_exponent_len(digit_count, leading_digits, options)
+        },
+        Layout::NegativeExponent => {
+            let leading_zeros = sci_exp.wrapping_neg() as usize + 1;
+            shared::negative_exponent_len(digit_count, leading_zeros, options)
+        },
+    }
 }
 
 /// Write float to string in scientific notation.
@@ -151,11 +196,39 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
 
+/// Write float to string in engineering notation.
+///
+/// # Safety
+///
+/// Safe as long as `bytes` is large enough to hold the number of digits,
+/// the trailing zeros, and the engineering notation's exponent digits.
+pub unsafe fn write_float_engineering<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
+    debug_assert!(digit_count <= 20);
+
+    // Copy our significant digits, which are already rounded.
+    // SAFETY: safe if `digit_count <= digits.len()` and bytes can hold the digits.
+    unsafe {
+        let src = index_unchecked!(digits[..digit_count]).as_ptr();
+        let dst = &mut index_unchecked_mut!(bytes[..digit_count]);
+        copy_nonoverlapping_unchecked!(dst, src, digit_count);
+    }
+
+    // SAFETY: safe since bytes must be large enough to store all digits.
+    unsafe { shared::write_engineering::<FORMAT>(bytes, digit_count, sci_exp, options) }
+}
+
 /// Write negative float to string without scientific notation.
 /// Has a negative exponent (shift right) and no scientific notation.
 ///
@@ -623,3 +696,6 @@ macro_rules! grisu_impl {
 }
 
 grisu_impl! { f32 f64 }
+
+#[cfg(feature = "half")]
+grisu_impl! { f16 bf16 }
@@ -0,0 +1,199 @@
+//! Write exact decimals in fixed-point or scientific notation.
+//!
+//! The coefficient digits are written like an integer, and then laid
+//! out around the decimal point, with leading or trailing zeros as
+//! required by the scale. Decimals that would require more than
+//! [`MAX_FIXED_ZEROS`] zeros are written in scientific notation, with
+//! every coefficient digit, so the scale is always preserved.
+
+#![doc(hidden)]
+
+use crate::options::Options;
+use crate::shared::{exponent_len, write_exponent};
+use lexical_util::decimal::Decimal;
+use lexical_util::format::NumberFormat;
+use lexical_write_integer::write::WriteInteger;
+
+/// The maximum number of digits in the coefficient.
+const MAX_DIGITS: usize = 39;
+
+/// The maximum number of leading or trailing zeros in fixed-point notation.
+pub const MAX_FIXED_ZEROS: i64 = 64;
+
+/// Get the number of zeros required to write the decimal in fixed-point notation.
+///
+/// `point` is the number of integer digits, which is negative for leading zeros.
+#[inline(always)]
+fn fixed_zeros(point: i64, scale: i64) -> i64 {
+    (-point).max(-scale).max(0)
+}
+
+/// Get the number of coefficient digits, ignoring the sign.
+#[inline]
+fn digit_count(value: Decimal) -> usize {
+    let mut coefficient = value.coefficient().unsigned_abs();
+    let mut count = 1;
+    while coefficient >= 10 {
+        coefficient /= 10;
+        count += 1;
+    }
+    count
+}
+
+/// Calculate the number of bytes required to write the decimal.
+///
+/// This is an upper bound, including the sign and group separators.
+#[inline]
+pub fn formatted_len(value: Decimal, options: &Options) -> usize {
+    let scale = value.scale() as i64;
+    let point = digit_count(value) as i64 - scale;
+    if fixed_zeros(point, scale) > MAX_FIXED_ZEROS {
+        // The digits, with any padding for a clamped exponent, the decimal
+        // point, and the exponent symbol, sign and up to 10 digits.
+        let exponent_digits = options.min_exponent_digits().map_or(10, |x| x.get().max(10));
+        return 1 + 2 * MAX_DIGITS + 1 + 2 + exponent_digits;
+    }
+    let integer = (MAX_DIGITS as i64 - scale).max(1) as usize;
+    // Include the decimal point, if there are any fraction digits.
+    let fraction = if scale > 0 {
+        scale as usize + 1
+    } else {
+        0
+    };
+    let groups = options.group_size().map_or(0, |x| (integer - 1) / x.get());
+    1 + integer + groups + fraction
+}
+
+/// Calculate the exact number of bytes written for the decimal.
+///
+/// This includes the sign and group separators.
+#[inline]
+pub fn written_len<const FORMAT: u128>(value: Decimal, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let sign = value.is_negative() || (cfg!(feature = "format") && format.required_mantissa_sign());
+    let count = digit_count(value) as i64;
+    let scale = value.scale() as i64;
+    let point = count - scale;
+
+    let length = if fixed_zeros(point, scale) > MAX_FIXED_ZEROS {
+        // Scientific notation, with any integer digits for a clamped exponent.
+        let adjusted = point - 1;
+        let exponent = adjusted.min(i32::MAX as i64);
+        let integer = adjusted - exponent + 1;
+        let fraction = if integer < count {
+            count - integer + 1
+        } else {
+            0
+        };
+        (integer + fraction) as usize + exponent_len::<FORMAT>(exponent as i32, options)
+    } else {
+        let integer = point.max(1) as usize;
+        let groups = options.group_size().map_or(0, |x| (integer - 1) / x.get());
+        let fraction = if scale > 0 {
+            scale as usize + 1
+        } else {
+            0
+        };
+        integer + groups + fraction
+    };
+    sign as usize + length
+}
+
+/// Write a decimal to the buffer, returning the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`formatted_len`] and [`written_len`].
+pub fn write_decimal<const FORMAT: u128>(
+    value: Decimal,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize {
+    // Only calculate the exact length if the upper bound does not fit.
+    assert!(
+        buffer.len() >= formatted_len(value, options)
+            || buffer.len() >= written_len::<FORMAT>(value, options),
+        "Buffer is too small: may overwrite buffer, panicking!"
+    );
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut index = if value.is_negative() {
+        buffer[0] = b'-';
+        1
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        buffer[0] = b'+';
+        1
+    } else {
+        0
+    };
+
+    let mut digits = [b'0'; MAX_DIGITS];
+    // SAFETY: safe since the buffer can hold every `u128`.
+    let count =
+        unsafe { value.coefficient().unsigned_abs().write_mantissa::<u128, FORMAT>(&mut digits) };
+    // The digits are followed by any trailing zeros, and `point` is the
+    // number of integer digits, which is negative for leading zeros.
+    let scale = value.scale() as i64;
+    let point = count as i64 - scale;
+    let digit = |position: i64| {
+        if position < 0 || position >= count as i64 {
+            b'0'
+        } else {
+            digits[position as usize]
+        }
+    };
+
+    if fixed_zeros(point, scale) > MAX_FIXED_ZEROS {
+        // Scientific notation, with the exponent of the first digit. The
+        // exponent may not fit in an `i32` for the smallest scales, so it
+        // is clamped, and the difference is written as integer digits.
+        let adjusted = point - 1;
+        let exponent = adjusted.min(i32::MAX as i64);
+        let integer = adjusted - exponent + 1;
+        for position in 0..integer {
+            buffer[index] = digit(position);
+            index += 1;
+        }
+        if integer < count as i64 {
+            buffer[index] = options.decimal_point();
+            index += 1;
+            for position in integer..count as i64 {
+                buffer[index] = digit(position);
+                index += 1;
+            }
+        }
+        // SAFETY: safe since the buffer can hold the exponent.
+        unsafe { write_exponent::<FORMAT>(buffer, &mut index, exponent as i32, options) };
+        return index;
+    }
+
+    // Write the integer digits, or a single zero, with any group separators.
+    // Ungrouped digits are a single group, so no separators are written.
+    let integer = point.max(1);
+    let size = options.group_size().map_or(usize::MAX, |x| x.get());
+    let mut group = (integer as usize - 1) % size + 1;
+    for position in 0..integer {
+        if group == 0 {
+            buffer[index] = options.group_separator();
+            index += 1;
+            group = size;
+        }
+        group -= 1;
+        buffer[index] = if point > 0 {
+            digit(position)
+        } else {
+            b'0'
+        };
+        index += 1;
+    }
+
+    // Write the fraction digits, with any leading zeros.
+    if value.scale() > 0 {
+        buffer[index] = options.decimal_point();
+        index += 1;
+        for position in point..count as i64 {
+            buffer[index] = digit(position);
+            index += 1;
+        }
+    }
+    index
+}
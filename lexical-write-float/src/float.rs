@@ -9,6 +9,8 @@ use crate::algorithm::DragonboxFloat;
 #[cfg(feature = "compact")]
 use crate::compact::GrisuFloat;
 use lexical_util::extended_float::ExtendedFloat;
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 
 /// Alias with ~80 bits of precision, 64 for the mantissa and 16 for exponent.
 /// This exponent is biased, and if the exponent is negative, it represents
@@ -26,3 +28,10 @@ impl RawFloat for f32 {
 }
 impl RawFloat for f64 {
 }
+
+#[cfg(feature = "half")]
+impl RawFloat for f16 {
+}
+#[cfg(feature = "half")]
+impl RawFloat for bf16 {
+}
@@ -21,12 +21,14 @@ use crate::binary::{
     calculate_shl,
     fast_ceildiv,
     fast_log2,
+    significant_bits,
+    trim_zero_digits,
     truncate_and_round,
     write_float_negative_exponent,
     write_float_positive_exponent,
 };
 use crate::options::Options;
-use crate::shared;
+use crate::shared::{self, Layout};
 use lexical_util::algorithm::rtrim_char_count;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::format::NumberFormat;
@@ -111,6 +113,7 @@ where
         sci_exp,
         options,
         write_float_scientific,
+        write_float_scientific,
         write_float_positive_exponent,
         write_float_negative_exponent,
         generic => _,
@@ -118,6 +121,40 @@ where
     )
 }
 
+/// Get the number of bytes written by the hexadecimal float-to-string algorithm.
+///
+/// This assumes the float is not special (NaN or Infinity), and
+/// is non-negative.
+pub fn written_len<F: Float, const FORMAT: u128>(float: F, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.mantissa_radix();
+    let (mantissa, mantissa_bits) = truncate_and_round(float.mantissa(), radix, options);
+    let exp = float.exponent();
+    let mut sci_exp = exp + mantissa_bits as i32 - 1;
+    if mantissa == <F as Float>::Unsigned::ZERO {
+        sci_exp = 0;
+    }
+
+    let bits_per_digit = fast_log2(radix);
+    let value = trim_zero_digits(mantissa << calculate_shl(exp, bits_per_digit), bits_per_digit);
+    let digit_count = fast_ceildiv(significant_bits(value) as i32, bits_per_digit) as usize;
+    match shared::layout::<FORMAT>(sci_exp, sci_exp, options) {
+        Layout::Scientific | Layout::Engineering => {
+            let bits_per_base = fast_log2(format.exponent_base());
+            let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
+            shared::scientific_len::<FORMAT>(digit_count.max(1), scaled_sci_exp, options)
+        },
+        Layout::PositiveExponent => {
+            let leading_digits = (sci_exp / bits_per_digit) as usize + 1;
+            shared::positive_exponent_len(digit_count, leading_digits, options)
+        },
+        Layout::NegativeExponent => {
+            let zero_digits = fast_ceildiv(sci_exp.wrapping_neg(), bits_per_digit) as usize;
+            shared::negative_exponent_len(digit_count, zero_digits + 1, options)
+        },
+    }
+}
+
 /// Write float to string in scientific notation.
 ///
 /// # Safety
@@ -142,7 +179,6 @@ where
 {
     // Just decent size bounds checks to ensure we have a lot of space.
     assert!(M::FORMATTED_SIZE < BUFFER_SIZE - 2);
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options
     let format = NumberFormat::<{ FORMAT }> {};
@@ -157,7 +193,7 @@ where
     // radix powers can be **exactly** represented by exponent bases,
     // we can just shift this into the mantissa.
     let shl = calculate_shl(exp, bits_per_digit);
-    let value = mantissa << shl;
+    let value = trim_zero_digits(mantissa << shl, bits_per_digit);
 
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let digit_count = unsafe {
@@ -198,9 +234,7 @@ where
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit, bits_per_base);
-    unsafe {
-        shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent())
-    };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options) };
 
     cursor
 }
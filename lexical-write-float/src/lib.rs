@@ -36,6 +36,8 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `compact` - Reduce code size at the cost of performance.
 //! * `safe` - Ensure only memory-safe indexing is used.
+//! * `ieee-decimal` - Add support for writing IEEE 754-2008 decimal floats.
+//! * `half` - Add support for writing `f16` and `bf16` from `half`.
 //!
 //! # Note
 //!
@@ -71,11 +73,13 @@
 #[macro_use]
 mod index;
 #[macro_use]
-mod shared;
+pub mod shared;
 
 pub mod algorithm;
+pub mod bid;
 pub mod binary;
 pub mod compact;
+pub mod decimal;
 pub mod float;
 pub mod hex;
 pub mod options;
@@ -90,7 +94,12 @@ mod table_grisu;
 // Re-exports
 pub use self::api::{ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::options::{Notation, Options, OptionsBuilder, RoundMode};
+#[cfg(feature = "ieee-decimal")]
+pub use lexical_util::bid::{Decimal128, Decimal64};
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
+pub use lexical_util::decimal::Decimal;
 pub use lexical_util::format::{self, NumberFormatBuilder};
+#[cfg(feature = "half")]
+pub use lexical_util::num::{bf16, f16};
 pub use lexical_util::options::WriteOptions;
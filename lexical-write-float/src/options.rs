@@ -28,6 +28,41 @@ pub enum RoundMode {
     Truncate,
 }
 
+/// Enumeration for how to choose between scientific and fixed notation.
+///
+/// Combined with the significant digit options, this can reproduce
+/// the `printf` float conversions:
+///
+/// - `%.Pe`: [`Scientific`] with `P + 1` minimum and maximum significant digits.
+/// - `%.Pg`: [`General`] with `P` maximum significant digits, trimming floats.
+/// - `%#.Pg`: [`General`] with `P` minimum and maximum significant digits.
+///
+/// Like `printf`, the digits are rounded from the exact value of the float.
+/// Unlike `printf`, any digits past the shortest representation of the
+/// float are written as zeros, so `0.1` with `%.20e` is
+/// `1.00000000000000000000e-1`, rather than `1.00000000000000005551e-1`.
+///
+/// [`Scientific`]: Notation::Scientific
+/// [`General`]: Notation::General
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Notation {
+    /// Use scientific notation if the exponent is outside the exponent breaks.
+    Auto,
+    /// Always use scientific notation, like `%e`.
+    Scientific,
+    /// Always use engineering notation, where the exponent is a multiple
+    /// of `3`, with 1 to 3 integer digits. This is the same as scientific
+    /// notation for non-decimal floats.
+    Engineering,
+    /// Use scientific notation if the exponent is less than `-4`, or
+    /// is greater than or equal to the precision, like `%g`.
+    ///
+    /// The precision is the maximum number of significant digits,
+    /// or `6` if not set, and the exponent is determined after
+    /// rounding to the precision.
+    General,
+}
+
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 const_assert!(MAX_SPECIAL_STRING_LENGTH < f32::FORMATTED_SIZE_DECIMAL);
@@ -53,14 +88,28 @@ pub struct OptionsBuilder {
     /// This is ignored if the exponent base is not the same as the mantissa radix.
     /// If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
+    /// Notation to use for the float, either fixed or scientific.
+    notation: Notation,
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Use SI prefixes instead of the exponent in engineering notation.
+    si_prefix: bool,
+    /// Minimum number of digits to write for the exponent.
+    /// Shorter exponents are padded with leading zeros.
+    min_exponent_digits: OptionUsize,
+    /// Write a `+` sign for non-negative exponents.
+    positive_exponent_sign: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
+    /// Number of integer digits in each group, for decimals.
+    /// If not provided, the digits are not grouped.
+    group_size: OptionUsize,
+    /// Character to separate groups of integer digits, for decimals.
+    group_separator: u8,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
@@ -77,10 +126,16 @@ impl OptionsBuilder {
             min_significant_digits: None,
             positive_exponent_break: None,
             negative_exponent_break: None,
+            notation: Notation::Auto,
             round_mode: RoundMode::Round,
             trim_floats: false,
+            si_prefix: false,
+            min_exponent_digits: None,
+            positive_exponent_sign: false,
             exponent: b'e',
             decimal_point: b'.',
+            group_size: None,
+            group_separator: b',',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
         }
@@ -112,6 +167,12 @@ impl OptionsBuilder {
         self.negative_exponent_break
     }
 
+    /// Get the notation to use for the float.
+    #[inline(always)]
+    pub const fn get_notation(&self) -> Notation {
+        self.notation
+    }
+
     /// Get the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn get_round_mode(&self) -> RoundMode {
@@ -124,6 +185,24 @@ impl OptionsBuilder {
         self.trim_floats
     }
 
+    /// Get if we should use SI prefixes instead of the exponent in engineering notation.
+    #[inline(always)]
+    pub const fn get_si_prefix(&self) -> bool {
+        self.si_prefix
+    }
+
+    /// Get the minimum number of digits to write for the exponent.
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should write a `+` sign for non-negative exponents.
+    #[inline(always)]
+    pub const fn get_positive_exponent_sign(&self) -> bool {
+        self.positive_exponent_sign
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn get_exponent(&self) -> u8 {
@@ -136,6 +215,18 @@ impl OptionsBuilder {
         self.decimal_point
     }
 
+    /// Get the number of integer digits in each group, for decimals.
+    #[inline(always)]
+    pub const fn get_group_size(&self) -> OptionUsize {
+        self.group_size
+    }
+
+    /// Get the character to separate groups of integer digits, for decimals.
+    #[inline(always)]
+    pub const fn get_group_separator(&self) -> u8 {
+        self.group_separator
+    }
+
     /// Get the string representation for `NaN`.
     #[inline(always)]
     pub const fn get_nan_string(&self) -> Option<&'static [u8]> {
@@ -178,6 +269,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the notation to use for the float.
+    #[inline(always)]
+    pub const fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
     /// Set the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn round_mode(mut self, round_mode: RoundMode) -> Self {
@@ -192,6 +290,33 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we should use SI prefixes instead of the exponent in engineering notation.
+    ///
+    /// Exponents without a prefix, such as `e33`, are written as usual,
+    /// and the prefix for `e-6` is the micro sign, `µ`.
+    #[inline(always)]
+    pub const fn si_prefix(mut self, si_prefix: bool) -> Self {
+        self.si_prefix = si_prefix;
+        self
+    }
+
+    /// Set the minimum number of digits to write for the exponent.
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: OptionUsize) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set if we should write a `+` sign for non-negative exponents.
+    ///
+    /// Formats with the `required_exponent_sign` flag always write the
+    /// sign, whether or not this is set.
+    #[inline(always)]
+    pub const fn positive_exponent_sign(mut self, positive_exponent_sign: bool) -> Self {
+        self.positive_exponent_sign = positive_exponent_sign;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(mut self, exponent: u8) -> Self {
@@ -206,6 +331,23 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the number of integer digits in each group, for decimals.
+    ///
+    /// Floats are never grouped, since the grouped digits could not be
+    /// parsed as a float.
+    #[inline(always)]
+    pub const fn group_size(mut self, group_size: OptionUsize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Set the character to separate groups of integer digits, for decimals.
+    #[inline(always)]
+    pub const fn group_separator(mut self, group_separator: u8) -> Self {
+        self.group_separator = group_separator;
+        self
+    }
+
     /// Set the string representation for `NaN`.
     #[inline(always)]
     pub const fn nan_string(mut self, nan_string: Option<&'static [u8]>) -> Self {
@@ -264,6 +406,21 @@ impl OptionsBuilder {
         }
     }
 
+    /// Determine if `group_separator` is valid.
+    ///
+    /// The separator must be an ASCII character that is not a digit or
+    /// a sign. If digits are grouped, it also cannot be the decimal point,
+    /// so the groups are unambiguous.
+    #[inline(always)]
+    pub const fn group_separator_is_valid(&self) -> bool {
+        let c = self.group_separator;
+        let is_grouped = self.group_size.is_some();
+        is_valid_ascii(c)
+            && !c.is_ascii_digit()
+            && !matches!(c, b'+' | b'-')
+            && (!is_grouped || c != self.decimal_point)
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)]
@@ -272,6 +429,8 @@ impl OptionsBuilder {
             false
         } else if !is_valid_ascii(self.decimal_point) {
             false
+        } else if !self.group_separator_is_valid() {
+            false
         } else if !self.nan_str_is_valid() {
             false
         } else if !self.inf_str_is_valid() {
@@ -295,10 +454,16 @@ impl OptionsBuilder {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
+            notation: self.notation,
             round_mode: self.round_mode,
             trim_floats: self.trim_floats,
+            si_prefix: self.si_prefix,
+            min_exponent_digits: self.min_exponent_digits,
+            positive_exponent_sign: self.positive_exponent_sign,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
+            group_size: self.group_size,
+            group_separator: self.group_separator,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
         }
@@ -342,6 +507,8 @@ impl OptionsBuilder {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if !self.group_separator_is_valid() {
+            Err(Error::InvalidDigitSeparator)
         } else {
             // SAFETY: always safe, since it must be valid.
             Ok(unsafe { self.build_unchecked() })
@@ -389,14 +556,28 @@ pub struct Options {
     /// This is ignored if the exponent base is not the same as the mantissa radix.
     /// If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
+    /// Notation to use for the float, either fixed or scientific.
+    notation: Notation,
     /// Rounding mode for writing digits with precision control.
     round_mode: RoundMode,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Use SI prefixes instead of the exponent in engineering notation.
+    si_prefix: bool,
+    /// Minimum number of digits to write for the exponent.
+    /// Shorter exponents are padded with leading zeros.
+    min_exponent_digits: OptionUsize,
+    /// Write a `+` sign for non-negative exponents.
+    positive_exponent_sign: bool,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
     decimal_point: u8,
+    /// Number of integer digits in each group, for decimals.
+    /// If not provided, the digits are not grouped.
+    group_size: OptionUsize,
+    /// Character to separate groups of integer digits, for decimals.
+    group_separator: u8,
     /// String representation of Not A Number, aka `NaN`.
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
@@ -445,6 +626,12 @@ impl Options {
         self.negative_exponent_break
     }
 
+    /// Get the notation to use for the float.
+    #[inline(always)]
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+
     /// Get the rounding mode for writing digits with precision control.
     #[inline(always)]
     pub const fn round_mode(&self) -> RoundMode {
@@ -457,6 +644,24 @@ impl Options {
         self.trim_floats
     }
 
+    /// Get if we should use SI prefixes instead of the exponent in engineering notation.
+    #[inline(always)]
+    pub const fn si_prefix(&self) -> bool {
+        self.si_prefix
+    }
+
+    /// Get the minimum number of digits to write for the exponent.
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should write a `+` sign for non-negative exponents.
+    #[inline(always)]
+    pub const fn positive_exponent_sign(&self) -> bool {
+        self.positive_exponent_sign
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(&self) -> u8 {
@@ -469,6 +674,18 @@ impl Options {
         self.decimal_point
     }
 
+    /// Get the number of integer digits in each group, for decimals.
+    #[inline(always)]
+    pub const fn group_size(&self) -> OptionUsize {
+        self.group_size
+    }
+
+    /// Get the character to separate groups of integer digits, for decimals.
+    #[inline(always)]
+    pub const fn group_separator(&self) -> u8 {
+        self.group_separator
+    }
+
     /// Get the string representation for `NaN`.
     #[inline(always)]
     pub const fn nan_string(&self) -> Option<&'static [u8]> {
@@ -525,6 +742,16 @@ impl Options {
         self.negative_exponent_break = negative_exponent_break;
     }
 
+    /// Set the notation to use for the float.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_notation(&mut self, notation: Notation) {
+        self.notation = notation;
+    }
+
     /// Set the rounding mode for writing digits with precision control.
     ///
     /// # Safety
@@ -546,6 +773,39 @@ impl Options {
         self.trim_floats = trim_floats;
     }
 
+    /// Set if we should use SI prefixes instead of the exponent in engineering notation.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_si_prefix(&mut self, si_prefix: bool) {
+        self.si_prefix = si_prefix;
+    }
+
+    /// Set the minimum number of digits to write for the exponent.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_min_exponent_digits(&mut self, min_exponent_digits: OptionUsize) {
+        self.min_exponent_digits = min_exponent_digits;
+    }
+
+    /// Set if we should write a `+` sign for non-negative exponents.
+    ///
+    /// Formats with the `required_exponent_sign` flag always write the
+    /// sign, whether or not this is set.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_positive_exponent_sign(&mut self, positive_exponent_sign: bool) {
+        self.positive_exponent_sign = positive_exponent_sign;
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// # Safety
@@ -568,6 +828,27 @@ impl Options {
         self.decimal_point = decimal_point;
     }
 
+    /// Set the number of integer digits in each group, for decimals.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, just marked as unsafe for API compatibility.
+    #[inline(always)]
+    pub unsafe fn set_group_size(&mut self, group_size: OptionUsize) {
+        self.group_size = group_size;
+    }
+
+    /// Set the character to separate groups of integer digits, for decimals.
+    ///
+    /// # Safety
+    ///
+    /// Never unsafe, but may produce invalid output if the group separator
+    /// is not a valid ASCII character, or is a digit or the decimal point.
+    #[inline(always)]
+    pub unsafe fn set_group_separator(&mut self, group_separator: u8) {
+        self.group_separator = group_separator;
+    }
+
     /// Set the string representation for `NaN`.
     /// Unsafe, use the builder API for option validation.
     ///
@@ -606,10 +887,16 @@ impl Options {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
+            notation: self.notation,
             round_mode: self.round_mode,
             trim_floats: self.trim_floats,
+            si_prefix: self.si_prefix,
+            min_exponent_digits: self.min_exponent_digits,
+            positive_exponent_sign: self.positive_exponent_sign,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
+            group_size: self.group_size,
+            group_separator: self.group_separator,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
         }
@@ -639,9 +926,16 @@ impl WriteOptions for Options {
         // First need to calculate maximum number of digits from leading or
         // trailing zeros, IE, the exponent break.
         if !format.no_exponent_notation() {
-            let min_exp = self.negative_exponent_break().map_or(-5, |x| x.get());
-            let max_exp = self.positive_exponent_break().map_or(9, |x| x.get());
-            let exp = min_exp.abs().max(max_exp) as usize;
+            let exp = match self.notation() {
+                Notation::Auto => {
+                    let min_exp = self.negative_exponent_break().map_or(-5, |x| x.get());
+                    let max_exp = self.positive_exponent_break().map_or(9, |x| x.get());
+                    min_exp.abs().max(max_exp) as usize
+                },
+                Notation::Scientific | Notation::Engineering => 0,
+                // Fixed notation is used for exponents in `[-4, precision)`.
+                Notation::General => self.max_significant_digits().map_or(6, |x| x.get()),
+            };
             if cfg!(feature = "power-of-two") && exp < 13 {
                 // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
                 count += 13;
@@ -652,6 +946,12 @@ impl WriteOptions for Options {
                 // More leading or trailing zeros than the exponent digits.
                 count += exp;
             }
+            // Any padding for the exponent digits.
+            count += self.min_exponent_digits().map_or(0, |x| x.get());
+            // Up to 2 trailing zeros for the integer digits in engineering notation.
+            if self.notation() == Notation::Engineering {
+                count += 2;
+            }
         } else if cfg!(feature = "power-of-two") {
             // Min is 2^-1075.
             count += 1075;
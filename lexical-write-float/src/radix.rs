@@ -13,7 +13,7 @@
 #![doc(hidden)]
 
 use crate::options::{Options, RoundMode};
-use crate::shared;
+use crate::shared::{self, Layout};
 use core::mem;
 use lexical_util::algorithm::{ltrim_char_count, rtrim_char_count};
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
@@ -40,7 +40,6 @@ use lexical_write_integer::write::WriteInteger;
 /// # Panics
 ///
 /// Panics if exponent notation is used.
-#[allow(clippy::collapsible_if)]
 pub unsafe fn write_float<F: Float, const FORMAT: u128>(
     float: F,
     bytes: &mut [u8],
@@ -61,115 +60,19 @@ where
     // Validate our options: we don't support different exponent bases here.
     debug_assert!(format.mantissa_radix() == format.exponent_base());
 
-    // Temporary buffer for the result. We start with the decimal point in the
-    // middle and write to the left for the integer part and to the right for the
-    // fractional part. 1024 characters for the exponent and 52 for the mantissa
-    // either way, with additional space for sign, decimal point and string
-    // termination should be sufficient.
-    const SIZE: usize = 2200;
+    // Temporary buffer for the result.
     let buffer: mem::MaybeUninit<[u8; SIZE]> = mem::MaybeUninit::uninit();
     // SAFETY: safe, since we never read bytes that weren't written.
     let mut buffer = unsafe { buffer.assume_init() };
-    //let buffer = buffer.as_mut_ptr();
-    let initial_cursor: usize = SIZE / 2;
-    let mut integer_cursor = initial_cursor;
-    let mut fraction_cursor = initial_cursor;
-    let base = F::as_cast(format.radix());
-
-    // Split the float into an integer part and a fractional part.
-    let mut integer = float.floor();
-    let mut fraction = float - integer;
-
-    // We only compute fractional digits up to the input double's precision.
-    // This fails if the value is at f64::MAX. IF we take the next positive,
-    // we'll get literal infinite. We don't care about NaN comparisons, since
-    // the float **must** be finite, so do this.
-    let mut delta = if float.to_bits() == F::MAX.to_bits() {
-        F::as_cast(0.5) * (float - float.prev_positive())
-    } else {
-        F::as_cast(0.5) * (float.next_positive() - float)
-    };
-    delta = F::ZERO.next_positive().max_finite(delta);
-    debug_assert!(delta > F::ZERO);
+    let (sci_exp, integer_cursor, fraction_cursor) = write_digits::<F, FORMAT>(float, &mut buffer);
+    let initial_cursor = INITIAL_CURSOR;
 
-    // Write our fraction digits.
-    // SAFETY: we have 1100 digits, which is enough for any float f64 or smaller.
-    if fraction > delta {
-        loop {
-            // Shift up by one digit.
-            fraction *= base;
-            delta *= base;
-            // Write digit.
-            let digit = fraction.as_u32();
-            let c = digit_to_char_const(digit, format.radix());
-            unsafe { index_unchecked_mut!(buffer[fraction_cursor]) = c };
-            fraction_cursor += 1;
-            // Calculate remainder.
-            fraction -= F::as_cast(digit);
-            // Round to even.
-            if fraction > F::as_cast(0.5) || (fraction == F::as_cast(0.5) && (digit & 1) != 0) {
-                if fraction + delta > F::ONE {
-                    // We need to back trace already written digits in case of carry-over.
-                    loop {
-                        fraction_cursor -= 1;
-                        if fraction_cursor == initial_cursor - 1 {
-                            // Carry over to the integer part.
-                            integer += F::ONE;
-                            break;
-                        }
-                        // Reconstruct digit.
-                        let c = unsafe { index_unchecked!(buffer[fraction_cursor]) };
-                        if let Some(digit) = char_to_digit_const(c, format.radix()) {
-                            let idx = digit + 1;
-                            let c = digit_to_char_const(idx, format.radix());
-                            unsafe { index_unchecked_mut!(buffer[fraction_cursor]) = c };
-                            fraction_cursor += 1;
-                            break;
-                        }
-                    }
-                    break;
-                }
-            }
-
-            if delta >= fraction {
-                break;
-            }
-        }
-    }
-
-    // Compute integer digits. Fill unrepresented digits with zero.
-    // SAFETY: we have 1100 digits, which is enough for any float f64 or smaller.
-    // We do this first, so we can do extended precision control later.
-    while (integer / base).exponent() > 0 {
-        integer /= base;
-        integer_cursor -= 1;
-        unsafe { index_unchecked_mut!(buffer[integer_cursor]) = b'0' };
-    }
-
-    loop {
-        let remainder = integer % base;
-        integer_cursor -= 1;
-        let idx = remainder.as_u32();
-        let c = digit_to_char_const(idx, format.radix());
-        unsafe { index_unchecked_mut!(buffer[integer_cursor]) = c };
-        integer = (integer - remainder) / base;
-
-        if integer <= F::ZERO {
-            break;
-        }
-    }
-
-    // Write our exponent.
-    let sci_exp = if float == F::ZERO {
-        0
-    } else {
-        naive_exponent(float, format.radix())
-    };
     write_float!(
         FORMAT,
         sci_exp,
         options,
         write_float_scientific,
+        write_float_scientific,
         write_float_nonscientific,
         write_float_nonscientific,
         args => sci_exp, &mut buffer, bytes, initial_cursor,
@@ -177,6 +80,63 @@ where
     )
 }
 
+/// Get the number of bytes written by the radix float-to-string algorithm.
+///
+/// This assumes the float is not special (NaN or Infinity), and
+/// is non-negative.
+pub fn written_len<F: Float, const FORMAT: u128>(float: F, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let buffer: mem::MaybeUninit<[u8; SIZE]> = mem::MaybeUninit::uninit();
+    // SAFETY: safe, since we never read bytes that weren't written.
+    let mut buffer = unsafe { buffer.assume_init() };
+    let (sci_exp, integer_cursor, fraction_cursor) = write_digits::<F, FORMAT>(float, &mut buffer);
+
+    match shared::layout::<FORMAT>(sci_exp, sci_exp, options) {
+        Layout::Scientific | Layout::Engineering => {
+            let start = if sci_exp <= 0 {
+                ((INITIAL_CURSOR as i32) - sci_exp - 1) as usize
+            } else {
+                integer_cursor
+            };
+            let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
+            // SAFETY: safe since `end <= buffer.len()`.
+            let (digit_count, carried) =
+                unsafe { truncate_and_round(&mut buffer, start, end, format.radix(), options) };
+            let digits = &buffer[start..start + digit_count];
+            let digit_count = digit_count - rtrim_char_count(&digits[1..], b'0');
+            shared::scientific_len::<FORMAT>(digit_count, sci_exp + carried as i32, options)
+        },
+        Layout::PositiveExponent | Layout::NegativeExponent => {
+            let mut start = integer_cursor;
+            let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
+            // SAFETY: safe since `end <= buffer.len()`.
+            let (mut digit_count, carried) =
+                unsafe { truncate_and_round(&mut buffer, start, end, format.radix(), options) };
+            if carried {
+                start -= 1;
+                buffer[start] = b'1';
+            }
+            let digits = &buffer[start..start + digit_count];
+
+            let integer_length = INITIAL_CURSOR - start;
+            let fraction_count = digit_count.saturating_sub(integer_length);
+            let mut length = integer_length;
+            if fraction_count > 0 {
+                let fraction = &digits[integer_length..];
+                length += 1 + fraction_count - rtrim_char_count(fraction, b'0');
+            } else if !options.trim_floats() {
+                length += 2;
+                digit_count += 1;
+            }
+            let exact_count = shared::min_exact_digits(digit_count, options);
+            if (fraction_count > 0 || !options.trim_floats()) && exact_count > digit_count {
+                length += exact_count - digit_count;
+            }
+            length
+        },
+    }
+}
+
 /// Write float to string in scientific notation.
 ///
 /// # Safety
@@ -199,9 +159,6 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
     fraction_cursor: usize,
     options: &Options,
 ) -> usize {
-    // PRECONDITIONS
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
-
     // Config options.
     let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
@@ -229,15 +186,18 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
     // Get as many digits as possible, up to `MAX_DIGIT_LENGTH+1`
     // since we are ignoring the digit for the first digit,
     // or the number of written digits.
+    // Trim trailing zeros before writing the digits, so we never write
+    // past the end of the float.
     // SAFETY: safe since the buffer must be larger than `M::FORMATTED_SIZE`.
     let digit_count = unsafe {
+        let zeros = rtrim_char_count(&index_unchecked!(digits[1..digit_count]), b'0');
+        let digit_count = digit_count - zeros;
         index_unchecked_mut!(bytes[0] = digits[0]);
         index_unchecked_mut!(bytes[1]) = decimal_point;
         let src = digits.as_ptr().add(1);
         let dst = &mut index_unchecked_mut!(bytes[2..digit_count + 1]);
         copy_nonoverlapping_unchecked!(dst, src, digit_count - 1);
-        let zeros = rtrim_char_count(&index_unchecked!(bytes[2..digit_count + 1]), b'0');
-        digit_count - zeros
+        digit_count
     };
     // Extra 1 since we have the decimal point.
     let mut cursor = digit_count + 1;
@@ -269,7 +229,7 @@ pub unsafe fn write_float_scientific<const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // SAFETY: safe since bytes must be large enough to store all digits.
-    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent()) };
+    unsafe { shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options) };
 
     cursor
 }
@@ -290,9 +250,6 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
     fraction_cursor: usize,
     options: &Options,
 ) -> usize {
-    // PRECONDITIONS
-    debug_assert!(bytes.len() >= BUFFER_SIZE);
-
     // Config options.
     let format = NumberFormat::<{ FORMAT }> {};
     assert!(format.is_valid());
@@ -337,32 +294,31 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
     }
     let mut cursor = integer_length;
 
-    // SAFETY: safe if the buffer is large enough to hold the significant digits.
-    unsafe { index_unchecked_mut!(bytes[cursor]) = decimal_point };
-    cursor += 1;
-
     // Write the fraction component.
     // We've only consumed `integer_count` digits, since this input
     // may have been truncated.
     let digits = unsafe { &index_unchecked!(digits[integer_count..]) };
     let fraction_count = digit_count.saturating_sub(integer_length);
     if fraction_count > 0 {
-        // Need to write additional fraction digits.
+        // Need to write additional fraction digits, without trailing zeros.
         // SAFETY: safe if the buffer is large enough to hold the significant digits.
         unsafe {
+            let zeros = rtrim_char_count(&index_unchecked!(digits[..fraction_count]), b'0');
+            let count = fraction_count - zeros;
+            index_unchecked_mut!(bytes[cursor]) = decimal_point;
+            cursor += 1;
             let src = digits.as_ptr();
-            let end = cursor + fraction_count;
-            let dst = &mut index_unchecked_mut!(bytes[cursor..end]);
-            copy_nonoverlapping_unchecked!(dst, src, fraction_count);
-            let zeros = rtrim_char_count(&index_unchecked!(bytes[cursor..end]), b'0');
-            cursor += fraction_count - zeros;
+            let dst = &mut index_unchecked_mut!(bytes[cursor..cursor + count]);
+            copy_nonoverlapping_unchecked!(dst, src, count);
+            cursor += count;
         }
-    } else if options.trim_floats() {
-        // Remove the decimal point, went too far.
-        cursor -= 1;
-    } else {
-        unsafe { index_unchecked_mut!(bytes[cursor]) = b'0' };
-        cursor += 1;
+    } else if !options.trim_floats() {
+        // SAFETY: safe if the buffer is large enough to hold the significant digits.
+        unsafe {
+            index_unchecked_mut!(bytes[cursor]) = decimal_point;
+            index_unchecked_mut!(bytes[cursor + 1]) = b'0';
+        }
+        cursor += 2;
         digit_count += 1;
     }
 
@@ -405,6 +361,133 @@ pub unsafe fn write_float_nonscientific<const FORMAT: u128>(
 const MAX_NONDIGIT_LENGTH: usize = 25;
 const MAX_DIGIT_LENGTH: usize = BUFFER_SIZE - MAX_NONDIGIT_LENGTH;
 
+/// The size of the temporary buffer for the digits.
+///
+/// We start with the decimal point in the middle and write to the left for
+/// the integer part and to the right for the fractional part. 1024 characters
+/// for the exponent and 52 for the mantissa either way, with additional space
+/// for sign, decimal point and string termination should be sufficient.
+const SIZE: usize = 2200;
+
+/// The index of the decimal point in the temporary buffer.
+const INITIAL_CURSOR: usize = SIZE / 2;
+
+/// Write the significant digits to the temporary buffer.
+///
+/// Returns the scientific exponent, and the start of the integer digits
+/// and the end of the fraction digits, which are separated by
+/// [`INITIAL_CURSOR`].
+#[inline]
+#[allow(clippy::collapsible_if)]
+fn write_digits<F: Float, const FORMAT: u128>(
+    float: F,
+    buffer: &mut [u8; SIZE],
+) -> (i32, usize, usize) {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let initial_cursor = INITIAL_CURSOR;
+    let mut integer_cursor = initial_cursor;
+    let mut fraction_cursor = initial_cursor;
+    let base = F::as_cast(format.radix());
+
+    // Negative zero is not negative, so it's written like zero.
+    let float = if float == F::ZERO {
+        F::ZERO
+    } else {
+        float
+    };
+
+    // Split the float into an integer part and a fractional part.
+    let mut integer = float.floor();
+    let mut fraction = float - integer;
+
+    // We only compute fractional digits up to the input double's precision.
+    // This fails if the value is at f64::MAX. IF we take the next positive,
+    // we'll get literal infinite. We don't care about NaN comparisons, since
+    // the float **must** be finite, so do this.
+    let mut delta = if float.to_bits() == F::MAX.to_bits() {
+        F::as_cast(0.5) * (float - float.prev_positive())
+    } else {
+        F::as_cast(0.5) * (float.next_positive() - float)
+    };
+    delta = F::ZERO.next_positive().max_finite(delta);
+    debug_assert!(delta > F::ZERO);
+
+    // Write our fraction digits.
+    // SAFETY: we have 1100 digits, which is enough for any float f64 or smaller.
+    if fraction >= delta {
+        loop {
+            // Shift up by one digit.
+            fraction *= base;
+            delta *= base;
+            // Write digit.
+            let digit = fraction.as_u32();
+            let c = digit_to_char_const(digit, format.radix());
+            unsafe { index_unchecked_mut!(buffer[fraction_cursor]) = c };
+            fraction_cursor += 1;
+            // Calculate remainder.
+            fraction -= F::as_cast(digit);
+            // Round to even.
+            if fraction > F::as_cast(0.5) || (fraction == F::as_cast(0.5) && (digit & 1) != 0) {
+                if fraction + delta > F::ONE {
+                    // We need to back trace already written digits in case of carry-over.
+                    loop {
+                        fraction_cursor -= 1;
+                        if fraction_cursor == initial_cursor - 1 {
+                            // Carry over to the integer part.
+                            integer += F::ONE;
+                            break;
+                        }
+                        // Reconstruct digit.
+                        let c = unsafe { index_unchecked!(buffer[fraction_cursor]) };
+                        if let Some(digit) = char_to_digit_const(c, format.radix()) {
+                            let idx = digit + 1;
+                            let c = digit_to_char_const(idx, format.radix());
+                            unsafe { index_unchecked_mut!(buffer[fraction_cursor]) = c };
+                            fraction_cursor += 1;
+                            break;
+                        }
+                    }
+                    break;
+                }
+            }
+
+            if delta > fraction {
+                break;
+            }
+        }
+    }
+
+    // Compute integer digits. Fill unrepresented digits with zero.
+    // SAFETY: we have 1100 digits, which is enough for any float f64 or smaller.
+    // We do this first, so we can do extended precision control later.
+    while (integer / base).exponent() > 0 {
+        integer /= base;
+        integer_cursor -= 1;
+        unsafe { index_unchecked_mut!(buffer[integer_cursor]) = b'0' };
+    }
+
+    loop {
+        let remainder = integer % base;
+        integer_cursor -= 1;
+        let idx = remainder.as_u32();
+        let c = digit_to_char_const(idx, format.radix());
+        unsafe { index_unchecked_mut!(buffer[integer_cursor]) = c };
+        integer = (integer - remainder) / base;
+
+        if integer <= F::ZERO {
+            break;
+        }
+    }
+
+    // Calculate our exponent.
+    let sci_exp = if float == F::ZERO {
+        0
+    } else {
+        naive_exponent(float, format.radix())
+    };
+    (sci_exp, integer_cursor, fraction_cursor)
+}
+
 /// Round mantissa to the nearest value, returning only the number
 /// of significant digits. Returns the number of digits of the mantissa,
 /// and if the rounding did a full carry.
@@ -1,9 +1,16 @@
 //! Shared utilities for writing floats.
 
-use crate::options::{Options, RoundMode};
+#![doc(hidden)]
+
+use crate::float::RawFloat;
+use crate::options::{Notation, Options, RoundMode};
+use core::cmp;
+#[cfg(any(feature = "compact", feature = "radix"))]
 use lexical_util::digit::digit_to_char_const;
 use lexical_util::format::NumberFormat;
-use lexical_write_integer::write::WriteInteger;
+use lexical_util::num::AsPrimitive;
+use lexical_util::wide::Uint;
+use lexical_write_integer::write::{digit_count, WriteInteger};
 
 /// Get the exact number of digits from a minimum bound.
 #[inline(always)]
@@ -23,6 +30,7 @@ pub fn min_exact_digits(digit_count: usize, options: &Options) -> usize {
 /// # Safety
 ///
 /// Safe as long as `count <= digits.len()`.
+#[cfg(any(feature = "compact", feature = "radix"))]
 #[cfg_attr(not(feature = "compact"), inline)]
 pub unsafe fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
     debug_assert!(count <= digits.len());
@@ -50,6 +58,64 @@ pub unsafe fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, b
     (1, true)
 }
 
+/// Big integer large enough to hold any float or its shortest representation,
+/// when scaled to a common denominator.
+type ExactInt = Uint<16>;
+
+/// Multiply a big integer by `base^exp`.
+#[inline]
+fn mul_pow(x: &mut ExactInt, base: u64, exp: i32) {
+    for _ in 0..exp {
+        let carry = x.mul_small_add(base, 0);
+        debug_assert!(carry == 0);
+    }
+}
+
+/// Compare the exact value of a float to a decimal representation, `mant * 10^exp`.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn cmp_exact<F: RawFloat>(float: F, mant: u64, exp: i32) -> cmp::Ordering {
+    // Compare `m * 2^e` to `mant * 5^exp * 2^exp`, moving any negative
+    // powers to the other side.
+    let pow2 = float.exponent() - exp;
+    let mut x = ExactInt::from_u128(float.mantissa().as_u64() as u128);
+    let mut y = ExactInt::from_u128(mant as u128);
+    mul_pow(&mut x, 2, pow2.max(0));
+    mul_pow(&mut x, 5, (-exp).max(0));
+    mul_pow(&mut y, 2, (-pow2).max(0));
+    mul_pow(&mut y, 5, exp.max(0));
+    x.cmp(&y)
+}
+
+/// Adjust the shortest digits so rounding them rounds like the exact value.
+///
+/// Rounding the shortest representation to fewer digits only differs from
+/// rounding the exact value of the float if the shortest representation
+/// is exactly halfway: any closer halfway point would be a shorter
+/// representation. In that case, the last digit, which must be `5`, is
+/// nudged toward the exact value, or is unchanged if they are equal, so
+/// round-nearest, tie-even gives the same result as for the exact value.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_exact_halfway<F: RawFloat>(
+    float: F,
+    mant: u64,
+    exp: i32,
+    digit_count: usize,
+    options: &Options,
+) -> u64 {
+    let is_halfway = match options.max_significant_digits() {
+        Some(max_digits) => max_digits.get() + 1 == digit_count && mant % 10 == 5,
+        None => false,
+    };
+    if !is_halfway || options.round_mode() == RoundMode::Truncate {
+        return mant;
+    }
+    match cmp_exact(float, mant, exp) {
+        cmp::Ordering::Less => mant - 1,
+        cmp::Ordering::Equal => mant,
+        cmp::Ordering::Greater => mant + 1,
+    }
+}
+
 /// Round the number of digits based on the maximum digits, for decimal digits.
 /// `digits` is a mutable buffer of the current digits, `digit_count` is the
 /// length of the written digits in `digits`, and `exp` is the decimal exponent
@@ -59,6 +125,7 @@ pub unsafe fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, b
 /// # Safety
 ///
 /// Safe as long as `ndigits <= digits.len()`.
+#[cfg(feature = "compact")]
 #[allow(clippy::comparison_chain)]
 #[cfg_attr(not(feature = "compact"), inline)]
 pub unsafe fn truncate_and_round_decimal(
@@ -126,14 +193,16 @@ pub unsafe fn write_exponent_sign<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
+    options: &Options,
 ) -> u32 {
     let format = NumberFormat::<{ FORMAT }> {};
+    let required_sign = cfg!(feature = "format") && format.required_exponent_sign();
     if exp < 0 {
         // SAFETY: safe if bytes is large enough to hold the output
         unsafe { index_unchecked_mut!(bytes[*cursor]) = b'-' };
         *cursor += 1;
         exp.wrapping_neg() as u32
-    } else if cfg!(feature = "format") && format.required_exponent_sign() {
+    } else if required_sign || options.positive_exponent_sign() {
         // SAFETY: safe if bytes is large enough to hold the output
         unsafe { index_unchecked_mut!(bytes[*cursor]) = b'+' };
         *cursor += 1;
@@ -145,57 +214,355 @@ pub unsafe fn write_exponent_sign<const FORMAT: u128>(
 
 /// Write the symbol, sign, and digits for the exponent.
 ///
+/// The exponent digits are padded with leading zeros to the minimum
+/// number of exponent digits.
+///
 /// # Safety
 ///
-/// Safe if the buffer can hold all the significant digits and the sign
-/// starting from cursor.
+/// Safe if the buffer can hold all the significant digits, the sign,
+/// and the padded exponent digits starting from cursor.
 #[cfg_attr(not(feature = "compact"), inline)]
 pub unsafe fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
-    *cursor += unsafe {
-        index_unchecked_mut!(bytes[*cursor]) = exponent_character;
+    // SAFETY: safe if bytes is large enough to hold the output
+    let count = unsafe {
+        index_unchecked_mut!(bytes[*cursor]) = options.exponent();
         *cursor += 1;
-        let positive_exp = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
+        let positive_exp = write_exponent_sign::<FORMAT>(bytes, cursor, exp, options);
         positive_exp.write_exponent::<u32, FORMAT>(&mut index_unchecked_mut!(bytes[*cursor..]))
     };
+
+    let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+    if count < min_digits {
+        // Write the leading zeros after the digits, and rotate them to the front.
+        let zeros = min_digits - count;
+        // SAFETY: safe if bytes is large enough to hold the padded output
+        unsafe {
+            let digits = &mut index_unchecked_mut!(bytes[*cursor..*cursor + min_digits]);
+            slice_fill_unchecked!(index_unchecked_mut!(digits[count..]), b'0');
+            digits.rotate_right(zeros);
+        }
+        *cursor += min_digits;
+    } else {
+        *cursor += count;
+    }
+}
+
+/// Get the SI prefix for an exponent that is a multiple of 3.
+#[inline(always)]
+pub const fn si_prefix(exp: i32) -> Option<&'static [u8]> {
+    let prefix: &'static [u8] = match exp {
+        -30 => b"q",
+        -27 => b"r",
+        -24 => b"y",
+        -21 => b"z",
+        -18 => b"a",
+        -15 => b"f",
+        -12 => b"p",
+        -9 => b"n",
+        -6 => "\u{b5}".as_bytes(),
+        -3 => b"m",
+        0 => b"",
+        3 => b"k",
+        6 => b"M",
+        9 => b"G",
+        12 => b"T",
+        15 => b"P",
+        18 => b"E",
+        21 => b"Z",
+        24 => b"Y",
+        27 => b"R",
+        30 => b"Q",
+        _ => return None,
+    };
+    Some(prefix)
+}
+
+/// Write the rounded significant digits in engineering notation.
+///
+/// The significant digits must already be written to the start of the
+/// buffer, and `sci_exp` must be the exponent of the first digit.
+///
+/// # Safety
+///
+/// Safe if the buffer can hold the significant digits, 2 trailing
+/// zeros, the decimal point and the exponent.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub unsafe fn write_engineering<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let decimal_point = options.decimal_point();
+
+    // Get the exponent as a multiple of 3, and the 1-3 leading digits.
+    let eng_exp = sci_exp - sci_exp.rem_euclid(3);
+    let leading_digits = (sci_exp - eng_exp) as usize + 1;
+
+    // Determine the exact number of digits to write.
+    let exact_count = min_exact_digits(digit_count, options);
+
+    let mut cursor: usize;
+    if leading_digits >= digit_count {
+        // Pad the integer digits with trailing zeros.
+        // SAFETY: safe if the buffer can hold the significant digits and 2 zeros.
+        unsafe {
+            let digits = &mut index_unchecked_mut!(bytes[digit_count..leading_digits]);
+            slice_fill_unchecked!(digits, b'0');
+        }
+        cursor = leading_digits;
+        let trim = !format.no_exponent_without_fraction() && options.trim_floats();
+        if exact_count > leading_digits {
+            let zeros = exact_count - leading_digits;
+            // SAFETY: safe if the buffer can hold the significant digits.
+            unsafe {
+                index_unchecked_mut!(bytes[cursor]) = decimal_point;
+                let digits = &mut index_unchecked_mut!(bytes[cursor + 1..cursor + 1 + zeros]);
+                slice_fill_unchecked!(digits, b'0');
+            }
+            cursor += zeros + 1;
+        } else if !trim {
+            // SAFETY: safe if the buffer can hold the significant digits.
+            unsafe {
+                index_unchecked_mut!(bytes[cursor]) = decimal_point;
+                index_unchecked_mut!(bytes[cursor + 1]) = b'0';
+            }
+            cursor += 2;
+        }
+    } else {
+        // Move the fraction digits by 1, and write the decimal point.
+        // SAFETY: safe if the buffer can hold the significant digits.
+        let count = digit_count - leading_digits;
+        unsafe {
+            let src = index_unchecked!(bytes[leading_digits..digit_count]).as_ptr();
+            let dst = &mut index_unchecked_mut!(bytes[leading_digits + 1..digit_count + 1]);
+            copy_unchecked!(dst, src, count);
+            index_unchecked_mut!(bytes[leading_digits]) = decimal_point;
+        }
+        cursor = digit_count + 1;
+        if exact_count > digit_count {
+            let zeros = exact_count - digit_count;
+            // SAFETY: safe if the buffer can hold the significant digits.
+            unsafe {
+                let digits = &mut index_unchecked_mut!(bytes[cursor..cursor + zeros]);
+                slice_fill_unchecked!(digits, b'0');
+            }
+            cursor += zeros;
+        }
+    }
+
+    // Write the SI prefix, or the exponent if there is no prefix.
+    match si_prefix(eng_exp) {
+        Some(prefix) if options.si_prefix() => {
+            let length = prefix.len();
+            // SAFETY: safe since the prefix is shorter than the exponent.
+            unsafe {
+                let dst = &mut index_unchecked_mut!(bytes[cursor..cursor + length]);
+                copy_nonoverlapping_unchecked!(dst, prefix.as_ptr(), length);
+            }
+            cursor += length;
+        },
+        // SAFETY: safe since bytes must be large enough to store all digits.
+        _ => unsafe { write_exponent::<FORMAT>(bytes, &mut cursor, eng_exp, options) },
+    }
+
+    cursor
+}
+
+/// The layout of the significant digits for the float formatter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// Scientific notation, with 1 leading digit.
+    Scientific,
+    /// Engineering notation, with 1-3 leading digits.
+    Engineering,
+    /// No exponent notation, with a non-negative exponent.
+    PositiveExponent,
+    /// No exponent notation, with a negative exponent.
+    NegativeExponent,
+}
+
+/// Detect the notation to use for the float formatter.
+///
+/// `notation_exp` is the scientific exponent used to choose the notation,
+/// which may differ from `sci_exp` for algorithms that only round the
+/// digits once the notation is known.
+#[inline(always)]
+pub fn layout<const FORMAT: u128>(sci_exp: i32, notation_exp: i32, options: &Options) -> Layout {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let require_exponent = match options.notation() {
+        Notation::Auto => {
+            let min_exp = options.negative_exponent_break().map_or(-5, |x| x.get());
+            let max_exp = options.positive_exponent_break().map_or(9, |x| x.get());
+            let outside_break = notation_exp < min_exp || notation_exp > max_exp;
+            format.required_exponent_notation() || outside_break
+        },
+        Notation::Scientific | Notation::Engineering => true,
+        Notation::General => {
+            let precision = options.max_significant_digits().map_or(6, |x| x.get());
+            let outside_break = notation_exp < -4 || notation_exp as i64 >= precision as i64;
+            format.required_exponent_notation() || outside_break
+        },
+    };
+    let use_exponent = !format.no_exponent_notation() && require_exponent;
+    if use_exponent && options.notation() == Notation::Engineering {
+        Layout::Engineering
+    } else if use_exponent {
+        Layout::Scientific
+    } else if sci_exp >= 0 {
+        Layout::PositiveExponent
+    } else {
+        Layout::NegativeExponent
+    }
+}
+
+/// Get the number of bytes written for the exponent, including the symbol.
+#[inline]
+pub fn exponent_len<const FORMAT: u128>(exp: i32, options: &Options) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let required_sign = cfg!(feature = "format") && format.required_exponent_sign();
+    let sign = exp < 0 || required_sign || options.positive_exponent_sign();
+    let digits = digit_count(exp.unsigned_abs(), format.exponent_radix());
+    let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+    1 + sign as usize + digits.max(min_digits)
+}
+
+/// Get the number of bytes written in scientific notation.
+///
+/// `digit_count` is the number of significant digits, without trailing
+/// zeros, and `sci_exp` is the exponent of the first digit.
+#[inline]
+pub fn scientific_len<const FORMAT: u128>(
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let exact_count = min_exact_digits(digit_count, options);
+    let count =
+        if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+            1
+        } else if digit_count < exact_count {
+            exact_count + 1
+        } else if digit_count == 1 {
+            3
+        } else {
+            digit_count + 1
+        };
+    count + exponent_len::<FORMAT>(sci_exp, options)
+}
+
+/// Get the number of bytes written by [`write_engineering`].
+#[inline]
+pub fn engineering_len<const FORMAT: u128>(
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let eng_exp = sci_exp - sci_exp.rem_euclid(3);
+    let leading_digits = (sci_exp - eng_exp) as usize + 1;
+    let exact_count = min_exact_digits(digit_count, options);
+
+    let count = if leading_digits >= digit_count {
+        let trim = !format.no_exponent_without_fraction() && options.trim_floats();
+        if exact_count > leading_digits {
+            exact_count + 1
+        } else if !trim {
+            leading_digits + 2
+        } else {
+            leading_digits
+        }
+    } else {
+        exact_count.max(digit_count) + 1
+    };
+    match si_prefix(eng_exp) {
+        Some(prefix) if options.si_prefix() => count + prefix.len(),
+        _ => count + exponent_len::<FORMAT>(eng_exp, options),
+    }
+}
+
+/// Get the number of bytes written without exponent notation, for a
+/// non-negative exponent.
+///
+/// `leading_digits` is the number of digits before the decimal point.
+#[inline]
+pub fn positive_exponent_len(
+    digit_count: usize,
+    leading_digits: usize,
+    options: &Options,
+) -> usize {
+    if leading_digits >= digit_count {
+        if options.trim_floats() {
+            leading_digits
+        } else {
+            let exact_count = min_exact_digits(leading_digits + 1, options);
+            exact_count + 1
+        }
+    } else {
+        let exact_count = min_exact_digits(digit_count, options);
+        exact_count + 1
+    }
+}
+
+/// Get the number of bytes written without exponent notation, for a
+/// negative exponent.
+///
+/// `leading_zeros` is the number of bytes before the significant digits,
+/// including the decimal point.
+#[inline]
+pub fn negative_exponent_len(digit_count: usize, leading_zeros: usize, options: &Options) -> usize {
+    leading_zeros + min_exact_digits(digit_count, options)
 }
 
 /// Detect the notation to use for the float formatter and call the appropriate function..
+///
+/// The optional `carried` expression is added to the scientific exponent
+/// when choosing the notation, for algorithms that only round the digits
+/// once the notation is known.
 macro_rules! write_float {
     (
         $format:ident,
         $sci_exp:ident,
         $options:ident,
         $write_scientific:ident,
+        $write_engineering:ident,
         $write_positive:ident,
         $write_negative:ident,
         $(generic => $generic:tt,)?
+        $(carried => $carried:expr,)?
         args => $($args:expr,)*
     ) => {{
-        use lexical_util::format::NumberFormat;
+        use crate::shared::{layout, Layout};
 
-        let format = NumberFormat::<{ $format }> {};
-        let min_exp = $options.negative_exponent_break().map_or(-5, |x| x.get());
-        let max_exp = $options.positive_exponent_break().map_or(9, |x| x.get());
-
-        let outside_break = $sci_exp < min_exp || $sci_exp > max_exp;
-        let require_exponent = format.required_exponent_notation() || outside_break;
-        if !format.no_exponent_notation() && require_exponent {
+        let notation_exp = $sci_exp $(+ $carried as i32)?;
+        match layout::<$format>($sci_exp, notation_exp, $options) {
+            // Write digits in engineering notation.
+            // SAFETY: safe as long as bytes is large enough to hold all the digits.
+            Layout::Engineering => unsafe {
+                $write_engineering::<$($generic,)? FORMAT>($($args,)*)
+            },
             // Write digits in scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_scientific::<$($generic,)? FORMAT>($($args,)*) }
-        } else if $sci_exp >= 0 {
+            Layout::Scientific => unsafe {
+                $write_scientific::<$($generic,)? FORMAT>($($args,)*)
+            },
             // Write positive exponent without scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_positive::<$($generic,)? FORMAT>($($args,)*) }
-        } else {
+            Layout::PositiveExponent => unsafe {
+                $write_positive::<$($generic,)? FORMAT>($($args,)*)
+            },
             // Write negative exponent without scientific notation.
             // SAFETY: safe as long as bytes is large enough to hold all the digits.
-            unsafe { $write_negative::<$($generic,)? FORMAT>($($args,)*) }
+            Layout::NegativeExponent => unsafe {
+                $write_negative::<$($generic,)? FORMAT>($($args,)*)
+            },
         }
     }};
 }
@@ -3,12 +3,12 @@
 #![doc(hidden)]
 
 #[cfg(not(feature = "compact"))]
-use crate::algorithm::write_float as write_float_decimal;
+use crate::algorithm::{write_float as write_float_decimal, written_len as written_len_decimal};
 #[cfg(feature = "power-of-two")]
 use crate::binary;
 /// Select the back-end.
 #[cfg(feature = "compact")]
-use crate::compact::write_float as write_float_decimal;
+use crate::compact::{write_float as write_float_decimal, written_len as written_len_decimal};
 #[cfg(feature = "power-of-two")]
 use crate::hex;
 #[cfg(feature = "radix")]
@@ -18,6 +18,8 @@ use crate::float::RawFloat;
 use crate::options::Options;
 use lexical_util::constants::FormattedSize;
 use lexical_util::format::NumberFormat;
+#[cfg(feature = "half")]
+use lexical_util::num::{bf16, f16};
 use lexical_write_integer::write::WriteInteger;
 
 /// Write float trait.
@@ -150,6 +152,90 @@ pub trait WriteFloat: RawFloat {
             }
         }
     }
+
+    /// Get the number of bytes written by [`write_float`].
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`write_float`].
+    ///
+    /// [`write_float`]: WriteFloat::write_float
+    #[inline]
+    fn written_float_len<const FORMAT: u128>(self, options: &Options) -> usize
+    where
+        Self::Unsigned: FormattedSize + WriteInteger,
+    {
+        let format = NumberFormat::<FORMAT> {};
+        assert!(format.is_valid());
+        assert!(Self::BITS <= 64);
+
+        #[cfg(feature = "power-of-two")]
+        {
+            if format.radix() != format.exponent_base() {
+                assert!(matches!(
+                    (format.radix(), format.exponent_base()),
+                    (4, 2) | (8, 2) | (16, 2) | (32, 2) | (16, 4)
+                ));
+            }
+        }
+
+        let (float, count) = if self < Self::ZERO {
+            (-self, 1)
+        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+            (self, 1)
+        } else {
+            (self, 0)
+        };
+
+        if !self.is_special() {
+            #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+            {
+                let radix = format.radix();
+                let exponent_base = format.exponent_base();
+                count
+                    + if radix == 10 {
+                        written_len_decimal::<_, FORMAT>(float, options)
+                    } else if radix != exponent_base {
+                        hex::written_len::<_, FORMAT>(float, options)
+                    } else {
+                        binary::written_len::<_, FORMAT>(float, options)
+                    }
+            }
+
+            #[cfg(feature = "radix")]
+            {
+                let radix = format.radix();
+                let exponent_base = format.exponent_base();
+                count
+                    + if radix == 10 {
+                        written_len_decimal::<_, FORMAT>(float, options)
+                    } else if radix != exponent_base {
+                        hex::written_len::<_, FORMAT>(float, options)
+                    } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
+                        binary::written_len::<_, FORMAT>(float, options)
+                    } else {
+                        radix::written_len::<_, FORMAT>(float, options)
+                    }
+            }
+
+            #[cfg(not(feature = "power-of-two"))]
+            {
+                count + written_len_decimal::<_, FORMAT>(float, options)
+            }
+        } else if self.is_nan() {
+            match options.nan_string() {
+                Some(nan_string) => count + nan_string.len(),
+                // PANIC: cannot serialize NaN.
+                None => panic!("NaN explicitly disabled but asked to write NaN as string."),
+            }
+        } else {
+            match options.inf_string() {
+                Some(inf_string) => count + inf_string.len(),
+                // PANIC: cannot serialize inf.
+                None => panic!("Inf explicitly disabled but asked to write Inf as string."),
+            }
+        }
+    }
 }
 
 macro_rules! write_float_impl {
@@ -159,3 +245,6 @@ macro_rules! write_float_impl {
 }
 
 write_float_impl! { f32 f64 }
+
+#[cfg(feature = "half")]
+write_float_impl! { f16 bf16 }
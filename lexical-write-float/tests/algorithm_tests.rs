@@ -667,6 +667,29 @@ fn f64_roundtrip_test() {
     }
 }
 
+#[test]
+fn power_of_two_roundtrip_test() {
+    // Powers of two use the shorter interval, where the left endpoint is
+    // only an integer for a few exponents. `9.860761e-32` is shorter, but
+    // is outside the interval, so it does not round-trip to `2^-103`.
+    let options = Options::builder().build().unwrap();
+    write_float::<_, DECIMAL>(f32::from_bits(24 << 23), &options, "9.8607613e-32");
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for exp in 1..f32::MAX_EXPONENT + f32::EXPONENT_BIAS {
+        let float = f32::from_bits((exp as u32) << 23);
+        let count = unsafe { algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
+        assert_eq!(actual.parse::<f32>(), Ok(float));
+    }
+    for exp in 1..f64::MAX_EXPONENT + f64::EXPONENT_BIAS {
+        let float = f64::from_bits((exp as u64) << 52);
+        let count = unsafe { algorithm::write_float::<_, DECIMAL>(float, &mut buffer, &options) };
+        let actual = unsafe { std::str::from_utf8_unchecked(&buffer[..count]) };
+        assert_eq!(actual.parse::<f64>(), Ok(float));
+    }
+}
+
 #[test]
 fn is_endpoint_test() {
     assert_eq!(algorithm::is_endpoint(5, 2, 10), true);
@@ -1,6 +1,9 @@
+use core::num;
 use lexical_util::constants::BUFFER_SIZE;
 use lexical_util::format::STANDARD;
-use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_float::{
+    Decimal, FormattedSize, Notation, Options, RoundMode, ToLexical, ToLexicalWithOptions,
+};
 use proptest::prelude::*;
 use quickcheck::quickcheck;
 
@@ -50,7 +53,6 @@ fn invalid_inf_test() {
 #[test]
 #[cfg(feature = "power-of-two")]
 fn hex_test() {
-    use core::num;
     use lexical_util::format::NumberFormatBuilder;
 
     const BASE16_2_10: u128 = NumberFormatBuilder::new()
@@ -66,6 +68,540 @@ fn hex_test() {
     assert_eq!(result, b"3.039^12");
 }
 
+fn write_float(f: f64, options: &Options, expected: &str) {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = f.to_lexical_with_options::<{ STANDARD }>(&mut buffer, options);
+    assert_eq!(unsafe { std::str::from_utf8_unchecked(bytes) }, expected);
+}
+
+#[test]
+fn scientific_notation_test() {
+    // `%.2e`
+    let options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(3))
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    write_float(0.0, &options, "0.00e0");
+    write_float(1.0, &options, "1.00e0");
+    write_float(0.5, &options, "5.00e-1");
+    write_float(123456.0, &options, "1.23e5");
+    write_float(9.999, &options, "1.00e1");
+
+    // `%.0e`
+    let options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(1))
+        .max_significant_digits(num::NonZeroUsize::new(1))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    write_float(1.0, &options, "1e0");
+    write_float(2.5, &options, "2e0");
+    write_float(3.5, &options, "4e0");
+    write_float(0.01, &options, "1e-2");
+}
+
+#[test]
+fn engineering_notation_test() {
+    let options = Options::builder().notation(Notation::Engineering).build().unwrap();
+    write_float(0.0, &options, "0.0e0");
+    write_float(1.5, &options, "1.5e0");
+    write_float(12500.0, &options, "12.5e3");
+    write_float(125000.0, &options, "125.0e3");
+    write_float(1e6, &options, "1.0e6");
+    write_float(4.7e-7, &options, "470.0e-9");
+    write_float(0.015625, &options, "15.625e-3");
+    write_float(1.2345e-100, &options, "123.45e-102");
+
+    let options = Options::builder()
+        .notation(Notation::Engineering)
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    write_float(12345.0, &options, "12.3e3");
+    write_float(4.7e-7, &options, "470e-9");
+    write_float(999.6, &options, "1e3");
+    write_float(99960.0, &options, "100e3");
+    write_float(1.0, &options, "1e0");
+
+    let options = Options::builder()
+        .notation(Notation::Engineering)
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .build()
+        .unwrap();
+    write_float(12500.0, &options, "12.50e3");
+    write_float(470e-9, &options, "470.0e-9");
+    write_float(1e6, &options, "1.000e6");
+
+    let options = Options::builder()
+        .notation(Notation::Engineering)
+        .si_prefix(true)
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    write_float(12500.0, &options, "12.5k");
+    write_float(4.7e-7, &options, "470n");
+    write_float(2.2e-6, &options, "2.2\u{b5}");
+    write_float(1e6, &options, "1M");
+    write_float(1.5, &options, "1.5");
+    write_float(0.001, &options, "1m");
+    write_float(1e33, &options, "1e33");
+    write_float(1e-33, &options, "1e-33");
+}
+
+#[test]
+fn general_notation_test() {
+    // `%g`
+    let options = Options::builder()
+        .notation(Notation::General)
+        .max_significant_digits(num::NonZeroUsize::new(6))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    write_float(0.0, &options, "0");
+    write_float(0.5, &options, "0.5");
+    write_float(100.0, &options, "100");
+    write_float(123456.0, &options, "123456");
+    write_float(1234567.0, &options, "1.23457e6");
+    write_float(1e6, &options, "1e6");
+    write_float(999999.5, &options, "1e6");
+    write_float(0.0001, &options, "0.0001");
+    write_float(0.00001, &options, "1e-5");
+    write_float(0.00009999995, &options, "0.0001");
+    write_float(0.000099999, &options, "9.9999e-5");
+
+    // `%#.3g`
+    let options = Options::builder()
+        .notation(Notation::General)
+        .min_significant_digits(num::NonZeroUsize::new(3))
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    write_float(1.0, &options, "1.00");
+    write_float(12.0, &options, "12.0");
+    write_float(1e6, &options, "1.00e6");
+    write_float(999.9, &options, "1.00e3");
+    write_float(0.000123, &options, "0.000123");
+    write_float(0.00001, &options, "1.00e-5");
+
+    // Without a precision, the shortest digits are used with a precision of `6`.
+    let options = Options::builder().notation(Notation::General).build().unwrap();
+    write_float(123456.0, &options, "123456.0");
+    write_float(1234567.0, &options, "1.234567e6");
+
+    // Rounds the exact value, which is below halfway.
+    let options = Options::builder()
+        .notation(Notation::General)
+        .max_significant_digits(num::NonZeroUsize::new(1))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    write_float(0.15, &options, "0.1");
+    write_float(0.25, &options, "0.2");
+    write_float(0.35, &options, "0.3");
+    write_float(9.5, &options, "1e1");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn general_required_exponent_notation_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_exponent_notation(true).build();
+    let options = Options::builder()
+        .notation(Notation::General)
+        .max_significant_digits(num::NonZeroUsize::new(6))
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(1.5f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options), b"1.5e0");
+    assert_eq!(100.0f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options), b"1e2");
+}
+
+#[test]
+fn scientific_exact_rounding_test() {
+    // Rounding must match the standard library, which rounds the exact value.
+    let values = [
+        0.15f64,
+        0.25,
+        0.35,
+        0.45,
+        1.5,
+        2.5,
+        0.125,
+        0.375,
+        1.005,
+        2.675,
+        1.2345e-300,
+        5e-324,
+        9.5,
+        1e23,
+        8.5e-5,
+        1.7976931348623157e308,
+    ];
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for &value in values.iter() {
+        for precision in 0..17 {
+            let options = Options::builder()
+                .notation(Notation::Scientific)
+                .min_significant_digits(num::NonZeroUsize::new(precision + 1))
+                .max_significant_digits(num::NonZeroUsize::new(precision + 1))
+                .trim_floats(precision == 0)
+                .build()
+                .unwrap();
+            let bytes = value.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+            let digits = std::str::from_utf8(bytes).unwrap();
+            let shortest = format!("{:e}", value);
+            let expected = format!("{:.*e}", precision, value);
+            // Digits past the shortest representation are always zeros.
+            let significant = shortest.split('e').next().unwrap().replace('.', "").len();
+            if precision < significant {
+                assert_eq!(digits, expected, "{} with precision {}", value, precision);
+            }
+        }
+    }
+}
+
+#[test]
+fn exponent_format_test() {
+    // Python's `repr`.
+    let options = Options::builder()
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .positive_exponent_sign(true)
+        .build()
+        .unwrap();
+    write_float(1e16, &options, "1.0e+16");
+    write_float(1e100, &options, "1.0e+100");
+    write_float(1.5e-7, &options, "1.5e-07");
+    write_float(1.5, &options, "1.5");
+
+    // C's `%.3e`.
+    let options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(4))
+        .max_significant_digits(num::NonZeroUsize::new(4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .positive_exponent_sign(true)
+        .build()
+        .unwrap();
+    write_float(0.0, &options, "0.000e+00");
+    write_float(1e5, &options, "1.000e+05");
+    write_float(-1.2345e-300, &options, "-1.234e-300");
+
+    // Fortran's `ES` edit descriptor.
+    let options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_exponent_digits(num::NonZeroUsize::new(3))
+        .positive_exponent_sign(true)
+        .exponent(b'E')
+        .build()
+        .unwrap();
+    write_float(1.0, &options, "1.0E+000");
+    write_float(0.015625, &options, "1.5625E-002");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn required_exponent_sign_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_exponent_sign(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    let bytes = 1e20f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e+20");
+    let bytes = 1e-20f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(bytes, b"1.0e-20");
+
+    #[cfg(feature = "ieee-decimal")]
+    {
+        let value = lexical_write_float::Decimal64::new(false, 12, 2).unwrap();
+        let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+        assert_eq!(bytes, b"1.2e+3");
+        check_written_len::<_, FORMAT>(value, &options);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn required_mantissa_sign_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    let value = Decimal::new(150, 2);
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(bytes, b"+1.50");
+    check_written_len::<_, FORMAT>(value, &options);
+
+    #[cfg(feature = "ieee-decimal")]
+    {
+        let value = lexical_write_float::Decimal64::new(false, 12, 2).unwrap();
+        let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+        assert_eq!(bytes, b"+1.2e3");
+        check_written_len::<_, FORMAT>(value, &options);
+    }
+}
+
+fn check_written_len<T, const FORMAT: u128>(value: T, options: &Options)
+where
+    T: ToLexicalWithOptions<Options = Options> + Copy,
+{
+    let mut buffer = [b'\x00'; 4096];
+    let expected = value.to_lexical_with_options::<FORMAT>(&mut buffer, options).len();
+    let length = value.written_len_with_options::<FORMAT>(options).unwrap();
+    assert_eq!(length, expected);
+    assert!(length <= value.formatted_len_with_options::<FORMAT>(options));
+
+    // Nothing is written past the end of an exactly-sized buffer.
+    let mut exact = [b'\xff'; 4096];
+    let written = unsafe {
+        value.to_lexical_with_options_unchecked::<FORMAT>(&mut exact[..length], options).len()
+    };
+    assert_eq!(written, length);
+    assert_eq!(&exact[..length], &buffer[..length]);
+    assert!(exact[length..].iter().all(|&x| x == b'\xff'));
+}
+
+fn written_len_options() -> Vec<Options> {
+    let digits = |x: usize| num::NonZeroUsize::new(x);
+    let mut options = vec![Options::new()];
+    for notation in [Notation::Auto, Notation::Scientific, Notation::Engineering, Notation::General]
+    {
+        for trim_floats in [false, true] {
+            let builder = || Options::builder().notation(notation).trim_floats(trim_floats);
+            options.push(builder().build().unwrap());
+            options.push(builder().max_significant_digits(digits(1)).build().unwrap());
+            options.push(builder().max_significant_digits(digits(3)).build().unwrap());
+            options.push(
+                builder()
+                    .max_significant_digits(digits(2))
+                    .round_mode(RoundMode::Truncate)
+                    .build()
+                    .unwrap(),
+            );
+            options.push(builder().min_significant_digits(digits(30)).build().unwrap());
+            options.push(
+                builder()
+                    .max_significant_digits(digits(4))
+                    .min_significant_digits(digits(4))
+                    .build()
+                    .unwrap(),
+            );
+            options.push(
+                builder()
+                    .min_exponent_digits(digits(3))
+                    .positive_exponent_sign(true)
+                    .si_prefix(true)
+                    .build()
+                    .unwrap(),
+            );
+            options.push(
+                builder()
+                    .positive_exponent_break(num::NonZeroI32::new(400))
+                    .negative_exponent_break(num::NonZeroI32::new(-400))
+                    .build()
+                    .unwrap(),
+            );
+        }
+    }
+    options
+}
+
+const WRITTEN_LEN_VALUES: [f64; 22] = [
+    0.0,
+    -0.0,
+    1.0,
+    -1.5,
+    0.1,
+    0.35,
+    0.95,
+    0.999,
+    0.0999,
+    9.5,
+    99.99,
+    12345.678,
+    9.999e9,
+    1.2e-7,
+    123456789012345680.0,
+    1e300,
+    -1.7976931348623157e308,
+    2.2250738585072014e-308,
+    5e-324,
+    f64::NAN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+];
+
+#[test]
+fn written_len_test() {
+    for options in written_len_options() {
+        for value in WRITTEN_LEN_VALUES {
+            check_written_len::<_, { STANDARD }>(value, &options);
+            check_written_len::<_, { STANDARD }>(value as f32, &options);
+        }
+    }
+    for value in WRITTEN_LEN_VALUES {
+        let mut buffer = vec![b'\x00'; value.formatted_len()];
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+    }
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn written_len_power_of_two_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    const BASE32: u128 = NumberFormatBuilder::from_radix(32);
+    const BASE16_2_10: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build();
+
+    for options in written_len_options() {
+        let options = options.rebuild().exponent(b'^').build().unwrap();
+        for value in WRITTEN_LEN_VALUES {
+            check_written_len::<_, BINARY>(value, &options);
+            check_written_len::<_, BASE32>(value as f32, &options);
+            check_written_len::<_, BASE16_2_10>(value, &options);
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn written_len_radix_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    const BASE36: u128 = NumberFormatBuilder::from_radix(36);
+
+    for options in written_len_options() {
+        let options = options.rebuild().exponent(b'^').build().unwrap();
+        for value in WRITTEN_LEN_VALUES {
+            check_written_len::<_, BASE3>(value as f32, &options);
+            check_written_len::<_, BASE36>(value, &options);
+        }
+    }
+}
+
+#[test]
+fn written_len_error_test() {
+    use lexical_util::error::Error;
+
+    let options = Options::builder().nan_string(None).inf_string(None).build().unwrap();
+    let result = f64::NAN.written_len_with_options::<{ STANDARD }>(&options);
+    assert_eq!(result, Err(Error::InvalidNanString));
+    let result = f64::INFINITY.written_len_with_options::<{ STANDARD }>(&options);
+    assert_eq!(result, Err(Error::InvalidInfString));
+    assert_eq!(1.0f64.written_len_with_options::<{ STANDARD }>(&options), Ok(3));
+
+    let options = Options::builder().decimal_point(b'e').build().unwrap();
+    let result = 1.0f64.written_len_with_options::<{ STANDARD }>(&options);
+    assert_eq!(result, Err(Error::InvalidPunctuation));
+}
+
+#[test]
+fn decimal_written_len_test() {
+    let values = [
+        Decimal::new(i128::MIN, 0),
+        Decimal::new(i128::MAX, 38),
+        Decimal::new(i128::MIN, 50),
+        Decimal::new(i128::MIN, 103),
+        Decimal::new(i128::MIN, -64),
+        Decimal::new(-1, -20),
+        Decimal::new(0, 5),
+        Decimal::new(99, -1),
+        Decimal::new(1, -2000000000),
+        Decimal::new(i128::MIN, i32::MIN),
+        Decimal::new(i128::MIN, i32::MAX),
+        Decimal::negative_zero(70),
+    ];
+    let grouped = Options::builder().group_size(num::NonZeroUsize::new(1)).build().unwrap();
+    for value in values {
+        assert!(value.formatted_len() <= Decimal::FORMATTED_SIZE_DECIMAL);
+        let mut buffer = vec![b'\x00'; value.formatted_len()];
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+        check_written_len::<_, { STANDARD }>(value, &Options::new());
+        check_written_len::<_, { STANDARD }>(value, &grouped);
+    }
+}
+
+#[test]
+#[should_panic]
+fn decimal_small_buffer_test() {
+    let mut buffer = [b'\x00'; 4];
+    Decimal::new(123456, 2).to_lexical(&mut buffer);
+}
+
+#[test]
+#[cfg(feature = "ieee-decimal")]
+fn bid_written_len_test() {
+    use lexical_write_float::{Decimal128, Decimal64};
+
+    let new64 = |is_negative, coefficient, exponent| {
+        Decimal64::new(is_negative, coefficient, exponent).unwrap()
+    };
+    let new128 = |is_negative, coefficient, exponent| {
+        Decimal128::new(is_negative, coefficient, exponent).unwrap()
+    };
+    let options = [
+        Options::new(),
+        Options::builder()
+            .min_exponent_digits(num::NonZeroUsize::new(8))
+            .inf_string(Some(b"InfinityInfinityInfinityInfinityInfinityInfinity"))
+            .build()
+            .unwrap(),
+    ];
+    assert_eq!(Decimal64::FORMATTED_SIZE_DECIMAL, 24);
+    assert_eq!(Decimal128::FORMATTED_SIZE_DECIMAL, 42);
+
+    let values = [
+        new64(true, 9999999999999999, -398),
+        new64(true, 9999999999999999, -21),
+        new64(true, 9999999999999999, 369),
+        Decimal64::NEG_INFINITY,
+        Decimal64::NAN,
+    ];
+    for value in values {
+        let mut buffer = vec![b'\x00'; value.formatted_len()];
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+        for options in options.iter() {
+            check_written_len::<_, { STANDARD }>(value, options);
+        }
+    }
+    let values = [
+        new128(true, 9999999999999999999999999999999999, -6176),
+        new128(true, 9999999999999999999999999999999999, -39),
+        new128(true, 9999999999999999999999999999999999, 6111),
+        Decimal128::NEG_INFINITY,
+        Decimal128::NAN,
+    ];
+    for value in values {
+        let mut buffer = vec![b'\x00'; value.formatted_len()];
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+        for options in options.iter() {
+            check_written_len::<_, { STANDARD }>(value, options);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "ieee-decimal")]
+fn bid_small_buffer_test() {
+    let mut buffer = [b'\x00'; 4];
+    let value = lexical_write_float::Decimal64::new(false, 123456, -2).unwrap();
+    value.to_lexical(&mut buffer);
+}
+
 quickcheck! {
     #[cfg_attr(miri, ignore)]
     fn f32_quickcheck(f: f32) -> bool {
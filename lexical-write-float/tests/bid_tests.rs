@@ -0,0 +1,78 @@
+#![cfg(feature = "ieee-decimal")]
+
+mod util;
+
+use core::num;
+use lexical_write_float::{Decimal128, Decimal64, Options};
+use util::{write, write_with_options};
+
+fn new64(is_negative: bool, coefficient: u64, exponent: i32) -> Decimal64 {
+    Decimal64::new(is_negative, coefficient, exponent).unwrap()
+}
+
+fn new128(is_negative: bool, coefficient: u128, exponent: i32) -> Decimal128 {
+    Decimal128::new(is_negative, coefficient, exponent).unwrap()
+}
+
+#[test]
+fn write_fixed_test() {
+    assert_eq!(write(new64(false, 1, 0)), "1");
+    assert_eq!(write(new64(false, 150, -2)), "1.50");
+    assert_eq!(write(new64(false, 1200, 0)), "1200");
+    assert_eq!(write(new64(true, 1, -3)), "-0.001");
+    assert_eq!(write(new64(false, 123, -8)), "0.00000123");
+    assert_eq!(write(new64(false, 0, 0)), "0");
+    assert_eq!(write(new64(true, 0, -2)), "-0.00");
+    assert_eq!(
+        write(new128(false, 1234567890123456789012345678901234, -33)),
+        "1.234567890123456789012345678901234"
+    );
+}
+
+#[test]
+fn write_scientific_test() {
+    assert_eq!(write(new64(false, 12, 2)), "1.2e3");
+    assert_eq!(write(new64(false, 1, 3)), "1e3");
+    assert_eq!(write(new64(false, 0, 2)), "0e2");
+    assert_eq!(write(new64(false, 0, -8)), "0e-8");
+    assert_eq!(write(new64(false, 1, -7)), "1e-7");
+    assert_eq!(write(new64(true, 123, -10)), "-1.23e-8");
+    assert_eq!(write(new64(false, 9999999999999999, 369)), "9.999999999999999e384");
+    assert_eq!(write(new64(false, 1, -398)), "1e-398");
+    assert_eq!(
+        write(new128(true, 9999999999999999999999999999999999, 6111)),
+        "-9.999999999999999999999999999999999e6144"
+    );
+    assert_eq!(write(new128(false, 1, -6176)), "1e-6176");
+}
+
+#[test]
+fn write_special_test() {
+    assert_eq!(write(Decimal64::NAN), "NaN");
+    assert_eq!(write(Decimal64::INFINITY), "inf");
+    assert_eq!(write(Decimal64::NEG_INFINITY), "-inf");
+    assert_eq!(write(Decimal128::NAN), "NaN");
+    assert_eq!(write(Decimal128::NEG_INFINITY), "-inf");
+}
+
+#[test]
+#[should_panic]
+fn write_nan_disabled_test() {
+    let options = Options::builder().nan_string(None).build().unwrap();
+    write_with_options(Decimal64::NAN, &options);
+}
+
+#[test]
+fn write_options_test() {
+    let options = Options::builder()
+        .exponent(b'E')
+        .decimal_point(b',')
+        .positive_exponent_sign(true)
+        .min_exponent_digits(num::NonZeroUsize::new(5))
+        .inf_string(Some(b"Infinity"))
+        .build()
+        .unwrap();
+    assert_eq!(write_with_options(new64(false, 12, 2), &options), "1,2E+00003");
+    assert_eq!(write_with_options(new64(false, 150, -2), &options), "1,50");
+    assert_eq!(write_with_options(Decimal128::NEG_INFINITY, &options), "-Infinity");
+}
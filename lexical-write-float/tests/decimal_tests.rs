@@ -0,0 +1,79 @@
+mod util;
+
+use core::num;
+use lexical_write_float::{Decimal, Options};
+use util::{write, write_with_options};
+
+#[test]
+fn write_simple_test() {
+    assert_eq!(write(Decimal::new(150, 2)), "1.50");
+    assert_eq!(write(Decimal::new(15, 1)), "1.5");
+    assert_eq!(write(Decimal::new(12, -3)), "12000");
+    assert_eq!(write(Decimal::new(12, 4)), "0.0012");
+    assert_eq!(write(Decimal::new(-1, 1)), "-0.1");
+    assert_eq!(write(Decimal::new(0, 2)), "0.00");
+    assert_eq!(write(Decimal::new(0, 0)), "0");
+    assert_eq!(write(Decimal::default()), "0");
+    assert_eq!(write(Decimal::new(7, 0)), "7");
+    assert_eq!(write(Decimal::new(-12345, 0)), "-12345");
+}
+
+#[test]
+fn write_limits_test() {
+    assert_eq!(write(Decimal::new(i128::MAX, 0)), i128::MAX.to_string());
+    assert_eq!(write(Decimal::new(i128::MIN, 0)), i128::MIN.to_string());
+    assert_eq!(write(Decimal::new(i128::MIN, 39)), "-0.170141183460469231731687303715884105728");
+    assert_eq!(write(Decimal::new(i128::MAX, 20)), "1701411834604692317.31687303715884105727");
+    assert_eq!(write(Decimal::new(1, 40)), format!("0.{}1", "0".repeat(39)));
+    assert_eq!(write(Decimal::new(-1, -40)), format!("-1{}", "0".repeat(40)));
+}
+
+#[test]
+fn write_scientific_test() {
+    assert_eq!(write(Decimal::new(1, -2000000000)), "1e2000000000");
+    assert_eq!(write(Decimal::new(-15, 300)), "-1.5e-299");
+    assert_eq!(write(Decimal::new(0, 100)), "0e-100");
+    assert_eq!(write(Decimal::new(120, 68)), "1.20e-66");
+    assert_eq!(write(Decimal::new(120, 66)), format!("0.{}120", "0".repeat(63)));
+    assert_eq!(write(Decimal::new(12, -65)), "1.2e66");
+    assert_eq!(
+        write(Decimal::new(i128::MIN, i32::MIN)),
+        "-1701411834604692317316873037158841057280e2147483647"
+    );
+    assert_eq!(
+        write(Decimal::new(i128::MAX, i32::MAX)),
+        "1.70141183460469231731687303715884105727e-2147483609"
+    );
+}
+
+#[test]
+fn write_negative_zero_test() {
+    assert_eq!(write(Decimal::negative_zero(0)), "-0");
+    assert_eq!(write(Decimal::negative_zero(2)), "-0.00");
+    assert_ne!(Decimal::negative_zero(1), Decimal::new(0, 1));
+}
+
+#[test]
+fn write_options_test() {
+    let options = Options::builder()
+        .group_size(num::NonZeroUsize::new(3))
+        .group_separator(b',')
+        .build()
+        .unwrap();
+    assert_eq!(write_with_options(Decimal::new(123456789, 2), &options), "1,234,567.89");
+    assert_eq!(write_with_options(Decimal::new(-123456, 0), &options), "-123,456");
+    assert_eq!(write_with_options(Decimal::new(12, -5), &options), "1,200,000");
+    assert_eq!(write_with_options(Decimal::new(123, 0), &options), "123");
+    assert_eq!(write_with_options(Decimal::new(5, 3), &options), "0.005");
+
+    let options = Options::builder()
+        .decimal_point(b',')
+        .group_size(num::NonZeroUsize::new(3))
+        .group_separator(b'.')
+        .build()
+        .unwrap();
+    assert_eq!(write_with_options(Decimal::new(123456789, 2), &options), "1.234.567,89");
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(write_with_options(Decimal::new(150, 2), &options), "1,50");
+}
@@ -0,0 +1,143 @@
+#![cfg(feature = "half")]
+
+use core::num;
+use lexical_util::constants::BUFFER_SIZE;
+#[cfg(feature = "power-of-two")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{bf16, f16, Notation, Options, ToLexical, ToLexicalWithOptions};
+
+fn write<T: ToLexical>(value: T) -> String {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let digits = value.to_lexical(&mut buffer);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+fn write_with_options<T: ToLexicalWithOptions<Options = Options>, const FORMAT: u128>(
+    value: T,
+    options: &Options,
+) -> String {
+    let size = value.formatted_len_with_options::<FORMAT>(options).max(BUFFER_SIZE);
+    let mut buffer = vec![b'\x00'; size];
+    let digits = value.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+/// Count the significant digits in a decimal float string.
+fn significant_digits(string: &str) -> usize {
+    let mantissa = string.split('e').next().unwrap();
+    let digits = mantissa.bytes().filter(u8::is_ascii_digit).skip_while(|&c| c == b'0');
+    let count = digits.collect::<Vec<_>>().iter().rev().skip_while(|&&c| c == b'0').count();
+    count.max(1)
+}
+
+#[test]
+fn f16_test() {
+    assert_eq!(write(f16::ZERO), "0.0");
+    assert_eq!(write(f16::ONE), "1.0");
+    assert_eq!(write(f16::from_f32(-1.5)), "-1.5");
+    assert_eq!(write(f16::from_f32(0.1)), "0.1");
+    assert_eq!(write(f16::from_f32(1.2345)), "1.234");
+    assert_eq!(write(f16::from_f32(2048.0)), "2048.0");
+    assert_eq!(write(f16::MAX), "65500.0");
+    assert_eq!(write(f16::MIN_POSITIVE), "0.00006104");
+    assert_eq!(write(f16::from_bits(1)), "6.0e-8");
+    assert_eq!(write(f16::INFINITY), "inf");
+    assert_eq!(write(f16::NAN), "NaN");
+}
+
+#[test]
+fn bf16_test() {
+    assert_eq!(write(bf16::ZERO), "0.0");
+    assert_eq!(write(bf16::ONE), "1.0");
+    assert_eq!(write(bf16::from_f32(0.1)), "0.1");
+    assert_eq!(write(bf16::from_f32(256.0)), "256.0");
+    assert_eq!(write(bf16::from_f32(260.0)), "260.0");
+    assert_eq!(write(bf16::MAX), "3.39e38");
+    assert_eq!(write(bf16::MIN_POSITIVE), "1.18e-38");
+    assert_eq!(write(bf16::from_bits(1)), "1.0e-40");
+    assert_eq!(write(bf16::NEG_INFINITY), "-inf");
+}
+
+#[test]
+fn options_test() {
+    let options = Options::builder()
+        .notation(Notation::Scientific)
+        .min_significant_digits(num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    assert_eq!(write_with_options::<_, STANDARD>(f16::from_f32(0.5), &options), "5.00e-1");
+    assert_eq!(write_with_options::<_, STANDARD>(bf16::from_f32(1024.0), &options), "1.024e3");
+}
+
+/// Check if the string rounds to the value, given the neighboring values.
+/// The halfway points between 16-bit floats are exact in `f64`.
+fn rounds_to(string: &str, value: f64, prev: f64, next: f64, is_even: bool) -> bool {
+    let x = string.parse::<f64>().unwrap();
+    let lower = (prev + value) / 2.0;
+    let upper = if next.is_infinite() {
+        value + (value - prev) / 2.0
+    } else {
+        (value + next) / 2.0
+    };
+    if is_even {
+        lower <= x && x <= upper
+    } else {
+        lower < x && x < upper
+    }
+}
+
+macro_rules! check_roundtrip {
+    ($t:ident, $max:literal) => {
+        for bits in 1..$max {
+            let value = $t::from_bits(bits);
+            let prev = $t::from_bits(bits - 1).to_f64();
+            let next = $t::from_bits(bits + 1).to_f64();
+            let is_even = bits % 2 == 0;
+            let string = write(value);
+            assert!(rounds_to(&string, value.to_f64(), prev, next, is_even), "{}", string);
+
+            // The nearest string with one fewer digit must not round-trip.
+            // Grisu excludes the boundaries, so it may not be the shortest.
+            let digits = significant_digits(&string);
+            if cfg!(not(feature = "compact")) && digits > 1 {
+                let shorter = format!("{:.*e}", digits - 2, value.to_f64());
+                assert!(!rounds_to(&shorter, value.to_f64(), prev, next, is_even), "{}", string);
+            }
+        }
+    };
+}
+
+#[test]
+fn roundtrip_test() {
+    // Every value must round-trip, and there must be no shorter
+    // representation than the one written.
+    check_roundtrip!(f16, 0x7C00);
+    check_roundtrip!(bf16, 0x7F80);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn power_of_two_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    const HEX: u128 = NumberFormatBuilder::from_radix(16);
+    let options = Options::new();
+    assert_eq!(
+        write_with_options::<_, BINARY>(f16::from_f32(2047.0), &options),
+        "1.1111111111e1010"
+    );
+    assert_eq!(write_with_options::<_, BINARY>(f16::from_f32(0.5), &options), "0.1");
+    assert_eq!(write_with_options::<_, BINARY>(bf16::from_f32(3.0), &options), "11.0");
+
+    let options = Options::builder().exponent(b'^').build().unwrap();
+    assert_eq!(write_with_options::<_, HEX>(f16::from_f32(255.5), &options), "FF.8");
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn radix_test() {
+    const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+    let options = Options::new();
+    assert_eq!(write_with_options::<_, BASE3>(f16::from_f32(9.5), &options), "100.11112");
+    assert_eq!(write_with_options::<_, BASE3>(bf16::from_f32(0.5), &options), "0.111112");
+}
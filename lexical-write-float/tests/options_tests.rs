@@ -27,6 +27,23 @@ fn invalid_decimal_point_test() {
     assert!(builder.build().is_ok());
 }
 
+#[test]
+fn invalid_group_separator_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.group_separator(b'0');
+    assert!(!builder.is_valid());
+    builder = builder.group_separator(b'-');
+    assert!(!builder.is_valid());
+    builder = builder.group_separator(b'.');
+    assert!(builder.is_valid());
+    builder = builder.group_size(num::NonZeroUsize::new(3));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.group_separator(b'_');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn invalid_nan_test() {
     let mut builder = OptionsBuilder::default();
@@ -69,8 +86,14 @@ fn builder_test() {
     builder = builder.min_significant_digits(num::NonZeroUsize::new(5));
     builder = builder.positive_exponent_break(num::NonZeroI32::new(9));
     builder = builder.negative_exponent_break(num::NonZeroI32::new(-9));
+    builder = builder.notation(options::Notation::General);
     builder = builder.round_mode(options::RoundMode::Truncate);
     builder = builder.trim_floats(true);
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(2));
+    builder = builder.positive_exponent_sign(true);
+    builder = builder.si_prefix(true);
+    builder = builder.group_size(num::NonZeroUsize::new(3));
+    builder = builder.group_separator(b'_');
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
@@ -80,8 +103,14 @@ fn builder_test() {
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
     assert_eq!(builder.get_positive_exponent_break().unwrap().get(), 9);
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
+    assert_eq!(builder.get_notation(), options::Notation::General);
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
     assert_eq!(builder.get_trim_floats(), true);
+    assert_eq!(builder.get_min_exponent_digits().unwrap().get(), 2);
+    assert!(builder.get_positive_exponent_sign());
+    assert!(builder.get_si_prefix());
+    assert_eq!(builder.get_group_size().unwrap().get(), 3);
+    assert_eq!(builder.get_group_separator(), b'_');
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
@@ -100,8 +129,14 @@ fn options_test() {
         opts.set_min_significant_digits(num::NonZeroUsize::new(5));
         opts.set_positive_exponent_break(num::NonZeroI32::new(9));
         opts.set_negative_exponent_break(num::NonZeroI32::new(-9));
+        opts.set_notation(options::Notation::Scientific);
         opts.set_round_mode(options::RoundMode::Truncate);
         opts.set_trim_floats(true);
+        opts.set_min_exponent_digits(num::NonZeroUsize::new(2));
+        opts.set_positive_exponent_sign(true);
+        opts.set_si_prefix(true);
+        opts.set_group_size(num::NonZeroUsize::new(4));
+        opts.set_group_separator(b' ');
         opts.set_exponent(b'^');
         opts.set_decimal_point(b',');
         opts.set_nan_string(Some(b"nan"));
@@ -112,8 +147,14 @@ fn options_test() {
     assert_eq!(opts.min_significant_digits().unwrap().get(), 5);
     assert_eq!(opts.positive_exponent_break().unwrap().get(), 9);
     assert_eq!(opts.negative_exponent_break().unwrap().get(), -9);
+    assert_eq!(opts.notation(), options::Notation::Scientific);
     assert_eq!(opts.round_mode(), options::RoundMode::Truncate);
     assert_eq!(opts.trim_floats(), true);
+    assert_eq!(opts.min_exponent_digits().unwrap().get(), 2);
+    assert!(opts.positive_exponent_sign());
+    assert!(opts.si_prefix());
+    assert_eq!(opts.group_size().unwrap().get(), 4);
+    assert_eq!(opts.group_separator(), b' ');
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
@@ -0,0 +1,17 @@
+use lexical_util::format::STANDARD;
+use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+
+pub fn write<T: ToLexical>(value: T) -> String {
+    let mut buffer = vec![b'\x00'; value.formatted_len()];
+    let digits = value.to_lexical(&mut buffer);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+pub fn write_with_options<T: ToLexicalWithOptions<Options = Options>>(
+    value: T,
+    options: &Options,
+) -> String {
+    let mut buffer = vec![b'\x00'; value.formatted_len_with_options::<STANDARD>(options)];
+    let digits = value.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+    String::from_utf8(digits.to_vec()).unwrap()
+}
@@ -50,7 +50,7 @@ macro_rules! write_digit {
 ///
 /// This is safe as long as the buffer is large enough to hold `T::MAX`
 /// digits in radix `N`.
-unsafe fn write_digits<T: UnsignedInteger>(
+pub(crate) unsafe fn write_digits<T: UnsignedInteger>(
     mut value: T,
     radix: u32,
     table: &[u8],
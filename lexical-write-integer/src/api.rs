@@ -2,11 +2,20 @@
 
 #![doc(hidden)]
 
+#[cfg(feature = "std")]
+use crate::bigint::{formatted_len, write_bigint, written_len};
 use crate::options::Options;
-use crate::write::WriteInteger;
-use lexical_util::assert::{assert_buffer, debug_assert_buffer};
+use crate::wide::{signed_wide, signed_wide_len, unsigned_wide, unsigned_wide_len};
+use crate::write::{digit_count, WriteInteger};
+use lexical_util::assert::assert_buffer;
+use lexical_util::constants::FormattedSize;
+#[cfg(feature = "std")]
+use lexical_util::bigint::Bigint;
 use lexical_util::format::{NumberFormat, STANDARD};
-use lexical_util::num::SignedInteger;
+use lexical_util::num::{SignedInteger, UnsignedInteger};
+use lexical_util::options::WriteOptions;
+use lexical_util::result::Result;
+use lexical_util::wide::{Int, Uint};
 use lexical_util::{to_lexical, to_lexical_with_options};
 
 // UNSIGNED
@@ -15,8 +24,8 @@ use lexical_util::{to_lexical, to_lexical_with_options};
 ///
 /// # Safety
 ///
-/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// Safe as long as the buffer can hold `buffer_size` elements, which is
+/// `FORMATTED_SIZE_DECIMAL` for decimal without a required sign.
 #[inline]
 unsafe fn unsigned<Narrow, Wide, const FORMAT: u128>(value: Narrow, buffer: &mut [u8]) -> usize
 where
@@ -43,8 +52,8 @@ where
 ///
 /// # Safety
 ///
-/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// Safe as long as the buffer can hold `buffer_size` elements, which is
+/// `FORMATTED_SIZE_DECIMAL` for decimal without a required sign.
 #[inline]
 unsafe fn signed<Narrow, Wide, Unsigned, const FORMAT: u128>(
     value: Narrow,
@@ -84,6 +93,43 @@ where
     }
 }
 
+/// Get the number of bytes written by the unsigned integer formatter.
+#[inline]
+fn unsigned_len<T: UnsignedInteger, const FORMAT: u128>(value: T) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    let sign = cfg!(feature = "format") && format.required_mantissa_sign();
+    sign as usize + digit_count(value, format.radix())
+}
+
+/// Get the number of bytes written by the signed integer formatter.
+#[inline]
+fn signed_len<Narrow, Wide, Unsigned, const FORMAT: u128>(value: Narrow) -> usize
+where
+    Narrow: SignedInteger,
+    Wide: SignedInteger,
+    Unsigned: UnsignedInteger,
+{
+    let format = NumberFormat::<FORMAT> {};
+    if value < Narrow::ZERO {
+        let value = Wide::as_cast(value);
+        let unsigned = Unsigned::as_cast(value.wrapping_neg());
+        1 + digit_count(unsigned, format.radix())
+    } else {
+        unsigned_len::<Unsigned, FORMAT>(Unsigned::as_cast(value))
+    }
+}
+
+/// Get the number of bytes written with custom options, if the format is valid.
+#[inline]
+fn checked_len<const FORMAT: u128>(len: impl FnOnce() -> usize) -> Result<usize> {
+    let format = NumberFormat::<FORMAT> {};
+    if format.is_valid() {
+        Ok(len())
+    } else {
+        Err(format.error())
+    }
+}
+
 // API
 
 // Implement ToLexical for numeric type.
@@ -94,8 +140,12 @@ macro_rules! unsigned_to_lexical {
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(10, bytes.len());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
+                debug_assert!(
+                    bytes.len() >= Self::FORMATTED_SIZE_DECIMAL || bytes.len() >= self.written_len(),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = unsigned::<$narrow, $wide, { STANDARD }>(self, bytes);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -110,6 +160,11 @@ macro_rules! unsigned_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len(&self) -> usize {
+                unsigned_len::<$narrow, { STANDARD }>(*self)
+            }
         }
 
         impl ToLexicalWithOptions for $narrow {
@@ -119,12 +174,17 @@ macro_rules! unsigned_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                debug_assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>()
+                        || bytes.len() >= unsigned_len::<$narrow, FORMAT>(self),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe if `bytes.len() >= options.buffer_size()`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = unsigned::<$narrow, $wide, FORMAT>(self, bytes);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -138,11 +198,22 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>(),
+                    "Buffer is too small: may overwrite buffer, panicking!"
+                );
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe since `bytes.len() >= options.buffer_size()`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                _: &Self::Options,
+            ) -> Result<usize> {
+                checked_len::<FORMAT>(|| unsigned_len::<$narrow, FORMAT>(*self))
+            }
         }
     )*)
 }
@@ -171,8 +242,12 @@ macro_rules! signed_to_lexical {
             unsafe fn to_lexical_unchecked<'a>(self, bytes: &'a mut [u8])
                 -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(10, bytes.len());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
+                debug_assert!(
+                    bytes.len() >= Self::FORMATTED_SIZE_DECIMAL || bytes.len() >= self.written_len(),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = signed::<$narrow, $wide, $unsigned, { STANDARD }>(self, bytes);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -187,6 +262,11 @@ macro_rules! signed_to_lexical {
                 // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
                 unsafe { self.to_lexical_unchecked(bytes) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len(&self) -> usize {
+                signed_len::<$narrow, $wide, $unsigned, { STANDARD }>(*self)
+            }
         }
 
         impl ToLexicalWithOptions for $narrow {
@@ -196,12 +276,17 @@ macro_rules! signed_to_lexical {
             unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
                 self,
                 bytes: &'a mut [u8],
-                _: &Self::Options,
+                options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                debug_assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                debug_assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>()
+                        || bytes.len() >= signed_len::<$narrow, $wide, $unsigned, FORMAT>(self),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe if `bytes.len() >= options.buffer_size()`,
+                // or if the buffer can hold the written bytes.
                 unsafe {
                     let len = signed::<$narrow, $wide, $unsigned, FORMAT>(self, bytes);
                     &mut index_unchecked_mut!(bytes[..len])
@@ -215,11 +300,22 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                assert_buffer::<$narrow>(NumberFormat::<{ FORMAT }>::RADIX, bytes.len());
+                assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>(),
+                    "Buffer is too small: may overwrite buffer, panicking!"
+                );
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE`.
+                // SAFETY: safe since `bytes.len() >= options.buffer_size()`.
                 unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
             }
+
+            $(#[$meta:meta])?
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                _: &Self::Options,
+            ) -> Result<usize> {
+                checked_len::<FORMAT>(|| signed_len::<$narrow, $wide, $unsigned, FORMAT>(*self))
+            }
         }
     )*)
 }
@@ -237,3 +333,145 @@ signed_to_lexical! { isize i32 u32 ; }
 
 #[cfg(target_pointer_width = "64")]
 signed_to_lexical! { isize i64 u64 ; }
+
+// Implement ToLexical for wide integer types.
+macro_rules! wide_to_lexical {
+    ($($t:ident $cb:ident $len:ident ; )*) => ($(
+        impl<const N: usize> ToLexical for $t<N> {
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                debug_assert!(
+                    bytes.len() >= Self::FORMATTED_SIZE_DECIMAL || bytes.len() >= self.written_len(),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
+                // SAFETY: safe if `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`,
+                // or if the buffer can hold the written bytes.
+                unsafe {
+                    let len = $cb::<N, { STANDARD }>(self, bytes);
+                    &mut index_unchecked_mut!(bytes[..len])
+                }
+            }
+
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                assert_buffer::<Self>(10, bytes.len());
+                // SAFETY: safe since `bytes.len() > Self::FORMATTED_SIZE_DECIMAL`.
+                unsafe { self.to_lexical_unchecked(bytes) }
+            }
+
+            fn written_len(&self) -> usize {
+                $len::<N, { STANDARD }>(*self)
+            }
+        }
+
+        impl<const N: usize> ToLexicalWithOptions for $t<N> {
+            type Options = Options;
+
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                debug_assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>()
+                        || bytes.len() >= $len::<N, FORMAT>(self),
+                    "Buffer is too small: may overwrite buffer in release builds."
+                );
+                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                // SAFETY: safe if `bytes.len() >= options.buffer_size()`,
+                // or if the buffer can hold the written bytes.
+                unsafe {
+                    let len = $cb::<N, FORMAT>(self, bytes);
+                    &mut index_unchecked_mut!(bytes[..len])
+                }
+            }
+
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                assert!(
+                    bytes.len() >= options.buffer_size::<Self, FORMAT>(),
+                    "Buffer is too small: may overwrite buffer, panicking!"
+                );
+                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                // SAFETY: safe since `bytes.len() >= options.buffer_size()`.
+                unsafe { self.to_lexical_with_options_unchecked::<FORMAT>(bytes, options) }
+            }
+
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                _: &Self::Options,
+            ) -> Result<usize> {
+                checked_len::<FORMAT>(|| $len::<N, FORMAT>(*self))
+            }
+        }
+    )*)
+}
+
+wide_to_lexical! {
+    Uint unsigned_wide unsigned_wide_len ;
+    Int signed_wide signed_wide_len ;
+}
+
+// Implement ToLexical for big integers, which are serialized by value
+// or by reference, since they're not `Copy`.
+macro_rules! bigint_to_lexical {
+    ($($t:ty)*) => ($(
+        impl ToLexical for $t {
+            unsafe fn to_lexical_unchecked(self, bytes: &mut [u8]) -> &mut [u8] {
+                // The size of the buffer must always be checked, since it depends on the value.
+                self.to_lexical(bytes)
+            }
+
+            fn to_lexical(self, bytes: &mut [u8]) -> &mut [u8] {
+                let len = write_bigint::<{ STANDARD }>(&self, bytes);
+                &mut bytes[..len]
+            }
+
+            fn formatted_len(&self) -> usize {
+                formatted_len::<{ STANDARD }>(self)
+            }
+
+            fn written_len(&self) -> usize {
+                written_len::<{ STANDARD }>(self)
+            }
+        }
+
+        impl ToLexicalWithOptions for $t {
+            type Options = Options;
+
+            unsafe fn to_lexical_with_options_unchecked<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                // The size of the buffer must always be checked, since it depends on the value.
+                self.to_lexical_with_options::<FORMAT>(bytes, options)
+            }
+
+            fn to_lexical_with_options<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [u8],
+                _: &Self::Options,
+            ) -> &'a mut [u8] {
+                assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
+                let len = write_bigint::<FORMAT>(&self, bytes);
+                &mut bytes[..len]
+            }
+
+            fn formatted_len_with_options<const FORMAT: u128>(&self, _: &Self::Options) -> usize {
+                formatted_len::<FORMAT>(self)
+            }
+
+            fn written_len_with_options<const FORMAT: u128>(
+                &self,
+                _: &Self::Options,
+            ) -> Result<usize> {
+                checked_len::<FORMAT>(|| written_len::<FORMAT>(self))
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "std")]
+bigint_to_lexical! { Bigint &Bigint }
@@ -0,0 +1,232 @@
+//! Radix-generic integer-to-string conversion routines for big integers.
+//!
+//! Small big integers are written a limb of digits at a time, like the
+//! wide integers. Large big integers use subquadratic, divide-and-conquer
+//! base conversion: the value is split into a quotient and remainder by
+//! a power of the radix with half the digits, which are then written
+//! recursively, so the cost is dominated by the fast division of the
+//! large halves. Power-of-two radixes are written directly from the bits.
+
+#![cfg(feature = "std")]
+#![doc(hidden)]
+
+use crate::wide::write_limb;
+use crate::write;
+use lexical_util::bigint::Bigint;
+use lexical_util::digit::digit_to_char_const;
+use lexical_util::format::{self, NumberFormat};
+use lexical_util::limb::{Limb, LIMB_BITS};
+use lexical_util::wide::limb_power;
+
+/// The number of limbs where divide-and-conquer conversion is faster.
+const DIVIDE_CONQUER_CUTOFF: usize = 32;
+
+/// Calculate the number of bytes required to write the big integer.
+///
+/// This is an upper bound, including the sign.
+#[inline]
+pub fn formatted_len<const FORMAT: u128>(value: &Bigint) -> usize {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let bits = value.bit_length();
+    // `1234 / 4096` slightly overestimates `log10(2)`, and the bits per
+    // digit for other radixes is rounded down, so both overestimate.
+    let digits = match radix {
+        10 => bits * 1234 / 4096,
+        _ => bits / (31 - radix.leading_zeros()) as usize,
+    };
+    digits + 2
+}
+
+/// Get the number of digits of the magnitude of the big integer.
+fn digit_count<const FORMAT: u128>(value: &Bigint) -> usize {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let magnitude = value.unsigned_abs();
+    if let Some(value) = magnitude.to_u128() {
+        return write::digit_count(value, radix);
+    }
+
+    let bits = magnitude.bit_length();
+    if radix.is_power_of_two() {
+        // Each digit holds the same number of bits.
+        let bits_per_digit = radix.trailing_zeros() as usize;
+        return (bits - 1) / bits_per_digit + 1;
+    }
+
+    // Estimate the digits from the bits, with a margin for the rounding
+    // error, so `radix^count <= 2^(bits - 1) <= value`. Then, compare
+    // against the next powers of the radix, which is only a few steps.
+    let scale = std::f64::consts::LN_2 / (radix as f64).ln();
+    let mut count = ((bits - 1) as f64 * scale - 1.0).max(0.0) as usize;
+    let mut power = Bigint::from(radix as Limb).pow(count as u32);
+    while magnitude >= power {
+        power.mul_small_add(radix as Limb, 0);
+        count += 1;
+    }
+    count
+}
+
+/// Calculate the exact number of bytes written for the big integer.
+///
+/// This includes the sign.
+#[inline]
+pub fn written_len<const FORMAT: u128>(value: &Bigint) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let sign = value.is_negative() || (cfg!(feature = "format") && format.required_mantissa_sign());
+    sign as usize + digit_count::<FORMAT>(value)
+}
+
+/// Write the digits of a single limb, padded to `step` digits.
+///
+/// Returns the start of the digits.
+#[inline]
+fn write_padded<const FORMAT: u128>(
+    value: Limb,
+    step: usize,
+    buffer: &mut [u8],
+    end: usize,
+) -> usize {
+    // SAFETY: safe since the buffer can hold all the digits.
+    let index = unsafe {
+        write_limb::<_, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(value, buffer, end)
+    };
+    buffer[end - step..index].fill(b'0');
+    end - step
+}
+
+/// Write the big integer a limb of digits at a time.
+///
+/// Returns the start of the digits, which end at `end`.
+fn write_chunks<const FORMAT: u128>(
+    mut value: Bigint,
+    power: Limb,
+    step: usize,
+    buffer: &mut [u8],
+    end: usize,
+) -> usize {
+    let mut index = end;
+    while value.as_limbs().len() > 1 {
+        let rem = value.div_small(power);
+        index = write_padded::<FORMAT>(rem, step, buffer, index);
+    }
+    let value = value.as_limbs().first().copied().unwrap_or(0);
+    // SAFETY: safe since the buffer can hold all the digits.
+    unsafe {
+        write_limb::<_, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(value, buffer, index)
+    }
+}
+
+/// Write the big integer using divide-and-conquer base conversion.
+///
+/// The value must be less than `powers[level]^2`, where each power is
+/// the square of the previous, and the first is the largest power of
+/// the radix that fits in a limb, with `step` digits. If `pad` is set,
+/// exactly `step * 2^(level + 1)` digits are written, with leading
+/// zeros. Returns the start of the digits, which end at `end`.
+#[allow(clippy::too_many_arguments)]
+fn write_recursive<const FORMAT: u128>(
+    value: Bigint,
+    powers: &[Bigint],
+    level: usize,
+    step: usize,
+    buffer: &mut [u8],
+    end: usize,
+    pad: bool,
+) -> usize {
+    let start = if level == 0 || value.as_limbs().len() <= DIVIDE_CONQUER_CUTOFF {
+        let power = powers[0].as_limbs()[0];
+        write_chunks::<FORMAT>(value, power, step, buffer, end)
+    } else if !pad && value < powers[level] {
+        // Skip levels that would have a zero quotient, or leading zeros.
+        return write_recursive::<FORMAT>(value, powers, level - 1, step, buffer, end, false);
+    } else {
+        let (hi, lo) = value.div_rem(&powers[level]);
+        let mid = write_recursive::<FORMAT>(lo, powers, level - 1, step, buffer, end, true);
+        write_recursive::<FORMAT>(hi, powers, level - 1, step, buffer, mid, pad)
+    };
+
+    if pad {
+        let digits = (2 * step) << level;
+        buffer[end - digits..start].fill(b'0');
+        end - digits
+    } else {
+        start
+    }
+}
+
+/// Write the digits of a power-of-two radix directly from the bits.
+///
+/// Returns the start of the digits, which end at `end`.
+fn write_bits(value: &Bigint, radix: u32, buffer: &mut [u8], end: usize) -> usize {
+    let bits = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as Limb;
+    let limbs = value.as_limbs();
+    let mut count = value.bit_length() / bits;
+    if count * bits < value.bit_length() || count == 0 {
+        count += 1;
+    }
+    let start = end - count;
+    for (index, byte) in buffer[start..end].iter_mut().rev().enumerate() {
+        let bit = index * bits;
+        let (limb, shift) = (bit / LIMB_BITS, bit % LIMB_BITS);
+        let mut digit = limbs.get(limb).map_or(0, |&x| x >> shift);
+        if shift + bits > LIMB_BITS {
+            digit |= limbs.get(limb + 1).map_or(0, |&x| x << (LIMB_BITS - shift));
+        }
+        *byte = digit_to_char_const((digit & mask) as u32, radix);
+    }
+    start
+}
+
+/// Write the magnitude of a big integer to the buffer.
+///
+/// Returns the number of digits written.
+fn write_magnitude<const FORMAT: u128>(value: Bigint, buffer: &mut [u8]) -> usize {
+    let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+    let end = buffer.len();
+
+    let start = if cfg!(feature = "power-of-two") && radix.is_power_of_two() {
+        write_bits(&value, radix, buffer, end)
+    } else {
+        // Calculate the powers required so `value < powers[level]^2`.
+        let (power, step) = limb_power(radix);
+        let mut powers = vec![Bigint::from(power)];
+        while let Some(last) = powers.last() {
+            if 2 * last.as_limbs().len() > value.as_limbs().len() + 1 {
+                break;
+            }
+            let next = last * last;
+            powers.push(next);
+        }
+        let level = powers.len() - 1;
+        write_recursive::<FORMAT>(value, &powers, level, step, buffer, end, false)
+    };
+
+    // Move the digits to the start of the buffer.
+    buffer.copy_within(start.., 0);
+    end - start
+}
+
+/// Write a big integer to the buffer, returning the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`formatted_len`] and [`written_len`].
+pub fn write_bigint<const FORMAT: u128>(value: &Bigint, buffer: &mut [u8]) -> usize {
+    // Only calculate the exact length if the upper bound does not fit.
+    assert!(
+        buffer.len() >= formatted_len::<FORMAT>(value)
+            || buffer.len() >= written_len::<FORMAT>(value),
+        "Buffer is too small: may overwrite buffer, panicking!"
+    );
+    let format = NumberFormat::<{ FORMAT }> {};
+    let index = if value.is_negative() {
+        buffer[0] = b'-';
+        1
+    } else if cfg!(feature = "format") && format.required_mantissa_sign() {
+        buffer[0] = b'+';
+        1
+    } else {
+        0
+    };
+    index + write_magnitude::<FORMAT>(value.unsigned_abs(), &mut buffer[index..])
+}
@@ -52,11 +52,13 @@
 mod index;
 
 pub mod algorithm;
+pub mod bigint;
 pub mod compact;
 pub mod decimal;
 pub mod options;
 pub mod radix;
 pub mod table;
+pub mod wide;
 pub mod write;
 
 mod api;
@@ -68,6 +70,9 @@ mod table_radix;
 pub use self::api::{ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+#[cfg(feature = "std")]
+pub use lexical_util::bigint::Bigint;
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::WriteOptions;
+pub use lexical_util::wide::{Int, Uint, I256, I512, U256, U512};
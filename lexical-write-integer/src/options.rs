@@ -3,6 +3,7 @@
 //! This is a dummy implementation, since writing integers never have options.
 
 use lexical_util::constants::FormattedSize;
+use lexical_util::format::NumberFormat;
 use lexical_util::options::WriteOptions;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -108,9 +109,25 @@ impl WriteOptions for Options {
         Self::is_valid(self)
     }
 
+    /// Get an upper bound on the buffer size.
+    ///
+    /// Decimal integers are never longer than `FORMATTED_SIZE_DECIMAL`,
+    /// which the writers already accept for decimal formats, so larger
+    /// buffers are only required for other radixes. The decimal sizes of
+    /// unsigned integers have no room for a sign, so a required sign
+    /// needs an extra byte.
     #[inline(always)]
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        T::FORMATTED_SIZE
+        let format = NumberFormat::<{ FORMAT }> {};
+        let size = match format.radix() {
+            10 => T::FORMATTED_SIZE_DECIMAL,
+            _ => T::FORMATTED_SIZE,
+        };
+        if cfg!(feature = "format") && format.required_mantissa_sign() {
+            size.saturating_add(1)
+        } else {
+            size
+        }
     }
 }
 
@@ -44,7 +44,25 @@ macro_rules! radix_unimpl {
     )*);
 }
 
-radix_unimpl! { u8 u16 usize }
+radix_unimpl! { usize }
+
+// Write small types as a `u32`, which cannot overflow.
+macro_rules! radix_widen {
+    ($($t:ty)*) => ($(
+        impl Radix for $t {
+            #[inline(always)]
+            unsafe fn radix<const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
+                self,
+                buffer: &mut [u8]
+            ) -> usize {
+                // SAFETY: safe as long as buffer is large enough to hold the max value.
+                unsafe { (self as u32).radix::<FORMAT, MASK, SHIFT>(buffer) }
+            }
+        }
+    )*);
+}
+
+radix_widen! { u8 u16 }
 
 // Implement radix for type.
 macro_rules! radix_impl {
@@ -0,0 +1,235 @@
+//! Radix-generic integer-to-string conversion routines for wide integers.
+//!
+//! Wide integers are written by chunked division: each division by the
+//! largest power of the radix that fits in a limb yields a limb of
+//! digits, which is written using the native integer algorithms. All but
+//! the most-significant chunk are padded with leading zeros. The digits
+//! are written from the end of the buffer, and then moved to the start,
+//! so no temporary buffers are required.
+
+#![doc(hidden)]
+
+#[cfg(not(feature = "compact"))]
+use crate::algorithm::write_digits;
+#[cfg(feature = "compact")]
+use crate::compact::write_digits;
+#[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
+use crate::table::get_table;
+#[cfg(all(not(feature = "compact"), not(feature = "power-of-two")))]
+use crate::table::DIGIT_TO_BASE10_SQUARED;
+use crate::write;
+#[cfg(not(feature = "compact"))]
+use lexical_util::div128::u128_divrem;
+use lexical_util::format::{self, radix_from_flags, NumberFormat};
+#[cfg(feature = "compact")]
+use lexical_util::num::AsPrimitive;
+use lexical_util::num::UnsignedInteger;
+#[cfg(not(feature = "compact"))]
+use lexical_util::step::u64_step;
+#[cfg(feature = "compact")]
+use lexical_util::wide::limb_power;
+use lexical_util::wide::{Int, Uint};
+
+/// Divide the value by a power of the radix, a limb of digits at a time.
+///
+/// Returns the remainder and the number of digits it represents.
+#[inline]
+#[cfg(not(feature = "compact"))]
+fn divrem_chunk<const N: usize>(value: &mut Uint<N>, radix: u32) -> (u64, usize) {
+    // Use the fast, 128-bit division algorithms: the high limb of each
+    // step is the previous remainder, which is always smaller than the
+    // divisor, so each quotient fits in a limb.
+    let mut rem: u64 = 0;
+    for xi in value.as_limbs_mut().iter_mut().rev() {
+        let n = ((rem as u128) << 64) | (*xi as u128);
+        let (quot, r) = u128_divrem(n, radix);
+        *xi = quot as u64;
+        rem = r;
+    }
+    (rem, u64_step(radix))
+}
+
+/// Divide the value by a power of the radix, a limb of digits at a time.
+///
+/// Returns the remainder and the number of digits it represents.
+#[inline]
+#[cfg(feature = "compact")]
+fn divrem_chunk<const N: usize>(value: &mut Uint<N>, radix: u32) -> (u64, usize) {
+    let (power, step) = limb_power(radix);
+    (value.div_small(power.as_u64()), step)
+}
+
+/// Write the digits of a single limb, returning the start of the digits.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold the digits before `index`.
+#[inline]
+pub(crate) unsafe fn write_limb<
+    T: UnsignedInteger,
+    const FORMAT: u128,
+    const MASK: u128,
+    const SHIFT: i32,
+>(
+    value: T,
+    buffer: &mut [u8],
+    index: usize,
+) -> usize {
+    let radix = radix_from_flags(FORMAT, MASK, SHIFT);
+    #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
+    let table = unsafe { get_table::<FORMAT, MASK, SHIFT>() };
+    #[cfg(all(not(feature = "compact"), not(feature = "power-of-two")))]
+    let table = &DIGIT_TO_BASE10_SQUARED;
+
+    // SAFETY: safe as long as the buffer can hold the digits.
+    #[cfg(not(feature = "compact"))]
+    return unsafe { write_digits(value, radix, table, buffer, index) };
+
+    // SAFETY: safe as long as the buffer can hold the digits.
+    #[cfg(feature = "compact")]
+    return unsafe { write_digits(value, radix, buffer, index) };
+}
+
+/// Get the number of digits written for an unsigned, wide integer in a radix.
+#[inline]
+pub fn digit_count<const N: usize>(value: Uint<N>, radix: u32) -> usize {
+    if let Some(value) = value.to_limb() {
+        return write::digit_count(value, radix);
+    }
+    if radix.is_power_of_two() {
+        // Each digit holds the same number of bits.
+        let limbs = value.as_limbs();
+        let index = limbs.iter().rposition(|&x| x != 0).unwrap_or(0);
+        let bits = index * 64 + (64 - limbs[index].leading_zeros() as usize);
+        let bits_per_digit = radix.trailing_zeros() as usize;
+        (bits - 1) / bits_per_digit + 1
+    } else {
+        // Compare against powers of the radix, until the power overflows.
+        let mut count = 1;
+        let mut power = Uint::<N>::from_u128(radix as u128);
+        while value >= power {
+            count += 1;
+            if power.mul_small_add(radix as u64, 0) != 0 {
+                break;
+            }
+        }
+        count
+    }
+}
+
+/// Write an unsigned, wide integer to the buffer.
+///
+/// Returns the number of digits written.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold [`FORMATTED_SIZE`] elements
+/// (or [`FORMATTED_SIZE_DECIMAL`] for decimal).
+///
+/// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
+/// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+pub unsafe fn write_wide<const N: usize, const FORMAT: u128, const MASK: u128, const SHIFT: i32>(
+    mut value: Uint<N>,
+    buffer: &mut [u8],
+) -> usize {
+    let radix = radix_from_flags(FORMAT, MASK, SHIFT);
+    let mut index = buffer.len();
+    loop {
+        if let Some(value) = value.to_limb() {
+            // SAFETY: safe as long as the buffer can hold the digits.
+            index = unsafe { write_limb::<_, FORMAT, MASK, SHIFT>(value, buffer, index) };
+            break;
+        }
+
+        let (rem, step) = divrem_chunk(&mut value, radix);
+        let start = index;
+        // SAFETY: safe as long as the buffer can hold the digits.
+        index = unsafe { write_limb::<_, FORMAT, MASK, SHIFT>(rem, buffer, index) };
+        // Pad the chunk with leading zeros.
+        let end = start - step;
+        // SAFETY: safe since `end <= index <= buffer.len()`.
+        unsafe {
+            let zeros = &mut index_unchecked_mut!(buffer[end..index]);
+            slice_fill_unchecked!(zeros, b'0');
+        }
+        index = end;
+    }
+
+    // Move the digits to the start of the buffer.
+    let count = buffer.len() - index;
+    buffer.copy_within(index.., 0);
+    count
+}
+
+/// Write an unsigned, wide integer with an optional sign to the buffer.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
+/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+#[inline]
+pub unsafe fn unsigned_wide<const N: usize, const FORMAT: u128>(
+    value: Uint<N>,
+    buffer: &mut [u8],
+) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    if cfg!(feature = "format") && format.required_mantissa_sign() {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+        unsafe {
+            index_unchecked_mut!(buffer[0]) = b'+';
+            let buffer = &mut index_unchecked_mut!(buffer[1..]);
+            write_wide::<N, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(value, buffer) + 1
+        }
+    } else {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+        unsafe {
+            write_wide::<N, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(value, buffer)
+        }
+    }
+}
+
+/// Write a signed, wide integer to the buffer.
+///
+/// # Safety
+///
+/// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
+/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+#[inline]
+pub unsafe fn signed_wide<const N: usize, const FORMAT: u128>(
+    value: Int<N>,
+    buffer: &mut [u8],
+) -> usize {
+    if value.is_negative() {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+        unsafe {
+            index_unchecked_mut!(buffer[0]) = b'-';
+            let buffer = &mut index_unchecked_mut!(buffer[1..]);
+            write_wide::<N, FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(
+                value.unsigned_abs(),
+                buffer,
+            ) + 1
+        }
+    } else {
+        // SAFETY: safe as long as there is at least `FORMATTED_SIZE` elements.
+        unsafe { unsigned_wide::<N, FORMAT>(value.to_bits(), buffer) }
+    }
+}
+
+/// Get the number of bytes written for an unsigned, wide integer with an optional sign.
+#[inline]
+pub fn unsigned_wide_len<const N: usize, const FORMAT: u128>(value: Uint<N>) -> usize {
+    let format = NumberFormat::<FORMAT> {};
+    let sign = cfg!(feature = "format") && format.required_mantissa_sign();
+    sign as usize + digit_count(value, format.radix())
+}
+
+/// Get the number of bytes written for a signed, wide integer.
+#[inline]
+pub fn signed_wide_len<const N: usize, const FORMAT: u128>(value: Int<N>) -> usize {
+    if value.is_negative() {
+        let format = NumberFormat::<FORMAT> {};
+        1 + digit_count(value.unsigned_abs(), format.radix())
+    } else {
+        unsigned_wide_len::<N, FORMAT>(value.to_bits())
+    }
+}
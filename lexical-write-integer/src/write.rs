@@ -10,6 +10,7 @@ use crate::decimal::Decimal;
 #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
 use crate::radix::Radix;
 use lexical_util::format;
+use lexical_util::num::UnsignedInteger;
 
 /// Define the implementation to write significant digits.
 macro_rules! write_mantissa {
@@ -156,3 +157,27 @@ macro_rules! write_integer_impl {
 }
 
 write_integer_impl! { u8 u16 u32 u64 u128 usize }
+
+/// Get the number of digits written for an unsigned integer in a radix.
+#[inline]
+pub fn digit_count<T: UnsignedInteger>(value: T, radix: u32) -> usize {
+    if radix.is_power_of_two() {
+        // Each digit holds the same number of bits.
+        let bits = (T::BITS - value.leading_zeros() as usize).max(1);
+        let bits_per_digit = radix.trailing_zeros() as usize;
+        (bits - 1) / bits_per_digit + 1
+    } else {
+        // Compare against powers of the radix, until the power overflows.
+        let radix = T::from_u32(radix);
+        let mut count = 1;
+        let mut power = radix;
+        while value >= power {
+            count += 1;
+            match power.checked_mul(radix) {
+                Some(next) => power = next,
+                None => break,
+            }
+        }
+        count
+    }
+}
@@ -217,6 +217,69 @@ fn options_radix_test() {
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
 }
 
+fn check_written_len<T: ToLexical + Copy>(value: T) {
+    let mut buffer = [b'\x00'; 48];
+    let length = value.written_len();
+    assert_eq!(length, value.to_lexical(&mut buffer).len());
+
+    // Nothing is written past the end of an exactly-sized buffer.
+    let mut exact = [b'\xff'; 48];
+    unsafe { value.to_lexical_unchecked(&mut exact[..length]) };
+    assert_eq!(exact[..length], buffer[..length]);
+    assert!(exact[length..].iter().all(|&x| x == b'\xff'));
+}
+
+#[test]
+fn written_len_test() {
+    for &i in [0u64, 1, 9, 10, 99, 100, 12345, u64::MAX / 10, u64::MAX].iter() {
+        check_written_len(i);
+        check_written_len(i as u8);
+        check_written_len(i as u16);
+        check_written_len(i as u32);
+        check_written_len(i as u128);
+        check_written_len(i as usize);
+        check_written_len(i as i8);
+        check_written_len(i as i16);
+        check_written_len(i as i32);
+        check_written_len(i as i64);
+        check_written_len(i as isize);
+        check_written_len(-(i as i128));
+    }
+    check_written_len(u128::MAX);
+    check_written_len(i128::MIN);
+    check_written_len(i128::MAX);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn written_len_radix_test() {
+    const BASE3: u128 = from_radix(3);
+    const BASE32: u128 = from_radix(32);
+    let options = Options::new();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for &i in [0i128, 1, 2, 3, 26, 27, -27, 1 << 100, i128::MIN, i128::MAX].iter() {
+        let length = i.written_len_with_options::<BASE3>(&options).unwrap();
+        assert_eq!(length, i.to_lexical_with_options::<BASE3>(&mut buffer, &options).len());
+        let length = i.written_len_with_options::<BASE32>(&options).unwrap();
+        assert_eq!(length, i.to_lexical_with_options::<BASE32>(&mut buffer, &options).len());
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn written_len_format_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let options = Options::new();
+    assert_eq!(0u8.written_len_with_options::<FORMAT>(&options), Ok(2));
+    assert_eq!((-1i8).written_len_with_options::<FORMAT>(&options), Ok(2));
+    assert_eq!(100i32.written_len_with_options::<FORMAT>(&options), Ok(4));
+
+    const INVALID: u128 =
+        NumberFormatBuilder::new().no_positive_mantissa_sign(true).required_mantissa_sign(true).build();
+    let result = 0u8.written_len_with_options::<INVALID>(&options);
+    assert_eq!(result, Err(lexical_util::error::Error::InvalidMantissaSign));
+}
+
 fn roundtrip<T>(x: T) -> T
 where
     T: Roundtrip,
@@ -0,0 +1,130 @@
+#![cfg(feature = "std")]
+
+#[cfg(feature = "power-of-two")]
+mod util;
+
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_write_integer::{Bigint, ToLexical};
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_write_integer::{Options, ToLexicalWithOptions};
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+/// Write the big integer to a new buffer.
+fn write(value: &Bigint) -> Vec<u8> {
+    let mut buffer = vec![0u8; value.formatted_len()];
+    let len = value.to_lexical(&mut buffer).len();
+    buffer.truncate(len);
+    buffer
+}
+
+/// Write the digits of a positive integer a single digit at a time, in quadratic time.
+fn write_slow(value: &Bigint, radix: u32) -> Vec<u8> {
+    let mut value = value.clone();
+    let mut digits = Vec::new();
+    while !value.is_zero() {
+        let digit = value.div_small(radix as _) as u32;
+        digits.push(char::from_digit(digit, radix).unwrap().to_ascii_uppercase() as u8);
+    }
+    digits.reverse();
+    digits
+}
+
+#[test]
+fn write_test() {
+    assert_eq!(write(&Bigint::new()), b"0");
+    assert_eq!(write(&Bigint::from(1u32)), b"1");
+    assert_eq!(write(&Bigint::from(-12i32)), b"-12");
+    assert_eq!(write(&Bigint::from(u128::MAX)), b"340282366920938463463374607431768211455");
+    assert_eq!(write(&Bigint::from(i128::MIN)), b"-170141183460469231731687303715884105728");
+    assert_eq!(
+        write(&(Bigint::from(u128::MAX) * Bigint::from(u128::MAX))),
+        b"115792089237316195423570985008687907852589419931798687112530834793049593217025".as_ref()
+    );
+}
+
+#[test]
+fn write_large_test() {
+    // Large enough to use divide-and-conquer conversion, with zeros
+    // in the lower halves that must be padded.
+    let mut expected = vec![b'1'];
+    expected.extend_from_slice(&[b'0'; 10000]);
+    assert_eq!(write(&Bigint::from(10u32).pow(10000)), expected);
+    let mut expected = vec![b'-'];
+    expected.extend_from_slice(&[b'9'; 10000]);
+    assert_eq!(write(&(Bigint::from(1u32) - Bigint::from(10u32).pow(10000))), expected);
+
+    for &count in [100, 1000, 4321, 10000].iter() {
+        let value = Bigint::from(7u32).pow(count);
+        let digits = write(&value);
+        assert!(digits.len() <= value.formatted_len());
+        assert_eq!(digits, write_slow(&value, 10));
+    }
+}
+
+#[test]
+fn written_len_test() {
+    assert_eq!(Bigint::new().written_len(), 1);
+    assert_eq!(Bigint::from(-12i32).written_len(), 3);
+    for &count in [1, 38, 39, 100, 1000, 4321].iter() {
+        let value = Bigint::from(10u32).pow(count);
+        assert_eq!(value.written_len(), write(&value).len());
+        let value = Bigint::from(1u32) - value;
+        assert_eq!(value.written_len(), write(&value).len());
+    }
+
+    // The exact length fits when the upper bound does not.
+    let value = Bigint::from(7u32).pow(1000);
+    let mut buffer = vec![0u8; value.written_len()];
+    assert!(buffer.len() < value.formatted_len());
+    assert_eq!((&value).to_lexical(&mut buffer), write_slow(&value, 10));
+}
+
+#[test]
+#[should_panic]
+fn write_buffer_test() {
+    let value = Bigint::from(10u32).pow(1000);
+    let mut buffer = [0u8; 1000];
+    value.to_lexical(&mut buffer);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn write_radix_test() {
+    let options = Options::new();
+    const HEX: u128 = from_radix(16);
+    const BINARY: u128 = from_radix(2);
+    let value = (Bigint::from(1u32) << 12000) - Bigint::from(1u32);
+    let mut buffer = vec![0u8; value.formatted_len_with_options::<HEX>(&options)];
+    assert_eq!(value.to_lexical_with_options::<HEX>(&mut buffer, &options), [b'F'; 3000].as_ref());
+
+    let value = -(Bigint::from(1u32) << 70);
+    let mut buffer = vec![0u8; value.formatted_len_with_options::<BINARY>(&options)];
+    let mut expected = vec![b'-', b'1'];
+    expected.extend_from_slice(&[b'0'; 70]);
+    assert_eq!(value.to_lexical_with_options::<BINARY>(&mut buffer, &options), expected);
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn write_radix36_test() {
+    let options = Options::new();
+    const BASE36: u128 = from_radix(36);
+    let value = Bigint::from(7u32).pow(6000);
+    let mut buffer = vec![0u8; value.formatted_len_with_options::<BASE36>(&options)];
+    let digits = (&value).to_lexical_with_options::<BASE36>(&mut buffer, &options);
+    assert_eq!(digits, write_slow(&value, 36));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn write_format_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let value = Bigint::from(10u32).pow(50);
+    let mut buffer = vec![0u8; value.formatted_len_with_options::<FORMAT>(&options)];
+    let digits = value.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+    assert_eq!(digits[..2], *b"+1");
+    assert_eq!(digits.len(), 52);
+}
@@ -1,4 +1,10 @@
+use lexical_util::constants::FormattedSize;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_util::format::NumberFormatBuilder;
+use lexical_util::format::STANDARD;
+use lexical_util::options::WriteOptions;
 use lexical_write_integer::options::{Options, OptionsBuilder};
+use lexical_write_integer::ToLexicalWithOptions;
 
 #[test]
 fn options_tests() {
@@ -10,3 +16,44 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
 }
+
+#[test]
+fn buffer_size_test() {
+    let options = Options::new();
+    assert_eq!(options.buffer_size::<u8, STANDARD>(), u8::FORMATTED_SIZE_DECIMAL);
+    assert_eq!(options.buffer_size::<i128, STANDARD>(), i128::FORMATTED_SIZE_DECIMAL);
+
+    // The decimal size is enough for every value, even if `FORMATTED_SIZE` is larger.
+    let mut buffer = [b'\x00'; u64::FORMATTED_SIZE_DECIMAL];
+    let digits = u64::MAX.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+    assert_eq!(digits, b"18446744073709551615");
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn buffer_size_radix_test() {
+    const BINARY: u128 = NumberFormatBuilder::binary();
+    let options = Options::new();
+    assert_eq!(options.buffer_size::<u8, BINARY>(), u8::FORMATTED_SIZE);
+    assert!(options.buffer_size::<u8, BINARY>() > u8::FORMATTED_SIZE_DECIMAL);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn buffer_size_sign_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let options = Options::new();
+    assert_eq!(options.buffer_size::<u8, FORMAT>(), u8::FORMATTED_SIZE_DECIMAL + 1);
+    let mut buffer = [b'\x00'; u8::FORMATTED_SIZE_DECIMAL + 1];
+    assert_eq!(b"+255", 255u8.to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "format")]
+fn buffer_size_sign_small_buffer_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let options = Options::new();
+    let mut buffer = [b'\x00'; u8::FORMATTED_SIZE_DECIMAL];
+    255u8.to_lexical_with_options::<FORMAT>(&mut buffer, &options);
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "power-of-two")]
+mod util;
+
+use lexical_util::constants::FormattedSize;
+#[cfg(feature = "format")]
+use lexical_util::format::NumberFormatBuilder;
+#[cfg(any(feature = "format", feature = "power-of-two"))]
+use lexical_write_integer::{Options, ToLexicalWithOptions};
+use lexical_write_integer::{ToLexical, I256, I512, U256, U512};
+#[cfg(feature = "power-of-two")]
+use util::from_radix;
+
+const U256_MAX: &[u8] =
+    b"115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MIN: &[u8] =
+    b"-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_MAX: &[u8] =
+    b"57896044618658097711785492504343953926634992332820282019728792003956564819967";
+
+/// Calculate `10^exp` as a wide integer.
+fn pow10(exp: u32) -> U256 {
+    let mut value = U256::from_u128(1);
+    for _ in 0..exp {
+        value.mul_small_add(10, 0);
+    }
+    value
+}
+
+#[test]
+fn formatted_size_test() {
+    assert!(U256::FORMATTED_SIZE_DECIMAL >= U256_MAX.len());
+    assert!(I256::FORMATTED_SIZE_DECIMAL >= I256_MIN.len());
+    assert_eq!(U512::FORMATTED_SIZE_DECIMAL, 156);
+    assert_eq!(I512::FORMATTED_SIZE_DECIMAL, 157);
+    #[cfg(feature = "power-of-two")]
+    assert_eq!(I256::FORMATTED_SIZE, 513);
+}
+
+#[test]
+fn write_unsigned_test() {
+    let mut buffer = [b'\x00'; U512::FORMATTED_SIZE];
+    assert_eq!(b"0", U256::ZERO.to_lexical(&mut buffer));
+    assert_eq!(b"1", U256::from_u128(1).to_lexical(&mut buffer));
+    assert_eq!(b"12345", U256::from_u128(12345).to_lexical(&mut buffer));
+    assert_eq!(
+        b"340282366920938463463374607431768211455",
+        U256::from_u128(u128::MAX).to_lexical(&mut buffer)
+    );
+    assert_eq!(U256_MAX, U256::MAX.to_lexical(&mut buffer));
+
+    // Internal chunks must be padded with zeros.
+    assert_eq!(b"10000000000000000000", pow10(19).to_lexical(&mut buffer));
+    assert_eq!(b"100000000000000000000", pow10(20).to_lexical(&mut buffer));
+    let mut expected = vec![b'1'];
+    expected.extend_from_slice(&[b'0'; 76]);
+    assert_eq!(&expected[..], pow10(76).to_lexical(&mut buffer));
+
+    let mut value = pow10(60);
+    value.mul_small_add(1, 7);
+    let mut expected = vec![b'1'];
+    expected.extend_from_slice(&[b'0'; 59]);
+    expected.push(b'7');
+    assert_eq!(&expected[..], value.to_lexical(&mut buffer));
+
+    let mut max = [b'\x00'; U512::FORMATTED_SIZE_DECIMAL];
+    let written = U512::MAX.to_lexical(&mut max);
+    assert_eq!(written.len(), 155);
+    assert!(written.starts_with(b"134078079299425970995740249982058461274793658205923933"));
+}
+
+#[test]
+fn write_signed_test() {
+    let mut buffer = [b'\x00'; I256::FORMATTED_SIZE];
+    assert_eq!(b"0", I256::ZERO.to_lexical(&mut buffer));
+    assert_eq!(b"-1", I256::from_i128(-1).to_lexical(&mut buffer));
+    assert_eq!(
+        b"-170141183460469231731687303715884105728",
+        I256::from_i128(i128::MIN).to_lexical(&mut buffer)
+    );
+    assert_eq!(I256_MIN, I256::MIN.to_lexical(&mut buffer));
+    assert_eq!(I256_MAX, I256::MAX.to_lexical(&mut buffer));
+}
+
+#[test]
+fn written_len_test() {
+    let mut buffer = [b'\x00'; I512::FORMATTED_SIZE_DECIMAL];
+    for exp in [0, 1, 19, 20, 38, 39, 40, 76, 77] {
+        let value = pow10(exp);
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+        let value = I256::from_bits(value);
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+        let value = value.wrapping_neg();
+        assert_eq!(value.written_len(), value.to_lexical(&mut buffer).len());
+    }
+    assert_eq!(U256::ZERO.written_len(), 1);
+    assert_eq!(U256::MAX.written_len(), U256_MAX.len());
+    assert_eq!(I256::MIN.written_len(), I256_MIN.len());
+    assert_eq!(I256::MAX.written_len(), I256_MAX.len());
+
+    // Nothing is written past the end of an exactly-sized buffer.
+    let mut exact = [b'\xff'; I256::FORMATTED_SIZE_DECIMAL];
+    let length = I256::MIN.written_len();
+    let bytes = unsafe { I256::MIN.to_lexical_unchecked(&mut exact[..length]) };
+    assert_eq!(I256_MIN, bytes);
+    assert!(exact[length..].iter().all(|&x| x == b'\xff'));
+}
+
+#[test]
+#[should_panic]
+fn write_buffer_test() {
+    let mut buffer = [b'\x00'; 64];
+    U256::MAX.to_lexical(&mut buffer);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn write_radix_test() {
+    let mut buffer = [b'\x00'; I256::FORMATTED_SIZE];
+    let options = Options::new();
+    const HEX: u128 = from_radix(16);
+    const BINARY: u128 = from_radix(2);
+    let hex = [b'F'; 64];
+    assert_eq!(&hex[..], U256::MAX.to_lexical_with_options::<HEX>(&mut buffer, &options));
+    let binary = [b'1'; 256];
+    assert_eq!(&binary[..], U256::MAX.to_lexical_with_options::<BINARY>(&mut buffer, &options));
+
+    let mut expected = vec![b'-', b'8'];
+    expected.extend_from_slice(&[b'0'; 63]);
+    assert_eq!(&expected[..], I256::MIN.to_lexical_with_options::<HEX>(&mut buffer, &options));
+
+    // 2^128 in hexadecimal has internal zero chunks.
+    let mut value = U256::from_u128(u128::MAX);
+    value.mul_small_add(1, 1);
+    let mut expected = vec![b'1'];
+    expected.extend_from_slice(&[b'0'; 32]);
+    assert_eq!(&expected[..], value.to_lexical_with_options::<HEX>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn write_radix36_test() {
+    let mut buffer = [b'\x00'; U256::FORMATTED_SIZE];
+    let options = Options::new();
+    const BASE36: u128 = from_radix(36);
+    assert_eq!(
+        b"6DP5QCB22IM238NR3WVP0IC7Q99W035JMY2IW7I6N43D37JTOF",
+        U256::MAX.to_lexical_with_options::<BASE36>(&mut buffer, &options)
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn mandatory_sign_test() {
+    let mut buffer = [b'\x00'; I256::FORMATTED_SIZE];
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    assert_eq!(b"+0", U256::ZERO.to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(b"+1", I256::from_i128(1).to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(b"-1", I256::from_i128(-1).to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+}
@@ -0,0 +1,506 @@
+//! Format numbers with lexical in format strings.
+
+#![cfg(feature = "write")]
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(feature = "write-floats")]
+use core::cmp;
+use core::{fmt, str};
+#[cfg(feature = "write-floats")]
+use lexical_core::format::NumberFormat;
+use lexical_core::format::STANDARD;
+#[cfg(feature = "write-floats")]
+use lexical_core::write_float_shared::cmp_exact;
+#[cfg(all(feature = "write-integers", feature = "power-of-two"))]
+use lexical_core::NumberFormatBuilder;
+#[cfg(feature = "write-floats")]
+use lexical_core::WriteFloatOptions;
+#[cfg(feature = "write-integers")]
+use lexical_core::WriteIntegerOptions;
+use lexical_core::{ToLexicalWithOptions, BUFFER_SIZE};
+#[cfg(feature = "std")]
+use std::vec;
+
+/// Adapter to use lexical's writers in format strings.
+///
+/// Implements [`Display`] for all numbers, [`LowerHex`] and [`UpperHex`]
+/// for integers, and [`LowerExp`] for floats, writing the number into a
+/// stack buffer. The width, fill, alignment, sign and zero-padding flags
+/// of the formatter are always respected. For floats, the precision sets
+/// the number of digits after the decimal point, or in the significand
+/// for [`LowerExp`], like for the standard library's formatters.
+///
+/// The digits are rounded like the exact value of the float, so `0.35`
+/// with a precision of 1 is `0.3`, since the exact value is
+/// `0.34999999999999997779...`. Unlike the standard library, any digits
+/// past the shortest representation of the float that round-trips are
+/// zeros, so `0.1` with a precision of 20 is `0.10000000000000000000`,
+/// rather than `0.10000000000000000555`.
+///
+/// Precision and [`LowerExp`] require decimal floats: for other radixes,
+/// the precision is ignored, and [`LowerExp`] is the same as [`Display`].
+/// [`LowerHex`] and [`UpperHex`] always write hexadecimal integers in the
+/// standard format, and write negative integers in two's complement,
+/// like the standard library's formatters.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical::Fmt;
+///
+/// assert_eq!(format!("{}", Fmt::new(1.5f64)), "1.5");
+/// assert_eq!(format!("[{:>+8.2}]", Fmt::new(3.14159f64)), "[   +3.14]");
+/// assert_eq!(format!("{:e}", Fmt::new(1234.5f64)), "1.2345e3");
+///
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(format!("{:06}", Fmt::<_, FORMAT>::with_options(-2.0f64, options)), "-00002");
+/// # }
+/// # }
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`LowerHex`]: core::fmt::LowerHex
+/// [`UpperHex`]: core::fmt::UpperHex
+/// [`LowerExp`]: core::fmt::LowerExp
+pub struct Fmt<T: ToLexicalWithOptions, const FORMAT: u128> {
+    /// The number to format.
+    value: T,
+    /// The options used to write the number.
+    options: T::Options,
+}
+
+impl<T: ToLexicalWithOptions> Fmt<T, STANDARD> {
+    /// Create an adapter to format a number with the default options.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            options: T::Options::default(),
+        }
+    }
+}
+
+impl<T: ToLexicalWithOptions, const FORMAT: u128> Fmt<T, FORMAT> {
+    /// Create an adapter to format a number with custom formats and options.
+    ///
+    /// If the provided `FORMAT` is not valid, formatting the number may
+    /// panic. Please ensure `is_valid()` is called prior to using the
+    /// format, or checking its validity using a static assertion.
+    #[inline]
+    pub fn with_options(value: T, options: T::Options) -> Self {
+        Self {
+            value,
+            options,
+        }
+    }
+
+    /// Get the number to format.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Get the options used to write the number.
+    #[inline]
+    pub fn options(&self) -> &T::Options {
+        &self.options
+    }
+}
+
+// HELPERS
+
+/// Call the callback with a zeroed buffer of at least `size` bytes.
+///
+/// Uses a stack buffer unless the size is larger than any formatted number.
+#[inline]
+pub(crate) fn with_buffer<R, Cb: FnOnce(&mut [u8]) -> R>(size: usize, cb: Cb) -> R {
+    if size <= BUFFER_SIZE {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        cb(&mut buffer)
+    } else {
+        let mut buffer = vec![0u8; size];
+        cb(&mut buffer)
+    }
+}
+
+/// Split the leading minus sign from the written number.
+#[inline]
+fn split_sign(bytes: &[u8]) -> (bool, &[u8]) {
+    match bytes.split_first() {
+        Some((&b'-', rest)) => (false, rest),
+        _ => (true, bytes),
+    }
+}
+
+/// Write the unsigned digits with the sign, padding and alignment of the formatter.
+#[inline]
+fn pad(
+    formatter: &mut fmt::Formatter<'_>,
+    is_nonnegative: bool,
+    prefix: &str,
+    digits: &[u8],
+) -> fmt::Result {
+    // SAFETY: safe since the written number is always valid UTF-8.
+    let digits = unsafe { str::from_utf8_unchecked(digits) };
+    formatter.pad_integral(is_nonnegative, prefix, digits)
+}
+
+// INTEGER
+
+/// Write an integer with the formatter.
+#[inline]
+#[cfg(feature = "write-integers")]
+fn write_integer<T, const FORMAT: u128>(
+    value: T,
+    options: &WriteIntegerOptions,
+    formatter: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    case: Option<bool>,
+) -> fmt::Result
+where
+    T: ToLexicalWithOptions<Options = WriteIntegerOptions>,
+{
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = value.to_lexical_with_options::<FORMAT>(&mut buffer, options);
+    match case {
+        Some(true) => bytes.make_ascii_uppercase(),
+        Some(false) => bytes.make_ascii_lowercase(),
+        None => (),
+    }
+    let (is_nonnegative, digits) = split_sign(bytes);
+    let prefix = if formatter.alternate() {
+        prefix
+    } else {
+        ""
+    };
+    pad(formatter, is_nonnegative, prefix, digits)
+}
+
+/// Number format for hexadecimal integers.
+#[cfg(all(feature = "write-integers", feature = "power-of-two"))]
+const HEXADECIMAL: u128 = NumberFormatBuilder::hexadecimal();
+
+/// Implement the formatting traits for integers.
+#[cfg(feature = "write-integers")]
+macro_rules! integer_fmt {
+    ($($t:ident $unsigned:ident ; )*) => ($(
+        impl<const FORMAT: u128> fmt::Display for Fmt<$t, FORMAT> {
+            #[inline]
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_integer::<_, FORMAT>(self.value, &self.options, formatter, "", None)
+            }
+        }
+
+        #[cfg(feature = "power-of-two")]
+        impl<const FORMAT: u128> fmt::LowerHex for Fmt<$t, FORMAT> {
+            #[inline]
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = self.value as $unsigned;
+                write_integer::<_, HEXADECIMAL>(value, &self.options, formatter, "0x", Some(false))
+            }
+        }
+
+        #[cfg(feature = "power-of-two")]
+        impl<const FORMAT: u128> fmt::UpperHex for Fmt<$t, FORMAT> {
+            #[inline]
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = self.value as $unsigned;
+                write_integer::<_, HEXADECIMAL>(value, &self.options, formatter, "0x", Some(true))
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "write-integers")]
+integer_fmt! {
+    u8 u8 ;
+    u16 u16 ;
+    u32 u32 ;
+    u64 u64 ;
+    u128 u128 ;
+    usize usize ;
+    i8 u8 ;
+    i16 u16 ;
+    i32 u32 ;
+    i64 u64 ;
+    i128 u128 ;
+    isize usize ;
+}
+
+// FLOAT
+
+/// Notation to write a float with.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg(feature = "write-floats")]
+enum Notation {
+    /// Write the float with the options, or with a fixed precision.
+    Display,
+    /// Write the float in scientific notation.
+    Exponent,
+}
+
+/// Round the significant digits to `count` digits, like the exact value.
+///
+/// The digits must start with a spare byte, which is used if the carry
+/// adds another digit. Rounding only differs from rounding the exact
+/// value if the digits are exactly halfway, so `cmp_exact`, which
+/// compares the exact value to the digits, is only called then. Returns
+/// the rounded digits and the change to the position of the decimal point.
+#[cfg(feature = "write-floats")]
+fn round_digits<Cmp>(digits: &mut [u8], count: usize, cmp_exact: Cmp) -> (&mut [u8], i32)
+where
+    Cmp: FnOnce() -> cmp::Ordering,
+{
+    let end = count + 1;
+    if end >= digits.len() {
+        return (&mut digits[1..], 0);
+    }
+    let first = digits[end];
+    let is_above_half =
+        first > b'5' || (first == b'5' && digits[end + 1..].iter().any(|&c| c != b'0'));
+    let is_half = first == b'5' && !is_above_half;
+    let is_odd = count != 0 && (digits[count] - b'0') % 2 == 1;
+    let is_round_up = if is_half {
+        match cmp_exact() {
+            cmp::Ordering::Less => false,
+            cmp::Ordering::Equal => is_odd,
+            cmp::Ordering::Greater => true,
+        }
+    } else {
+        is_above_half
+    };
+    if !is_round_up {
+        return (&mut digits[1..end], 0);
+    }
+
+    // Round-up, and propagate the carry.
+    let mut index = end;
+    while index > 1 {
+        index -= 1;
+        if digits[index] == b'9' {
+            digits[index] = b'0';
+        } else {
+            digits[index] += 1;
+            return (&mut digits[1..end], 0);
+        }
+    }
+    // All digits were 9s (or no digits were kept): add a leading 1.
+    digits[0] = b'1';
+    (&mut digits[..end], 1)
+}
+
+/// Write a decimal float with the formatter, using a fixed precision or scientific notation.
+///
+/// The bytes must be the unsigned, written float, using the default
+/// notation. The digits buffer must be at least 1 longer than the bytes.
+#[cfg(feature = "write-floats")]
+fn write_decimal(
+    float: f64,
+    bytes: &[u8],
+    digits: &mut [u8],
+    is_nonnegative: bool,
+    options: &WriteFloatOptions,
+    formatter: &mut fmt::Formatter<'_>,
+    notation: Notation,
+) -> fmt::Result {
+    let decimal_point = options.decimal_point();
+    let exponent_char = options.exponent();
+
+    // Decompose the float into the significant digits and the position of
+    // the decimal point relative to the first digit.
+    let mut count = 0;
+    let mut point = 0i32;
+    let mut index = 0;
+    let mut in_fraction = false;
+    while index < bytes.len() {
+        let c = bytes[index];
+        if c == decimal_point {
+            in_fraction = true;
+        } else if c == exponent_char {
+            break;
+        } else if count == 0 && c == b'0' {
+            // Skip leading zeros.
+            point -= in_fraction as i32;
+        } else {
+            count += 1;
+            digits[count] = c;
+            point += !in_fraction as i32;
+        }
+        index += 1;
+    }
+    if index < bytes.len() {
+        let exponent = &bytes[index + 1..];
+        let (is_negative, exponent) = match exponent.split_first() {
+            Some((&b'-', rest)) => (true, rest),
+            Some((&b'+', rest)) => (false, rest),
+            _ => (false, exponent),
+        };
+        let value = exponent.iter().fold(0i32, |acc, &c| acc * 10 + (c - b'0') as i32);
+        point += if is_negative {
+            -value
+        } else {
+            value
+        };
+    }
+    // Trim trailing zeros, which are not significant.
+    while count > 0 && digits[count] == b'0' {
+        count -= 1;
+    }
+    let digits = &mut digits[..count + 1];
+    // The shortest representation has at most 17 digits, so it fits in a `u64`.
+    let mant = digits[1..].iter().fold(0u64, |acc, &c| acc * 10 + (c - b'0') as u64);
+    let exact = || cmp_exact(float, mant, point - count as i32);
+
+    match notation {
+        Notation::Display => {
+            // Zero, or rounded to zero, has no significant digits.
+            let precision = formatter.precision().unwrap_or(0);
+            let keep = (point as isize + precision as isize).max(-1);
+            let (digits, shift) = if count == 0 || keep < 0 {
+                (&mut digits[1..1], 0)
+            } else {
+                round_digits(digits, keep as usize, exact)
+            };
+            let point = if digits.is_empty() {
+                0
+            } else {
+                point + shift
+            };
+            let integer_count = point.max(1) as usize;
+            let size = integer_count + precision + (precision != 0) as usize;
+            with_buffer(size, |buffer| {
+                let digit = |index: i32| match index {
+                    _ if index < 0 => b'0',
+                    _ => digits.get(index as usize).map_or(b'0', |&c| c),
+                };
+                let integer_start = point - integer_count as i32;
+                for (i, c) in buffer[..integer_count].iter_mut().enumerate() {
+                    *c = digit(integer_start + i as i32);
+                }
+                if precision != 0 {
+                    buffer[integer_count] = decimal_point;
+                    let fraction = &mut buffer[integer_count + 1..size];
+                    for (i, c) in fraction.iter_mut().enumerate() {
+                        *c = digit(point + i as i32);
+                    }
+                }
+                pad(formatter, is_nonnegative, "", &buffer[..size])
+            })
+        },
+        Notation::Exponent => {
+            let precision = formatter.precision();
+            let (digits, shift) = match precision {
+                Some(_) if count == 0 => (&mut digits[1..1], 0),
+                Some(precision) => round_digits(digits, precision + 1, exact),
+                None => (&mut digits[1..], 0),
+            };
+            let significant = precision.map_or(digits.len().max(1), |x| x + 1);
+            let exponent = if digits.is_empty() {
+                0
+            } else {
+                point + shift - 1
+            };
+            // Significant digits, decimal point, exponent character and exponent.
+            let size = significant + 1 + 1 + 11;
+            with_buffer(size, |buffer| {
+                for (i, c) in buffer[..significant].iter_mut().enumerate() {
+                    *c = digits.get(i).map_or(b'0', |&c| c);
+                }
+                let mut index = significant;
+                if significant > 1 {
+                    buffer.copy_within(1..index, 2);
+                    buffer[1] = decimal_point;
+                    index += 1;
+                }
+                buffer[index] = b'e';
+                index += 1;
+                if exponent < 0 {
+                    buffer[index] = b'-';
+                    index += 1;
+                }
+                index += write_exponent(exponent.unsigned_abs(), &mut buffer[index..]);
+                pad(formatter, is_nonnegative, "", &buffer[..index])
+            })
+        },
+    }
+}
+
+/// Write the exponent digits, returning the number of written bytes.
+#[cfg(feature = "write-floats")]
+fn write_exponent(mut exponent: u32, bytes: &mut [u8]) -> usize {
+    let mut digits = [0u8; 10];
+    let mut index = digits.len();
+    loop {
+        index -= 1;
+        digits[index] = b'0' + (exponent % 10) as u8;
+        exponent /= 10;
+        if exponent == 0 {
+            break;
+        }
+    }
+    let count = digits.len() - index;
+    bytes[..count].copy_from_slice(&digits[index..]);
+    count
+}
+
+/// Write a float with the formatter.
+#[cfg(feature = "write-floats")]
+fn write_float<T, const FORMAT: u128>(
+    value: T,
+    options: &WriteFloatOptions,
+    formatter: &mut fmt::Formatter<'_>,
+    notation: Notation,
+) -> fmt::Result
+where
+    T: ToLexicalWithOptions<Options = WriteFloatOptions> + Into<f64> + Copy,
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    let is_decimal = format.mantissa_radix() == 10
+        && format.exponent_base() == 10
+        && format.exponent_radix() == 10;
+    let size = value.formatted_len_with_options::<FORMAT>(options);
+    with_buffer(size, |buffer| {
+        let bytes = value.to_lexical_with_options::<FORMAT>(buffer, options);
+        let (is_nonnegative, bytes) = split_sign(bytes);
+        let is_special = !matches!(bytes.first(), Some(c) if c.is_ascii_digit());
+        let is_default = notation == Notation::Display && formatter.precision().is_none();
+        if !is_decimal || is_special || is_default {
+            pad(formatter, is_nonnegative, "", bytes)
+        } else {
+            with_buffer(bytes.len() + 1, |digits| {
+                let float = value.into();
+                write_decimal(float, bytes, digits, is_nonnegative, options, formatter, notation)
+            })
+        }
+    })
+}
+
+/// Implement the formatting traits for floats.
+#[cfg(feature = "write-floats")]
+macro_rules! float_fmt {
+    ($($t:ident)*) => ($(
+        impl<const FORMAT: u128> fmt::Display for Fmt<$t, FORMAT> {
+            #[inline]
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_float::<_, FORMAT>(self.value, &self.options, formatter, Notation::Display)
+            }
+        }
+
+        impl<const FORMAT: u128> fmt::LowerExp for Fmt<$t, FORMAT> {
+            #[inline]
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_float::<_, FORMAT>(self.value, &self.options, formatter, Notation::Exponent)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "write-floats")]
+float_fmt! { f32 f64 }
@@ -41,6 +41,15 @@
 #![cfg_attr(feature = "write", doc = "")]
 #![cfg_attr(feature = "write", doc = " - [`to_string`]")]
 #![cfg_attr(feature = "write", doc = " - [`to_string_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_vec`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_vec_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_string`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_string_with_options`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_io`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_io_with_options`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_fmt`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_fmt_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`Fmt`]")]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -66,24 +75,32 @@
 //! ### std
 //!
 //! Enable use of the standard library. Currently, the standard library
-//! is not used for any functionality, and may be disabled without any
-//! change in functionality on stable.
+//! is only used for the arbitrary-precision `Bigint` and `Rational`,
+//! which require heap allocation, and writing to `io::Write` sinks.
 //!
 //! ### write-integers
 //!
-//! Enable support for writing integers to string.
+//! Enable support for writing integers to string, including the wide,
+//! fixed-width integers such as `U256` and `I512`, and the arbitrary-precision
+//! `Bigint` with the `std` feature.
 //!
 //! ### write-floats
 //!
-//! Enable support for writing floating-point numbers to string.
+//! Enable support for writing floating-point numbers, and the exact
+//! `Decimal`, to string.
 //!
 //! ### parse-integers
 //!
-//! Enable support for parsing integers from string.
+//! Enable support for parsing integers from string, including the wide,
+//! fixed-width integers such as `U256` and `I512`, and the arbitrary-precision
+//! `Bigint` with the `std` feature.
 //!
 //! ### parsing-floats
 //!
-//! Enable support for parsing floating-point numbers from string.
+//! Enable support for parsing floating-point numbers, and the exact
+//! `Decimal`, from string. With the `std` feature, this also supports
+//! parsing the exact `Rational`, with a big-integer numerator and a
+//! power-of-radix denominator, for floats of any radix.
 //!
 //! ### format
 //!
@@ -142,6 +159,54 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! ### saturating
+//!
+//! Implement the conversion traits for `core::num::Saturating` integers,
+//! which clamp on overflow when parsed. This requires Rust 1.74 or later.
+//! The other integer wrappers, `core::num::Wrapping` and the `NonZero*`
+//! types, are always supported.
+//!
+//! ### ieee-decimal
+//!
+//! Enable support for the IEEE 754-2008 decimal floats, `Decimal64` and
+//! `Decimal128`, in the binary integer decimal (BID) encoding. These are
+//! parsed with ties-to-even rounding, preserving the quantum, and written
+//! in their canonical, shortest form. This requires the `parse-floats` or
+//! `write-floats` features.
+//!
+//! ### half
+//!
+//! Enable support for the 16-bit floats from the `half` crate, `f16` and
+//! `bf16`. These are parsed with correct rounding and written in their
+//! shortest, round-trip form. This requires Rust 1.70 or later, and the
+//! `parse-floats` or `write-floats` features.
+//! The previous `f16` feature is kept as an alias for `half`.
+//!
+//! ### serde
+//!
+//! Add the `serde` module, to serialize and deserialize numbers as strings
+//! with `#[serde(with = "...")]`, using custom number formats and options.
+//! The `serde_module!` macro defines new modules for a number format and
+//! options, such as for digit separators or decimal commas. This requires
+//! the features to both write and parse the numbers, such as
+//! `write-integers` and `parse-integers`.
+//!
+//! ### nom
+//!
+//! Add the `nom` module, with number parsers for the `nom` parser
+//! combinator library. The parsers support any number format and options,
+//! for both `&str` and `&[u8]` inputs, and convert lexical's errors to
+//! nom errors. This requires the `parse-integers` or `parse-floats`
+//! features.
+//!
+//! ### num-traits
+//!
+//! Add the `num_traits` module, to parse numbers with lexical in generic
+//! code using `num-traits`. The `Lexical` wrapper implements `Num`, so
+//! `Num::from_str_radix` is correctly rounded for floats, and numbers in
+//! a runtime radix can be parsed with the `FromLexicalRadix` trait. This
+//! requires the `parse-integers` or `parse-floats` features.
+//!
 //! ### safe
 //!
 //! All numeric parsers are memory-safe by default, since parsing complex
@@ -251,6 +316,15 @@
 //!
 //! [`to_string`]: fn.to_string.html
 //! [`to_string_with_options`]: fn.to_string_with_options.html
+//! [`write_to_vec`]: crate::write_to_vec
+//! [`write_to_vec_with_options`]: crate::write_to_vec_with_options
+//! [`write_to_string`]: crate::write_to_string
+//! [`write_to_string_with_options`]: crate::write_to_string_with_options
+//! [`write_to_io`]: crate::write_to_io
+//! [`write_to_io_with_options`]: crate::write_to_io_with_options
+//! [`write_to_fmt`]: crate::write_to_fmt
+//! [`write_to_fmt_with_options`]: crate::write_to_fmt_with_options
+//! [`Fmt`]: crate::Fmt
 //! [`write_with_options`]: crate::write_with_options
 //! [`write_with_options_unchecked`]: crate::write_with_options_unchecked
 //! [`parse`]: crate::parse
@@ -278,43 +352,116 @@ extern crate alloc;
 use alloc::string::String;
 #[cfg(all(feature = "write", not(feature = "std")))]
 use alloc::vec::Vec;
+#[cfg(feature = "write")]
+use core::mem::MaybeUninit;
 #[cfg(all(feature = "write", feature = "std"))]
 use std::string::String;
 #[cfg(all(feature = "write", feature = "std"))]
 use std::vec::Vec;
 
+mod display;
+pub mod nom;
+pub mod num_traits;
+pub mod serde;
+mod sink;
+
+#[cfg(feature = "write")]
+pub use self::display::Fmt;
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::sink::{
+    write_to_fmt,
+    write_to_fmt_with_options,
+    write_to_io,
+    write_to_io_with_options,
+};
+
 pub use lexical_core::format::{self, NumberFormatBuilder};
+#[cfg(all(feature = "std", any(feature = "parse-integers", feature = "write-integers")))]
+pub use lexical_core::Bigint;
+#[cfg(any(feature = "parse-floats", feature = "write-floats"))]
+pub use lexical_core::Decimal;
 #[cfg(feature = "parse")]
 pub use lexical_core::Error;
 #[cfg(feature = "parse")]
 pub use lexical_core::ParseOptions;
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+pub use lexical_core::Rational;
 #[cfg(feature = "parse")]
 pub use lexical_core::Result;
+#[cfg(feature = "parse")]
+pub use lexical_core::{Suffix, SuffixMode};
+#[cfg(feature = "parse")]
+pub use lexical_core::WhitespaceMode;
 #[cfg(feature = "write")]
 pub use lexical_core::WriteOptions;
+#[cfg(feature = "parse")]
+pub use lexical_core::{parse_delimited, parse_delimited_with_options, FieldError};
+#[cfg(all(feature = "parse", feature = "std"))]
+pub use lexical_core::{parse_delimited_to_vec, parse_delimited_to_vec_with_options};
 #[cfg(feature = "parse-floats")]
 pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
 #[cfg(feature = "parse-integers")]
 pub use lexical_core::{parse_integer_options, ParseIntegerOptions, ParseIntegerOptionsBuilder};
+#[cfg(feature = "parse")]
+pub use lexical_core::{scan, scan_with_options, Scanner};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{
+    tokenize,
+    tokenize_partial,
+    tokenize_partial_with_options,
+    tokenize_with_options,
+    validate,
+    validate_partial,
+    validate_partial_with_options,
+    validate_with_options,
+    NumberKind,
+    Token,
+};
 #[cfg(feature = "write-floats")]
 pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptionsBuilder};
 #[cfg(feature = "write-integers")]
 pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
 #[cfg(feature = "write")]
+pub use lexical_core::{
+    write_many,
+    write_many_size,
+    write_many_size_with_options,
+    write_many_with_options,
+};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use lexical_core::{write_many_to_vec, write_many_to_vec_with_options};
+#[cfg(all(feature = "ieee-decimal", any(feature = "parse-floats", feature = "write-floats")))]
+pub use lexical_core::{Decimal128, Decimal64};
+#[cfg(all(feature = "half", any(feature = "parse-floats", feature = "write-floats")))]
+pub use lexical_core::{bf16, f16};
+#[cfg(feature = "write")]
 pub use lexical_core::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "parse")]
 pub use lexical_core::{FromLexical, FromLexicalWithOptions};
+#[cfg(any(feature = "parse-integers", feature = "write-integers"))]
+pub use lexical_core::{Int, Uint, I256, I512, U256, U512};
 #[cfg(feature = "write")]
 pub use lexical_core::{ToLexical, ToLexicalWithOptions};
 
 // HELPERS
 
-/// Get a vector as a slice, including the capacity.
+/// Append at most `size` bytes to the vector using the callback.
+///
+/// The callback writes to the uninitialized spare capacity of the vector,
+/// and returns the number of initialized bytes, which are then appended.
+/// Returns the number of written bytes.
 #[inline]
 #[cfg(feature = "write")]
-unsafe fn vector_as_slice<T>(buf: &mut Vec<T>) -> &mut [T] {
-    let first = buf.as_mut_ptr();
-    unsafe { core::slice::from_raw_parts_mut(first, buf.capacity()) }
+fn append_to_vec<Cb>(bytes: &mut Vec<u8>, size: usize, cb: Cb) -> usize
+where
+    Cb: FnOnce(&mut [MaybeUninit<u8>]) -> usize,
+{
+    bytes.reserve(size);
+    let count = cb(bytes.spare_capacity_mut());
+    // SAFETY: safe since the callback initialized the first `count` bytes
+    // of the spare capacity.
+    unsafe { bytes.set_len(bytes.len() + count) };
+    count
 }
 
 /// High-level conversion of a number to a decimal-encoded string.
@@ -333,10 +480,11 @@ unsafe fn vector_as_slice<T>(buf: &mut Vec<T>) -> &mut [T] {
 #[inline]
 #[cfg(feature = "write")]
 pub fn to_string<N: ToLexical>(n: N) -> String {
-    // SAFETY: safe since the buffer is of sufficient size.
+    let mut buf = Vec::<u8>::with_capacity(n.formatted_len());
+    let len = n.to_lexical_uninit(buf.spare_capacity_mut()).len();
+    // SAFETY: safe since the first `len` bytes have been initialized,
+    // and the written number is always valid UTF-8.
     unsafe {
-        let mut buf = Vec::<u8>::with_capacity(N::FORMATTED_SIZE_DECIMAL);
-        let len = lexical_core::write_unchecked(n, vector_as_slice(&mut buf)).len();
         buf.set_len(len);
         String::from_utf8_unchecked(buf)
     }
@@ -367,22 +515,139 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     n: N,
     options: &N::Options,
 ) -> String {
-    // Need to use the buffer_size hint to properly deal with float formatting options.
-    let size = N::Options::buffer_size::<N, FORMAT>(options);
-    // SAFETY: safe since the buffer is of sufficient size.
+    // Need to use the buffer size hint to properly deal with float formatting options.
+    let size = n.formatted_len_with_options::<FORMAT>(options);
+    let mut buf = Vec::<u8>::with_capacity(size);
+    let spare = buf.spare_capacity_mut();
+    let len = n.to_lexical_with_options_uninit::<FORMAT>(spare, options).len();
+    // SAFETY: safe since the first `len` bytes have been initialized,
+    // and the written number is always valid UTF-8.
     unsafe {
-        let mut buf = Vec::<u8>::with_capacity(size);
-        let len = lexical_core::write_with_options_unchecked::<_, FORMAT>(
-            n,
-            vector_as_slice(&mut buf),
-            options,
-        )
-        .len();
         buf.set_len(len);
         String::from_utf8_unchecked(buf)
     }
 }
 
+/// High-level conversion of a number to decimal-encoded bytes, appended to a vector.
+///
+/// Reserves enough space for the number, which is then written in place
+/// at the end of the vector. Returns the number of written bytes.
+///
+/// * `n`       - Number to convert to string.
+/// * `bytes`   - Vector to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut bytes = b"x=".to_vec();
+/// assert_eq!(lexical::write_to_vec(-12, &mut bytes), 3);
+/// assert_eq!(bytes, b"x=-12");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to_vec<N: ToLexical>(n: N, bytes: &mut Vec<u8>) -> usize {
+    append_to_vec(bytes, n.formatted_len(), |spare| n.to_lexical_uninit(spare).len())
+}
+
+/// High-level conversion of a number to bytes with custom writing options, appended to a vector.
+///
+/// Reserves enough space for the number, which is then written in place
+/// at the end of the vector. Returns the number of written bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `bytes`   - Vector to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let mut bytes = b"[".to_vec();
+/// lexical::write_to_vec_with_options::<_, FORMAT>(1.0, &mut bytes, &options);
+/// bytes.push(b',');
+/// lexical::write_to_vec_with_options::<_, FORMAT>(2.5, &mut bytes, &options);
+/// bytes.push(b']');
+/// assert_eq!(bytes, b"[1,2.5]");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to_vec_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &mut Vec<u8>,
+    options: &N::Options,
+) -> usize {
+    // Need to use the buffer size hint to properly deal with float formatting options.
+    let size = n.formatted_len_with_options::<FORMAT>(options);
+    append_to_vec(bytes, size, |spare| {
+        n.to_lexical_with_options_uninit::<FORMAT>(spare, options).len()
+    })
+}
+
+/// High-level conversion of a number to a decimal-encoded string, appended to a string.
+///
+/// Reserves enough space for the number, which is then written in place
+/// at the end of the string. Returns the number of written bytes.
+///
+/// * `n`       - Number to convert to string.
+/// * `string`  - String to append the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut string = String::from("total: ");
+/// lexical::write_to_string(1.5, &mut string);
+/// assert_eq!(string, "total: 1.5");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to_string<N: ToLexical>(n: N, string: &mut String) -> usize {
+    // SAFETY: safe since the written number is always valid UTF-8.
+    unsafe { write_to_vec(n, string.as_mut_vec()) }
+}
+
+/// High-level conversion of a number to a string with custom writing options, appended to a string.
+///
+/// Reserves enough space for the number, which is then written in place
+/// at the end of the string. Returns the number of written bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `string`  - String to append the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteIntegerOptions::new();
+/// let mut string = String::from("id=");
+/// lexical::write_to_string_with_options::<_, FORMAT>(42u64, &mut string, &options);
+/// assert_eq!(string, "id=42");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    string: &mut String,
+    options: &N::Options,
+) -> usize {
+    // SAFETY: safe since the written number is always valid UTF-8.
+    unsafe { write_to_vec_with_options::<N, FORMAT>(n, string.as_mut_vec(), options) }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
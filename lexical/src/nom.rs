@@ -0,0 +1,171 @@
+//! Number parsers for the `nom` parser combinator library.
+//!
+//! The parsers consume the longest prefix of the input that is a valid
+//! number, like [`parse_partial`], and return the remaining input. They
+//! support both `&str` and `&[u8]` inputs, and any number format. The
+//! parsers are for complete input: a number at the end of the input is
+//! always accepted.
+//!
+//! If no number could be parsed, the parser returns a recoverable
+//! [`Err::Error`], built from the input at the index of lexical's
+//! [`Error`], the [`ErrorKind`] given by [`error_kind`], and the lexical
+//! error itself, through [`FromExternalError`]. A number must consume
+//! at least one character, so input without any digits is an error,
+//! even if lexical's partial parsers would return a value.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-integers", feature = "parse-floats"))] {
+//! use lexical::nom::number;
+//! use nom::character::complete::char;
+//! use nom::sequence::separated_pair;
+//! use nom::IResult;
+//!
+//! fn point(input: &str) -> IResult<&str, (f64, i32)> {
+//!     separated_pair(number, char(','), number)(input)
+//! }
+//!
+//! assert_eq!(point("1.5,-3)"), Ok((")", (1.5, -3))));
+//! # }
+//! ```
+//!
+//! [`parse_partial`]: crate::parse_partial
+//! [`Error`]: crate::Error
+
+#![cfg(all(feature = "nom", feature = "parse"))]
+
+use ::nom::error::{ErrorKind, FromExternalError, ParseError};
+use ::nom::{AsBytes, Err, IResult, Slice};
+use core::ops::RangeFrom;
+use lexical_core::{Error, FromLexical, FromLexicalWithOptions};
+
+/// Parse a number with the standard format from the start of the input.
+///
+/// * `input`   - Input to parse the number from.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parse-integers")] {
+/// use lexical::nom::number;
+/// use nom::error::{Error, ErrorKind};
+/// use nom::Err;
+///
+/// assert_eq!(number::<u8, _, ()>(&b"12abc"[..]), Ok((&b"abc"[..], 12)));
+/// let error = Error::new("x1", ErrorKind::Digit);
+/// assert_eq!(number::<u8, _, _>("x1"), Err(Err::Error(error)));
+/// # }
+/// ```
+#[inline]
+pub fn number<N, I, E>(input: I) -> IResult<I, N, E>
+where
+    N: FromLexical,
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    let result = N::from_lexical_partial(input.as_bytes());
+    process_result(input, result)
+}
+
+/// Create a parser for a number with custom parsing options.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `options` - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the parser may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "parse-floats")] {
+/// use lexical::nom::number_with_options;
+/// use nom::IResult;
+///
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// const OPTIONS: lexical::ParseFloatOptions = lexical::parse_float_options::DECIMAL_COMMA;
+///
+/// fn float(input: &str) -> IResult<&str, f64> {
+///     number_with_options::<_, FORMAT, _, _>(&OPTIONS)(input)
+/// }
+///
+/// assert_eq!(float("1,5;"), Ok((";", 1.5)));
+/// # }
+/// ```
+#[inline]
+pub fn number_with_options<N, const FORMAT: u128, I, E>(
+    options: &N::Options,
+) -> impl Fn(I) -> IResult<I, N, E> + '_
+where
+    N: FromLexicalWithOptions,
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    move |input: I| {
+        let result = N::from_lexical_partial_with_options::<FORMAT>(input.as_bytes(), options);
+        process_result(input, result)
+    }
+}
+
+/// Get the nom error kind for a lexical error.
+///
+/// Numeric overflow and underflow are [`ErrorKind::TooLarge`], an empty
+/// input is [`ErrorKind::Eof`], errors in the structure of a float, such
+/// as a missing exponent or fraction, are [`ErrorKind::Float`], and any
+/// other invalid digit or sign is [`ErrorKind::Digit`]. Errors for an
+/// invalid number format or options are [`ErrorKind::Fail`].
+///
+/// * `error`   - Lexical error to convert.
+#[inline]
+pub fn error_kind(error: &Error) -> ErrorKind {
+    match error {
+        Error::Overflow(_) | Error::Underflow(_) => ErrorKind::TooLarge,
+        Error::Empty(_) => ErrorKind::Eof,
+        Error::EmptyMantissa(_)
+        | Error::EmptyExponent(_)
+        | Error::EmptyInteger(_)
+        | Error::EmptyFraction(_)
+        | Error::InvalidPositiveMantissaSign(_)
+        | Error::MissingMantissaSign(_)
+        | Error::InvalidExponent(_)
+        | Error::InvalidPositiveExponentSign(_)
+        | Error::MissingExponentSign(_)
+        | Error::ExponentWithoutFraction(_)
+        | Error::MissingExponent(_) => ErrorKind::Float,
+        Error::InvalidDigit(_)
+        | Error::InvalidLeadingZeros(_)
+        | Error::MissingSign(_)
+        | Error::InvalidPositiveSign(_)
+        | Error::InvalidNegativeSign(_)
+        | Error::InvalidZero(_) => ErrorKind::Digit,
+        _ => ErrorKind::Fail,
+    }
+}
+
+/// Convert the result of a partial parser to a nom result.
+#[inline]
+fn process_result<N, I, E>(input: I, result: lexical_core::Result<(N, usize)>) -> IResult<I, N, E>
+where
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    let bytes = input.as_bytes();
+    let result = match result {
+        // Lexical may return a value without consuming any digits.
+        Ok((_, 0)) if bytes.is_empty() => Err(Error::Empty(0)),
+        Ok((_, 0)) => Err(Error::InvalidDigit(0)),
+        result => result,
+    };
+    match result {
+        Ok((value, count)) => Ok((input.slice(count..), value)),
+        Err(error) => {
+            let index = error.index().map_or(0, |&index| index.min(bytes.len()));
+            let kind = error_kind(&error);
+            Err(Err::Error(E::from_external_error(input.slice(index..), kind, error)))
+        },
+    }
+}
@@ -0,0 +1,214 @@
+//! Parse numbers with lexical in generic code using `num-traits`.
+//!
+//! The [`Lexical`] wrapper implements [`Num`], so generic code using
+//! [`Num::from_str_radix`] parses the wrapped number with lexical,
+//! which is correctly rounded for floats. The [`FromLexicalRadix`]
+//! trait parses numbers in a radix only known at runtime.
+//!
+//! Radixes other than 10 require the `power-of-two` feature, for powers
+//! of two, or the `radix` feature, for all radixes from 2 to 36. Floats
+//! use the radix as the exponent base, and use `^` as the exponent
+//! character if the radix is larger than 14, since `e` is then a digit.
+//!
+//! This only bridges parsing. Lexical's internal `Integer` and `Float`
+//! traits are not implemented for types bounded by `num-traits`: they
+//! require the bit layout and fixed width of the primitive numbers, and
+//! a blanket implementation would conflict with the implementations for
+//! the primitives. User numeric types may instead implement
+//! [`FromLexical`] and [`FromLexicalWithOptions`] directly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parse-floats")] {
+//! use lexical::num_traits::Lexical;
+//! use num_traits::Num;
+//!
+//! fn parse<T: Num>(string: &str) -> Option<T> {
+//!     T::from_str_radix(string, 10).ok()
+//! }
+//!
+//! assert_eq!(parse::<Lexical<f64>>("2.2250738585072011e-308"), Some(Lexical(2.225073858507201e-308)));
+//! # }
+//! ```
+
+#![cfg(all(feature = "num-traits", feature = "parse"))]
+
+use ::num_traits::{Num, One, Zero};
+use core::{fmt, ops, str};
+#[cfg(feature = "parse-floats")]
+use lexical_core::parse_float_options;
+#[cfg(any(feature = "power-of-two", feature = "radix"))]
+use lexical_core::NumberFormatBuilder;
+#[cfg(feature = "parse-integers")]
+use lexical_core::ParseIntegerOptions;
+use lexical_core::{format::STANDARD, Error, FromLexical, FromLexicalWithOptions, Result};
+
+/// Parse a number from bytes in a runtime radix.
+pub trait FromLexicalRadix: Sized {
+    /// Checked parser for a number in a radix from bytes.
+    ///
+    /// This function only returns a value if the entire string is
+    /// successfully parsed. If the radix is not supported, this returns
+    /// [`Error::InvalidRadix`].
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `radix`   - Radix of the digits, from 2 to 36.
+    fn from_lexical_radix(bytes: &[u8], radix: u32) -> Result<Self>;
+}
+
+/// Parse a number in a runtime radix, with the given options.
+#[inline]
+fn parse_radix<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    radix: u32,
+    options: &N::Options,
+) -> Result<N> {
+    macro_rules! parse_radix {
+        (power_of_two: $($p:literal)* ; radix: $($r:literal)* ;) => {
+            match radix {
+                10 => N::from_lexical_with_options::<STANDARD>(bytes, options),
+                $(
+                    #[cfg(any(feature = "power-of-two", feature = "radix"))]
+                    $p => {
+                        const FORMAT: u128 = NumberFormatBuilder::from_radix($p);
+                        N::from_lexical_with_options::<FORMAT>(bytes, options)
+                    },
+                )*
+                $(
+                    #[cfg(feature = "radix")]
+                    $r => {
+                        const FORMAT: u128 = NumberFormatBuilder::from_radix($r);
+                        N::from_lexical_with_options::<FORMAT>(bytes, options)
+                    },
+                )*
+                _ => Err(Error::InvalidRadix),
+            }
+        };
+    }
+
+    parse_radix! {
+        power_of_two: 2 4 8 16 32 ;
+        radix: 3 5 6 7 9 11 12 13 14 15 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 33 34 35 36 ;
+    }
+}
+
+#[cfg(feature = "parse-integers")]
+macro_rules! integer_from_lexical_radix {
+    ($($t:ty)*) => ($(
+        impl FromLexicalRadix for $t {
+            #[inline]
+            fn from_lexical_radix(bytes: &[u8], radix: u32) -> Result<Self> {
+                parse_radix::<Self>(bytes, radix, &ParseIntegerOptions::new())
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "parse-integers")]
+integer_from_lexical_radix! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg(feature = "parse-floats")]
+macro_rules! float_from_lexical_radix {
+    ($($t:ty)*) => ($(
+        impl FromLexicalRadix for $t {
+            #[inline]
+            fn from_lexical_radix(bytes: &[u8], radix: u32) -> Result<Self> {
+                let options = if radix <= 14 {
+                    parse_float_options::STANDARD
+                } else {
+                    parse_float_options::CARAT_EXPONENT
+                };
+                parse_radix::<Self>(bytes, radix, &options)
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "parse-floats")]
+float_from_lexical_radix! { f32 f64 }
+
+/// Wrapper to parse numbers with lexical through the `num-traits` API.
+///
+/// Implements [`Num`], where [`Num::from_str_radix`] parses the number
+/// with [`FromLexicalRadix`], as well as [`Zero`], [`One`], the arithmetic
+/// operators, and [`FromStr`], which parses a decimal number.
+///
+/// [`FromStr`]: core::str::FromStr
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lexical<T>(pub T);
+
+impl<T: Num + FromLexicalRadix> Num for Lexical<T> {
+    type FromStrRadixErr = Error;
+
+    #[inline]
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self> {
+        T::from_lexical_radix(string.as_bytes(), radix).map(Lexical)
+    }
+}
+
+impl<T: Zero> Zero for Lexical<T> {
+    #[inline]
+    fn zero() -> Self {
+        Lexical(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One> One for Lexical<T> {
+    #[inline]
+    fn one() -> Self {
+        Lexical(T::one())
+    }
+}
+
+impl<T: FromLexical> str::FromStr for Lexical<T> {
+    type Err = Error;
+
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        T::from_lexical(string.as_bytes()).map(Lexical)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Lexical<T> {
+    #[inline]
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl<T: ops::Neg<Output = T>> ops::Neg for Lexical<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Lexical(-self.0)
+    }
+}
+
+macro_rules! wrapper_ops {
+    ($($trait:ident $method:ident ;)*) => ($(
+        impl<T: ops::$trait<Output = T>> ops::$trait for Lexical<T> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Lexical(ops::$trait::$method(self.0, rhs.0))
+            }
+        }
+    )*)
+}
+
+wrapper_ops! {
+    Add add ;
+    Sub sub ;
+    Mul mul ;
+    Div div ;
+    Rem rem ;
+}
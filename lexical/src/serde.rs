@@ -0,0 +1,371 @@
+//! Serialize and deserialize numbers as strings with custom formats.
+//!
+//! The functions in this module can be used with serde's field attributes,
+//! to (de)serialize numbers as strings with lexical's number formats and
+//! options, for example with digit separators, hexadecimal integers or
+//! decimal commas. This module itself uses the standard format, and the
+//! [`serde_module`] macro defines new modules from a `FORMAT` and options.
+//!
+//! Errors while deserializing are reported as custom serde errors,
+//! containing lexical's [`Error`] and the index at which it occurred.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "power-of-two"))] {
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "lexical::serde")]
+//!     count: u32,
+//!     #[serde(with = "lexical::serde::hex")]
+//!     mask: u32,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"count": "12", "mask": "FF"}"#).unwrap();
+//! assert_eq!((config.count, config.mask), (12, 255));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"count":"12","mask":"FF"}"#);
+//! # }
+//! ```
+//!
+//! [`Error`]: crate::Error
+
+#![cfg(feature = "serde")]
+
+#[cfg(feature = "write")]
+use crate::display::with_buffer;
+#[cfg(feature = "parse")]
+use ::serde::de;
+#[cfg(feature = "parse")]
+use core::fmt;
+#[cfg(feature = "parse")]
+use core::marker::PhantomData;
+#[cfg(feature = "format")]
+use core::num;
+#[cfg(feature = "write")]
+use core::str;
+#[cfg(any(feature = "power-of-two", feature = "format"))]
+use lexical_core::NumberFormatBuilder;
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+use lexical_core::{
+    parse_float_options,
+    write_float_options,
+    ParseFloatOptions,
+    WriteFloatOptions,
+};
+#[cfg(feature = "parse")]
+use lexical_core::{FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "write")]
+use lexical_core::{ToLexical, ToLexicalWithOptions};
+
+#[cfg(feature = "parse")]
+#[doc(no_inline)]
+pub use ::serde::Deserializer;
+#[cfg(feature = "write")]
+#[doc(no_inline)]
+pub use ::serde::Serializer;
+
+/// Serialize a number as a decimal string.
+///
+/// * `n`           - Number to serialize.
+/// * `serializer`  - Serializer to write the string to.
+#[inline]
+#[cfg(feature = "write")]
+pub fn serialize<N: ToLexical + Copy, S: Serializer>(
+    n: &N,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let n = *n;
+    with_buffer(n.formatted_len(), |buffer| {
+        let bytes = n.to_lexical(buffer);
+        // SAFETY: safe since the written number is always valid UTF-8.
+        serializer.serialize_str(unsafe { str::from_utf8_unchecked(bytes) })
+    })
+}
+
+/// Serialize a number as a string with custom writing options.
+///
+/// * `FORMAT`      - Packed struct containing the number format.
+/// * `n`           - Number to serialize.
+/// * `serializer`  - Serializer to write the string to.
+/// * `options`     - Options to specify number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "write")]
+pub fn serialize_with_options<N, const FORMAT: u128, S>(
+    n: &N,
+    serializer: S,
+    options: &N::Options,
+) -> Result<S::Ok, S::Error>
+where
+    N: ToLexicalWithOptions + Copy,
+    S: Serializer,
+{
+    let n = *n;
+    with_buffer(n.formatted_len_with_options::<FORMAT>(options), |buffer| {
+        let bytes = n.to_lexical_with_options::<FORMAT>(buffer, options);
+        // SAFETY: safe since the written number is always valid UTF-8.
+        serializer.serialize_str(unsafe { str::from_utf8_unchecked(bytes) })
+    })
+}
+
+/// Deserialize a number from a decimal string.
+///
+/// The entire string must be a valid number.
+///
+/// * `deserializer`    - Deserializer to read the string from.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn deserialize<'de, N, D>(deserializer: D) -> Result<N, D::Error>
+where
+    N: FromLexical,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(NumberVisitor {
+        parse: N::from_lexical,
+        _marker: PhantomData,
+    })
+}
+
+/// Deserialize a number from a string with custom parsing options.
+///
+/// The entire string must be a valid number.
+///
+/// * `FORMAT`          - Packed struct containing the number format.
+/// * `deserializer`    - Deserializer to read the string from.
+/// * `options`         - Options to specify number parsing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn deserialize_with_options<'de, N, const FORMAT: u128, D>(
+    deserializer: D,
+    options: &N::Options,
+) -> Result<N, D::Error>
+where
+    N: FromLexicalWithOptions,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(NumberVisitor {
+        parse: |bytes: &[u8]| N::from_lexical_with_options::<FORMAT>(bytes, options),
+        _marker: PhantomData,
+    })
+}
+
+/// Visitor to parse a number from a string or bytes.
+#[cfg(feature = "parse")]
+struct NumberVisitor<N, Cb> {
+    parse: Cb,
+    _marker: PhantomData<N>,
+}
+
+#[cfg(feature = "parse")]
+impl<'de, N, Cb> de::Visitor<'de> for NumberVisitor<N, Cb>
+where
+    Cb: FnOnce(&[u8]) -> lexical_core::Result<N>,
+{
+    type Value = N;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string containing a number")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<N, E> {
+        self.visit_bytes(value.as_bytes())
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<N, E> {
+        (self.parse)(value).map_err(E::custom)
+    }
+}
+
+/// Define a module to (de)serialize numbers as strings with a custom format.
+///
+/// The module contains `serialize` and `deserialize` functions, so it can
+/// be used with `#[serde(with = "...")]`. The number format is given by
+/// the `FORMAT` constant, and the optional `PARSE_OPTIONS` and
+/// `WRITE_OPTIONS` constants give the options: if they are not provided,
+/// the default options for each number type are used. If the options are
+/// provided, the module only supports the numbers using these options.
+/// Items from the parent module are in scope for the constants.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "write-floats", feature = "parse-floats"))] {
+/// use serde::{Deserialize, Serialize};
+///
+/// lexical::serde_module! {
+///     /// Floats with a `^` exponent character.
+///     pub mod carat {
+///         const FORMAT: u128 = lexical::format::STANDARD;
+///         const PARSE_OPTIONS: lexical::ParseFloatOptions =
+///             lexical::parse_float_options::CARAT_EXPONENT;
+///         const WRITE_OPTIONS: lexical::WriteFloatOptions =
+///             lexical::write_float_options::CARAT_EXPONENT;
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Value {
+///     #[serde(with = "carat")]
+///     x: f64,
+/// }
+///
+/// let value: Value = serde_json::from_str(r#"{"x": "1.5^3"}"#).unwrap();
+/// assert_eq!(value.x, 1500.0);
+/// assert_eq!(serde_json::to_string(&Value { x: 1e100 }).unwrap(), r#"{"x":"1.0^100"}"#);
+/// # }
+/// ```
+#[macro_export]
+#[cfg(all(feature = "serde", feature = "write", feature = "parse"))]
+macro_rules! serde_module {
+    (
+        $(#[$attr:meta])*
+        $vis:vis mod $name:ident {
+            const FORMAT: u128 = $format:expr;
+            $(const PARSE_OPTIONS: $parse_type:ty = $parse_options:expr;)?
+            $(const WRITE_OPTIONS: $write_type:ty = $write_options:expr;)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            const FORMAT: u128 = $format;
+
+            $crate::serde_module!(@serialize $($write_type = $write_options)?);
+            $crate::serde_module!(@deserialize $($parse_type = $parse_options)?);
+        }
+    };
+
+    (@serialize) => {
+        /// Serialize a number as a string.
+        #[inline]
+        pub fn serialize<N, S>(n: &N, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+        where
+            N: $crate::ToLexicalWithOptions + Copy,
+            S: $crate::serde::Serializer,
+        {
+            let options = ::core::default::Default::default();
+            $crate::serde::serialize_with_options::<N, FORMAT, S>(n, serializer, &options)
+        }
+    };
+
+    (@serialize $type:ty = $options:expr) => {
+        const WRITE_OPTIONS: $type = $options;
+
+        /// Serialize a number as a string.
+        #[inline]
+        pub fn serialize<N, S>(n: &N, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+        where
+            N: $crate::ToLexicalWithOptions<Options = $type> + Copy,
+            S: $crate::serde::Serializer,
+        {
+            $crate::serde::serialize_with_options::<N, FORMAT, S>(n, serializer, &WRITE_OPTIONS)
+        }
+    };
+
+    (@deserialize) => {
+        /// Deserialize a number from a string.
+        #[inline]
+        pub fn deserialize<'de, N, D>(deserializer: D) -> ::core::result::Result<N, D::Error>
+        where
+            N: $crate::FromLexicalWithOptions,
+            D: $crate::serde::Deserializer<'de>,
+        {
+            let options = ::core::default::Default::default();
+            $crate::serde::deserialize_with_options::<N, FORMAT, D>(deserializer, &options)
+        }
+    };
+
+    (@deserialize $type:ty = $options:expr) => {
+        const PARSE_OPTIONS: $type = $options;
+
+        /// Deserialize a number from a string.
+        #[inline]
+        pub fn deserialize<'de, N, D>(deserializer: D) -> ::core::result::Result<N, D::Error>
+        where
+            N: $crate::FromLexicalWithOptions<Options = $type>,
+            D: $crate::serde::Deserializer<'de>,
+        {
+            $crate::serde::deserialize_with_options::<N, FORMAT, D>(deserializer, &PARSE_OPTIONS)
+        }
+    };
+}
+
+#[cfg(all(feature = "write", feature = "parse"))]
+serde_module! {
+    /// (De)serialize numbers as binary strings.
+    ///
+    /// This uses the default options, so it is best suited for integers:
+    /// floats should use a custom module with a valid exponent character.
+    #[cfg(feature = "power-of-two")]
+    pub mod binary {
+        const FORMAT: u128 = NumberFormatBuilder::from_radix(2);
+    }
+}
+
+#[cfg(all(feature = "write", feature = "parse"))]
+serde_module! {
+    /// (De)serialize numbers as octal strings.
+    ///
+    /// This uses the default options, so it is best suited for integers:
+    /// floats should use a custom module with a valid exponent character.
+    #[cfg(feature = "power-of-two")]
+    pub mod octal {
+        const FORMAT: u128 = NumberFormatBuilder::from_radix(8);
+    }
+}
+
+#[cfg(all(feature = "write", feature = "parse"))]
+serde_module! {
+    /// (De)serialize numbers as hexadecimal strings.
+    ///
+    /// This uses the default options, so it is best suited for integers:
+    /// floats should use a custom module with a valid exponent character,
+    /// since `e` is a hexadecimal digit.
+    #[cfg(feature = "power-of-two")]
+    pub mod hex {
+        const FORMAT: u128 = NumberFormatBuilder::from_radix(16);
+    }
+}
+
+#[cfg(all(feature = "write", feature = "parse"))]
+serde_module! {
+    /// (De)serialize numbers as decimal strings, with `_` digit separators.
+    ///
+    /// Digit separators are allowed between any two digits when parsing,
+    /// such as `1_000_000`, and are never written.
+    #[cfg(feature = "format")]
+    pub mod underscores {
+        const FORMAT: u128 = NumberFormatBuilder::new()
+            .digit_separator(num::NonZeroU8::new(b'_'))
+            .internal_digit_separator(true)
+            .build();
+    }
+}
+
+#[cfg(all(feature = "write", feature = "parse"))]
+serde_module! {
+    /// (De)serialize floats as decimal strings, with a `,` decimal point.
+    #[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+    pub mod decimal_comma {
+        const FORMAT: u128 = lexical_core::format::STANDARD;
+        const PARSE_OPTIONS: ParseFloatOptions = parse_float_options::DECIMAL_COMMA;
+        const WRITE_OPTIONS: WriteFloatOptions = write_float_options::DECIMAL_COMMA;
+    }
+}
@@ -0,0 +1,174 @@
+//! Write numbers directly to byte and text sinks.
+
+#![cfg(all(feature = "write", feature = "std"))]
+
+use crate::display::with_buffer;
+use core::{fmt, str};
+use lexical_core::{ToLexical, ToLexicalWithOptions};
+use std::io;
+
+/// High-level conversion of a number to decimal-encoded bytes, written to an [`io::Write`].
+///
+/// Writes the number into a stack buffer, which is then written to the
+/// sink in a single call to [`write_all`]. Returns the number of written
+/// bytes.
+///
+/// * `n`       - Number to convert to string.
+/// * `writer`  - Sink to write the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut bytes = Vec::new();
+/// assert_eq!(lexical::write_to_io(-12, &mut bytes).unwrap(), 3);
+/// assert_eq!(bytes, b"-12");
+/// # }
+/// ```
+///
+/// [`write_all`]: io::Write::write_all
+#[inline]
+pub fn write_to_io<N: ToLexical, W: io::Write + ?Sized>(n: N, writer: &mut W) -> io::Result<usize> {
+    with_buffer(n.formatted_len(), |buffer| {
+        let bytes = n.to_lexical(buffer);
+        writer.write_all(bytes)?;
+        Ok(bytes.len())
+    })
+}
+
+/// High-level conversion of a number to bytes with custom writing options, written to an [`io::Write`].
+///
+/// Writes the number into a stack buffer, which is then written to the
+/// sink in a single call to [`write_all`]. Returns the number of written
+/// bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `writer`  - Sink to write the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .trim_floats(true)
+///     .build()
+///     .unwrap();
+/// let mut bytes = Vec::new();
+/// lexical::write_to_io_with_options::<_, FORMAT, _>(2.0f64, &mut bytes, &options).unwrap();
+/// assert_eq!(bytes, b"2");
+/// # }
+/// ```
+///
+/// [`write_all`]: io::Write::write_all
+#[inline]
+pub fn write_to_io_with_options<N, const FORMAT: u128, W>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> io::Result<usize>
+where
+    N: ToLexicalWithOptions,
+    W: io::Write + ?Sized,
+{
+    let size = n.formatted_len_with_options::<FORMAT>(options);
+    with_buffer(size, |buffer| {
+        let bytes = n.to_lexical_with_options::<FORMAT>(buffer, options);
+        writer.write_all(bytes)?;
+        Ok(bytes.len())
+    })
+}
+
+/// High-level conversion of a number to a decimal string, written to a [`fmt::Write`].
+///
+/// Writes the number into a stack buffer, which is then written to the
+/// sink in a single call to [`write_str`]. Returns the number of written
+/// bytes.
+///
+/// * `n`       - Number to convert to string.
+/// * `writer`  - Sink to write the number to.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// let mut string = String::from("total: ");
+/// lexical::write_to_fmt(1.5, &mut string).unwrap();
+/// assert_eq!(string, "total: 1.5");
+/// # }
+/// ```
+///
+/// [`write_str`]: fmt::Write::write_str
+#[inline]
+pub fn write_to_fmt<N: ToLexical, W: fmt::Write + ?Sized>(
+    n: N,
+    writer: &mut W,
+) -> Result<usize, fmt::Error> {
+    with_buffer(n.formatted_len(), |buffer| write_str(n.to_lexical(buffer), writer))
+}
+
+/// High-level conversion of a number to a string with custom writing options, written to a [`fmt::Write`].
+///
+/// Writes the number into a stack buffer, which is then written to the
+/// sink in a single call to [`write_str`]. Returns the number of written
+/// bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to convert to string.
+/// * `writer`  - Sink to write the number to.
+/// * `options` - Options to specify number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::WriteFloatOptions::builder()
+///     .decimal_point(b',')
+///     .build()
+///     .unwrap();
+/// let mut string = String::new();
+/// lexical::write_to_fmt_with_options::<_, FORMAT, _>(1.5f64, &mut string, &options).unwrap();
+/// assert_eq!(string, "1,5");
+/// # }
+/// ```
+///
+/// [`write_str`]: fmt::Write::write_str
+#[inline]
+pub fn write_to_fmt_with_options<N, const FORMAT: u128, W>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> Result<usize, fmt::Error>
+where
+    N: ToLexicalWithOptions,
+    W: fmt::Write + ?Sized,
+{
+    let size = n.formatted_len_with_options::<FORMAT>(options);
+    with_buffer(size, |buffer| {
+        write_str(n.to_lexical_with_options::<FORMAT>(buffer, options), writer)
+    })
+}
+
+/// Write the serialized number to the text sink.
+#[inline]
+fn write_str<W: fmt::Write + ?Sized>(bytes: &[u8], writer: &mut W) -> Result<usize, fmt::Error> {
+    // SAFETY: safe since the written number is always valid UTF-8.
+    let string = unsafe { str::from_utf8_unchecked(bytes) };
+    writer.write_str(string)?;
+    Ok(bytes.len())
+}
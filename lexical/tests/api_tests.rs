@@ -14,6 +14,123 @@ fn float_to_string_test() {
     let options = lexical::WriteFloatOptions::new();
     const FORMAT: u128 = lexical::format::STANDARD;
     assert_eq!(lexical::to_string_with_options::<_, FORMAT>(12345.0f32, &options), "12345.0");
+
+    // Options requiring a larger buffer than any formatted size.
+    let options = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(300))
+        .build()
+        .unwrap();
+    let string = lexical::to_string_with_options::<_, FORMAT>(1.5f64, &options);
+    assert_eq!(string.len(), 301);
+    assert!(string.starts_with("1.5000"));
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_to_vec_test() {
+    let mut bytes = Vec::new();
+    assert_eq!(lexical::write_to_vec(12345u32, &mut bytes), 5);
+    assert_eq!(lexical::write_to_vec(-6i8, &mut bytes), 2);
+    assert_eq!(bytes, b"12345-6");
+
+    let mut string = String::from("a");
+    assert_eq!(lexical::write_to_string(u128::MAX, &mut string), 39);
+    assert_eq!(string, "a340282366920938463463374607431768211455");
+
+    let options = lexical::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    assert_eq!(lexical::write_to_vec_with_options::<_, FORMAT>(7u8, &mut bytes, &options), 1);
+    assert_eq!(bytes, b"12345-67");
+    assert_eq!(lexical::write_to_string_with_options::<_, FORMAT>(0i64, &mut string, &options), 1);
+    assert_eq!(string, "a3402823669209384634633746074317682114550");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_to_vec_test() {
+    let mut bytes = b"[".to_vec();
+    assert_eq!(lexical::write_to_vec(1.5f64, &mut bytes), 3);
+    assert_eq!(bytes, b"[1.5");
+
+    let mut string = String::new();
+    assert_eq!(lexical::write_to_string(-0.25f32, &mut string), 5);
+    assert_eq!(string, "-0.25");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(300))
+        .build()
+        .unwrap();
+    assert_eq!(
+        lexical::write_to_string_with_options::<_, FORMAT>(1.5f64, &mut string, &options),
+        301
+    );
+    assert_eq!(string.len(), 306);
+    assert!(string.starts_with("-0.251.5000"));
+    assert_eq!(lexical::write_to_vec_with_options::<_, FORMAT>(2.0f64, &mut bytes, &options), 301);
+    assert_eq!(bytes.len(), 305);
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn integer_write_to_io_test() {
+    let mut bytes = b"x=".to_vec();
+    assert_eq!(lexical::write_to_io(-12i32, &mut bytes).unwrap(), 3);
+    assert_eq!(bytes, b"x=-12");
+
+    let mut cursor = std::io::Cursor::new([0u8; 4]);
+    assert_eq!(lexical::write_to_io(255u8, &mut cursor).unwrap(), 3);
+    assert!(lexical::write_to_io(255u8, &mut cursor).is_err());
+
+    let mut string = String::from("x=");
+    assert_eq!(lexical::write_to_fmt(u128::MAX, &mut string).unwrap(), 39);
+    assert_eq!(string, "x=340282366920938463463374607431768211455");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteIntegerOptions::new();
+    let writer: &mut dyn std::io::Write = &mut bytes;
+    assert_eq!(
+        lexical::write_to_io_with_options::<_, FORMAT, _>(7u64, writer, &options).unwrap(),
+        1
+    );
+    assert_eq!(bytes, b"x=-127");
+    let writer: &mut dyn core::fmt::Write = &mut string;
+    assert_eq!(
+        lexical::write_to_fmt_with_options::<_, FORMAT, _>(-7i64, writer, &options).unwrap(),
+        2
+    );
+    assert_eq!(string, "x=340282366920938463463374607431768211455-7");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "std"))]
+fn float_write_to_io_test() {
+    let mut bytes = Vec::new();
+    assert_eq!(lexical::write_to_io(1.5f64, &mut bytes).unwrap(), 3);
+    assert_eq!(bytes, b"1.5");
+
+    let mut string = String::new();
+    assert_eq!(lexical::write_to_fmt(-0.25f32, &mut string).unwrap(), 5);
+    assert_eq!(string, "-0.25");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(300))
+        .build()
+        .unwrap();
+    assert_eq!(
+        lexical::write_to_io_with_options::<_, FORMAT, _>(2.0f64, &mut bytes, &options).unwrap(),
+        301
+    );
+    assert_eq!(bytes.len(), 304);
+    assert!(bytes.starts_with(b"1.52.000"));
+
+    let options = lexical::WriteFloatOptions::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(
+        lexical::write_to_fmt_with_options::<_, FORMAT, _>(1.5f64, &mut string, &options).unwrap(),
+        3
+    );
+    assert_eq!(string, "-0.251,5");
 }
 
 #[test]
@@ -45,3 +162,65 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "std"))]
+fn bigint_test() {
+    let value = lexical::Bigint::from(7u32).pow(5000);
+    let string = lexical::to_string(&value);
+    assert_eq!(string.len(), 4226);
+    assert_eq!(lexical::parse::<lexical::Bigint, _>(&string), Ok(value.clone()));
+
+    let mut bytes = b"x=".to_vec();
+    assert_eq!(lexical::write_to_vec(&value, &mut bytes), 4226);
+    assert_eq!(&bytes[2..], string.as_bytes());
+
+    let mut io = Vec::new();
+    assert_eq!(lexical::write_to_io(&value, &mut io).unwrap(), 4226);
+    assert_eq!(io, string.as_bytes());
+
+    let options = lexical::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    assert_eq!(
+        lexical::to_string_with_options::<_, FORMAT>(&-value, &options),
+        format!("-{}", string)
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+fn decimal_test() {
+    let value = lexical::parse::<lexical::Decimal, _>("-1234.500").unwrap();
+    assert_eq!(value, lexical::Decimal::new(-1234500, 3));
+    assert_eq!(lexical::to_string(value), "-1234.500");
+
+    let options = lexical::WriteFloatOptions::builder()
+        .group_size(core::num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    assert_eq!(lexical::to_string_with_options::<_, FORMAT>(value, &options), "-1,234.500");
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "ieee-decimal"))]
+fn ieee_decimal_test() {
+    for &string in ["1.50", "-0.00", "1.2e3", "9.999999999999999e384", "NaN", "-inf"].iter() {
+        let value = lexical::parse::<lexical::Decimal64, _>(string).unwrap();
+        assert_eq!(lexical::to_string(value), string);
+    }
+    let value = lexical::parse::<lexical::Decimal128, _>("0.1").unwrap();
+    assert_eq!(value.to_bits(), 0x303E0000000000000000000000000001);
+    assert_eq!(lexical::to_string(value), "0.1");
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn rational_test() {
+    let value = lexical::parse::<lexical::Rational, _>("-0.125").unwrap();
+    assert_eq!(value.numerator().to_i128(), Some(-125));
+    assert_eq!(value.denominator().to_i128(), Some(1000));
+
+    let (value, count) = lexical::parse_partial::<lexical::Rational, _>("1.50e2 units").unwrap();
+    assert_eq!((value.numerator().to_i128(), value.scale(), count), (Some(150), 0, 6));
+}
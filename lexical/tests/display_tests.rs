@@ -0,0 +1,144 @@
+#[cfg(feature = "write")]
+use lexical::Fmt;
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_display_test() {
+    assert_eq!(format!("{}", Fmt::new(12345u32)), "12345");
+    assert_eq!(format!("{}", Fmt::new(-12345i32)), "-12345");
+    assert_eq!(format!("{}", Fmt::new(i128::MIN)), i128::MIN.to_string());
+    assert_eq!(format!("{:+}", Fmt::new(5u8)), "+5");
+    assert_eq!(format!("{:>6}", Fmt::new(-42i64)), "   -42");
+    assert_eq!(format!("{:<6}|", Fmt::new(-42i64)), "-42   |");
+    assert_eq!(format!("{:*^7}", Fmt::new(-42i64)), "**-42**");
+    assert_eq!(format!("{:06}", Fmt::new(-42i64)), "-00042");
+    assert_eq!(format!("{:+06}", Fmt::new(42i64)), "+00042");
+    // Precision is ignored for integers.
+    assert_eq!(format!("{:.3}", Fmt::new(42i64)), "42");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteIntegerOptions::new();
+    assert_eq!(format!("{:4}", Fmt::<_, FORMAT>::with_options(7u16, options)), "   7");
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "power-of-two"))]
+fn integer_hex_test() {
+    for &value in [0i32, 1, 255, -1, i32::MIN, i32::MAX].iter() {
+        assert_eq!(format!("{:x}", Fmt::new(value)), format!("{:x}", value));
+        assert_eq!(format!("{:X}", Fmt::new(value)), format!("{:X}", value));
+        assert_eq!(format!("{:#010x}", Fmt::new(value)), format!("{:#010x}", value));
+    }
+    assert_eq!(format!("{:x}", Fmt::new(u128::MAX)), format!("{:x}", u128::MAX));
+    assert_eq!(format!("{:>4X}", Fmt::new(-1i8)), "  FF");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_display_test() {
+    assert_eq!(format!("{}", Fmt::new(1.5f64)), "1.5");
+    assert_eq!(format!("{}", Fmt::new(-1.5f32)), "-1.5");
+    assert_eq!(format!("{}", Fmt::new(1e300f64)), "1.0e300");
+    assert_eq!(format!("{:>8}", Fmt::new(-1.5f64)), "    -1.5");
+    assert_eq!(format!("{:+}", Fmt::new(1.5f64)), "+1.5");
+    assert_eq!(format!("{:08}", Fmt::new(-1.5f64)), "-00001.5");
+    assert_eq!(format!("{:^9}", Fmt::new(f64::NAN)), "   NaN   ");
+    assert_eq!(format!("{}", Fmt::new(f64::NEG_INFINITY)), "-inf");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_precision_test() {
+    let values = [
+        0.0f64,
+        1.0,
+        1.5,
+        2.5,
+        0.125,
+        4.14159,
+        9.99,
+        99.95,
+        0.0006,
+        1234.5678,
+        1e-10,
+        1e21,
+        123456789.0,
+        0.35,
+        0.05,
+        2.675,
+        1.005,
+        0.375,
+        0.45,
+    ];
+    for &value in values.iter() {
+        for precision in 0..6 {
+            assert_eq!(
+                format!("{:.*}", precision, Fmt::new(value)),
+                format!("{:.*}", precision, value),
+                "{} with precision {}",
+                value,
+                precision
+            );
+        }
+    }
+    assert_eq!(format!("{:+10.3}", Fmt::new(-2.5f32)), "    -2.500");
+    assert_eq!(format!("{:010.1}", Fmt::new(-2.25f32)), "-0000002.2");
+    assert_eq!(format!("{:.2}", Fmt::new(f64::INFINITY)), "inf");
+    // Digits past the shortest representation are zeros.
+    assert_eq!(format!("{:.0}", Fmt::new(1e300f64)), format!("1{}", "0".repeat(300)));
+    assert_eq!(format!("{:.20}", Fmt::new(0.1f64)), "0.10000000000000000000");
+    // Halfway digits are rounded like the exact value.
+    assert_eq!(format!("{:.1}", Fmt::new(0.35f64)), "0.3");
+    assert_eq!(format!("{:.1}", Fmt::new(-0.35f64)), "-0.3");
+    assert_eq!(format!("{:.1}", Fmt::new(0.35f32)), format!("{:.1}", 0.35f32));
+    assert_eq!(format!("{:.0}", Fmt::new(0.5f64)), "0");
+    assert_eq!(format!("{:.0}", Fmt::new(1.5f64)), "2");
+
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteFloatOptions::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(format!("{:.3}", Fmt::<_, FORMAT>::with_options(1.5f64, options)), "1,500");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_exponent_test() {
+    let values = [
+        0.0f64,
+        1.0,
+        1.5,
+        2.5,
+        0.125,
+        4.14159,
+        9.99,
+        99.95,
+        0.0006,
+        1234.5678,
+        1e-10,
+        1e21,
+        123456789.0,
+        0.35,
+        0.05,
+        2.675,
+        1.005,
+        0.375,
+        0.45,
+    ];
+    for &value in values.iter() {
+        assert_eq!(format!("{:e}", Fmt::new(value)), format!("{:e}", value));
+        for precision in 0..6 {
+            assert_eq!(
+                format!("{:.*e}", precision, Fmt::new(value)),
+                format!("{:.*e}", precision, value),
+                "{} with precision {}",
+                value,
+                precision
+            );
+        }
+    }
+    assert_eq!(format!("{:>12e}", Fmt::new(-1234.5f32)), "   -1.2345e3");
+    assert_eq!(format!("{:+e}", Fmt::new(0.5f64)), "+5e-1");
+    // Digits past the shortest representation are zeros.
+    assert_eq!(format!("{:.1e}", Fmt::new(5e-324f64)), "5.0e-324");
+    // Halfway digits are rounded like the exact value.
+    assert_eq!(format!("{:.0e}", Fmt::new(0.35f64)), "3e-1");
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "nom")]
+
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+use lexical::nom::number;
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+use nom::error::{Error, ErrorKind};
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+use nom::Err;
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn integer_test() {
+    assert_eq!(number::<i32, _, Error<_>>("-12, 3"), Ok((", 3", -12)));
+    assert_eq!(number::<u8, _, Error<_>>(&b"255"[..]), Ok((&b""[..], 255)));
+    assert_eq!(number::<u8, _, Error<_>>("1a"), Ok(("a", 1)));
+
+    let error = number::<u8, _, Error<_>>("256 ");
+    assert_eq!(error, Err(Err::Error(Error::new("6 ", ErrorKind::TooLarge))));
+    let error = number::<u8, _, Error<_>>("-1");
+    assert_eq!(error, Err(Err::Error(Error::new("-1", ErrorKind::Digit))));
+    let error = number::<u8, _, Error<_>>("x1");
+    assert_eq!(error, Err(Err::Error(Error::new("x1", ErrorKind::Digit))));
+    let error = number::<u8, _, Error<_>>(&b""[..]);
+    assert_eq!(error, Err(Err::Error(Error::new(&b""[..], ErrorKind::Eof))));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_test() {
+    assert_eq!(number::<f64, _, Error<_>>("1.5e3]"), Ok(("]", 1500.0)));
+    assert_eq!(number::<f32, _, Error<_>>(&b"-0.25x"[..]), Ok((&b"x"[..], -0.25)));
+
+    let error = number::<f64, _, Error<_>>("1ex");
+    assert_eq!(error, Err(Err::Error(Error::new("x", ErrorKind::Float))));
+    let error = number::<f64, _, Error<_>>("-.");
+    assert_eq!(error, Err(Err::Error(Error::new("", ErrorKind::Float))));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats"))]
+fn combinator_test() {
+    use nom::bytes::complete::tag;
+    use nom::multi::separated_list1;
+    use nom::sequence::{delimited, separated_pair};
+    use nom::IResult;
+
+    fn pairs(input: &[u8]) -> IResult<&[u8], Vec<(u16, f32)>> {
+        let pair = separated_pair(number, tag("="), number);
+        delimited(tag("{"), separated_list1(tag(";"), pair), tag("}"))(input)
+    }
+
+    let result = pairs(b"{1=0.5;20=-2}rest");
+    assert_eq!(result, Ok((&b"rest"[..], vec![(1, 0.5), (20, -2.0)])));
+    assert!(pairs(b"{1=0.5;x=2}").is_err());
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn external_error_test() {
+    use nom::error::{FromExternalError, ParseError};
+
+    #[derive(Debug, PartialEq)]
+    struct CustomError(Option<lexical::Error>);
+
+    impl<I> ParseError<I> for CustomError {
+        fn from_error_kind(_: I, _: ErrorKind) -> Self {
+            CustomError(None)
+        }
+
+        fn append(_: I, _: ErrorKind, other: Self) -> Self {
+            other
+        }
+    }
+
+    impl<I> FromExternalError<I, lexical::Error> for CustomError {
+        fn from_external_error(_: I, _: ErrorKind, error: lexical::Error) -> Self {
+            CustomError(Some(error))
+        }
+    }
+
+    let error = number::<i8, _, CustomError>("200");
+    assert_eq!(error, Err(Err::Error(CustomError(Some(lexical::Error::Overflow(2))))));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "parse-floats", feature = "format"))]
+fn options_test() {
+    use lexical::nom::number_with_options;
+
+    const FORMAT: u128 = lexical::NumberFormatBuilder::new()
+        .digit_separator(core::num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build();
+    let options = lexical::ParseIntegerOptions::new();
+    let parser = number_with_options::<u32, FORMAT, &str, Error<_>>(&options);
+    assert_eq!(parser("1_000 EUR"), Ok((" EUR", 1000)));
+
+    const STANDARD: u128 = lexical::format::STANDARD;
+    let options = lexical::ParseFloatOptions::builder().decimal_point(b',').build().unwrap();
+    let parser = number_with_options::<f64, STANDARD, &[u8], Error<_>>(&options);
+    assert_eq!(parser(b"2,25;"), Ok((&b";"[..], 2.25)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "power-of-two"))]
+fn radix_test() {
+    use lexical::nom::number_with_options;
+
+    const HEX: u128 = lexical::NumberFormatBuilder::from_radix(16);
+    let options = lexical::ParseIntegerOptions::new();
+    let parser = number_with_options::<u32, HEX, _, Error<_>>(&options);
+    assert_eq!(parser("FFg"), Ok(("g", 255)));
+    assert_eq!(parser("g"), Err(Err::Error(Error::new("g", ErrorKind::Digit))));
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "num-traits")]
+
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+use lexical::num_traits::{FromLexicalRadix, Lexical};
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+use num_traits::Num;
+
+#[cfg(any(feature = "parse-integers", feature = "parse-floats"))]
+fn from_str_radix<T: Num>(string: &str, radix: u32) -> Result<T, T::FromStrRadixErr> {
+    T::from_str_radix(string, radix)
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn integer_test() {
+    assert_eq!(from_str_radix::<Lexical<i32>>("-27", 10), Ok(Lexical(-27)));
+    assert_eq!(from_str_radix::<Lexical<u8>>("+255", 10), Ok(Lexical(255)));
+    assert_eq!(from_str_radix::<Lexical<u8>>("256", 10), Err(lexical::Error::Overflow(2)));
+    assert_eq!(from_str_radix::<Lexical<u8>>("1a", 10), Err(lexical::Error::InvalidDigit(1)));
+    assert_eq!(from_str_radix::<Lexical<u8>>("1", 37), Err(lexical::Error::InvalidRadix));
+    assert_eq!(u64::from_lexical_radix(b"12345", 10), Ok(12345));
+    assert_eq!("42".parse::<Lexical<i64>>(), Ok(Lexical(42)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_test() {
+    assert_eq!(from_str_radix::<Lexical<f64>>("1.5e3", 10), Ok(Lexical(1500.0)));
+    assert_eq!(from_str_radix::<Lexical<f32>>("-0.1", 10), Ok(Lexical(-0.1)));
+    // The halfway point between zero and the smallest subnormal float.
+    let halfway = "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+    let string = format!("{}e-324", halfway);
+    assert_eq!(from_str_radix::<Lexical<f64>>(&string, 10), Ok(Lexical(0.0)));
+    let string = format!("{}1e-324", halfway);
+    assert_eq!(from_str_radix::<Lexical<f64>>(&string, 10), Ok(Lexical(5e-324)));
+    assert_eq!(f64::from_lexical_radix(b"1e", 10), Err(lexical::Error::EmptyExponent(2)));
+    assert_eq!("1.5".parse::<Lexical<f64>>(), Ok(Lexical(1.5)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "power-of-two"))]
+fn power_of_two_test() {
+    assert_eq!(from_str_radix::<Lexical<u32>>("ff", 16), Ok(Lexical(255)));
+    assert_eq!(from_str_radix::<Lexical<i8>>("-1000", 2), Ok(Lexical(-8)));
+    assert_eq!(from_str_radix::<Lexical<u64>>("DEADBEEFCAFE", 16), Ok(Lexical(0xDEADBEEFCAFE)));
+    assert_eq!(from_str_radix::<Lexical<u32>>("vv", 32), Ok(Lexical(1023)));
+    assert_eq!(from_str_radix::<Lexical<u32>>("2", 2), Err(lexical::Error::InvalidDigit(0)));
+    let result = from_str_radix::<Lexical<u32>>("12", 3);
+    if cfg!(feature = "radix") {
+        assert_eq!(result, Ok(Lexical(5)));
+    } else {
+        assert_eq!(result, Err(lexical::Error::InvalidRadix));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "power-of-two"))]
+fn float_power_of_two_test() {
+    assert_eq!(from_str_radix::<Lexical<f64>>("1.1e10", 2), Ok(Lexical(6.0)));
+    assert_eq!(from_str_radix::<Lexical<f64>>("A.8^1", 16), Ok(Lexical(168.0)));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "radix"))]
+fn float_radix_test() {
+    assert_eq!(from_str_radix::<Lexical<f64>>("12.1", 3), Ok(Lexical(5.0 + 1.0 / 3.0)));
+    assert_eq!(from_str_radix::<Lexical<f32>>("z.i", 36), Ok(Lexical(35.5)));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn wrapper_test() {
+    use num_traits::{One, Zero};
+
+    let x = Lexical(7i32);
+    let y = Lexical(2i32);
+    assert_eq!(x + y, Lexical(9));
+    assert_eq!(x - y, Lexical(5));
+    assert_eq!(x * y, Lexical(14));
+    assert_eq!(x / y, Lexical(3));
+    assert_eq!(x % y, Lexical(1));
+    assert_eq!(-x, Lexical(-7));
+    assert!(Lexical::<i32>::zero().is_zero());
+    assert_eq!(Lexical::<i32>::one(), Lexical(1));
+    assert_eq!(format!("{:>4}", x), "   7");
+}
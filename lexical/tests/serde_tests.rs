@@ -0,0 +1,174 @@
+#![cfg(feature = "serde")]
+
+#[cfg(any(
+    all(feature = "write-integers", feature = "parse-integers"),
+    all(feature = "write-floats", feature = "parse-floats")
+))]
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Standard {
+    #[serde(with = "lexical::serde")]
+    value: i32,
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers"))]
+fn standard_test() {
+    let value: Standard = serde_json::from_str(r#"{"value": "-12"}"#).unwrap();
+    assert_eq!(
+        value,
+        Standard {
+            value: -12,
+        }
+    );
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"-12"}"#);
+
+    // Only strings are accepted, and errors contain the index.
+    let error = serde_json::from_str::<Standard>(r#"{"value": 12}"#).unwrap_err();
+    assert!(error.to_string().contains("expected a string containing a number"));
+    let error = serde_json::from_str::<Standard>(r#"{"value": "1x2"}"#).unwrap_err();
+    assert!(error.to_string().contains("'invalid digit found' at index 1"));
+    let error = serde_json::from_str::<Standard>(r#"{"value": "3000000000"}"#).unwrap_err();
+    assert!(error.to_string().contains("'numeric overflow occurred' at index"));
+}
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "power-of-two"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Radix {
+    #[serde(with = "lexical::serde::binary")]
+    binary: u8,
+    #[serde(with = "lexical::serde::octal")]
+    octal: u16,
+    #[serde(with = "lexical::serde::hex")]
+    hex: i64,
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "power-of-two"))]
+fn radix_test() {
+    let json = r#"{"binary": "101", "octal": "777", "hex": "-deadbeefcafe"}"#;
+    let value: Radix = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        value,
+        Radix {
+            binary: 5,
+            octal: 511,
+            hex: -0xDEADBEEFCAFE,
+        }
+    );
+    let json = r#"{"binary":"101","octal":"777","hex":"-DEADBEEFCAFE"}"#;
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+
+    let error = serde_json::from_str::<Radix>(r#"{"binary": "102"}"#).unwrap_err();
+    assert!(error.to_string().contains("'invalid digit found' at index 2"));
+}
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Separators {
+    #[serde(with = "lexical::serde::underscores")]
+    value: u64,
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+fn underscores_test() {
+    let value: Separators = serde_json::from_str(r#"{"value": "1_000_000"}"#).unwrap();
+    assert_eq!(
+        value,
+        Separators {
+            value: 1000000,
+        }
+    );
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"1000000"}"#);
+
+    let error = serde_json::from_str::<Separators>(r#"{"value": "_1"}"#).unwrap_err();
+    assert!(error.to_string().contains("at index 0"));
+}
+
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DecimalComma {
+    #[serde(with = "lexical::serde::decimal_comma")]
+    value: f64,
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats"))]
+fn decimal_comma_test() {
+    let value: DecimalComma = serde_json::from_str(r#"{"value": "1,5"}"#).unwrap();
+    assert_eq!(
+        value,
+        DecimalComma {
+            value: 1.5,
+        }
+    );
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"1,5"}"#);
+
+    let error = serde_json::from_str::<DecimalComma>(r#"{"value": "1.5"}"#).unwrap_err();
+    assert!(error.to_string().contains("at index 1"));
+}
+
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "power-of-two"))]
+lexical::serde_module! {
+    pub mod hex_float {
+        const FORMAT: u128 = lexical::NumberFormatBuilder::from_radix(16);
+        const PARSE_OPTIONS: lexical::ParseFloatOptions = lexical::parse_float_options::HEX_FLOAT;
+        const WRITE_OPTIONS: lexical::WriteFloatOptions = lexical::write_float_options::HEX_FLOAT;
+    }
+}
+
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "power-of-two"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct HexFloat {
+    #[serde(with = "hex_float")]
+    value: f32,
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "parse-floats", feature = "power-of-two"))]
+fn serde_module_test() {
+    let value: HexFloat = serde_json::from_str(r#"{"value": "A.8p1"}"#).unwrap();
+    assert_eq!(
+        value,
+        HexFloat {
+            value: 168.0,
+        }
+    );
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"A8.0"}"#);
+}
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+const APOSTROPHE: u128 = lexical::NumberFormatBuilder::new()
+    .digit_separator(core::num::NonZeroU8::new(b'\''))
+    .internal_digit_separator(true)
+    .build();
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+lexical::serde_module! {
+    mod apostrophes {
+        const FORMAT: u128 = APOSTROPHE;
+    }
+}
+
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Apostrophes {
+    #[serde(with = "apostrophes")]
+    value: u32,
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "parse-integers", feature = "format"))]
+fn serde_module_default_options_test() {
+    let value: Apostrophes = serde_json::from_str(r#"{"value": "1'234"}"#).unwrap();
+    assert_eq!(
+        value,
+        Apostrophes {
+            value: 1234,
+        }
+    );
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"1234"}"#);
+}